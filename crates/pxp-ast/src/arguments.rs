@@ -2,24 +2,28 @@ use std::slice::Iter;
 
 use crate::comments::CommentGroup;
 use crate::identifiers::SimpleIdentifier;
-use crate::node::Node;
 use crate::Expression;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionalArgument {
     pub comments: CommentGroup,
     pub ellipsis: Option<Span>, // `...`
     pub value: Expression,      // `$var`
 }
 
-impl Node for PositionalArgument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.value]
+impl Spanned for PositionalArgument {
+    fn span(&self) -> Span {
+        self
+            .ellipsis
+            .unwrap_or_else(|| self.value.span()).join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedArgument {
     pub comments: CommentGroup,
     pub name: SimpleIdentifier, // `foo`
@@ -28,30 +32,30 @@ pub struct NamedArgument {
     pub value: Expression,      // `$var`
 }
 
-impl Node for NamedArgument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.value]
+impl Spanned for NamedArgument {
+    fn span(&self) -> Span {
+        self.name.span.join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Argument {
     Positional(PositionalArgument),
     Named(NamedArgument),
 }
 
-impl Node for Argument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Argument {
+    fn span(&self) -> Span {
         match self {
-            Argument::Positional(argument) => vec![argument],
-            Argument::Named(argument) => vec![argument],
+            Argument::Positional(argument) => argument.span(),
+            Argument::Named(argument) => argument.span(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArgumentList {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,   // `(`
@@ -74,17 +78,14 @@ impl IntoIterator for ArgumentList {
     }
 }
 
-impl Node for ArgumentList {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.arguments
-            .iter_mut()
-            .map(|a| a as &mut dyn Node)
-            .collect()
+impl Spanned for ArgumentList {
+    fn span(&self) -> Span {
+        self.left_parenthesis.join(self.right_parenthesis)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SingleArgument {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,  // `(`
@@ -92,17 +93,23 @@ pub struct SingleArgument {
     pub right_parenthesis: Span, // `)`
 }
 
-impl Node for SingleArgument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.argument]
+impl Spanned for SingleArgument {
+    fn span(&self) -> Span {
+        self.left_parenthesis.join(self.right_parenthesis)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArgumentPlaceholder {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,  // `(`
     pub ellipsis: Span,          // `...`
     pub right_parenthesis: Span, // `)`
 }
+
+impl Spanned for ArgumentPlaceholder {
+    fn span(&self) -> Span {
+        self.left_parenthesis.join(self.right_parenthesis)
+    }
+}
@@ -2,10 +2,11 @@ use std::slice::Iter;
 
 use crate::arguments::ArgumentList;
 use crate::identifiers::SimpleIdentifier;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attribute {
     pub start: Span,
     pub end: Span,
@@ -13,14 +14,26 @@ pub struct Attribute {
     pub arguments: Option<ArgumentList>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl Spanned for Attribute {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeGroup {
     pub start: Span,
     pub end: Span,
     pub members: Vec<Attribute>,
 }
 
+impl Spanned for AttributeGroup {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
+    }
+}
+
 impl AttributeGroup {
     pub fn iter(&self) -> Iter<'_, Attribute> {
         self.members.iter()
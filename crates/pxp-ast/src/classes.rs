@@ -1,6 +1,7 @@
 use std::slice::Iter;
 
 use crate::attributes::AttributeGroup;
+use crate::comments::CommentGroup;
 use crate::constant::ClassishConstant;
 use crate::functions::AbstractConstructor;
 use crate::functions::AbstractMethod;
@@ -8,18 +9,19 @@ use crate::functions::ConcreteConstructor;
 use crate::functions::ConcreteMethod;
 use crate::identifiers::SimpleIdentifier;
 use crate::modifiers::ClassModifierGroup;
-use crate::node::Node;
 use crate::properties::Property;
 use crate::properties::VariableProperty;
+use crate::spanned::Spanned;
 use crate::traits::TraitUsage;
 use crate::utils::CommaSeparated;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassBody {
     pub left_brace: Span, // `{`
     pub members: Vec<ClassMember>,
+    pub comments: CommentGroup, // after the last member
     pub right_brace: Span, // `}`
 }
 
@@ -38,18 +40,16 @@ impl IntoIterator for ClassBody {
     }
 }
 
-impl Node for ClassBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.members
-            .iter_mut()
-            .map(|member| member as &mut dyn Node)
-            .collect()
+impl Spanned for ClassBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassStatement {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>, // `#[Qux]`
 
     pub modifiers: ClassModifierGroup,       // `abstract`, `final`
@@ -60,25 +60,25 @@ pub struct ClassStatement {
     pub body: ClassBody,                     // `{ ... }`
 }
 
-impl Node for ClassStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
-        if let Some(extends) = &mut self.extends {
-            children.push(extends);
-        }
-        if let Some(implements) = &mut self.implements {
-            children.push(implements);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for ClassStatement {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .unwrap_or(self.class);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnonymousClassBody {
     pub left_brace: Span, // `{`
     pub members: Vec<AnonymousClassMember>,
+    pub comments: CommentGroup, // after the last member
     pub right_brace: Span, // `}`
 }
 
@@ -97,17 +97,14 @@ impl IntoIterator for AnonymousClassBody {
     }
 }
 
-impl Node for AnonymousClassBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.members
-            .iter_mut()
-            .map(|member| member as &mut dyn Node)
-            .collect()
+impl Spanned for AnonymousClassBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnonymousClassExpression {
     pub attributes: Vec<AttributeGroup>,     // `#[Qux]`
     pub class: Span,                         // `class`
@@ -116,35 +113,32 @@ pub struct AnonymousClassExpression {
     pub body: AnonymousClassBody,            // `{ ... }`
 }
 
-impl Node for AnonymousClassExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(extends) = &mut self.extends {
-            children.push(extends);
-        }
-        if let Some(implements) = &mut self.implements {
-            children.push(implements);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for AnonymousClassExpression {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.class, Spanned::span);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassExtends {
     pub extends: Span,            // `extends`
     pub parent: SimpleIdentifier, // `Foo`
 }
 
-impl Node for ClassExtends {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.parent]
+impl Spanned for ClassExtends {
+    fn span(&self) -> Span {
+        self.extends.join(self.parent.span)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassImplements {
     pub implements: Span,                             // `implements`
     pub interfaces: CommaSeparated<SimpleIdentifier>, // `Bar, Baz`
@@ -165,14 +159,14 @@ impl IntoIterator for ClassImplements {
     }
 }
 
-impl Node for ClassImplements {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.interfaces.children()
+impl Spanned for ClassImplements {
+    fn span(&self) -> Span {
+        self.implements.join(self.interfaces.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClassMember {
     Constant(ClassishConstant),
     TraitUsage(TraitUsage),
@@ -182,25 +176,28 @@ pub enum ClassMember {
     AbstractConstructor(AbstractConstructor),
     ConcreteMethod(ConcreteMethod),
     ConcreteConstructor(ConcreteConstructor),
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
-impl Node for ClassMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ClassMember {
+    fn span(&self) -> Span {
         match self {
-            ClassMember::Constant(constant) => vec![constant],
-            ClassMember::TraitUsage(usage) => vec![usage],
-            ClassMember::Property(property) => vec![property],
-            ClassMember::VariableProperty(property) => vec![property],
-            ClassMember::AbstractMethod(method) => vec![method],
-            ClassMember::AbstractConstructor(method) => vec![method],
-            ClassMember::ConcreteMethod(method) => vec![method],
-            ClassMember::ConcreteConstructor(method) => vec![method],
+            ClassMember::Constant(member) => member.span(),
+            ClassMember::TraitUsage(member) => member.span(),
+            ClassMember::Property(member) => member.span(),
+            ClassMember::VariableProperty(member) => member.span(),
+            ClassMember::AbstractMethod(member) => member.span(),
+            ClassMember::AbstractConstructor(member) => member.span(),
+            ClassMember::ConcreteMethod(member) => member.span(),
+            ClassMember::ConcreteConstructor(member) => member.span(),
+            ClassMember::Error(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AnonymousClassMember {
     Constant(ClassishConstant),
     TraitUsage(TraitUsage),
@@ -208,17 +205,20 @@ pub enum AnonymousClassMember {
     VariableProperty(VariableProperty),
     ConcreteMethod(ConcreteMethod),
     ConcreteConstructor(ConcreteConstructor),
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
-impl Node for AnonymousClassMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for AnonymousClassMember {
+    fn span(&self) -> Span {
         match self {
-            AnonymousClassMember::Constant(constant) => vec![constant],
-            AnonymousClassMember::TraitUsage(usage) => vec![usage],
-            AnonymousClassMember::Property(property) => vec![property],
-            AnonymousClassMember::VariableProperty(property) => vec![property],
-            AnonymousClassMember::ConcreteMethod(method) => vec![method],
-            AnonymousClassMember::ConcreteConstructor(method) => vec![method],
+            AnonymousClassMember::Constant(member) => member.span(),
+            AnonymousClassMember::TraitUsage(member) => member.span(),
+            AnonymousClassMember::Property(member) => member.span(),
+            AnonymousClassMember::VariableProperty(member) => member.span(),
+            AnonymousClassMember::ConcreteMethod(member) => member.span(),
+            AnonymousClassMember::ConcreteConstructor(member) => member.span(),
+            AnonymousClassMember::Error(span) => *span,
         }
     }
 }
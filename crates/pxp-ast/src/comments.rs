@@ -1,11 +1,12 @@
 use std::slice::Iter;
 
-use crate::node::Node;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_bytestring::ByteString;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CommentFormat {
     SingleLine,
     MultiLine,
@@ -14,25 +15,47 @@ pub enum CommentFormat {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Comment {
     pub span: Span,
     pub format: CommentFormat,
     pub content: ByteString,
 }
 
-impl Node for Comment {}
+impl Spanned for Comment {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommentGroup {
     pub comments: Vec<Comment>,
 }
 
+impl Spanned for CommentGroup {
+    fn span(&self) -> Span {
+        span_of(&self.comments).unwrap_or_default()
+    }
+}
+
 impl CommentGroup {
     pub fn iter(&self) -> Iter<'_, Comment> {
         self.comments.iter()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    /// The last `/** ... */` comment in the group, which documents whatever comes after it.
+    pub fn doc_comment(&self) -> Option<&Comment> {
+        self.comments
+            .iter()
+            .rev()
+            .find(|comment| comment.format == CommentFormat::Document)
+    }
 }
 
 impl IntoIterator for CommentGroup {
@@ -4,26 +4,26 @@ use crate::attributes::AttributeGroup;
 use crate::comments::CommentGroup;
 use crate::identifiers::SimpleIdentifier;
 use crate::modifiers::ConstantModifierGroup;
-use crate::node::Node;
 use crate::Expression;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantEntry {
     pub name: SimpleIdentifier, // `FOO`
     pub equals: Span,           // `=`
     pub value: Expression,      // `123`
 }
 
-impl Node for ConstantEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.value]
+impl Spanned for ConstantEntry {
+    fn span(&self) -> Span {
+        self.name.span.join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantStatement {
     pub comments: CommentGroup,
     pub r#const: Span,               // `const`
@@ -46,17 +46,14 @@ impl IntoIterator for ConstantStatement {
     }
 }
 
-impl Node for ConstantStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.entries
-            .iter_mut()
-            .map(|e| e as &mut dyn Node)
-            .collect()
+impl Spanned for ConstantStatement {
+    fn span(&self) -> Span {
+        self.r#const.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassishConstant {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,  // `#[Foo]`
@@ -82,11 +79,15 @@ impl IntoIterator for ClassishConstant {
     }
 }
 
-impl Node for ClassishConstant {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.entries
-            .iter_mut()
-            .map(|e| e as &mut dyn Node)
-            .collect()
+impl Spanned for ClassishConstant {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .unwrap_or(self.r#const);
+
+        start.join(self.semicolon)
     }
 }
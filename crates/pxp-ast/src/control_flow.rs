@@ -1,11 +1,12 @@
-use crate::node::Node;
 use crate::Ending;
 use crate::Expression;
 use crate::Statement;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStatement {
     pub r#if: Span,              // `if`
     pub left_parenthesis: Span,  // `(`
@@ -14,14 +15,14 @@ pub struct IfStatement {
     pub body: IfStatementBody,   // `{ ... }`
 }
 
-impl Node for IfStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.condition, &mut self.body]
+impl Spanned for IfStatement {
+    fn span(&self) -> Span {
+        self.r#if.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IfStatementBody {
     Statement {
         statement: Box<Statement>,       // `*statement*`
@@ -38,50 +39,29 @@ pub enum IfStatementBody {
     },
 }
 
-impl Node for IfStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for IfStatementBody {
+    fn span(&self) -> Span {
         match self {
             IfStatementBody::Statement {
                 statement,
                 elseifs,
                 r#else,
             } => {
-                let mut children: Vec<&mut dyn Node> = vec![statement.as_mut()];
-                children.extend(
-                    elseifs
-                        .iter_mut()
-                        .map(|elseif| elseif as &mut dyn Node)
-                        .collect::<Vec<&mut dyn Node>>(),
-                );
-                if let Some(r#else) = r#else {
-                    children.push(r#else as &mut dyn Node);
-                }
-                children
-            }
-            IfStatementBody::Block {
-                statements,
-                elseifs,
-                r#else,
-                ..
-            } => {
-                let mut children: Vec<&mut dyn Node> = vec![];
-                children.extend(
-                    statements
-                        .iter_mut()
-                        .map(|statement| statement as &mut dyn Node),
-                );
-                children.extend(elseifs.iter_mut().map(|elseif| elseif as &mut dyn Node));
-                if let Some(r#else) = r#else {
-                    children.push(r#else as &mut dyn Node);
-                }
-                children
+                let end = match (r#else, elseifs.last()) {
+                    (Some(r#else), _) => r#else.span(),
+                    (None, Some(elseif)) => elseif.span(),
+                    (None, None) => statement.span(),
+                };
+
+                statement.span().join(end)
             }
+            IfStatementBody::Block { colon, ending, .. } => colon.join(ending.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStatementElseIf {
     pub elseif: Span,              // `elseif`
     pub left_parenthesis: Span,    // `(`
@@ -90,27 +70,27 @@ pub struct IfStatementElseIf {
     pub statement: Box<Statement>, // `*statement*`
 }
 
-impl Node for IfStatementElseIf {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.condition, self.statement.as_mut()]
+impl Spanned for IfStatementElseIf {
+    fn span(&self) -> Span {
+        self.elseif.join(self.statement.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStatementElse {
     pub r#else: Span,              // `else`
     pub statement: Box<Statement>, // `*statement*`
 }
 
-impl Node for IfStatementElse {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.statement.as_mut()]
+impl Spanned for IfStatementElse {
+    fn span(&self) -> Span {
+        self.r#else.join(self.statement.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStatementElseIfBlock {
     pub elseif: Span,               // `elseif`
     pub left_parenthesis: Span,     // `(`
@@ -120,31 +100,22 @@ pub struct IfStatementElseIfBlock {
     pub statements: Vec<Statement>, // `*statements*`
 }
 
-impl Node for IfStatementElseIfBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.condition];
-        children.extend(
-            self.statements
-                .iter_mut()
-                .map(|statement| statement as &mut dyn Node),
-        );
-        children
+impl Spanned for IfStatementElseIfBlock {
+    fn span(&self) -> Span {
+        self.elseif.join(span_of(&self.statements).unwrap_or(self.colon))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStatementElseBlock {
     pub r#else: Span,               // `else`
     pub colon: Span,                // `:`
     pub statements: Vec<Statement>, // `*statements*`
 }
 
-impl Node for IfStatementElseBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.statements
-            .iter_mut()
-            .map(|statement| statement as &mut dyn Node)
-            .collect()
+impl Spanned for IfStatementElseBlock {
+    fn span(&self) -> Span {
+        self.r#else.join(span_of(&self.statements).unwrap_or(self.colon))
     }
 }
@@ -1,11 +1,12 @@
 use std::fmt::Display;
 
-use crate::node::Node;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_bytestring::ByteString;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
     Named(Span, ByteString),
     Nullable(Span, Box<Type>),
@@ -65,32 +66,6 @@ impl Type {
     pub fn is_bottom(&self) -> bool {
         matches!(self, Type::Never(_) | Type::Void(_))
     }
-
-    pub fn first_span(&self) -> Span {
-        match &self {
-            Type::Named(span, _) => *span,
-            Type::Nullable(span, _) => *span,
-            Type::Union(inner) => inner[0].first_span(),
-            Type::Intersection(inner) => inner[0].first_span(),
-            Type::Void(span) => *span,
-            Type::Null(span) => *span,
-            Type::True(span) => *span,
-            Type::False(span) => *span,
-            Type::Never(span) => *span,
-            Type::Float(span) => *span,
-            Type::Boolean(span) => *span,
-            Type::Integer(span) => *span,
-            Type::String(span) => *span,
-            Type::Array(span) => *span,
-            Type::Object(span) => *span,
-            Type::Mixed(span) => *span,
-            Type::Callable(span) => *span,
-            Type::Iterable(span) => *span,
-            Type::StaticReference(span) => *span,
-            Type::SelfReference(span) => *span,
-            Type::ParentReference(span) => *span,
-        }
-    }
 }
 
 impl Display for Type {
@@ -137,13 +112,29 @@ impl Display for Type {
     }
 }
 
-impl Node for Type {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Type {
+    fn span(&self) -> Span {
         match self {
-            Type::Nullable(_, t) => vec![t.as_mut() as &mut dyn Node],
-            Type::Union(ts) => ts.iter_mut().map(|x| x as &mut dyn Node).collect(),
-            Type::Intersection(ts) => ts.iter_mut().map(|x| x as &mut dyn Node).collect(),
-            _ => vec![],
+            Type::Nullable(span, inner) => span.join(inner.span()),
+            Type::Union(inner) | Type::Intersection(inner) => span_of(inner).unwrap_or_default(),
+            Type::Named(span, _)
+            | Type::Void(span)
+            | Type::Null(span)
+            | Type::True(span)
+            | Type::False(span)
+            | Type::Never(span)
+            | Type::Float(span)
+            | Type::Boolean(span)
+            | Type::Integer(span)
+            | Type::String(span)
+            | Type::Array(span)
+            | Type::Object(span)
+            | Type::Mixed(span)
+            | Type::Callable(span)
+            | Type::Iterable(span)
+            | Type::StaticReference(span)
+            | Type::SelfReference(span)
+            | Type::ParentReference(span) => *span,
         }
     }
 }
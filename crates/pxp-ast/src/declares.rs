@@ -1,43 +1,40 @@
 use crate::identifiers::SimpleIdentifier;
 use crate::literals::Literal;
-use crate::node::Node;
 use crate::Expression;
 use crate::Statement;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclareEntry {
     pub key: SimpleIdentifier, // `strict_types`
     pub equals: Span,          // `=`
     pub value: Literal,        // `1`
 }
 
-impl Node for DeclareEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.key, &mut self.value]
+impl Spanned for DeclareEntry {
+    fn span(&self) -> Span {
+        self.key.span.join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclareEntryGroup {
     pub left_parenthesis: Span,     // `(`
     pub right_parenthesis: Span,    // `)`
     pub entries: Vec<DeclareEntry>, // `strict_types = 1`
 }
 
-impl Node for DeclareEntryGroup {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.entries
-            .iter_mut()
-            .map(|e| e as &mut dyn Node)
-            .collect()
+impl Spanned for DeclareEntryGroup {
+    fn span(&self) -> Span {
+        self.left_parenthesis.join(self.right_parenthesis)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeclareBody {
     // declaration is terminated with `;`
     Noop {
@@ -62,31 +59,34 @@ pub enum DeclareBody {
     },
 }
 
-impl Node for DeclareBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for DeclareBody {
+    fn span(&self) -> Span {
         match self {
-            DeclareBody::Noop { .. } => vec![],
-            DeclareBody::Braced { statements, .. } => {
-                statements.iter_mut().map(|s| s as &mut dyn Node).collect()
-            }
-            DeclareBody::Expression { expression, .. } => vec![expression],
-            DeclareBody::Block { statements, .. } => {
-                statements.iter_mut().map(|s| s as &mut dyn Node).collect()
-            }
+            DeclareBody::Noop { semicolon } => *semicolon,
+            DeclareBody::Braced {
+                left_brace,
+                right_brace,
+                ..
+            } => left_brace.join(*right_brace),
+            DeclareBody::Expression {
+                expression,
+                semicolon,
+            } => expression.span().join(*semicolon),
+            DeclareBody::Block { colon, end, .. } => colon.join(end.1),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclareStatement {
     pub declare: Span,              // `declare`
     pub entries: DeclareEntryGroup, // `(strict_types = 1)`
     pub body: DeclareBody,          // `;`
 }
 
-impl Node for DeclareStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.entries, &mut self.body]
+impl Spanned for DeclareStatement {
+    fn span(&self) -> Span {
+        self.declare.join(self.body.span())
     }
 }
@@ -1,68 +1,77 @@
 use crate::attributes::AttributeGroup;
+use crate::comments::CommentGroup;
 use crate::constant::ClassishConstant;
 use crate::functions::ConcreteMethod;
 use crate::identifiers::SimpleIdentifier;
-use crate::node::Node;
 use crate::Expression;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 use super::traits::TraitUsage;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitEnumCase {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>, // `#[Foo]`
     pub start: Span,                     // `case`
     pub name: SimpleIdentifier,          // `Bar`
     pub end: Span,                       // `;`
 }
 
-impl Node for UnitEnumCase {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name]
+impl Spanned for UnitEnumCase {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.start, Spanned::span);
+
+        start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnitEnumMember {
     Case(UnitEnumCase),         // `case Bar;`
     Method(ConcreteMethod),     // `public function foo(): void { ... }`
     Constant(ClassishConstant), // `public const FOO = 123;`
     TraitUsage(TraitUsage),
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
-impl Node for UnitEnumMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for UnitEnumMember {
+    fn span(&self) -> Span {
         match self {
-            UnitEnumMember::Case(case) => vec![case],
-            UnitEnumMember::Method(method) => vec![method],
-            UnitEnumMember::Constant(constant) => vec![constant],
-            UnitEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+            UnitEnumMember::Case(member) => member.span(),
+            UnitEnumMember::Method(member) => member.span(),
+            UnitEnumMember::Constant(member) => member.span(),
+            UnitEnumMember::TraitUsage(member) => member.span(),
+            UnitEnumMember::Error(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitEnumBody {
     pub left_brace: Span,             // `{`
     pub members: Vec<UnitEnumMember>, // `...`
+    pub comments: CommentGroup,       // after the last member
     pub right_brace: Span,            // `}`
 }
 
-impl Node for UnitEnumBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.members
-            .iter_mut()
-            .map(|m| m as &mut dyn Node)
-            .collect()
+impl Spanned for UnitEnumBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitEnumStatement {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub r#enum: Span,                      // `enum`
     pub name: SimpleIdentifier,            // `Foo`
@@ -70,31 +79,38 @@ pub struct UnitEnumStatement {
     pub body: UnitEnumBody,                // `{ ... }`
 }
 
-impl Node for UnitEnumStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
-        for implement in &mut self.implements {
-            children.push(implement);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for UnitEnumStatement {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.r#enum, Spanned::span);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackedEnumType {
     String(Span, Span), // `:` + `string`
     Int(Span, Span),    // `:` + `int`
 }
 
-impl Node for BackedEnumType {
-    //
+impl Spanned for BackedEnumType {
+    fn span(&self) -> Span {
+        match self {
+            BackedEnumType::String(colon, identifier) | BackedEnumType::Int(colon, identifier) => {
+                colon.join(*identifier)
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackedEnumCase {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>, // `#[Foo]`
     pub case: Span,                      // `case`
     pub name: SimpleIdentifier,          // `Bar`
@@ -103,52 +119,59 @@ pub struct BackedEnumCase {
     pub semicolon: Span,                 // `;`
 }
 
-impl Node for BackedEnumCase {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.value]
+impl Spanned for BackedEnumCase {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.case, Spanned::span);
+
+        start.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackedEnumMember {
     Case(BackedEnumCase),
     Method(ConcreteMethod),
     Constant(ClassishConstant),
     TraitUsage(TraitUsage),
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
-impl Node for BackedEnumMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for BackedEnumMember {
+    fn span(&self) -> Span {
         match self {
-            BackedEnumMember::Case(case) => vec![case],
-            BackedEnumMember::Method(method) => vec![method],
-            BackedEnumMember::Constant(constant) => vec![constant],
-            BackedEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+            BackedEnumMember::Case(member) => member.span(),
+            BackedEnumMember::Method(member) => member.span(),
+            BackedEnumMember::Constant(member) => member.span(),
+            BackedEnumMember::TraitUsage(member) => member.span(),
+            BackedEnumMember::Error(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackedEnumBody {
     pub left_brace: Span,               // `{`
     pub members: Vec<BackedEnumMember>, // `...`
+    pub comments: CommentGroup,         // after the last member
     pub right_brace: Span,              // `}`
 }
 
-impl Node for BackedEnumBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.members
-            .iter_mut()
-            .map(|m| m as &mut dyn Node)
-            .collect()
+impl Spanned for BackedEnumBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackedEnumStatement {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub r#enum: Span,                      // `enum`
     pub name: SimpleIdentifier,            // `Foo`
@@ -157,13 +180,13 @@ pub struct BackedEnumStatement {
     pub body: BackedEnumBody,              // `{ ... }`
 }
 
-impl Node for BackedEnumStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name, &mut self.backed_type];
-        for implement in &mut self.implements {
-            children.push(implement);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for BackedEnumStatement {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.r#enum, Spanned::span);
+
+        start.join(self.body.span())
     }
 }
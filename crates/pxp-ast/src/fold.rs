@@ -0,0 +1,4296 @@
+// This file is generated by pxp-codegen, run `just codegen` to update it.
+
+//! Rewriting the syntax tree by taking it apart and putting it back together, with a method for
+//! each type of node.
+//!
+//! Unlike `VisitorMut`, each `fold_*` method takes its node by value and returns whatever should
+//! replace it, so a node can be turned into a different variant of the same type. The default
+//! methods fold the children of the node by calling the `fold_*` function of the same name.
+//!
+//! A statement in a list of statements, such as the body of a function, goes through
+//! `fold_statements`, which can replace it with any number of statements.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pxp_ast::fold::{fold_statement, Fold};
+//! use pxp_ast::{Program, Statement};
+//!
+//! // Removes every `echo` from the program.
+//! struct RemoveEchoes;
+//!
+//! impl Fold for RemoveEchoes {
+//!     fn fold_statements(&mut self, node: Statement) -> Vec<Statement> {
+//!         match node {
+//!             Statement::Echo(_) => vec![],
+//!             node => vec![fold_statement(self, node)],
+//!         }
+//!     }
+//! }
+//!
+//! fn remove_echoes(program: Program) -> Program {
+//!     RemoveEchoes.fold_block(program)
+//! }
+//! ```
+
+use crate::arguments::Argument;
+use crate::arguments::ArgumentList;
+use crate::arguments::ArgumentPlaceholder;
+use crate::arguments::NamedArgument;
+use crate::arguments::PositionalArgument;
+use crate::arguments::SingleArgument;
+use crate::attributes::Attribute;
+use crate::attributes::AttributeGroup;
+use crate::classes::AnonymousClassBody;
+use crate::classes::AnonymousClassExpression;
+use crate::classes::AnonymousClassMember;
+use crate::classes::ClassBody;
+use crate::classes::ClassExtends;
+use crate::classes::ClassImplements;
+use crate::classes::ClassMember;
+use crate::classes::ClassStatement;
+use crate::comments::Comment;
+use crate::comments::CommentGroup;
+use crate::constant::ClassishConstant;
+use crate::constant::ConstantEntry;
+use crate::constant::ConstantStatement;
+use crate::control_flow::IfStatement;
+use crate::control_flow::IfStatementBody;
+use crate::control_flow::IfStatementElse;
+use crate::control_flow::IfStatementElseBlock;
+use crate::control_flow::IfStatementElseIf;
+use crate::control_flow::IfStatementElseIfBlock;
+use crate::data_type::Type;
+use crate::declares::DeclareBody;
+use crate::declares::DeclareEntry;
+use crate::declares::DeclareEntryGroup;
+use crate::declares::DeclareStatement;
+use crate::enums::BackedEnumBody;
+use crate::enums::BackedEnumCase;
+use crate::enums::BackedEnumMember;
+use crate::enums::BackedEnumStatement;
+use crate::enums::BackedEnumType;
+use crate::enums::UnitEnumBody;
+use crate::enums::UnitEnumCase;
+use crate::enums::UnitEnumMember;
+use crate::enums::UnitEnumStatement;
+use crate::functions::AbstractConstructor;
+use crate::functions::AbstractMethod;
+use crate::functions::ArrowFunctionExpression;
+use crate::functions::ClosureExpression;
+use crate::functions::ClosureUse;
+use crate::functions::ClosureUseVariable;
+use crate::functions::ConcreteConstructor;
+use crate::functions::ConcreteMethod;
+use crate::functions::ConstructorParameter;
+use crate::functions::ConstructorParameterList;
+use crate::functions::FunctionBody;
+use crate::functions::FunctionParameter;
+use crate::functions::FunctionParameterList;
+use crate::functions::FunctionStatement;
+use crate::functions::MethodBody;
+use crate::functions::ReturnType;
+use crate::goto::GotoStatement;
+use crate::goto::LabelStatement;
+use crate::identifiers::DynamicIdentifier;
+use crate::identifiers::Identifier;
+use crate::identifiers::SimpleIdentifier;
+use crate::interfaces::InterfaceBody;
+use crate::interfaces::InterfaceExtends;
+use crate::interfaces::InterfaceMember;
+use crate::interfaces::InterfaceStatement;
+use crate::literals::Literal;
+use crate::literals::LiteralFloat;
+use crate::literals::LiteralInteger;
+use crate::literals::LiteralString;
+use crate::loops::BreakStatement;
+use crate::loops::ContinueStatement;
+use crate::loops::DoWhileStatement;
+use crate::loops::ForStatement;
+use crate::loops::ForStatementBody;
+use crate::loops::ForStatementIterator;
+use crate::loops::ForeachStatement;
+use crate::loops::ForeachStatementBody;
+use crate::loops::ForeachStatementIterator;
+use crate::loops::Level;
+use crate::loops::WhileStatement;
+use crate::loops::WhileStatementBody;
+use crate::modifiers::ClassModifier;
+use crate::modifiers::ClassModifierGroup;
+use crate::modifiers::ConstantModifier;
+use crate::modifiers::ConstantModifierGroup;
+use crate::modifiers::MethodModifier;
+use crate::modifiers::MethodModifierGroup;
+use crate::modifiers::PromotedPropertyModifier;
+use crate::modifiers::PromotedPropertyModifierGroup;
+use crate::modifiers::PropertyModifier;
+use crate::modifiers::PropertyModifierGroup;
+use crate::modifiers::VisibilityModifier;
+use crate::namespaces::BracedNamespace;
+use crate::namespaces::BracedNamespaceBody;
+use crate::namespaces::NamespaceStatement;
+use crate::namespaces::UnbracedNamespace;
+use crate::operators::ArithmeticOperationExpression;
+use crate::operators::AssignmentOperationExpression;
+use crate::operators::BitwiseOperationExpression;
+use crate::operators::ComparisonOperationExpression;
+use crate::operators::LogicalOperationExpression;
+use crate::properties::Property;
+use crate::properties::PropertyEntry;
+use crate::properties::VariableProperty;
+use crate::traits::TraitBody;
+use crate::traits::TraitMember;
+use crate::traits::TraitStatement;
+use crate::traits::TraitUsage;
+use crate::traits::TraitUsageAdaptation;
+use crate::try_block::CatchBlock;
+use crate::try_block::CatchType;
+use crate::try_block::FinallyBlock;
+use crate::try_block::TryStatement;
+use crate::utils::CommaSeparated;
+use crate::variables::BracedVariableVariable;
+use crate::variables::SimpleVariable;
+use crate::variables::Variable;
+use crate::variables::VariableVariable;
+use crate::ArrayExpression;
+use crate::ArrayIndexExpression;
+use crate::ArrayItem;
+use crate::Block;
+use crate::BlockStatement;
+use crate::BoolExpression;
+use crate::Case;
+use crate::CastExpression;
+use crate::CloneExpression;
+use crate::ClosingTagStatement;
+use crate::CoalesceExpression;
+use crate::ConcatExpression;
+use crate::ConstantFetchExpression;
+use crate::DefaultMatchArm;
+use crate::DieExpression;
+use crate::EchoOpeningTagStatement;
+use crate::EchoStatement;
+use crate::EmptyExpression;
+use crate::Ending;
+use crate::ErrorSuppressExpression;
+use crate::EvalExpression;
+use crate::ExitExpression;
+use crate::Expression;
+use crate::ExpressionStatement;
+use crate::ExpressionStringPart;
+use crate::FullOpeningTagStatement;
+use crate::FunctionCallExpression;
+use crate::FunctionClosureCreationExpression;
+use crate::GlobalStatement;
+use crate::GroupUseStatement;
+use crate::HaltCompilerStatement;
+use crate::HeredocExpression;
+use crate::IncludeExpression;
+use crate::IncludeOnceExpression;
+use crate::InlineHtmlStatement;
+use crate::InstanceofExpression;
+use crate::InterpolatedStringExpression;
+use crate::IssetExpression;
+use crate::ListEntry;
+use crate::ListExpression;
+use crate::LiteralStringPart;
+use crate::MagicConstantExpression;
+use crate::MatchArm;
+use crate::MatchExpression;
+use crate::MethodCallExpression;
+use crate::MethodClosureCreationExpression;
+use crate::NewExpression;
+use crate::NowdocExpression;
+use crate::NullsafeMethodCallExpression;
+use crate::NullsafePropertyFetchExpression;
+use crate::ParenthesizedExpression;
+use crate::PrintExpression;
+use crate::PropertyFetchExpression;
+use crate::ReferenceExpression;
+use crate::RequireExpression;
+use crate::RequireOnceExpression;
+use crate::ReturnStatement;
+use crate::ShellExecExpression;
+use crate::ShortArrayExpression;
+use crate::ShortOpeningTagStatement;
+use crate::ShortTernaryExpression;
+use crate::Statement;
+use crate::StaticMethodCallExpression;
+use crate::StaticMethodClosureCreationExpression;
+use crate::StaticPropertyFetchExpression;
+use crate::StaticStatement;
+use crate::StaticVar;
+use crate::StaticVariableMethodCallExpression;
+use crate::StaticVariableMethodClosureCreationExpression;
+use crate::StringPart;
+use crate::SwitchStatement;
+use crate::TernaryExpression;
+use crate::ThrowExpression;
+use crate::UnsetExpression;
+use crate::Use;
+use crate::UseStatement;
+use crate::YieldExpression;
+use crate::YieldFromExpression;
+
+/// Rewrites the syntax tree, see the [module documentation](self).
+pub trait Fold {
+    /// Folds a list of statements, such as the body of a function or the whole program.
+    fn fold_block(&mut self, node: Block) -> Block {
+        fold_block(self, node)
+    }
+
+    /// Folds a statement that's part of a list of statements into the statements that should
+    /// replace it, which can be none at all.
+    fn fold_statements(&mut self, node: Statement) -> Vec<Statement> {
+        vec![self.fold_statement(node)]
+    }
+
+    fn fold_abstract_constructor(&mut self, node: AbstractConstructor) -> AbstractConstructor {
+        fold_abstract_constructor(self, node)
+    }
+
+    fn fold_abstract_method(&mut self, node: AbstractMethod) -> AbstractMethod {
+        fold_abstract_method(self, node)
+    }
+
+    fn fold_anonymous_class_body(&mut self, node: AnonymousClassBody) -> AnonymousClassBody {
+        fold_anonymous_class_body(self, node)
+    }
+
+    fn fold_anonymous_class_expression(
+        &mut self,
+        node: AnonymousClassExpression,
+    ) -> AnonymousClassExpression {
+        fold_anonymous_class_expression(self, node)
+    }
+
+    fn fold_anonymous_class_member(&mut self, node: AnonymousClassMember) -> AnonymousClassMember {
+        fold_anonymous_class_member(self, node)
+    }
+
+    fn fold_argument(&mut self, node: Argument) -> Argument {
+        fold_argument(self, node)
+    }
+
+    fn fold_argument_list(&mut self, node: ArgumentList) -> ArgumentList {
+        fold_argument_list(self, node)
+    }
+
+    fn fold_argument_placeholder(&mut self, node: ArgumentPlaceholder) -> ArgumentPlaceholder {
+        fold_argument_placeholder(self, node)
+    }
+
+    fn fold_arithmetic_operation_expression(
+        &mut self,
+        node: ArithmeticOperationExpression,
+    ) -> ArithmeticOperationExpression {
+        fold_arithmetic_operation_expression(self, node)
+    }
+
+    fn fold_array_expression(&mut self, node: ArrayExpression) -> ArrayExpression {
+        fold_array_expression(self, node)
+    }
+
+    fn fold_array_index_expression(&mut self, node: ArrayIndexExpression) -> ArrayIndexExpression {
+        fold_array_index_expression(self, node)
+    }
+
+    fn fold_array_item(&mut self, node: ArrayItem) -> ArrayItem {
+        fold_array_item(self, node)
+    }
+
+    fn fold_arrow_function_expression(
+        &mut self,
+        node: ArrowFunctionExpression,
+    ) -> ArrowFunctionExpression {
+        fold_arrow_function_expression(self, node)
+    }
+
+    fn fold_assignment_operation_expression(
+        &mut self,
+        node: AssignmentOperationExpression,
+    ) -> AssignmentOperationExpression {
+        fold_assignment_operation_expression(self, node)
+    }
+
+    fn fold_attribute(&mut self, node: Attribute) -> Attribute {
+        fold_attribute(self, node)
+    }
+
+    fn fold_attribute_group(&mut self, node: AttributeGroup) -> AttributeGroup {
+        fold_attribute_group(self, node)
+    }
+
+    fn fold_backed_enum_body(&mut self, node: BackedEnumBody) -> BackedEnumBody {
+        fold_backed_enum_body(self, node)
+    }
+
+    fn fold_backed_enum_case(&mut self, node: BackedEnumCase) -> BackedEnumCase {
+        fold_backed_enum_case(self, node)
+    }
+
+    fn fold_backed_enum_member(&mut self, node: BackedEnumMember) -> BackedEnumMember {
+        fold_backed_enum_member(self, node)
+    }
+
+    fn fold_backed_enum_statement(&mut self, node: BackedEnumStatement) -> BackedEnumStatement {
+        fold_backed_enum_statement(self, node)
+    }
+
+    fn fold_backed_enum_type(&mut self, node: BackedEnumType) -> BackedEnumType {
+        fold_backed_enum_type(self, node)
+    }
+
+    fn fold_bitwise_operation_expression(
+        &mut self,
+        node: BitwiseOperationExpression,
+    ) -> BitwiseOperationExpression {
+        fold_bitwise_operation_expression(self, node)
+    }
+
+    fn fold_block_statement(&mut self, node: BlockStatement) -> BlockStatement {
+        fold_block_statement(self, node)
+    }
+
+    fn fold_bool_expression(&mut self, node: BoolExpression) -> BoolExpression {
+        fold_bool_expression(self, node)
+    }
+
+    fn fold_braced_namespace(&mut self, node: BracedNamespace) -> BracedNamespace {
+        fold_braced_namespace(self, node)
+    }
+
+    fn fold_braced_namespace_body(&mut self, node: BracedNamespaceBody) -> BracedNamespaceBody {
+        fold_braced_namespace_body(self, node)
+    }
+
+    fn fold_braced_variable_variable(
+        &mut self,
+        node: BracedVariableVariable,
+    ) -> BracedVariableVariable {
+        fold_braced_variable_variable(self, node)
+    }
+
+    fn fold_break_statement(&mut self, node: BreakStatement) -> BreakStatement {
+        fold_break_statement(self, node)
+    }
+
+    fn fold_case(&mut self, node: Case) -> Case {
+        fold_case(self, node)
+    }
+
+    fn fold_cast_expression(&mut self, node: CastExpression) -> CastExpression {
+        fold_cast_expression(self, node)
+    }
+
+    fn fold_catch_block(&mut self, node: CatchBlock) -> CatchBlock {
+        fold_catch_block(self, node)
+    }
+
+    fn fold_catch_type(&mut self, node: CatchType) -> CatchType {
+        fold_catch_type(self, node)
+    }
+
+    fn fold_class_body(&mut self, node: ClassBody) -> ClassBody {
+        fold_class_body(self, node)
+    }
+
+    fn fold_class_extends(&mut self, node: ClassExtends) -> ClassExtends {
+        fold_class_extends(self, node)
+    }
+
+    fn fold_class_implements(&mut self, node: ClassImplements) -> ClassImplements {
+        fold_class_implements(self, node)
+    }
+
+    fn fold_class_member(&mut self, node: ClassMember) -> ClassMember {
+        fold_class_member(self, node)
+    }
+
+    fn fold_class_modifier(&mut self, node: ClassModifier) -> ClassModifier {
+        fold_class_modifier(self, node)
+    }
+
+    fn fold_class_modifier_group(&mut self, node: ClassModifierGroup) -> ClassModifierGroup {
+        fold_class_modifier_group(self, node)
+    }
+
+    fn fold_class_statement(&mut self, node: ClassStatement) -> ClassStatement {
+        fold_class_statement(self, node)
+    }
+
+    fn fold_classish_constant(&mut self, node: ClassishConstant) -> ClassishConstant {
+        fold_classish_constant(self, node)
+    }
+
+    fn fold_clone_expression(&mut self, node: CloneExpression) -> CloneExpression {
+        fold_clone_expression(self, node)
+    }
+
+    fn fold_closing_tag_statement(&mut self, node: ClosingTagStatement) -> ClosingTagStatement {
+        fold_closing_tag_statement(self, node)
+    }
+
+    fn fold_closure_expression(&mut self, node: ClosureExpression) -> ClosureExpression {
+        fold_closure_expression(self, node)
+    }
+
+    fn fold_closure_use(&mut self, node: ClosureUse) -> ClosureUse {
+        fold_closure_use(self, node)
+    }
+
+    fn fold_closure_use_variable(&mut self, node: ClosureUseVariable) -> ClosureUseVariable {
+        fold_closure_use_variable(self, node)
+    }
+
+    fn fold_coalesce_expression(&mut self, node: CoalesceExpression) -> CoalesceExpression {
+        fold_coalesce_expression(self, node)
+    }
+
+    fn fold_comment(&mut self, node: Comment) -> Comment {
+        fold_comment(self, node)
+    }
+
+    fn fold_comment_group(&mut self, node: CommentGroup) -> CommentGroup {
+        fold_comment_group(self, node)
+    }
+
+    fn fold_comparison_operation_expression(
+        &mut self,
+        node: ComparisonOperationExpression,
+    ) -> ComparisonOperationExpression {
+        fold_comparison_operation_expression(self, node)
+    }
+
+    fn fold_concat_expression(&mut self, node: ConcatExpression) -> ConcatExpression {
+        fold_concat_expression(self, node)
+    }
+
+    fn fold_concrete_constructor(&mut self, node: ConcreteConstructor) -> ConcreteConstructor {
+        fold_concrete_constructor(self, node)
+    }
+
+    fn fold_concrete_method(&mut self, node: ConcreteMethod) -> ConcreteMethod {
+        fold_concrete_method(self, node)
+    }
+
+    fn fold_constant_entry(&mut self, node: ConstantEntry) -> ConstantEntry {
+        fold_constant_entry(self, node)
+    }
+
+    fn fold_constant_fetch_expression(
+        &mut self,
+        node: ConstantFetchExpression,
+    ) -> ConstantFetchExpression {
+        fold_constant_fetch_expression(self, node)
+    }
+
+    fn fold_constant_modifier(&mut self, node: ConstantModifier) -> ConstantModifier {
+        fold_constant_modifier(self, node)
+    }
+
+    fn fold_constant_modifier_group(
+        &mut self,
+        node: ConstantModifierGroup,
+    ) -> ConstantModifierGroup {
+        fold_constant_modifier_group(self, node)
+    }
+
+    fn fold_constant_statement(&mut self, node: ConstantStatement) -> ConstantStatement {
+        fold_constant_statement(self, node)
+    }
+
+    fn fold_constructor_parameter(&mut self, node: ConstructorParameter) -> ConstructorParameter {
+        fold_constructor_parameter(self, node)
+    }
+
+    fn fold_constructor_parameter_list(
+        &mut self,
+        node: ConstructorParameterList,
+    ) -> ConstructorParameterList {
+        fold_constructor_parameter_list(self, node)
+    }
+
+    fn fold_continue_statement(&mut self, node: ContinueStatement) -> ContinueStatement {
+        fold_continue_statement(self, node)
+    }
+
+    fn fold_declare_body(&mut self, node: DeclareBody) -> DeclareBody {
+        fold_declare_body(self, node)
+    }
+
+    fn fold_declare_entry(&mut self, node: DeclareEntry) -> DeclareEntry {
+        fold_declare_entry(self, node)
+    }
+
+    fn fold_declare_entry_group(&mut self, node: DeclareEntryGroup) -> DeclareEntryGroup {
+        fold_declare_entry_group(self, node)
+    }
+
+    fn fold_declare_statement(&mut self, node: DeclareStatement) -> DeclareStatement {
+        fold_declare_statement(self, node)
+    }
+
+    fn fold_default_match_arm(&mut self, node: DefaultMatchArm) -> DefaultMatchArm {
+        fold_default_match_arm(self, node)
+    }
+
+    fn fold_die_expression(&mut self, node: DieExpression) -> DieExpression {
+        fold_die_expression(self, node)
+    }
+
+    fn fold_do_while_statement(&mut self, node: DoWhileStatement) -> DoWhileStatement {
+        fold_do_while_statement(self, node)
+    }
+
+    fn fold_dynamic_identifier(&mut self, node: DynamicIdentifier) -> DynamicIdentifier {
+        fold_dynamic_identifier(self, node)
+    }
+
+    fn fold_echo_opening_tag_statement(
+        &mut self,
+        node: EchoOpeningTagStatement,
+    ) -> EchoOpeningTagStatement {
+        fold_echo_opening_tag_statement(self, node)
+    }
+
+    fn fold_echo_statement(&mut self, node: EchoStatement) -> EchoStatement {
+        fold_echo_statement(self, node)
+    }
+
+    fn fold_empty_expression(&mut self, node: EmptyExpression) -> EmptyExpression {
+        fold_empty_expression(self, node)
+    }
+
+    fn fold_ending(&mut self, node: Ending) -> Ending {
+        fold_ending(self, node)
+    }
+
+    fn fold_error_suppress_expression(
+        &mut self,
+        node: ErrorSuppressExpression,
+    ) -> ErrorSuppressExpression {
+        fold_error_suppress_expression(self, node)
+    }
+
+    fn fold_eval_expression(&mut self, node: EvalExpression) -> EvalExpression {
+        fold_eval_expression(self, node)
+    }
+
+    fn fold_exit_expression(&mut self, node: ExitExpression) -> ExitExpression {
+        fold_exit_expression(self, node)
+    }
+
+    fn fold_expression(&mut self, node: Expression) -> Expression {
+        fold_expression(self, node)
+    }
+
+    fn fold_expression_statement(&mut self, node: ExpressionStatement) -> ExpressionStatement {
+        fold_expression_statement(self, node)
+    }
+
+    fn fold_expression_string_part(&mut self, node: ExpressionStringPart) -> ExpressionStringPart {
+        fold_expression_string_part(self, node)
+    }
+
+    fn fold_finally_block(&mut self, node: FinallyBlock) -> FinallyBlock {
+        fold_finally_block(self, node)
+    }
+
+    fn fold_for_statement(&mut self, node: ForStatement) -> ForStatement {
+        fold_for_statement(self, node)
+    }
+
+    fn fold_for_statement_body(&mut self, node: ForStatementBody) -> ForStatementBody {
+        fold_for_statement_body(self, node)
+    }
+
+    fn fold_for_statement_iterator(&mut self, node: ForStatementIterator) -> ForStatementIterator {
+        fold_for_statement_iterator(self, node)
+    }
+
+    fn fold_foreach_statement(&mut self, node: ForeachStatement) -> ForeachStatement {
+        fold_foreach_statement(self, node)
+    }
+
+    fn fold_foreach_statement_body(&mut self, node: ForeachStatementBody) -> ForeachStatementBody {
+        fold_foreach_statement_body(self, node)
+    }
+
+    fn fold_foreach_statement_iterator(
+        &mut self,
+        node: ForeachStatementIterator,
+    ) -> ForeachStatementIterator {
+        fold_foreach_statement_iterator(self, node)
+    }
+
+    fn fold_full_opening_tag_statement(
+        &mut self,
+        node: FullOpeningTagStatement,
+    ) -> FullOpeningTagStatement {
+        fold_full_opening_tag_statement(self, node)
+    }
+
+    fn fold_function_body(&mut self, node: FunctionBody) -> FunctionBody {
+        fold_function_body(self, node)
+    }
+
+    fn fold_function_call_expression(
+        &mut self,
+        node: FunctionCallExpression,
+    ) -> FunctionCallExpression {
+        fold_function_call_expression(self, node)
+    }
+
+    fn fold_function_closure_creation_expression(
+        &mut self,
+        node: FunctionClosureCreationExpression,
+    ) -> FunctionClosureCreationExpression {
+        fold_function_closure_creation_expression(self, node)
+    }
+
+    fn fold_function_parameter(&mut self, node: FunctionParameter) -> FunctionParameter {
+        fold_function_parameter(self, node)
+    }
+
+    fn fold_function_parameter_list(
+        &mut self,
+        node: FunctionParameterList,
+    ) -> FunctionParameterList {
+        fold_function_parameter_list(self, node)
+    }
+
+    fn fold_function_statement(&mut self, node: FunctionStatement) -> FunctionStatement {
+        fold_function_statement(self, node)
+    }
+
+    fn fold_global_statement(&mut self, node: GlobalStatement) -> GlobalStatement {
+        fold_global_statement(self, node)
+    }
+
+    fn fold_goto_statement(&mut self, node: GotoStatement) -> GotoStatement {
+        fold_goto_statement(self, node)
+    }
+
+    fn fold_group_use_statement(&mut self, node: GroupUseStatement) -> GroupUseStatement {
+        fold_group_use_statement(self, node)
+    }
+
+    fn fold_halt_compiler_statement(
+        &mut self,
+        node: HaltCompilerStatement,
+    ) -> HaltCompilerStatement {
+        fold_halt_compiler_statement(self, node)
+    }
+
+    fn fold_heredoc_expression(&mut self, node: HeredocExpression) -> HeredocExpression {
+        fold_heredoc_expression(self, node)
+    }
+
+    fn fold_identifier(&mut self, node: Identifier) -> Identifier {
+        fold_identifier(self, node)
+    }
+
+    fn fold_if_statement(&mut self, node: IfStatement) -> IfStatement {
+        fold_if_statement(self, node)
+    }
+
+    fn fold_if_statement_body(&mut self, node: IfStatementBody) -> IfStatementBody {
+        fold_if_statement_body(self, node)
+    }
+
+    fn fold_if_statement_else(&mut self, node: IfStatementElse) -> IfStatementElse {
+        fold_if_statement_else(self, node)
+    }
+
+    fn fold_if_statement_else_block(&mut self, node: IfStatementElseBlock) -> IfStatementElseBlock {
+        fold_if_statement_else_block(self, node)
+    }
+
+    fn fold_if_statement_else_if(&mut self, node: IfStatementElseIf) -> IfStatementElseIf {
+        fold_if_statement_else_if(self, node)
+    }
+
+    fn fold_if_statement_else_if_block(
+        &mut self,
+        node: IfStatementElseIfBlock,
+    ) -> IfStatementElseIfBlock {
+        fold_if_statement_else_if_block(self, node)
+    }
+
+    fn fold_include_expression(&mut self, node: IncludeExpression) -> IncludeExpression {
+        fold_include_expression(self, node)
+    }
+
+    fn fold_include_once_expression(
+        &mut self,
+        node: IncludeOnceExpression,
+    ) -> IncludeOnceExpression {
+        fold_include_once_expression(self, node)
+    }
+
+    fn fold_inline_html_statement(&mut self, node: InlineHtmlStatement) -> InlineHtmlStatement {
+        fold_inline_html_statement(self, node)
+    }
+
+    fn fold_instanceof_expression(&mut self, node: InstanceofExpression) -> InstanceofExpression {
+        fold_instanceof_expression(self, node)
+    }
+
+    fn fold_interface_body(&mut self, node: InterfaceBody) -> InterfaceBody {
+        fold_interface_body(self, node)
+    }
+
+    fn fold_interface_extends(&mut self, node: InterfaceExtends) -> InterfaceExtends {
+        fold_interface_extends(self, node)
+    }
+
+    fn fold_interface_member(&mut self, node: InterfaceMember) -> InterfaceMember {
+        fold_interface_member(self, node)
+    }
+
+    fn fold_interface_statement(&mut self, node: InterfaceStatement) -> InterfaceStatement {
+        fold_interface_statement(self, node)
+    }
+
+    fn fold_interpolated_string_expression(
+        &mut self,
+        node: InterpolatedStringExpression,
+    ) -> InterpolatedStringExpression {
+        fold_interpolated_string_expression(self, node)
+    }
+
+    fn fold_isset_expression(&mut self, node: IssetExpression) -> IssetExpression {
+        fold_isset_expression(self, node)
+    }
+
+    fn fold_label_statement(&mut self, node: LabelStatement) -> LabelStatement {
+        fold_label_statement(self, node)
+    }
+
+    fn fold_level(&mut self, node: Level) -> Level {
+        fold_level(self, node)
+    }
+
+    fn fold_list_entry(&mut self, node: ListEntry) -> ListEntry {
+        fold_list_entry(self, node)
+    }
+
+    fn fold_list_expression(&mut self, node: ListExpression) -> ListExpression {
+        fold_list_expression(self, node)
+    }
+
+    fn fold_literal(&mut self, node: Literal) -> Literal {
+        fold_literal(self, node)
+    }
+
+    fn fold_literal_float(&mut self, node: LiteralFloat) -> LiteralFloat {
+        fold_literal_float(self, node)
+    }
+
+    fn fold_literal_integer(&mut self, node: LiteralInteger) -> LiteralInteger {
+        fold_literal_integer(self, node)
+    }
+
+    fn fold_literal_string(&mut self, node: LiteralString) -> LiteralString {
+        fold_literal_string(self, node)
+    }
+
+    fn fold_literal_string_part(&mut self, node: LiteralStringPart) -> LiteralStringPart {
+        fold_literal_string_part(self, node)
+    }
+
+    fn fold_logical_operation_expression(
+        &mut self,
+        node: LogicalOperationExpression,
+    ) -> LogicalOperationExpression {
+        fold_logical_operation_expression(self, node)
+    }
+
+    fn fold_magic_constant_expression(
+        &mut self,
+        node: MagicConstantExpression,
+    ) -> MagicConstantExpression {
+        fold_magic_constant_expression(self, node)
+    }
+
+    fn fold_match_arm(&mut self, node: MatchArm) -> MatchArm {
+        fold_match_arm(self, node)
+    }
+
+    fn fold_match_expression(&mut self, node: MatchExpression) -> MatchExpression {
+        fold_match_expression(self, node)
+    }
+
+    fn fold_method_body(&mut self, node: MethodBody) -> MethodBody {
+        fold_method_body(self, node)
+    }
+
+    fn fold_method_call_expression(&mut self, node: MethodCallExpression) -> MethodCallExpression {
+        fold_method_call_expression(self, node)
+    }
+
+    fn fold_method_closure_creation_expression(
+        &mut self,
+        node: MethodClosureCreationExpression,
+    ) -> MethodClosureCreationExpression {
+        fold_method_closure_creation_expression(self, node)
+    }
+
+    fn fold_method_modifier(&mut self, node: MethodModifier) -> MethodModifier {
+        fold_method_modifier(self, node)
+    }
+
+    fn fold_method_modifier_group(&mut self, node: MethodModifierGroup) -> MethodModifierGroup {
+        fold_method_modifier_group(self, node)
+    }
+
+    fn fold_named_argument(&mut self, node: NamedArgument) -> NamedArgument {
+        fold_named_argument(self, node)
+    }
+
+    fn fold_namespace_statement(&mut self, node: NamespaceStatement) -> NamespaceStatement {
+        fold_namespace_statement(self, node)
+    }
+
+    fn fold_new_expression(&mut self, node: NewExpression) -> NewExpression {
+        fold_new_expression(self, node)
+    }
+
+    fn fold_nowdoc_expression(&mut self, node: NowdocExpression) -> NowdocExpression {
+        fold_nowdoc_expression(self, node)
+    }
+
+    fn fold_nullsafe_method_call_expression(
+        &mut self,
+        node: NullsafeMethodCallExpression,
+    ) -> NullsafeMethodCallExpression {
+        fold_nullsafe_method_call_expression(self, node)
+    }
+
+    fn fold_nullsafe_property_fetch_expression(
+        &mut self,
+        node: NullsafePropertyFetchExpression,
+    ) -> NullsafePropertyFetchExpression {
+        fold_nullsafe_property_fetch_expression(self, node)
+    }
+
+    fn fold_parenthesized_expression(
+        &mut self,
+        node: ParenthesizedExpression,
+    ) -> ParenthesizedExpression {
+        fold_parenthesized_expression(self, node)
+    }
+
+    fn fold_positional_argument(&mut self, node: PositionalArgument) -> PositionalArgument {
+        fold_positional_argument(self, node)
+    }
+
+    fn fold_print_expression(&mut self, node: PrintExpression) -> PrintExpression {
+        fold_print_expression(self, node)
+    }
+
+    fn fold_promoted_property_modifier(
+        &mut self,
+        node: PromotedPropertyModifier,
+    ) -> PromotedPropertyModifier {
+        fold_promoted_property_modifier(self, node)
+    }
+
+    fn fold_promoted_property_modifier_group(
+        &mut self,
+        node: PromotedPropertyModifierGroup,
+    ) -> PromotedPropertyModifierGroup {
+        fold_promoted_property_modifier_group(self, node)
+    }
+
+    fn fold_property(&mut self, node: Property) -> Property {
+        fold_property(self, node)
+    }
+
+    fn fold_property_entry(&mut self, node: PropertyEntry) -> PropertyEntry {
+        fold_property_entry(self, node)
+    }
+
+    fn fold_property_fetch_expression(
+        &mut self,
+        node: PropertyFetchExpression,
+    ) -> PropertyFetchExpression {
+        fold_property_fetch_expression(self, node)
+    }
+
+    fn fold_property_modifier(&mut self, node: PropertyModifier) -> PropertyModifier {
+        fold_property_modifier(self, node)
+    }
+
+    fn fold_property_modifier_group(
+        &mut self,
+        node: PropertyModifierGroup,
+    ) -> PropertyModifierGroup {
+        fold_property_modifier_group(self, node)
+    }
+
+    fn fold_reference_expression(&mut self, node: ReferenceExpression) -> ReferenceExpression {
+        fold_reference_expression(self, node)
+    }
+
+    fn fold_require_expression(&mut self, node: RequireExpression) -> RequireExpression {
+        fold_require_expression(self, node)
+    }
+
+    fn fold_require_once_expression(
+        &mut self,
+        node: RequireOnceExpression,
+    ) -> RequireOnceExpression {
+        fold_require_once_expression(self, node)
+    }
+
+    fn fold_return_statement(&mut self, node: ReturnStatement) -> ReturnStatement {
+        fold_return_statement(self, node)
+    }
+
+    fn fold_return_type(&mut self, node: ReturnType) -> ReturnType {
+        fold_return_type(self, node)
+    }
+
+    fn fold_shell_exec_expression(&mut self, node: ShellExecExpression) -> ShellExecExpression {
+        fold_shell_exec_expression(self, node)
+    }
+
+    fn fold_short_array_expression(&mut self, node: ShortArrayExpression) -> ShortArrayExpression {
+        fold_short_array_expression(self, node)
+    }
+
+    fn fold_short_opening_tag_statement(
+        &mut self,
+        node: ShortOpeningTagStatement,
+    ) -> ShortOpeningTagStatement {
+        fold_short_opening_tag_statement(self, node)
+    }
+
+    fn fold_short_ternary_expression(
+        &mut self,
+        node: ShortTernaryExpression,
+    ) -> ShortTernaryExpression {
+        fold_short_ternary_expression(self, node)
+    }
+
+    fn fold_simple_identifier(&mut self, node: SimpleIdentifier) -> SimpleIdentifier {
+        fold_simple_identifier(self, node)
+    }
+
+    fn fold_simple_variable(&mut self, node: SimpleVariable) -> SimpleVariable {
+        fold_simple_variable(self, node)
+    }
+
+    fn fold_single_argument(&mut self, node: SingleArgument) -> SingleArgument {
+        fold_single_argument(self, node)
+    }
+
+    fn fold_statement(&mut self, node: Statement) -> Statement {
+        fold_statement(self, node)
+    }
+
+    fn fold_static_method_call_expression(
+        &mut self,
+        node: StaticMethodCallExpression,
+    ) -> StaticMethodCallExpression {
+        fold_static_method_call_expression(self, node)
+    }
+
+    fn fold_static_method_closure_creation_expression(
+        &mut self,
+        node: StaticMethodClosureCreationExpression,
+    ) -> StaticMethodClosureCreationExpression {
+        fold_static_method_closure_creation_expression(self, node)
+    }
+
+    fn fold_static_property_fetch_expression(
+        &mut self,
+        node: StaticPropertyFetchExpression,
+    ) -> StaticPropertyFetchExpression {
+        fold_static_property_fetch_expression(self, node)
+    }
+
+    fn fold_static_statement(&mut self, node: StaticStatement) -> StaticStatement {
+        fold_static_statement(self, node)
+    }
+
+    fn fold_static_var(&mut self, node: StaticVar) -> StaticVar {
+        fold_static_var(self, node)
+    }
+
+    fn fold_static_variable_method_call_expression(
+        &mut self,
+        node: StaticVariableMethodCallExpression,
+    ) -> StaticVariableMethodCallExpression {
+        fold_static_variable_method_call_expression(self, node)
+    }
+
+    fn fold_static_variable_method_closure_creation_expression(
+        &mut self,
+        node: StaticVariableMethodClosureCreationExpression,
+    ) -> StaticVariableMethodClosureCreationExpression {
+        fold_static_variable_method_closure_creation_expression(self, node)
+    }
+
+    fn fold_string_part(&mut self, node: StringPart) -> StringPart {
+        fold_string_part(self, node)
+    }
+
+    fn fold_switch_statement(&mut self, node: SwitchStatement) -> SwitchStatement {
+        fold_switch_statement(self, node)
+    }
+
+    fn fold_ternary_expression(&mut self, node: TernaryExpression) -> TernaryExpression {
+        fold_ternary_expression(self, node)
+    }
+
+    fn fold_throw_expression(&mut self, node: ThrowExpression) -> ThrowExpression {
+        fold_throw_expression(self, node)
+    }
+
+    fn fold_trait_body(&mut self, node: TraitBody) -> TraitBody {
+        fold_trait_body(self, node)
+    }
+
+    fn fold_trait_member(&mut self, node: TraitMember) -> TraitMember {
+        fold_trait_member(self, node)
+    }
+
+    fn fold_trait_statement(&mut self, node: TraitStatement) -> TraitStatement {
+        fold_trait_statement(self, node)
+    }
+
+    fn fold_trait_usage(&mut self, node: TraitUsage) -> TraitUsage {
+        fold_trait_usage(self, node)
+    }
+
+    fn fold_trait_usage_adaptation(&mut self, node: TraitUsageAdaptation) -> TraitUsageAdaptation {
+        fold_trait_usage_adaptation(self, node)
+    }
+
+    fn fold_try_statement(&mut self, node: TryStatement) -> TryStatement {
+        fold_try_statement(self, node)
+    }
+
+    fn fold_type(&mut self, node: Type) -> Type {
+        fold_type(self, node)
+    }
+
+    fn fold_unbraced_namespace(&mut self, node: UnbracedNamespace) -> UnbracedNamespace {
+        fold_unbraced_namespace(self, node)
+    }
+
+    fn fold_unit_enum_body(&mut self, node: UnitEnumBody) -> UnitEnumBody {
+        fold_unit_enum_body(self, node)
+    }
+
+    fn fold_unit_enum_case(&mut self, node: UnitEnumCase) -> UnitEnumCase {
+        fold_unit_enum_case(self, node)
+    }
+
+    fn fold_unit_enum_member(&mut self, node: UnitEnumMember) -> UnitEnumMember {
+        fold_unit_enum_member(self, node)
+    }
+
+    fn fold_unit_enum_statement(&mut self, node: UnitEnumStatement) -> UnitEnumStatement {
+        fold_unit_enum_statement(self, node)
+    }
+
+    fn fold_unset_expression(&mut self, node: UnsetExpression) -> UnsetExpression {
+        fold_unset_expression(self, node)
+    }
+
+    fn fold_use(&mut self, node: Use) -> Use {
+        fold_use(self, node)
+    }
+
+    fn fold_use_statement(&mut self, node: UseStatement) -> UseStatement {
+        fold_use_statement(self, node)
+    }
+
+    fn fold_variable(&mut self, node: Variable) -> Variable {
+        fold_variable(self, node)
+    }
+
+    fn fold_variable_property(&mut self, node: VariableProperty) -> VariableProperty {
+        fold_variable_property(self, node)
+    }
+
+    fn fold_variable_variable(&mut self, node: VariableVariable) -> VariableVariable {
+        fold_variable_variable(self, node)
+    }
+
+    fn fold_visibility_modifier(&mut self, node: VisibilityModifier) -> VisibilityModifier {
+        fold_visibility_modifier(self, node)
+    }
+
+    fn fold_while_statement(&mut self, node: WhileStatement) -> WhileStatement {
+        fold_while_statement(self, node)
+    }
+
+    fn fold_while_statement_body(&mut self, node: WhileStatementBody) -> WhileStatementBody {
+        fold_while_statement_body(self, node)
+    }
+
+    fn fold_yield_expression(&mut self, node: YieldExpression) -> YieldExpression {
+        fold_yield_expression(self, node)
+    }
+
+    fn fold_yield_from_expression(&mut self, node: YieldFromExpression) -> YieldFromExpression {
+        fold_yield_from_expression(self, node)
+    }
+}
+
+pub fn fold_block<F: Fold + ?Sized>(folder: &mut F, node: Block) -> Block {
+    node.into_iter()
+        .flat_map(|statement| folder.fold_statements(statement))
+        .collect()
+}
+
+pub fn fold_abstract_constructor<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AbstractConstructor,
+) -> AbstractConstructor {
+    AbstractConstructor {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_method_modifier_group(node.modifiers),
+        function: node.function,
+        ampersand: node.ampersand,
+        name: folder.fold_simple_identifier(node.name),
+        parameters: folder.fold_function_parameter_list(node.parameters),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_abstract_method<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AbstractMethod,
+) -> AbstractMethod {
+    AbstractMethod {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_method_modifier_group(node.modifiers),
+        function: node.function,
+        ampersand: node.ampersand,
+        name: folder.fold_simple_identifier(node.name),
+        parameters: folder.fold_function_parameter_list(node.parameters),
+        return_type: node.return_type.map(|item| folder.fold_return_type(item)),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_anonymous_class_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AnonymousClassBody,
+) -> AnonymousClassBody {
+    AnonymousClassBody {
+        left_brace: node.left_brace,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_anonymous_class_member(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_anonymous_class_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AnonymousClassExpression,
+) -> AnonymousClassExpression {
+    AnonymousClassExpression {
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        class: node.class,
+        extends: node.extends.map(|item| folder.fold_class_extends(item)),
+        implements: node
+            .implements
+            .map(|item| folder.fold_class_implements(item)),
+        body: folder.fold_anonymous_class_body(node.body),
+    }
+}
+
+pub fn fold_anonymous_class_member<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AnonymousClassMember,
+) -> AnonymousClassMember {
+    match node {
+        AnonymousClassMember::Constant(node) => {
+            AnonymousClassMember::Constant(folder.fold_classish_constant(node))
+        }
+        AnonymousClassMember::TraitUsage(node) => {
+            AnonymousClassMember::TraitUsage(folder.fold_trait_usage(node))
+        }
+        AnonymousClassMember::Property(node) => {
+            AnonymousClassMember::Property(folder.fold_property(node))
+        }
+        AnonymousClassMember::VariableProperty(node) => {
+            AnonymousClassMember::VariableProperty(folder.fold_variable_property(node))
+        }
+        AnonymousClassMember::ConcreteMethod(node) => {
+            AnonymousClassMember::ConcreteMethod(folder.fold_concrete_method(node))
+        }
+        AnonymousClassMember::ConcreteConstructor(node) => {
+            AnonymousClassMember::ConcreteConstructor(folder.fold_concrete_constructor(node))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_argument<F: Fold + ?Sized>(folder: &mut F, node: Argument) -> Argument {
+    match node {
+        Argument::Positional(node) => Argument::Positional(folder.fold_positional_argument(node)),
+        Argument::Named(node) => Argument::Named(folder.fold_named_argument(node)),
+    }
+}
+
+pub fn fold_argument_list<F: Fold + ?Sized>(folder: &mut F, node: ArgumentList) -> ArgumentList {
+    ArgumentList {
+        comments: folder.fold_comment_group(node.comments),
+        left_parenthesis: node.left_parenthesis,
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|item| folder.fold_argument(item))
+            .collect(),
+        right_parenthesis: node.right_parenthesis,
+    }
+}
+
+pub fn fold_argument_placeholder<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ArgumentPlaceholder,
+) -> ArgumentPlaceholder {
+    ArgumentPlaceholder {
+        comments: folder.fold_comment_group(node.comments),
+        left_parenthesis: node.left_parenthesis,
+        ellipsis: node.ellipsis,
+        right_parenthesis: node.right_parenthesis,
+    }
+}
+
+pub fn fold_arithmetic_operation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ArithmeticOperationExpression,
+) -> ArithmeticOperationExpression {
+    match node {
+        ArithmeticOperationExpression::Addition { left, plus, right } => {
+            ArithmeticOperationExpression::Addition {
+                left: Box::new(folder.fold_expression(*left)),
+                plus,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::Subtraction { left, minus, right } => {
+            ArithmeticOperationExpression::Subtraction {
+                left: Box::new(folder.fold_expression(*left)),
+                minus,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::Multiplication {
+            left,
+            asterisk,
+            right,
+        } => ArithmeticOperationExpression::Multiplication {
+            left: Box::new(folder.fold_expression(*left)),
+            asterisk,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ArithmeticOperationExpression::Division { left, slash, right } => {
+            ArithmeticOperationExpression::Division {
+                left: Box::new(folder.fold_expression(*left)),
+                slash,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::Modulo {
+            left,
+            percent,
+            right,
+        } => ArithmeticOperationExpression::Modulo {
+            left: Box::new(folder.fold_expression(*left)),
+            percent,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ArithmeticOperationExpression::Exponentiation { left, pow, right } => {
+            ArithmeticOperationExpression::Exponentiation {
+                left: Box::new(folder.fold_expression(*left)),
+                pow,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::Negative { minus, right } => {
+            ArithmeticOperationExpression::Negative {
+                minus,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::Positive { plus, right } => {
+            ArithmeticOperationExpression::Positive {
+                plus,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::PreIncrement { increment, right } => {
+            ArithmeticOperationExpression::PreIncrement {
+                increment,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::PostIncrement { left, increment } => {
+            ArithmeticOperationExpression::PostIncrement {
+                left: Box::new(folder.fold_expression(*left)),
+                increment,
+            }
+        }
+        ArithmeticOperationExpression::PreDecrement { decrement, right } => {
+            ArithmeticOperationExpression::PreDecrement {
+                decrement,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        ArithmeticOperationExpression::PostDecrement { left, decrement } => {
+            ArithmeticOperationExpression::PostDecrement {
+                left: Box::new(folder.fold_expression(*left)),
+                decrement,
+            }
+        }
+    }
+}
+
+pub fn fold_array_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ArrayExpression,
+) -> ArrayExpression {
+    ArrayExpression {
+        array: node.array,
+        start: node.start,
+        items: CommaSeparated {
+            inner: node
+                .items
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_array_item(item))
+                .collect(),
+            commas: node.items.commas,
+        },
+        end: node.end,
+    }
+}
+
+pub fn fold_array_index_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ArrayIndexExpression,
+) -> ArrayIndexExpression {
+    ArrayIndexExpression {
+        array: Box::new(folder.fold_expression(*node.array)),
+        left_bracket: node.left_bracket,
+        index: node
+            .index
+            .map(|item| Box::new(folder.fold_expression(*item))),
+        right_bracket: node.right_bracket,
+    }
+}
+
+pub fn fold_array_item<F: Fold + ?Sized>(folder: &mut F, node: ArrayItem) -> ArrayItem {
+    match node {
+        ArrayItem::Value { value } => ArrayItem::Value {
+            value: folder.fold_expression(value),
+        },
+        ArrayItem::ReferencedValue { ampersand, value } => ArrayItem::ReferencedValue {
+            ampersand,
+            value: folder.fold_expression(value),
+        },
+        ArrayItem::SpreadValue { ellipsis, value } => ArrayItem::SpreadValue {
+            ellipsis,
+            value: folder.fold_expression(value),
+        },
+        ArrayItem::KeyValue {
+            key,
+            double_arrow,
+            value,
+        } => ArrayItem::KeyValue {
+            key: folder.fold_expression(key),
+            double_arrow,
+            value: folder.fold_expression(value),
+        },
+        ArrayItem::ReferencedKeyValue {
+            key,
+            double_arrow,
+            ampersand,
+            value,
+        } => ArrayItem::ReferencedKeyValue {
+            key: folder.fold_expression(key),
+            double_arrow,
+            ampersand,
+            value: folder.fold_expression(value),
+        },
+        node => node,
+    }
+}
+
+pub fn fold_arrow_function_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ArrowFunctionExpression,
+) -> ArrowFunctionExpression {
+    ArrowFunctionExpression {
+        comments: folder.fold_comment_group(node.comments),
+        r#static: node.r#static,
+        ampersand: node.ampersand,
+        r#fn: node.r#fn,
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        parameters: folder.fold_function_parameter_list(node.parameters),
+        return_type: node.return_type.map(|item| folder.fold_return_type(item)),
+        double_arrow: node.double_arrow,
+        body: Box::new(folder.fold_expression(*node.body)),
+    }
+}
+
+pub fn fold_assignment_operation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AssignmentOperationExpression,
+) -> AssignmentOperationExpression {
+    match node {
+        AssignmentOperationExpression::Assign {
+            left,
+            equals,
+            right,
+        } => AssignmentOperationExpression::Assign {
+            left: Box::new(folder.fold_expression(*left)),
+            equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Addition {
+            left,
+            plus_equals,
+            right,
+        } => AssignmentOperationExpression::Addition {
+            left: Box::new(folder.fold_expression(*left)),
+            plus_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Subtraction {
+            left,
+            minus_equals,
+            right,
+        } => AssignmentOperationExpression::Subtraction {
+            left: Box::new(folder.fold_expression(*left)),
+            minus_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Multiplication {
+            left,
+            asterisk_equals,
+            right,
+        } => AssignmentOperationExpression::Multiplication {
+            left: Box::new(folder.fold_expression(*left)),
+            asterisk_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Division {
+            left,
+            slash_equals,
+            right,
+        } => AssignmentOperationExpression::Division {
+            left: Box::new(folder.fold_expression(*left)),
+            slash_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Modulo {
+            left,
+            percent_equals,
+            right,
+        } => AssignmentOperationExpression::Modulo {
+            left: Box::new(folder.fold_expression(*left)),
+            percent_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Exponentiation {
+            left,
+            pow_equals,
+            right,
+        } => AssignmentOperationExpression::Exponentiation {
+            left: Box::new(folder.fold_expression(*left)),
+            pow_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Concat {
+            left,
+            dot_equals,
+            right,
+        } => AssignmentOperationExpression::Concat {
+            left: Box::new(folder.fold_expression(*left)),
+            dot_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::BitwiseAnd {
+            left,
+            ampersand_equals,
+            right,
+        } => AssignmentOperationExpression::BitwiseAnd {
+            left: Box::new(folder.fold_expression(*left)),
+            ampersand_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::BitwiseOr {
+            left,
+            pipe_equals,
+            right,
+        } => AssignmentOperationExpression::BitwiseOr {
+            left: Box::new(folder.fold_expression(*left)),
+            pipe_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::BitwiseXor {
+            left,
+            caret_equals,
+            right,
+        } => AssignmentOperationExpression::BitwiseXor {
+            left: Box::new(folder.fold_expression(*left)),
+            caret_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::LeftShift {
+            left,
+            left_shift_equals,
+            right,
+        } => AssignmentOperationExpression::LeftShift {
+            left: Box::new(folder.fold_expression(*left)),
+            left_shift_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::RightShift {
+            left,
+            right_shift_equals,
+            right,
+        } => AssignmentOperationExpression::RightShift {
+            left: Box::new(folder.fold_expression(*left)),
+            right_shift_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        AssignmentOperationExpression::Coalesce {
+            left,
+            coalesce_equals,
+            right,
+        } => AssignmentOperationExpression::Coalesce {
+            left: Box::new(folder.fold_expression(*left)),
+            coalesce_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+    }
+}
+
+pub fn fold_attribute<F: Fold + ?Sized>(folder: &mut F, node: Attribute) -> Attribute {
+    Attribute {
+        start: node.start,
+        end: node.end,
+        name: folder.fold_simple_identifier(node.name),
+        arguments: node.arguments.map(|item| folder.fold_argument_list(item)),
+    }
+}
+
+pub fn fold_attribute_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AttributeGroup,
+) -> AttributeGroup {
+    AttributeGroup {
+        start: node.start,
+        end: node.end,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_attribute(item))
+            .collect(),
+    }
+}
+
+pub fn fold_backed_enum_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BackedEnumBody,
+) -> BackedEnumBody {
+    BackedEnumBody {
+        left_brace: node.left_brace,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_backed_enum_member(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_backed_enum_case<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BackedEnumCase,
+) -> BackedEnumCase {
+    BackedEnumCase {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        case: node.case,
+        name: folder.fold_simple_identifier(node.name),
+        equals: node.equals,
+        value: folder.fold_expression(node.value),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_backed_enum_member<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BackedEnumMember,
+) -> BackedEnumMember {
+    match node {
+        BackedEnumMember::Case(node) => BackedEnumMember::Case(folder.fold_backed_enum_case(node)),
+        BackedEnumMember::Method(node) => {
+            BackedEnumMember::Method(folder.fold_concrete_method(node))
+        }
+        BackedEnumMember::Constant(node) => {
+            BackedEnumMember::Constant(folder.fold_classish_constant(node))
+        }
+        BackedEnumMember::TraitUsage(node) => {
+            BackedEnumMember::TraitUsage(folder.fold_trait_usage(node))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_backed_enum_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BackedEnumStatement,
+) -> BackedEnumStatement {
+    BackedEnumStatement {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        r#enum: node.r#enum,
+        name: folder.fold_simple_identifier(node.name),
+        backed_type: folder.fold_backed_enum_type(node.backed_type),
+        implements: node
+            .implements
+            .into_iter()
+            .map(|item| folder.fold_simple_identifier(item))
+            .collect(),
+        body: folder.fold_backed_enum_body(node.body),
+    }
+}
+
+pub fn fold_backed_enum_type<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: BackedEnumType,
+) -> BackedEnumType {
+    node
+}
+
+pub fn fold_bitwise_operation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BitwiseOperationExpression,
+) -> BitwiseOperationExpression {
+    match node {
+        BitwiseOperationExpression::And { left, and, right } => BitwiseOperationExpression::And {
+            left: Box::new(folder.fold_expression(*left)),
+            and,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        BitwiseOperationExpression::Or { left, or, right } => BitwiseOperationExpression::Or {
+            left: Box::new(folder.fold_expression(*left)),
+            or,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        BitwiseOperationExpression::Xor { left, xor, right } => BitwiseOperationExpression::Xor {
+            left: Box::new(folder.fold_expression(*left)),
+            xor,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        BitwiseOperationExpression::LeftShift {
+            left,
+            left_shift,
+            right,
+        } => BitwiseOperationExpression::LeftShift {
+            left: Box::new(folder.fold_expression(*left)),
+            left_shift,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        BitwiseOperationExpression::RightShift {
+            left,
+            right_shift,
+            right,
+        } => BitwiseOperationExpression::RightShift {
+            left: Box::new(folder.fold_expression(*left)),
+            right_shift,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        BitwiseOperationExpression::Not { not, right } => BitwiseOperationExpression::Not {
+            not,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+    }
+}
+
+pub fn fold_block_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BlockStatement,
+) -> BlockStatement {
+    BlockStatement {
+        left_brace: node.left_brace,
+        statements: folder.fold_block(node.statements),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_bool_expression<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: BoolExpression,
+) -> BoolExpression {
+    node
+}
+
+pub fn fold_braced_namespace<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BracedNamespace,
+) -> BracedNamespace {
+    BracedNamespace {
+        namespace: node.namespace,
+        name: node.name.map(|item| folder.fold_simple_identifier(item)),
+        body: folder.fold_braced_namespace_body(node.body),
+    }
+}
+
+pub fn fold_braced_namespace_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BracedNamespaceBody,
+) -> BracedNamespaceBody {
+    BracedNamespaceBody {
+        start: node.start,
+        end: node.end,
+        statements: folder.fold_block(node.statements),
+    }
+}
+
+pub fn fold_braced_variable_variable<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BracedVariableVariable,
+) -> BracedVariableVariable {
+    BracedVariableVariable {
+        start: node.start,
+        variable: Box::new(folder.fold_expression(*node.variable)),
+        end: node.end,
+    }
+}
+
+pub fn fold_break_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BreakStatement,
+) -> BreakStatement {
+    BreakStatement {
+        r#break: node.r#break,
+        level: node.level.map(|item| folder.fold_level(item)),
+        ending: folder.fold_ending(node.ending),
+    }
+}
+
+pub fn fold_case<F: Fold + ?Sized>(folder: &mut F, node: Case) -> Case {
+    Case {
+        span: node.span,
+        condition: node.condition.map(|item| folder.fold_expression(item)),
+        body: folder.fold_block(node.body),
+    }
+}
+
+pub fn fold_cast_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: CastExpression,
+) -> CastExpression {
+    CastExpression {
+        cast: node.cast,
+        kind: node.kind,
+        value: Box::new(folder.fold_expression(*node.value)),
+    }
+}
+
+pub fn fold_catch_block<F: Fold + ?Sized>(folder: &mut F, node: CatchBlock) -> CatchBlock {
+    CatchBlock {
+        start: node.start,
+        end: node.end,
+        types: folder.fold_catch_type(node.types),
+        var: node.var.map(|item| folder.fold_simple_variable(item)),
+        body: folder.fold_block(node.body),
+    }
+}
+
+pub fn fold_catch_type<F: Fold + ?Sized>(folder: &mut F, node: CatchType) -> CatchType {
+    match node {
+        CatchType::Identifier { identifier } => CatchType::Identifier {
+            identifier: folder.fold_simple_identifier(identifier),
+        },
+        CatchType::Union { identifiers } => CatchType::Union {
+            identifiers: identifiers
+                .into_iter()
+                .map(|item| folder.fold_simple_identifier(item))
+                .collect(),
+        },
+    }
+}
+
+pub fn fold_class_body<F: Fold + ?Sized>(folder: &mut F, node: ClassBody) -> ClassBody {
+    ClassBody {
+        left_brace: node.left_brace,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_class_member(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_class_extends<F: Fold + ?Sized>(folder: &mut F, node: ClassExtends) -> ClassExtends {
+    ClassExtends {
+        extends: node.extends,
+        parent: folder.fold_simple_identifier(node.parent),
+    }
+}
+
+pub fn fold_class_implements<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ClassImplements,
+) -> ClassImplements {
+    ClassImplements {
+        implements: node.implements,
+        interfaces: CommaSeparated {
+            inner: node
+                .interfaces
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_simple_identifier(item))
+                .collect(),
+            commas: node.interfaces.commas,
+        },
+    }
+}
+
+pub fn fold_class_member<F: Fold + ?Sized>(folder: &mut F, node: ClassMember) -> ClassMember {
+    match node {
+        ClassMember::Constant(node) => ClassMember::Constant(folder.fold_classish_constant(node)),
+        ClassMember::TraitUsage(node) => ClassMember::TraitUsage(folder.fold_trait_usage(node)),
+        ClassMember::Property(node) => ClassMember::Property(folder.fold_property(node)),
+        ClassMember::VariableProperty(node) => {
+            ClassMember::VariableProperty(folder.fold_variable_property(node))
+        }
+        ClassMember::AbstractMethod(node) => {
+            ClassMember::AbstractMethod(folder.fold_abstract_method(node))
+        }
+        ClassMember::AbstractConstructor(node) => {
+            ClassMember::AbstractConstructor(folder.fold_abstract_constructor(node))
+        }
+        ClassMember::ConcreteMethod(node) => {
+            ClassMember::ConcreteMethod(folder.fold_concrete_method(node))
+        }
+        ClassMember::ConcreteConstructor(node) => {
+            ClassMember::ConcreteConstructor(folder.fold_concrete_constructor(node))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_class_modifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: ClassModifier,
+) -> ClassModifier {
+    node
+}
+
+pub fn fold_class_modifier_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ClassModifierGroup,
+) -> ClassModifierGroup {
+    ClassModifierGroup {
+        modifiers: node
+            .modifiers
+            .into_iter()
+            .map(|item| folder.fold_class_modifier(item))
+            .collect(),
+    }
+}
+
+pub fn fold_class_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ClassStatement,
+) -> ClassStatement {
+    ClassStatement {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_class_modifier_group(node.modifiers),
+        class: node.class,
+        name: folder.fold_simple_identifier(node.name),
+        extends: node.extends.map(|item| folder.fold_class_extends(item)),
+        implements: node
+            .implements
+            .map(|item| folder.fold_class_implements(item)),
+        body: folder.fold_class_body(node.body),
+    }
+}
+
+pub fn fold_classish_constant<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ClassishConstant,
+) -> ClassishConstant {
+    ClassishConstant {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_constant_modifier_group(node.modifiers),
+        r#const: node.r#const,
+        entries: node
+            .entries
+            .into_iter()
+            .map(|item| folder.fold_constant_entry(item))
+            .collect(),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_clone_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: CloneExpression,
+) -> CloneExpression {
+    CloneExpression {
+        clone: node.clone,
+        target: Box::new(folder.fold_expression(*node.target)),
+    }
+}
+
+pub fn fold_closing_tag_statement<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: ClosingTagStatement,
+) -> ClosingTagStatement {
+    node
+}
+
+pub fn fold_closure_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ClosureExpression,
+) -> ClosureExpression {
+    ClosureExpression {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        r#static: node.r#static,
+        function: node.function,
+        ampersand: node.ampersand,
+        parameters: folder.fold_function_parameter_list(node.parameters),
+        uses: node.uses.map(|item| folder.fold_closure_use(item)),
+        return_type: node.return_type.map(|item| folder.fold_return_type(item)),
+        body: folder.fold_function_body(node.body),
+    }
+}
+
+pub fn fold_closure_use<F: Fold + ?Sized>(folder: &mut F, node: ClosureUse) -> ClosureUse {
+    ClosureUse {
+        comments: folder.fold_comment_group(node.comments),
+        r#use: node.r#use,
+        left_parenthesis: node.left_parenthesis,
+        variables: CommaSeparated {
+            inner: node
+                .variables
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_closure_use_variable(item))
+                .collect(),
+            commas: node.variables.commas,
+        },
+        right_parenthesis: node.right_parenthesis,
+    }
+}
+
+pub fn fold_closure_use_variable<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ClosureUseVariable,
+) -> ClosureUseVariable {
+    ClosureUseVariable {
+        comments: folder.fold_comment_group(node.comments),
+        ampersand: node.ampersand,
+        variable: folder.fold_simple_variable(node.variable),
+    }
+}
+
+pub fn fold_coalesce_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: CoalesceExpression,
+) -> CoalesceExpression {
+    CoalesceExpression {
+        lhs: Box::new(folder.fold_expression(*node.lhs)),
+        double_question: node.double_question,
+        rhs: Box::new(folder.fold_expression(*node.rhs)),
+    }
+}
+
+pub fn fold_comment<F: Fold + ?Sized>(_folder: &mut F, node: Comment) -> Comment {
+    node
+}
+
+pub fn fold_comment_group<F: Fold + ?Sized>(folder: &mut F, node: CommentGroup) -> CommentGroup {
+    CommentGroup {
+        comments: node
+            .comments
+            .into_iter()
+            .map(|item| folder.fold_comment(item))
+            .collect(),
+    }
+}
+
+pub fn fold_comparison_operation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ComparisonOperationExpression,
+) -> ComparisonOperationExpression {
+    match node {
+        ComparisonOperationExpression::Equal {
+            left,
+            double_equals,
+            right,
+        } => ComparisonOperationExpression::Equal {
+            left: Box::new(folder.fold_expression(*left)),
+            double_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::Identical {
+            left,
+            triple_equals,
+            right,
+        } => ComparisonOperationExpression::Identical {
+            left: Box::new(folder.fold_expression(*left)),
+            triple_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::NotEqual {
+            left,
+            bang_equals,
+            right,
+        } => ComparisonOperationExpression::NotEqual {
+            left: Box::new(folder.fold_expression(*left)),
+            bang_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::AngledNotEqual {
+            left,
+            angled_left_right,
+            right,
+        } => ComparisonOperationExpression::AngledNotEqual {
+            left: Box::new(folder.fold_expression(*left)),
+            angled_left_right,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::NotIdentical {
+            left,
+            bang_double_equals,
+            right,
+        } => ComparisonOperationExpression::NotIdentical {
+            left: Box::new(folder.fold_expression(*left)),
+            bang_double_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::LessThan {
+            left,
+            less_than,
+            right,
+        } => ComparisonOperationExpression::LessThan {
+            left: Box::new(folder.fold_expression(*left)),
+            less_than,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::GreaterThan {
+            left,
+            greater_than,
+            right,
+        } => ComparisonOperationExpression::GreaterThan {
+            left: Box::new(folder.fold_expression(*left)),
+            greater_than,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::LessThanOrEqual {
+            left,
+            less_than_equals,
+            right,
+        } => ComparisonOperationExpression::LessThanOrEqual {
+            left: Box::new(folder.fold_expression(*left)),
+            less_than_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::GreaterThanOrEqual {
+            left,
+            greater_than_equals,
+            right,
+        } => ComparisonOperationExpression::GreaterThanOrEqual {
+            left: Box::new(folder.fold_expression(*left)),
+            greater_than_equals,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        ComparisonOperationExpression::Spaceship {
+            left,
+            spaceship,
+            right,
+        } => ComparisonOperationExpression::Spaceship {
+            left: Box::new(folder.fold_expression(*left)),
+            spaceship,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+    }
+}
+
+pub fn fold_concat_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConcatExpression,
+) -> ConcatExpression {
+    ConcatExpression {
+        left: Box::new(folder.fold_expression(*node.left)),
+        dot: node.dot,
+        right: Box::new(folder.fold_expression(*node.right)),
+    }
+}
+
+pub fn fold_concrete_constructor<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConcreteConstructor,
+) -> ConcreteConstructor {
+    ConcreteConstructor {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_method_modifier_group(node.modifiers),
+        function: node.function,
+        ampersand: node.ampersand,
+        name: folder.fold_simple_identifier(node.name),
+        parameters: folder.fold_constructor_parameter_list(node.parameters),
+        body: folder.fold_method_body(node.body),
+    }
+}
+
+pub fn fold_concrete_method<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConcreteMethod,
+) -> ConcreteMethod {
+    ConcreteMethod {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_method_modifier_group(node.modifiers),
+        function: node.function,
+        ampersand: node.ampersand,
+        name: folder.fold_simple_identifier(node.name),
+        parameters: folder.fold_function_parameter_list(node.parameters),
+        return_type: node.return_type.map(|item| folder.fold_return_type(item)),
+        body: folder.fold_method_body(node.body),
+    }
+}
+
+pub fn fold_constant_entry<F: Fold + ?Sized>(folder: &mut F, node: ConstantEntry) -> ConstantEntry {
+    ConstantEntry {
+        name: folder.fold_simple_identifier(node.name),
+        equals: node.equals,
+        value: folder.fold_expression(node.value),
+    }
+}
+
+pub fn fold_constant_fetch_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConstantFetchExpression,
+) -> ConstantFetchExpression {
+    ConstantFetchExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        double_colon: node.double_colon,
+        constant: folder.fold_identifier(node.constant),
+    }
+}
+
+pub fn fold_constant_modifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: ConstantModifier,
+) -> ConstantModifier {
+    node
+}
+
+pub fn fold_constant_modifier_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConstantModifierGroup,
+) -> ConstantModifierGroup {
+    ConstantModifierGroup {
+        modifiers: node
+            .modifiers
+            .into_iter()
+            .map(|item| folder.fold_constant_modifier(item))
+            .collect(),
+    }
+}
+
+pub fn fold_constant_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConstantStatement,
+) -> ConstantStatement {
+    ConstantStatement {
+        comments: folder.fold_comment_group(node.comments),
+        r#const: node.r#const,
+        entries: node
+            .entries
+            .into_iter()
+            .map(|item| folder.fold_constant_entry(item))
+            .collect(),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_constructor_parameter<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConstructorParameter,
+) -> ConstructorParameter {
+    ConstructorParameter {
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        ampersand: node.ampersand,
+        name: folder.fold_simple_variable(node.name),
+        data_type: node.data_type.map(|item| folder.fold_type(item)),
+        ellipsis: node.ellipsis,
+        default: node.default.map(|item| folder.fold_expression(item)),
+        modifiers: folder.fold_promoted_property_modifier_group(node.modifiers),
+    }
+}
+
+pub fn fold_constructor_parameter_list<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConstructorParameterList,
+) -> ConstructorParameterList {
+    ConstructorParameterList {
+        comments: folder.fold_comment_group(node.comments),
+        left_parenthesis: node.left_parenthesis,
+        parameters: CommaSeparated {
+            inner: node
+                .parameters
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_constructor_parameter(item))
+                .collect(),
+            commas: node.parameters.commas,
+        },
+        right_parenthesis: node.right_parenthesis,
+    }
+}
+
+pub fn fold_continue_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ContinueStatement,
+) -> ContinueStatement {
+    ContinueStatement {
+        r#continue: node.r#continue,
+        level: node.level.map(|item| folder.fold_level(item)),
+        ending: folder.fold_ending(node.ending),
+    }
+}
+
+pub fn fold_declare_body<F: Fold + ?Sized>(folder: &mut F, node: DeclareBody) -> DeclareBody {
+    match node {
+        DeclareBody::Braced {
+            left_brace,
+            statements,
+            right_brace,
+        } => DeclareBody::Braced {
+            left_brace,
+            statements: folder.fold_block(statements),
+            right_brace,
+        },
+        DeclareBody::Expression {
+            expression,
+            semicolon,
+        } => DeclareBody::Expression {
+            expression: folder.fold_expression(expression),
+            semicolon,
+        },
+        DeclareBody::Block {
+            colon,
+            statements,
+            end,
+        } => DeclareBody::Block {
+            colon,
+            statements: folder.fold_block(statements),
+            end,
+        },
+        node => node,
+    }
+}
+
+pub fn fold_declare_entry<F: Fold + ?Sized>(folder: &mut F, node: DeclareEntry) -> DeclareEntry {
+    DeclareEntry {
+        key: folder.fold_simple_identifier(node.key),
+        equals: node.equals,
+        value: folder.fold_literal(node.value),
+    }
+}
+
+pub fn fold_declare_entry_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DeclareEntryGroup,
+) -> DeclareEntryGroup {
+    DeclareEntryGroup {
+        left_parenthesis: node.left_parenthesis,
+        right_parenthesis: node.right_parenthesis,
+        entries: node
+            .entries
+            .into_iter()
+            .map(|item| folder.fold_declare_entry(item))
+            .collect(),
+    }
+}
+
+pub fn fold_declare_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DeclareStatement,
+) -> DeclareStatement {
+    DeclareStatement {
+        declare: node.declare,
+        entries: folder.fold_declare_entry_group(node.entries),
+        body: folder.fold_declare_body(node.body),
+    }
+}
+
+pub fn fold_default_match_arm<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DefaultMatchArm,
+) -> DefaultMatchArm {
+    DefaultMatchArm {
+        keyword: node.keyword,
+        double_arrow: node.double_arrow,
+        body: folder.fold_expression(node.body),
+    }
+}
+
+pub fn fold_die_expression<F: Fold + ?Sized>(folder: &mut F, node: DieExpression) -> DieExpression {
+    DieExpression {
+        die: node.die,
+        argument: node
+            .argument
+            .map(|item| Box::new(folder.fold_single_argument(*item))),
+    }
+}
+
+pub fn fold_do_while_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DoWhileStatement,
+) -> DoWhileStatement {
+    DoWhileStatement {
+        r#do: node.r#do,
+        body: Box::new(folder.fold_statement(*node.body)),
+        r#while: node.r#while,
+        left_parenthesis: node.left_parenthesis,
+        condition: folder.fold_expression(node.condition),
+        right_parenthesis: node.right_parenthesis,
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_dynamic_identifier<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DynamicIdentifier,
+) -> DynamicIdentifier {
+    DynamicIdentifier {
+        start: node.start,
+        expr: Box::new(folder.fold_expression(*node.expr)),
+        end: node.end,
+    }
+}
+
+pub fn fold_echo_opening_tag_statement<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: EchoOpeningTagStatement,
+) -> EchoOpeningTagStatement {
+    node
+}
+
+pub fn fold_echo_statement<F: Fold + ?Sized>(folder: &mut F, node: EchoStatement) -> EchoStatement {
+    EchoStatement {
+        echo: node.echo,
+        values: node
+            .values
+            .into_iter()
+            .map(|item| folder.fold_expression(item))
+            .collect(),
+        ending: folder.fold_ending(node.ending),
+    }
+}
+
+pub fn fold_empty_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: EmptyExpression,
+) -> EmptyExpression {
+    EmptyExpression {
+        empty: node.empty,
+        argument: Box::new(folder.fold_single_argument(*node.argument)),
+    }
+}
+
+pub fn fold_ending<F: Fold + ?Sized>(_folder: &mut F, node: Ending) -> Ending {
+    node
+}
+
+pub fn fold_error_suppress_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ErrorSuppressExpression,
+) -> ErrorSuppressExpression {
+    ErrorSuppressExpression {
+        at: node.at,
+        expr: Box::new(folder.fold_expression(*node.expr)),
+    }
+}
+
+pub fn fold_eval_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: EvalExpression,
+) -> EvalExpression {
+    EvalExpression {
+        eval: node.eval,
+        argument: Box::new(folder.fold_single_argument(*node.argument)),
+    }
+}
+
+pub fn fold_exit_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ExitExpression,
+) -> ExitExpression {
+    ExitExpression {
+        exit: node.exit,
+        argument: node
+            .argument
+            .map(|item| Box::new(folder.fold_single_argument(*item))),
+    }
+}
+
+pub fn fold_expression<F: Fold + ?Sized>(folder: &mut F, node: Expression) -> Expression {
+    match node {
+        Expression::Eval(node) => Expression::Eval(folder.fold_eval_expression(node)),
+        Expression::Empty(node) => Expression::Empty(folder.fold_empty_expression(node)),
+        Expression::Die(node) => Expression::Die(folder.fold_die_expression(node)),
+        Expression::Exit(node) => Expression::Exit(folder.fold_exit_expression(node)),
+        Expression::Isset(node) => Expression::Isset(folder.fold_isset_expression(node)),
+        Expression::Unset(node) => Expression::Unset(folder.fold_unset_expression(node)),
+        Expression::Print(node) => Expression::Print(folder.fold_print_expression(node)),
+        Expression::Literal(node) => Expression::Literal(folder.fold_literal(node)),
+        Expression::ArithmeticOperation(node) => {
+            Expression::ArithmeticOperation(folder.fold_arithmetic_operation_expression(node))
+        }
+        Expression::AssignmentOperation(node) => {
+            Expression::AssignmentOperation(folder.fold_assignment_operation_expression(node))
+        }
+        Expression::BitwiseOperation(node) => {
+            Expression::BitwiseOperation(folder.fold_bitwise_operation_expression(node))
+        }
+        Expression::ComparisonOperation(node) => {
+            Expression::ComparisonOperation(folder.fold_comparison_operation_expression(node))
+        }
+        Expression::LogicalOperation(node) => {
+            Expression::LogicalOperation(folder.fold_logical_operation_expression(node))
+        }
+        Expression::Concat(node) => Expression::Concat(folder.fold_concat_expression(node)),
+        Expression::Instanceof(node) => {
+            Expression::Instanceof(folder.fold_instanceof_expression(node))
+        }
+        Expression::Reference(node) => {
+            Expression::Reference(folder.fold_reference_expression(node))
+        }
+        Expression::Parenthesized(node) => {
+            Expression::Parenthesized(folder.fold_parenthesized_expression(node))
+        }
+        Expression::ErrorSuppress(node) => {
+            Expression::ErrorSuppress(folder.fold_error_suppress_expression(node))
+        }
+        Expression::Identifier(node) => Expression::Identifier(folder.fold_identifier(node)),
+        Expression::Variable(node) => Expression::Variable(folder.fold_variable(node)),
+        Expression::Include(node) => Expression::Include(folder.fold_include_expression(node)),
+        Expression::IncludeOnce(node) => {
+            Expression::IncludeOnce(folder.fold_include_once_expression(node))
+        }
+        Expression::Require(node) => Expression::Require(folder.fold_require_expression(node)),
+        Expression::RequireOnce(node) => {
+            Expression::RequireOnce(folder.fold_require_once_expression(node))
+        }
+        Expression::FunctionCall(node) => {
+            Expression::FunctionCall(folder.fold_function_call_expression(node))
+        }
+        Expression::FunctionClosureCreation(node) => Expression::FunctionClosureCreation(
+            folder.fold_function_closure_creation_expression(node),
+        ),
+        Expression::MethodCall(node) => {
+            Expression::MethodCall(folder.fold_method_call_expression(node))
+        }
+        Expression::MethodClosureCreation(node) => {
+            Expression::MethodClosureCreation(folder.fold_method_closure_creation_expression(node))
+        }
+        Expression::NullsafeMethodCall(node) => {
+            Expression::NullsafeMethodCall(folder.fold_nullsafe_method_call_expression(node))
+        }
+        Expression::StaticMethodCall(node) => {
+            Expression::StaticMethodCall(folder.fold_static_method_call_expression(node))
+        }
+        Expression::StaticVariableMethodCall(node) => Expression::StaticVariableMethodCall(
+            folder.fold_static_variable_method_call_expression(node),
+        ),
+        Expression::StaticMethodClosureCreation(node) => Expression::StaticMethodClosureCreation(
+            folder.fold_static_method_closure_creation_expression(node),
+        ),
+        Expression::StaticVariableMethodClosureCreation(node) => {
+            Expression::StaticVariableMethodClosureCreation(
+                folder.fold_static_variable_method_closure_creation_expression(node),
+            )
+        }
+        Expression::PropertyFetch(node) => {
+            Expression::PropertyFetch(folder.fold_property_fetch_expression(node))
+        }
+        Expression::NullsafePropertyFetch(node) => {
+            Expression::NullsafePropertyFetch(folder.fold_nullsafe_property_fetch_expression(node))
+        }
+        Expression::StaticPropertyFetch(node) => {
+            Expression::StaticPropertyFetch(folder.fold_static_property_fetch_expression(node))
+        }
+        Expression::ConstantFetch(node) => {
+            Expression::ConstantFetch(folder.fold_constant_fetch_expression(node))
+        }
+        Expression::ShortArray(node) => {
+            Expression::ShortArray(folder.fold_short_array_expression(node))
+        }
+        Expression::Array(node) => Expression::Array(folder.fold_array_expression(node)),
+        Expression::List(node) => Expression::List(folder.fold_list_expression(node)),
+        Expression::Closure(node) => Expression::Closure(folder.fold_closure_expression(node)),
+        Expression::ArrowFunction(node) => {
+            Expression::ArrowFunction(folder.fold_arrow_function_expression(node))
+        }
+        Expression::New(node) => Expression::New(folder.fold_new_expression(node)),
+        Expression::InterpolatedString(node) => {
+            Expression::InterpolatedString(folder.fold_interpolated_string_expression(node))
+        }
+        Expression::Heredoc(node) => Expression::Heredoc(folder.fold_heredoc_expression(node)),
+        Expression::Nowdoc(node) => Expression::Nowdoc(folder.fold_nowdoc_expression(node)),
+        Expression::ShellExec(node) => {
+            Expression::ShellExec(folder.fold_shell_exec_expression(node))
+        }
+        Expression::AnonymousClass(node) => {
+            Expression::AnonymousClass(folder.fold_anonymous_class_expression(node))
+        }
+        Expression::Bool(node) => Expression::Bool(folder.fold_bool_expression(node)),
+        Expression::ArrayIndex(node) => {
+            Expression::ArrayIndex(folder.fold_array_index_expression(node))
+        }
+        Expression::MagicConstant(node) => {
+            Expression::MagicConstant(folder.fold_magic_constant_expression(node))
+        }
+        Expression::ShortTernary(node) => {
+            Expression::ShortTernary(folder.fold_short_ternary_expression(node))
+        }
+        Expression::Ternary(node) => Expression::Ternary(folder.fold_ternary_expression(node)),
+        Expression::Coalesce(node) => Expression::Coalesce(folder.fold_coalesce_expression(node)),
+        Expression::Clone(node) => Expression::Clone(folder.fold_clone_expression(node)),
+        Expression::Match(node) => Expression::Match(folder.fold_match_expression(node)),
+        Expression::Throw(node) => Expression::Throw(folder.fold_throw_expression(node)),
+        Expression::Yield(node) => Expression::Yield(folder.fold_yield_expression(node)),
+        Expression::YieldFrom(node) => {
+            Expression::YieldFrom(folder.fold_yield_from_expression(node))
+        }
+        Expression::Cast(node) => Expression::Cast(folder.fold_cast_expression(node)),
+        node => node,
+    }
+}
+
+pub fn fold_expression_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ExpressionStatement,
+) -> ExpressionStatement {
+    ExpressionStatement {
+        expression: folder.fold_expression(node.expression),
+        ending: folder.fold_ending(node.ending),
+    }
+}
+
+pub fn fold_expression_string_part<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ExpressionStringPart,
+) -> ExpressionStringPart {
+    ExpressionStringPart {
+        expression: Box::new(folder.fold_expression(*node.expression)),
+    }
+}
+
+pub fn fold_finally_block<F: Fold + ?Sized>(folder: &mut F, node: FinallyBlock) -> FinallyBlock {
+    FinallyBlock {
+        start: node.start,
+        end: node.end,
+        body: folder.fold_block(node.body),
+    }
+}
+
+pub fn fold_for_statement<F: Fold + ?Sized>(folder: &mut F, node: ForStatement) -> ForStatement {
+    ForStatement {
+        r#for: node.r#for,
+        left_parenthesis: node.left_parenthesis,
+        iterator: folder.fold_for_statement_iterator(node.iterator),
+        right_parenthesis: node.right_parenthesis,
+        body: folder.fold_for_statement_body(node.body),
+    }
+}
+
+pub fn fold_for_statement_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ForStatementBody,
+) -> ForStatementBody {
+    match node {
+        ForStatementBody::Statement { statement } => ForStatementBody::Statement {
+            statement: Box::new(folder.fold_statement(*statement)),
+        },
+        ForStatementBody::Block {
+            colon,
+            statements,
+            endfor,
+            ending,
+        } => ForStatementBody::Block {
+            colon,
+            statements: folder.fold_block(statements),
+            endfor,
+            ending: folder.fold_ending(ending),
+        },
+    }
+}
+
+pub fn fold_for_statement_iterator<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ForStatementIterator,
+) -> ForStatementIterator {
+    ForStatementIterator {
+        initializations: CommaSeparated {
+            inner: node
+                .initializations
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_expression(item))
+                .collect(),
+            commas: node.initializations.commas,
+        },
+        initializations_semicolon: node.initializations_semicolon,
+        conditions: CommaSeparated {
+            inner: node
+                .conditions
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_expression(item))
+                .collect(),
+            commas: node.conditions.commas,
+        },
+        conditions_semicolon: node.conditions_semicolon,
+        r#loop: CommaSeparated {
+            inner: node
+                .r#loop
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_expression(item))
+                .collect(),
+            commas: node.r#loop.commas,
+        },
+    }
+}
+
+pub fn fold_foreach_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ForeachStatement,
+) -> ForeachStatement {
+    ForeachStatement {
+        foreach: node.foreach,
+        left_parenthesis: node.left_parenthesis,
+        iterator: folder.fold_foreach_statement_iterator(node.iterator),
+        right_parenthesis: node.right_parenthesis,
+        body: folder.fold_foreach_statement_body(node.body),
+    }
+}
+
+pub fn fold_foreach_statement_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ForeachStatementBody,
+) -> ForeachStatementBody {
+    match node {
+        ForeachStatementBody::Statement { statement } => ForeachStatementBody::Statement {
+            statement: Box::new(folder.fold_statement(*statement)),
+        },
+        ForeachStatementBody::Block {
+            colon,
+            statements,
+            endforeach,
+            ending,
+        } => ForeachStatementBody::Block {
+            colon,
+            statements: folder.fold_block(statements),
+            endforeach,
+            ending: folder.fold_ending(ending),
+        },
+    }
+}
+
+pub fn fold_foreach_statement_iterator<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ForeachStatementIterator,
+) -> ForeachStatementIterator {
+    match node {
+        ForeachStatementIterator::Value {
+            expression,
+            r#as,
+            ampersand,
+            value,
+        } => ForeachStatementIterator::Value {
+            expression: folder.fold_expression(expression),
+            r#as,
+            ampersand,
+            value: folder.fold_expression(value),
+        },
+        ForeachStatementIterator::KeyAndValue {
+            expression,
+            r#as,
+            ampersand,
+            key,
+            double_arrow,
+            value,
+        } => ForeachStatementIterator::KeyAndValue {
+            expression: folder.fold_expression(expression),
+            r#as,
+            ampersand,
+            key: folder.fold_expression(key),
+            double_arrow,
+            value: folder.fold_expression(value),
+        },
+    }
+}
+
+pub fn fold_full_opening_tag_statement<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: FullOpeningTagStatement,
+) -> FullOpeningTagStatement {
+    node
+}
+
+pub fn fold_function_body<F: Fold + ?Sized>(folder: &mut F, node: FunctionBody) -> FunctionBody {
+    FunctionBody {
+        comments: folder.fold_comment_group(node.comments),
+        left_brace: node.left_brace,
+        statements: folder.fold_block(node.statements),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_function_call_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: FunctionCallExpression,
+) -> FunctionCallExpression {
+    FunctionCallExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_function_closure_creation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: FunctionClosureCreationExpression,
+) -> FunctionClosureCreationExpression {
+    FunctionClosureCreationExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        placeholder: folder.fold_argument_placeholder(node.placeholder),
+    }
+}
+
+pub fn fold_function_parameter<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: FunctionParameter,
+) -> FunctionParameter {
+    FunctionParameter {
+        comments: folder.fold_comment_group(node.comments),
+        name: folder.fold_simple_variable(node.name),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        data_type: node.data_type.map(|item| folder.fold_type(item)),
+        ellipsis: node.ellipsis,
+        default: node.default.map(|item| folder.fold_expression(item)),
+        ampersand: node.ampersand,
+    }
+}
+
+pub fn fold_function_parameter_list<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: FunctionParameterList,
+) -> FunctionParameterList {
+    FunctionParameterList {
+        comments: folder.fold_comment_group(node.comments),
+        left_parenthesis: node.left_parenthesis,
+        parameters: CommaSeparated {
+            inner: node
+                .parameters
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_function_parameter(item))
+                .collect(),
+            commas: node.parameters.commas,
+        },
+        right_parenthesis: node.right_parenthesis,
+    }
+}
+
+pub fn fold_function_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: FunctionStatement,
+) -> FunctionStatement {
+    FunctionStatement {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        function: node.function,
+        ampersand: node.ampersand,
+        name: folder.fold_simple_identifier(node.name),
+        parameters: folder.fold_function_parameter_list(node.parameters),
+        return_type: node.return_type.map(|item| folder.fold_return_type(item)),
+        body: folder.fold_function_body(node.body),
+    }
+}
+
+pub fn fold_global_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: GlobalStatement,
+) -> GlobalStatement {
+    GlobalStatement {
+        global: node.global,
+        variables: node
+            .variables
+            .into_iter()
+            .map(|item| folder.fold_variable(item))
+            .collect(),
+    }
+}
+
+pub fn fold_goto_statement<F: Fold + ?Sized>(folder: &mut F, node: GotoStatement) -> GotoStatement {
+    GotoStatement {
+        comments: folder.fold_comment_group(node.comments),
+        keyword: node.keyword,
+        label: folder.fold_simple_identifier(node.label),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_group_use_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: GroupUseStatement,
+) -> GroupUseStatement {
+    GroupUseStatement {
+        r#use: node.r#use,
+        prefix: folder.fold_simple_identifier(node.prefix),
+        kind: node.kind,
+        uses: node
+            .uses
+            .into_iter()
+            .map(|item| folder.fold_use(item))
+            .collect(),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_halt_compiler_statement<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: HaltCompilerStatement,
+) -> HaltCompilerStatement {
+    node
+}
+
+pub fn fold_heredoc_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: HeredocExpression,
+) -> HeredocExpression {
+    HeredocExpression {
+        span: node.span,
+        label: node.label,
+        parts: node
+            .parts
+            .into_iter()
+            .map(|item| folder.fold_string_part(item))
+            .collect(),
+    }
+}
+
+pub fn fold_identifier<F: Fold + ?Sized>(folder: &mut F, node: Identifier) -> Identifier {
+    match node {
+        Identifier::SimpleIdentifier(node) => {
+            Identifier::SimpleIdentifier(folder.fold_simple_identifier(node))
+        }
+        Identifier::DynamicIdentifier(node) => {
+            Identifier::DynamicIdentifier(folder.fold_dynamic_identifier(node))
+        }
+    }
+}
+
+pub fn fold_if_statement<F: Fold + ?Sized>(folder: &mut F, node: IfStatement) -> IfStatement {
+    IfStatement {
+        r#if: node.r#if,
+        left_parenthesis: node.left_parenthesis,
+        condition: folder.fold_expression(node.condition),
+        right_parenthesis: node.right_parenthesis,
+        body: folder.fold_if_statement_body(node.body),
+    }
+}
+
+pub fn fold_if_statement_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IfStatementBody,
+) -> IfStatementBody {
+    match node {
+        IfStatementBody::Statement {
+            statement,
+            elseifs,
+            r#else,
+        } => IfStatementBody::Statement {
+            statement: Box::new(folder.fold_statement(*statement)),
+            elseifs: elseifs
+                .into_iter()
+                .map(|item| folder.fold_if_statement_else_if(item))
+                .collect(),
+            r#else: r#else.map(|item| folder.fold_if_statement_else(item)),
+        },
+        IfStatementBody::Block {
+            colon,
+            statements,
+            elseifs,
+            r#else,
+            endif,
+            ending,
+        } => IfStatementBody::Block {
+            colon,
+            statements: folder.fold_block(statements),
+            elseifs: elseifs
+                .into_iter()
+                .map(|item| folder.fold_if_statement_else_if_block(item))
+                .collect(),
+            r#else: r#else.map(|item| folder.fold_if_statement_else_block(item)),
+            endif,
+            ending: folder.fold_ending(ending),
+        },
+    }
+}
+
+pub fn fold_if_statement_else<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IfStatementElse,
+) -> IfStatementElse {
+    IfStatementElse {
+        r#else: node.r#else,
+        statement: Box::new(folder.fold_statement(*node.statement)),
+    }
+}
+
+pub fn fold_if_statement_else_block<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IfStatementElseBlock,
+) -> IfStatementElseBlock {
+    IfStatementElseBlock {
+        r#else: node.r#else,
+        colon: node.colon,
+        statements: folder.fold_block(node.statements),
+    }
+}
+
+pub fn fold_if_statement_else_if<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IfStatementElseIf,
+) -> IfStatementElseIf {
+    IfStatementElseIf {
+        elseif: node.elseif,
+        left_parenthesis: node.left_parenthesis,
+        condition: folder.fold_expression(node.condition),
+        right_parenthesis: node.right_parenthesis,
+        statement: Box::new(folder.fold_statement(*node.statement)),
+    }
+}
+
+pub fn fold_if_statement_else_if_block<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IfStatementElseIfBlock,
+) -> IfStatementElseIfBlock {
+    IfStatementElseIfBlock {
+        elseif: node.elseif,
+        left_parenthesis: node.left_parenthesis,
+        condition: folder.fold_expression(node.condition),
+        right_parenthesis: node.right_parenthesis,
+        colon: node.colon,
+        statements: folder.fold_block(node.statements),
+    }
+}
+
+pub fn fold_include_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IncludeExpression,
+) -> IncludeExpression {
+    IncludeExpression {
+        include: node.include,
+        path: Box::new(folder.fold_expression(*node.path)),
+    }
+}
+
+pub fn fold_include_once_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IncludeOnceExpression,
+) -> IncludeOnceExpression {
+    IncludeOnceExpression {
+        include_once: node.include_once,
+        path: Box::new(folder.fold_expression(*node.path)),
+    }
+}
+
+pub fn fold_inline_html_statement<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: InlineHtmlStatement,
+) -> InlineHtmlStatement {
+    node
+}
+
+pub fn fold_instanceof_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: InstanceofExpression,
+) -> InstanceofExpression {
+    InstanceofExpression {
+        left: Box::new(folder.fold_expression(*node.left)),
+        instanceof: node.instanceof,
+        right: Box::new(folder.fold_expression(*node.right)),
+    }
+}
+
+pub fn fold_interface_body<F: Fold + ?Sized>(folder: &mut F, node: InterfaceBody) -> InterfaceBody {
+    InterfaceBody {
+        left_brace: node.left_brace,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_interface_member(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_interface_extends<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: InterfaceExtends,
+) -> InterfaceExtends {
+    InterfaceExtends {
+        extends: node.extends,
+        parents: CommaSeparated {
+            inner: node
+                .parents
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_simple_identifier(item))
+                .collect(),
+            commas: node.parents.commas,
+        },
+    }
+}
+
+pub fn fold_interface_member<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: InterfaceMember,
+) -> InterfaceMember {
+    match node {
+        InterfaceMember::Constant(node) => {
+            InterfaceMember::Constant(folder.fold_classish_constant(node))
+        }
+        InterfaceMember::Constructor(node) => {
+            InterfaceMember::Constructor(folder.fold_abstract_constructor(node))
+        }
+        InterfaceMember::Method(node) => InterfaceMember::Method(folder.fold_abstract_method(node)),
+        node => node,
+    }
+}
+
+pub fn fold_interface_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: InterfaceStatement,
+) -> InterfaceStatement {
+    InterfaceStatement {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        interface: node.interface,
+        name: folder.fold_simple_identifier(node.name),
+        extends: node.extends.map(|item| folder.fold_interface_extends(item)),
+        body: folder.fold_interface_body(node.body),
+    }
+}
+
+pub fn fold_interpolated_string_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: InterpolatedStringExpression,
+) -> InterpolatedStringExpression {
+    InterpolatedStringExpression {
+        span: node.span,
+        parts: node
+            .parts
+            .into_iter()
+            .map(|item| folder.fold_string_part(item))
+            .collect(),
+    }
+}
+
+pub fn fold_isset_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: IssetExpression,
+) -> IssetExpression {
+    IssetExpression {
+        isset: node.isset,
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_label_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: LabelStatement,
+) -> LabelStatement {
+    LabelStatement {
+        comments: folder.fold_comment_group(node.comments),
+        label: folder.fold_simple_identifier(node.label),
+        colon: node.colon,
+    }
+}
+
+pub fn fold_level<F: Fold + ?Sized>(folder: &mut F, node: Level) -> Level {
+    match node {
+        Level::Literal(node) => Level::Literal(folder.fold_literal_integer(node)),
+        Level::Parenthesized {
+            left_parenthesis,
+            level,
+            right_parenthesis,
+        } => Level::Parenthesized {
+            left_parenthesis,
+            level: Box::new(folder.fold_level(*level)),
+            right_parenthesis,
+        },
+    }
+}
+
+pub fn fold_list_entry<F: Fold + ?Sized>(folder: &mut F, node: ListEntry) -> ListEntry {
+    match node {
+        ListEntry::Value { value } => ListEntry::Value {
+            value: folder.fold_expression(value),
+        },
+        ListEntry::KeyValue {
+            key,
+            double_arrow,
+            value,
+        } => ListEntry::KeyValue {
+            key: folder.fold_expression(key),
+            double_arrow,
+            value: folder.fold_expression(value),
+        },
+        node => node,
+    }
+}
+
+pub fn fold_list_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ListExpression,
+) -> ListExpression {
+    ListExpression {
+        list: node.list,
+        start: node.start,
+        items: node
+            .items
+            .into_iter()
+            .map(|item| folder.fold_list_entry(item))
+            .collect(),
+        end: node.end,
+    }
+}
+
+pub fn fold_literal<F: Fold + ?Sized>(folder: &mut F, node: Literal) -> Literal {
+    match node {
+        Literal::String(node) => Literal::String(folder.fold_literal_string(node)),
+        Literal::Integer(node) => Literal::Integer(folder.fold_literal_integer(node)),
+        Literal::Float(node) => Literal::Float(folder.fold_literal_float(node)),
+    }
+}
+
+pub fn fold_literal_float<F: Fold + ?Sized>(_folder: &mut F, node: LiteralFloat) -> LiteralFloat {
+    node
+}
+
+pub fn fold_literal_integer<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: LiteralInteger,
+) -> LiteralInteger {
+    node
+}
+
+pub fn fold_literal_string<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: LiteralString,
+) -> LiteralString {
+    node
+}
+
+pub fn fold_literal_string_part<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: LiteralStringPart,
+) -> LiteralStringPart {
+    node
+}
+
+pub fn fold_logical_operation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: LogicalOperationExpression,
+) -> LogicalOperationExpression {
+    match node {
+        LogicalOperationExpression::And {
+            left,
+            double_ampersand,
+            right,
+        } => LogicalOperationExpression::And {
+            left: Box::new(folder.fold_expression(*left)),
+            double_ampersand,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        LogicalOperationExpression::Or {
+            left,
+            double_pipe,
+            right,
+        } => LogicalOperationExpression::Or {
+            left: Box::new(folder.fold_expression(*left)),
+            double_pipe,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        LogicalOperationExpression::Not { bang, right } => LogicalOperationExpression::Not {
+            bang,
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        LogicalOperationExpression::LogicalAnd { left, and, right } => {
+            LogicalOperationExpression::LogicalAnd {
+                left: Box::new(folder.fold_expression(*left)),
+                and,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        LogicalOperationExpression::LogicalOr { left, or, right } => {
+            LogicalOperationExpression::LogicalOr {
+                left: Box::new(folder.fold_expression(*left)),
+                or,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+        LogicalOperationExpression::LogicalXor { left, xor, right } => {
+            LogicalOperationExpression::LogicalXor {
+                left: Box::new(folder.fold_expression(*left)),
+                xor,
+                right: Box::new(folder.fold_expression(*right)),
+            }
+        }
+    }
+}
+
+pub fn fold_magic_constant_expression<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: MagicConstantExpression,
+) -> MagicConstantExpression {
+    node
+}
+
+pub fn fold_match_arm<F: Fold + ?Sized>(folder: &mut F, node: MatchArm) -> MatchArm {
+    MatchArm {
+        conditions: node
+            .conditions
+            .into_iter()
+            .map(|item| folder.fold_expression(item))
+            .collect(),
+        arrow: node.arrow,
+        body: folder.fold_expression(node.body),
+    }
+}
+
+pub fn fold_match_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: MatchExpression,
+) -> MatchExpression {
+    MatchExpression {
+        keyword: node.keyword,
+        left_parenthesis: node.left_parenthesis,
+        condition: Box::new(folder.fold_expression(*node.condition)),
+        right_parenthesis: node.right_parenthesis,
+        left_brace: node.left_brace,
+        default: node
+            .default
+            .map(|item| Box::new(folder.fold_default_match_arm(*item))),
+        arms: node
+            .arms
+            .into_iter()
+            .map(|item| folder.fold_match_arm(item))
+            .collect(),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_method_body<F: Fold + ?Sized>(folder: &mut F, node: MethodBody) -> MethodBody {
+    MethodBody {
+        comments: folder.fold_comment_group(node.comments),
+        left_brace: node.left_brace,
+        statements: folder.fold_block(node.statements),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_method_call_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: MethodCallExpression,
+) -> MethodCallExpression {
+    MethodCallExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        arrow: node.arrow,
+        method: Box::new(folder.fold_expression(*node.method)),
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_method_closure_creation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: MethodClosureCreationExpression,
+) -> MethodClosureCreationExpression {
+    MethodClosureCreationExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        arrow: node.arrow,
+        method: Box::new(folder.fold_expression(*node.method)),
+        placeholder: folder.fold_argument_placeholder(node.placeholder),
+    }
+}
+
+pub fn fold_method_modifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: MethodModifier,
+) -> MethodModifier {
+    node
+}
+
+pub fn fold_method_modifier_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: MethodModifierGroup,
+) -> MethodModifierGroup {
+    MethodModifierGroup {
+        modifiers: node
+            .modifiers
+            .into_iter()
+            .map(|item| folder.fold_method_modifier(item))
+            .collect(),
+    }
+}
+
+pub fn fold_named_argument<F: Fold + ?Sized>(folder: &mut F, node: NamedArgument) -> NamedArgument {
+    NamedArgument {
+        comments: folder.fold_comment_group(node.comments),
+        name: folder.fold_simple_identifier(node.name),
+        colon: node.colon,
+        ellipsis: node.ellipsis,
+        value: folder.fold_expression(node.value),
+    }
+}
+
+pub fn fold_namespace_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: NamespaceStatement,
+) -> NamespaceStatement {
+    match node {
+        NamespaceStatement::Unbraced(node) => {
+            NamespaceStatement::Unbraced(folder.fold_unbraced_namespace(node))
+        }
+        NamespaceStatement::Braced(node) => {
+            NamespaceStatement::Braced(folder.fold_braced_namespace(node))
+        }
+    }
+}
+
+pub fn fold_new_expression<F: Fold + ?Sized>(folder: &mut F, node: NewExpression) -> NewExpression {
+    NewExpression {
+        new: node.new,
+        target: Box::new(folder.fold_expression(*node.target)),
+        arguments: node.arguments.map(|item| folder.fold_argument_list(item)),
+    }
+}
+
+pub fn fold_nowdoc_expression<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: NowdocExpression,
+) -> NowdocExpression {
+    node
+}
+
+pub fn fold_nullsafe_method_call_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: NullsafeMethodCallExpression,
+) -> NullsafeMethodCallExpression {
+    NullsafeMethodCallExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        question_arrow: node.question_arrow,
+        method: Box::new(folder.fold_expression(*node.method)),
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_nullsafe_property_fetch_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: NullsafePropertyFetchExpression,
+) -> NullsafePropertyFetchExpression {
+    NullsafePropertyFetchExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        question_arrow: node.question_arrow,
+        property: Box::new(folder.fold_expression(*node.property)),
+    }
+}
+
+pub fn fold_parenthesized_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ParenthesizedExpression,
+) -> ParenthesizedExpression {
+    ParenthesizedExpression {
+        start: node.start,
+        expr: Box::new(folder.fold_expression(*node.expr)),
+        end: node.end,
+    }
+}
+
+pub fn fold_positional_argument<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PositionalArgument,
+) -> PositionalArgument {
+    PositionalArgument {
+        comments: folder.fold_comment_group(node.comments),
+        ellipsis: node.ellipsis,
+        value: folder.fold_expression(node.value),
+    }
+}
+
+pub fn fold_print_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PrintExpression,
+) -> PrintExpression {
+    PrintExpression {
+        print: node.print,
+        value: node
+            .value
+            .map(|item| Box::new(folder.fold_expression(*item))),
+        argument: node
+            .argument
+            .map(|item| Box::new(folder.fold_single_argument(*item))),
+    }
+}
+
+pub fn fold_promoted_property_modifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: PromotedPropertyModifier,
+) -> PromotedPropertyModifier {
+    node
+}
+
+pub fn fold_promoted_property_modifier_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PromotedPropertyModifierGroup,
+) -> PromotedPropertyModifierGroup {
+    PromotedPropertyModifierGroup {
+        modifiers: node
+            .modifiers
+            .into_iter()
+            .map(|item| folder.fold_promoted_property_modifier(item))
+            .collect(),
+    }
+}
+
+pub fn fold_property<F: Fold + ?Sized>(folder: &mut F, node: Property) -> Property {
+    Property {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        modifiers: folder.fold_property_modifier_group(node.modifiers),
+        r#type: node.r#type.map(|item| folder.fold_type(item)),
+        entries: node
+            .entries
+            .into_iter()
+            .map(|item| folder.fold_property_entry(item))
+            .collect(),
+        end: node.end,
+    }
+}
+
+pub fn fold_property_entry<F: Fold + ?Sized>(folder: &mut F, node: PropertyEntry) -> PropertyEntry {
+    match node {
+        PropertyEntry::Uninitialized { variable } => PropertyEntry::Uninitialized {
+            variable: folder.fold_simple_variable(variable),
+        },
+        PropertyEntry::Initialized {
+            variable,
+            equals,
+            value,
+        } => PropertyEntry::Initialized {
+            variable: folder.fold_simple_variable(variable),
+            equals,
+            value: folder.fold_expression(value),
+        },
+    }
+}
+
+pub fn fold_property_fetch_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PropertyFetchExpression,
+) -> PropertyFetchExpression {
+    PropertyFetchExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        arrow: node.arrow,
+        property: Box::new(folder.fold_expression(*node.property)),
+    }
+}
+
+pub fn fold_property_modifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: PropertyModifier,
+) -> PropertyModifier {
+    node
+}
+
+pub fn fold_property_modifier_group<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PropertyModifierGroup,
+) -> PropertyModifierGroup {
+    PropertyModifierGroup {
+        modifiers: node
+            .modifiers
+            .into_iter()
+            .map(|item| folder.fold_property_modifier(item))
+            .collect(),
+    }
+}
+
+pub fn fold_reference_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ReferenceExpression,
+) -> ReferenceExpression {
+    ReferenceExpression {
+        ampersand: node.ampersand,
+        right: Box::new(folder.fold_expression(*node.right)),
+    }
+}
+
+pub fn fold_require_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: RequireExpression,
+) -> RequireExpression {
+    RequireExpression {
+        require: node.require,
+        path: Box::new(folder.fold_expression(*node.path)),
+    }
+}
+
+pub fn fold_require_once_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: RequireOnceExpression,
+) -> RequireOnceExpression {
+    RequireOnceExpression {
+        require_once: node.require_once,
+        path: Box::new(folder.fold_expression(*node.path)),
+    }
+}
+
+pub fn fold_return_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ReturnStatement,
+) -> ReturnStatement {
+    ReturnStatement {
+        r#return: node.r#return,
+        value: node.value.map(|item| folder.fold_expression(item)),
+        ending: folder.fold_ending(node.ending),
+    }
+}
+
+pub fn fold_return_type<F: Fold + ?Sized>(folder: &mut F, node: ReturnType) -> ReturnType {
+    ReturnType {
+        colon: node.colon,
+        data_type: folder.fold_type(node.data_type),
+    }
+}
+
+pub fn fold_shell_exec_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ShellExecExpression,
+) -> ShellExecExpression {
+    ShellExecExpression {
+        span: node.span,
+        parts: node
+            .parts
+            .into_iter()
+            .map(|item| folder.fold_string_part(item))
+            .collect(),
+    }
+}
+
+pub fn fold_short_array_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ShortArrayExpression,
+) -> ShortArrayExpression {
+    ShortArrayExpression {
+        start: node.start,
+        items: CommaSeparated {
+            inner: node
+                .items
+                .inner
+                .into_iter()
+                .map(|item| folder.fold_array_item(item))
+                .collect(),
+            commas: node.items.commas,
+        },
+        end: node.end,
+    }
+}
+
+pub fn fold_short_opening_tag_statement<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: ShortOpeningTagStatement,
+) -> ShortOpeningTagStatement {
+    node
+}
+
+pub fn fold_short_ternary_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ShortTernaryExpression,
+) -> ShortTernaryExpression {
+    ShortTernaryExpression {
+        condition: Box::new(folder.fold_expression(*node.condition)),
+        question_colon: node.question_colon,
+        r#else: Box::new(folder.fold_expression(*node.r#else)),
+    }
+}
+
+pub fn fold_simple_identifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: SimpleIdentifier,
+) -> SimpleIdentifier {
+    node
+}
+
+pub fn fold_simple_variable<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: SimpleVariable,
+) -> SimpleVariable {
+    node
+}
+
+pub fn fold_single_argument<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: SingleArgument,
+) -> SingleArgument {
+    SingleArgument {
+        comments: folder.fold_comment_group(node.comments),
+        left_parenthesis: node.left_parenthesis,
+        argument: folder.fold_argument(node.argument),
+        right_parenthesis: node.right_parenthesis,
+    }
+}
+
+pub fn fold_statement<F: Fold + ?Sized>(folder: &mut F, node: Statement) -> Statement {
+    match node {
+        Statement::FullOpeningTag(node) => {
+            Statement::FullOpeningTag(folder.fold_full_opening_tag_statement(node))
+        }
+        Statement::ShortOpeningTag(node) => {
+            Statement::ShortOpeningTag(folder.fold_short_opening_tag_statement(node))
+        }
+        Statement::EchoOpeningTag(node) => {
+            Statement::EchoOpeningTag(folder.fold_echo_opening_tag_statement(node))
+        }
+        Statement::ClosingTag(node) => {
+            Statement::ClosingTag(folder.fold_closing_tag_statement(node))
+        }
+        Statement::InlineHtml(node) => {
+            Statement::InlineHtml(folder.fold_inline_html_statement(node))
+        }
+        Statement::Label(node) => Statement::Label(folder.fold_label_statement(node)),
+        Statement::Goto(node) => Statement::Goto(folder.fold_goto_statement(node)),
+        Statement::HaltCompiler(node) => {
+            Statement::HaltCompiler(folder.fold_halt_compiler_statement(node))
+        }
+        Statement::Static(node) => Statement::Static(folder.fold_static_statement(node)),
+        Statement::DoWhile(node) => Statement::DoWhile(folder.fold_do_while_statement(node)),
+        Statement::While(node) => Statement::While(folder.fold_while_statement(node)),
+        Statement::For(node) => Statement::For(folder.fold_for_statement(node)),
+        Statement::Foreach(node) => Statement::Foreach(folder.fold_foreach_statement(node)),
+        Statement::Break(node) => Statement::Break(folder.fold_break_statement(node)),
+        Statement::Continue(node) => Statement::Continue(folder.fold_continue_statement(node)),
+        Statement::Constant(node) => Statement::Constant(folder.fold_constant_statement(node)),
+        Statement::Function(node) => Statement::Function(folder.fold_function_statement(node)),
+        Statement::Class(node) => Statement::Class(folder.fold_class_statement(node)),
+        Statement::Trait(node) => Statement::Trait(folder.fold_trait_statement(node)),
+        Statement::Interface(node) => Statement::Interface(folder.fold_interface_statement(node)),
+        Statement::If(node) => Statement::If(folder.fold_if_statement(node)),
+        Statement::Switch(node) => Statement::Switch(folder.fold_switch_statement(node)),
+        Statement::Echo(node) => Statement::Echo(folder.fold_echo_statement(node)),
+        Statement::Expression(node) => {
+            Statement::Expression(folder.fold_expression_statement(node))
+        }
+        Statement::Return(node) => Statement::Return(folder.fold_return_statement(node)),
+        Statement::Namespace(node) => Statement::Namespace(folder.fold_namespace_statement(node)),
+        Statement::Use(node) => Statement::Use(folder.fold_use_statement(node)),
+        Statement::GroupUse(node) => Statement::GroupUse(folder.fold_group_use_statement(node)),
+        Statement::Comment(node) => Statement::Comment(folder.fold_comment(node)),
+        Statement::Try(node) => Statement::Try(folder.fold_try_statement(node)),
+        Statement::UnitEnum(node) => Statement::UnitEnum(folder.fold_unit_enum_statement(node)),
+        Statement::BackedEnum(node) => {
+            Statement::BackedEnum(folder.fold_backed_enum_statement(node))
+        }
+        Statement::Block(node) => Statement::Block(folder.fold_block_statement(node)),
+        Statement::Global(node) => Statement::Global(folder.fold_global_statement(node)),
+        Statement::Declare(node) => Statement::Declare(folder.fold_declare_statement(node)),
+        node => node,
+    }
+}
+
+pub fn fold_static_method_call_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: StaticMethodCallExpression,
+) -> StaticMethodCallExpression {
+    StaticMethodCallExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        double_colon: node.double_colon,
+        method: folder.fold_identifier(node.method),
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_static_method_closure_creation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: StaticMethodClosureCreationExpression,
+) -> StaticMethodClosureCreationExpression {
+    StaticMethodClosureCreationExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        double_colon: node.double_colon,
+        method: folder.fold_identifier(node.method),
+        placeholder: folder.fold_argument_placeholder(node.placeholder),
+    }
+}
+
+pub fn fold_static_property_fetch_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: StaticPropertyFetchExpression,
+) -> StaticPropertyFetchExpression {
+    StaticPropertyFetchExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        double_colon: node.double_colon,
+        property: folder.fold_variable(node.property),
+    }
+}
+
+pub fn fold_static_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: StaticStatement,
+) -> StaticStatement {
+    StaticStatement {
+        r#static: node.r#static,
+        vars: node
+            .vars
+            .into_iter()
+            .map(|item| folder.fold_static_var(item))
+            .collect(),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_static_var<F: Fold + ?Sized>(folder: &mut F, node: StaticVar) -> StaticVar {
+    StaticVar {
+        var: folder.fold_variable(node.var),
+        default: node.default.map(|item| folder.fold_expression(item)),
+    }
+}
+
+pub fn fold_static_variable_method_call_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: StaticVariableMethodCallExpression,
+) -> StaticVariableMethodCallExpression {
+    StaticVariableMethodCallExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        double_colon: node.double_colon,
+        method: folder.fold_variable(node.method),
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_static_variable_method_closure_creation_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: StaticVariableMethodClosureCreationExpression,
+) -> StaticVariableMethodClosureCreationExpression {
+    StaticVariableMethodClosureCreationExpression {
+        target: Box::new(folder.fold_expression(*node.target)),
+        double_colon: node.double_colon,
+        method: folder.fold_variable(node.method),
+        placeholder: folder.fold_argument_placeholder(node.placeholder),
+    }
+}
+
+pub fn fold_string_part<F: Fold + ?Sized>(folder: &mut F, node: StringPart) -> StringPart {
+    match node {
+        StringPart::Literal(node) => StringPart::Literal(folder.fold_literal_string_part(node)),
+        StringPart::Expression(node) => {
+            StringPart::Expression(folder.fold_expression_string_part(node))
+        }
+    }
+}
+
+pub fn fold_switch_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: SwitchStatement,
+) -> SwitchStatement {
+    SwitchStatement {
+        switch: node.switch,
+        left_parenthesis: node.left_parenthesis,
+        condition: folder.fold_expression(node.condition),
+        right_parenthesis: node.right_parenthesis,
+        cases: node
+            .cases
+            .into_iter()
+            .map(|item| folder.fold_case(item))
+            .collect(),
+    }
+}
+
+pub fn fold_ternary_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: TernaryExpression,
+) -> TernaryExpression {
+    TernaryExpression {
+        condition: Box::new(folder.fold_expression(*node.condition)),
+        question: node.question,
+        then: Box::new(folder.fold_expression(*node.then)),
+        colon: node.colon,
+        r#else: Box::new(folder.fold_expression(*node.r#else)),
+    }
+}
+
+pub fn fold_throw_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ThrowExpression,
+) -> ThrowExpression {
+    ThrowExpression {
+        throw: node.throw,
+        value: Box::new(folder.fold_expression(*node.value)),
+    }
+}
+
+pub fn fold_trait_body<F: Fold + ?Sized>(folder: &mut F, node: TraitBody) -> TraitBody {
+    TraitBody {
+        left_brace: node.left_brace,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_trait_member(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_trait_member<F: Fold + ?Sized>(folder: &mut F, node: TraitMember) -> TraitMember {
+    match node {
+        TraitMember::Constant(node) => TraitMember::Constant(folder.fold_classish_constant(node)),
+        TraitMember::TraitUsage(node) => TraitMember::TraitUsage(folder.fold_trait_usage(node)),
+        TraitMember::Property(node) => TraitMember::Property(folder.fold_property(node)),
+        TraitMember::VariableProperty(node) => {
+            TraitMember::VariableProperty(folder.fold_variable_property(node))
+        }
+        TraitMember::AbstractMethod(node) => {
+            TraitMember::AbstractMethod(folder.fold_abstract_method(node))
+        }
+        TraitMember::AbstractConstructor(node) => {
+            TraitMember::AbstractConstructor(folder.fold_abstract_constructor(node))
+        }
+        TraitMember::ConcreteMethod(node) => {
+            TraitMember::ConcreteMethod(folder.fold_concrete_method(node))
+        }
+        TraitMember::ConcreteConstructor(node) => {
+            TraitMember::ConcreteConstructor(folder.fold_concrete_constructor(node))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_trait_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: TraitStatement,
+) -> TraitStatement {
+    TraitStatement {
+        comments: folder.fold_comment_group(node.comments),
+        r#trait: node.r#trait,
+        name: folder.fold_simple_identifier(node.name),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        body: folder.fold_trait_body(node.body),
+    }
+}
+
+pub fn fold_trait_usage<F: Fold + ?Sized>(folder: &mut F, node: TraitUsage) -> TraitUsage {
+    TraitUsage {
+        r#use: node.r#use,
+        traits: node
+            .traits
+            .into_iter()
+            .map(|item| folder.fold_simple_identifier(item))
+            .collect(),
+        adaptations: node
+            .adaptations
+            .into_iter()
+            .map(|item| folder.fold_trait_usage_adaptation(item))
+            .collect(),
+    }
+}
+
+pub fn fold_trait_usage_adaptation<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: TraitUsageAdaptation,
+) -> TraitUsageAdaptation {
+    match node {
+        TraitUsageAdaptation::Alias {
+            r#trait,
+            method,
+            alias,
+            visibility,
+        } => TraitUsageAdaptation::Alias {
+            r#trait: r#trait.map(|item| folder.fold_simple_identifier(item)),
+            method: folder.fold_simple_identifier(method),
+            alias: folder.fold_simple_identifier(alias),
+            visibility: visibility.map(|item| folder.fold_visibility_modifier(item)),
+        },
+        TraitUsageAdaptation::Visibility {
+            r#trait,
+            method,
+            visibility,
+        } => TraitUsageAdaptation::Visibility {
+            r#trait: r#trait.map(|item| folder.fold_simple_identifier(item)),
+            method: folder.fold_simple_identifier(method),
+            visibility: folder.fold_visibility_modifier(visibility),
+        },
+        TraitUsageAdaptation::Precedence {
+            r#trait,
+            method,
+            insteadof,
+        } => TraitUsageAdaptation::Precedence {
+            r#trait: r#trait.map(|item| folder.fold_simple_identifier(item)),
+            method: folder.fold_simple_identifier(method),
+            insteadof: insteadof
+                .into_iter()
+                .map(|item| folder.fold_simple_identifier(item))
+                .collect(),
+        },
+    }
+}
+
+pub fn fold_try_statement<F: Fold + ?Sized>(folder: &mut F, node: TryStatement) -> TryStatement {
+    TryStatement {
+        start: node.start,
+        end: node.end,
+        body: folder.fold_block(node.body),
+        catches: node
+            .catches
+            .into_iter()
+            .map(|item| folder.fold_catch_block(item))
+            .collect(),
+        finally: node.finally.map(|item| folder.fold_finally_block(item)),
+    }
+}
+
+pub fn fold_type<F: Fold + ?Sized>(folder: &mut F, node: Type) -> Type {
+    match node {
+        Type::Nullable(node0, node1) => Type::Nullable(node0, Box::new(folder.fold_type(*node1))),
+        Type::Union(node) => Type::Union(
+            node.into_iter()
+                .map(|item| folder.fold_type(item))
+                .collect(),
+        ),
+        Type::Intersection(node) => Type::Intersection(
+            node.into_iter()
+                .map(|item| folder.fold_type(item))
+                .collect(),
+        ),
+        node => node,
+    }
+}
+
+pub fn fold_unbraced_namespace<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: UnbracedNamespace,
+) -> UnbracedNamespace {
+    UnbracedNamespace {
+        start: node.start,
+        name: folder.fold_simple_identifier(node.name),
+        end: node.end,
+        statements: folder.fold_block(node.statements),
+    }
+}
+
+pub fn fold_unit_enum_body<F: Fold + ?Sized>(folder: &mut F, node: UnitEnumBody) -> UnitEnumBody {
+    UnitEnumBody {
+        left_brace: node.left_brace,
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_unit_enum_member(item))
+            .collect(),
+        comments: folder.fold_comment_group(node.comments),
+        right_brace: node.right_brace,
+    }
+}
+
+pub fn fold_unit_enum_case<F: Fold + ?Sized>(folder: &mut F, node: UnitEnumCase) -> UnitEnumCase {
+    UnitEnumCase {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        start: node.start,
+        name: folder.fold_simple_identifier(node.name),
+        end: node.end,
+    }
+}
+
+pub fn fold_unit_enum_member<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: UnitEnumMember,
+) -> UnitEnumMember {
+    match node {
+        UnitEnumMember::Case(node) => UnitEnumMember::Case(folder.fold_unit_enum_case(node)),
+        UnitEnumMember::Method(node) => UnitEnumMember::Method(folder.fold_concrete_method(node)),
+        UnitEnumMember::Constant(node) => {
+            UnitEnumMember::Constant(folder.fold_classish_constant(node))
+        }
+        UnitEnumMember::TraitUsage(node) => {
+            UnitEnumMember::TraitUsage(folder.fold_trait_usage(node))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_unit_enum_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: UnitEnumStatement,
+) -> UnitEnumStatement {
+    UnitEnumStatement {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        r#enum: node.r#enum,
+        name: folder.fold_simple_identifier(node.name),
+        implements: node
+            .implements
+            .into_iter()
+            .map(|item| folder.fold_simple_identifier(item))
+            .collect(),
+        body: folder.fold_unit_enum_body(node.body),
+    }
+}
+
+pub fn fold_unset_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: UnsetExpression,
+) -> UnsetExpression {
+    UnsetExpression {
+        unset: node.unset,
+        arguments: folder.fold_argument_list(node.arguments),
+    }
+}
+
+pub fn fold_use<F: Fold + ?Sized>(folder: &mut F, node: Use) -> Use {
+    Use {
+        span: node.span,
+        name: folder.fold_simple_identifier(node.name),
+        alias: node.alias.map(|item| folder.fold_simple_identifier(item)),
+        kind: node.kind,
+    }
+}
+
+pub fn fold_use_statement<F: Fold + ?Sized>(folder: &mut F, node: UseStatement) -> UseStatement {
+    UseStatement {
+        r#use: node.r#use,
+        kind: node.kind,
+        uses: node
+            .uses
+            .into_iter()
+            .map(|item| folder.fold_use(item))
+            .collect(),
+        semicolon: node.semicolon,
+    }
+}
+
+pub fn fold_variable<F: Fold + ?Sized>(folder: &mut F, node: Variable) -> Variable {
+    match node {
+        Variable::SimpleVariable(node) => {
+            Variable::SimpleVariable(folder.fold_simple_variable(node))
+        }
+        Variable::VariableVariable(node) => {
+            Variable::VariableVariable(folder.fold_variable_variable(node))
+        }
+        Variable::BracedVariableVariable(node) => {
+            Variable::BracedVariableVariable(folder.fold_braced_variable_variable(node))
+        }
+    }
+}
+
+pub fn fold_variable_property<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: VariableProperty,
+) -> VariableProperty {
+    VariableProperty {
+        comments: folder.fold_comment_group(node.comments),
+        attributes: node
+            .attributes
+            .into_iter()
+            .map(|item| folder.fold_attribute_group(item))
+            .collect(),
+        r#type: node.r#type.map(|item| folder.fold_type(item)),
+        entries: node
+            .entries
+            .into_iter()
+            .map(|item| folder.fold_property_entry(item))
+            .collect(),
+        end: node.end,
+    }
+}
+
+pub fn fold_variable_variable<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: VariableVariable,
+) -> VariableVariable {
+    VariableVariable {
+        span: node.span,
+        variable: Box::new(folder.fold_variable(*node.variable)),
+    }
+}
+
+pub fn fold_visibility_modifier<F: Fold + ?Sized>(
+    _folder: &mut F,
+    node: VisibilityModifier,
+) -> VisibilityModifier {
+    node
+}
+
+pub fn fold_while_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: WhileStatement,
+) -> WhileStatement {
+    WhileStatement {
+        r#while: node.r#while,
+        left_parenthesis: node.left_parenthesis,
+        condition: folder.fold_expression(node.condition),
+        right_parenthesis: node.right_parenthesis,
+        body: folder.fold_while_statement_body(node.body),
+    }
+}
+
+pub fn fold_while_statement_body<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: WhileStatementBody,
+) -> WhileStatementBody {
+    match node {
+        WhileStatementBody::Statement { statement } => WhileStatementBody::Statement {
+            statement: Box::new(folder.fold_statement(*statement)),
+        },
+        WhileStatementBody::Block {
+            colon,
+            statements,
+            endwhile,
+            ending,
+        } => WhileStatementBody::Block {
+            colon,
+            statements: folder.fold_block(statements),
+            endwhile,
+            ending: folder.fold_ending(ending),
+        },
+    }
+}
+
+pub fn fold_yield_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: YieldExpression,
+) -> YieldExpression {
+    YieldExpression {
+        r#yield: node.r#yield,
+        key: node.key.map(|item| Box::new(folder.fold_expression(*item))),
+        value: node
+            .value
+            .map(|item| Box::new(folder.fold_expression(*item))),
+    }
+}
+
+pub fn fold_yield_from_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: YieldFromExpression,
+) -> YieldFromExpression {
+    YieldFromExpression {
+        r#yield: node.r#yield,
+        from: node.from,
+        value: Box::new(folder.fold_expression(*node.value)),
+    }
+}
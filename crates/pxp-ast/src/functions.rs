@@ -6,7 +6,7 @@ use crate::data_type::Type;
 use crate::identifiers::SimpleIdentifier;
 use crate::modifiers::MethodModifierGroup;
 use crate::modifiers::PromotedPropertyModifierGroup;
-use crate::node::Node;
+use crate::spanned::Spanned;
 use crate::utils::CommaSeparated;
 use crate::variables::SimpleVariable;
 use crate::Expression;
@@ -14,20 +14,20 @@ use crate::Statement;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReturnType {
     pub colon: Span,
     pub data_type: Type,
 }
 
-impl Node for ReturnType {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.data_type]
+impl Spanned for ReturnType {
+    fn span(&self) -> Span {
+        self.colon.join(self.data_type.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionParameter {
     pub comments: CommentGroup,
     pub name: SimpleVariable,
@@ -38,21 +38,24 @@ pub struct FunctionParameter {
     pub ampersand: Option<Span>,
 }
 
-impl Node for FunctionParameter {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
-        if let Some(data_type) = &mut self.data_type {
-            children.push(data_type);
-        }
-        if let Some(default) = &mut self.default {
-            children.push(default);
-        }
-        children
+impl Spanned for FunctionParameter {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.data_type.as_ref().map(Spanned::span))
+            .or(self.ampersand)
+            .or(self.ellipsis)
+            .unwrap_or(self.name.span);
+        let end = self.default.as_ref().map_or(self.name.span, Spanned::span);
+
+        start.join(end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionParameterList {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,
@@ -75,14 +78,14 @@ impl IntoIterator for FunctionParameterList {
     }
 }
 
-impl Node for FunctionParameterList {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.parameters.children()
+impl Spanned for FunctionParameterList {
+    fn span(&self) -> Span {
+        self.left_parenthesis.join(self.right_parenthesis)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionBody {
     pub comments: CommentGroup,
     pub left_brace: Span,
@@ -90,17 +93,14 @@ pub struct FunctionBody {
     pub right_brace: Span,
 }
 
-impl Node for FunctionBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.statements
-            .iter_mut()
-            .map(|x| x as &mut dyn Node)
-            .collect()
+impl Spanned for FunctionBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionStatement {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -112,33 +112,33 @@ pub struct FunctionStatement {
     pub body: FunctionBody,
 }
 
-impl Node for FunctionStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> =
-            vec![&mut self.name, &mut self.parameters, &mut self.body];
-        if let Some(return_type) = &mut self.return_type {
-            children.push(return_type);
-        }
-        children
+impl Spanned for FunctionStatement {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.function, Spanned::span);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClosureUseVariable {
     pub comments: CommentGroup,
     pub ampersand: Option<Span>,
     pub variable: SimpleVariable,
 }
 
-impl Node for ClosureUseVariable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.variable]
+impl Spanned for ClosureUseVariable {
+    fn span(&self) -> Span {
+        self.ampersand.unwrap_or(self.variable.span).join(self.variable.span)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClosureUse {
     pub comments: CommentGroup,
     pub r#use: Span,
@@ -147,14 +147,14 @@ pub struct ClosureUse {
     pub right_parenthesis: Span,
 }
 
-impl Node for ClosureUse {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.variables.children()
+impl Spanned for ClosureUse {
+    fn span(&self) -> Span {
+        self.r#use.join(self.right_parenthesis)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClosureExpression {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -167,22 +167,21 @@ pub struct ClosureExpression {
     pub body: FunctionBody,
 }
 
-impl Node for ClosureExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.parameters];
-        if let Some(uses) = &mut self.uses {
-            children.push(uses);
-        }
-        if let Some(return_type) = &mut self.return_type {
-            children.push(return_type);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for ClosureExpression {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or(self.r#static)
+            .unwrap_or(self.function);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArrowFunctionExpression {
     pub comments: CommentGroup,
     pub r#static: Option<Span>,
@@ -195,19 +194,21 @@ pub struct ArrowFunctionExpression {
     pub body: Box<Expression>,
 }
 
-impl Node for ArrowFunctionExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.parameters];
-        if let Some(return_type) = &mut self.return_type {
-            children.push(return_type);
-        }
-        children.push(self.body.as_mut());
-        children
+impl Spanned for ArrowFunctionExpression {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or(self.r#static)
+            .unwrap_or(self.r#fn);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstructorParameter {
     pub attributes: Vec<AttributeGroup>,
     pub comments: CommentGroup,
@@ -220,21 +221,25 @@ pub struct ConstructorParameter {
     pub modifiers: PromotedPropertyModifierGroup,
 }
 
-impl Node for ConstructorParameter {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
-        if let Some(data_type) = &mut self.data_type {
-            children.push(data_type);
-        }
-        if let Some(default) = &mut self.default {
-            children.push(default);
-        }
-        children
+impl Spanned for ConstructorParameter {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .or_else(|| self.data_type.as_ref().map(Spanned::span))
+            .or(self.ampersand)
+            .or(self.ellipsis)
+            .unwrap_or(self.name.span);
+        let end = self.default.as_ref().map_or(self.name.span, Spanned::span);
+
+        start.join(end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstructorParameterList {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,
@@ -242,14 +247,14 @@ pub struct ConstructorParameterList {
     pub right_parenthesis: Span,
 }
 
-impl Node for ConstructorParameterList {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.parameters.children()
+impl Spanned for ConstructorParameterList {
+    fn span(&self) -> Span {
+        self.left_parenthesis.join(self.right_parenthesis)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbstractConstructor {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -264,14 +269,21 @@ pub struct AbstractConstructor {
     pub semicolon: Span,
 }
 
-impl Node for AbstractConstructor {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.parameters]
+impl Spanned for AbstractConstructor {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .unwrap_or(self.function);
+
+        start.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConcreteConstructor {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -286,9 +298,16 @@ pub struct ConcreteConstructor {
     pub body: MethodBody,
 }
 
-impl Node for ConcreteConstructor {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.parameters, &mut self.body]
+impl Spanned for ConcreteConstructor {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .unwrap_or(self.function);
+
+        start.join(self.body.span())
     }
 }
 
@@ -311,7 +330,7 @@ impl ConcreteConstructor {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbstractMethod {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -325,18 +344,21 @@ pub struct AbstractMethod {
     pub semicolon: Span,
 }
 
-impl Node for AbstractMethod {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name, &mut self.parameters];
-        if let Some(return_type) = &mut self.return_type {
-            children.push(return_type);
-        }
-        children
+impl Spanned for AbstractMethod {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .unwrap_or(self.function);
+
+        start.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConcreteMethod {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -350,19 +372,21 @@ pub struct ConcreteMethod {
     pub body: MethodBody,
 }
 
-impl Node for ConcreteMethod {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name, &mut self.parameters];
-        if let Some(return_type) = &mut self.return_type {
-            children.push(return_type);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for ConcreteMethod {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .unwrap_or(self.function);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodBody {
     pub comments: CommentGroup,
     pub left_brace: Span, // `{`
@@ -370,11 +394,8 @@ pub struct MethodBody {
     pub right_brace: Span, // `}`
 }
 
-impl Node for MethodBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.statements
-            .iter_mut()
-            .map(|s| s as &mut dyn Node)
-            .collect()
+impl Spanned for MethodBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
@@ -1,24 +1,24 @@
 use crate::comments::CommentGroup;
 use crate::identifiers::SimpleIdentifier;
-use crate::node::Node;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LabelStatement {
     pub comments: CommentGroup,
     pub label: SimpleIdentifier, // `foo`
     pub colon: Span,             // `:`
 }
 
-impl Node for LabelStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.label]
+impl Spanned for LabelStatement {
+    fn span(&self) -> Span {
+        self.label.span.join(self.colon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GotoStatement {
     pub comments: CommentGroup,
     pub keyword: Span,           // `goto`
@@ -26,8 +26,8 @@ pub struct GotoStatement {
     pub semicolon: Span,         // `;`
 }
 
-impl Node for GotoStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.label]
+impl Spanned for GotoStatement {
+    fn span(&self) -> Span {
+        self.keyword.join(self.semicolon)
     }
 }
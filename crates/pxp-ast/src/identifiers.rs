@@ -1,35 +1,37 @@
 use std::fmt::Display;
 
-use crate::node::Node;
 use crate::Expression;
+use crate::spanned::Spanned;
 use pxp_bytestring::ByteString;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Identifier {
     SimpleIdentifier(SimpleIdentifier),
     DynamicIdentifier(DynamicIdentifier),
 }
 
-impl Node for Identifier {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Identifier {
+    fn span(&self) -> Span {
         match self {
-            Identifier::SimpleIdentifier(identifier) => identifier.children(),
-            Identifier::DynamicIdentifier(identifier) => identifier.children(),
+            Identifier::SimpleIdentifier(identifier) => identifier.span(),
+            Identifier::DynamicIdentifier(identifier) => identifier.span(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleIdentifier {
     pub span: Span,
     pub value: ByteString,
 }
 
-impl Node for SimpleIdentifier {
-    //
+impl Spanned for SimpleIdentifier {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl Display for SimpleIdentifier {
@@ -39,15 +41,15 @@ impl Display for SimpleIdentifier {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicIdentifier {
     pub start: Span,
     pub expr: Box<Expression>,
     pub end: Span,
 }
 
-impl Node for DynamicIdentifier {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.expr.as_mut()]
+impl Spanned for DynamicIdentifier {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
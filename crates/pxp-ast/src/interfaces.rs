@@ -1,63 +1,66 @@
 use crate::attributes::AttributeGroup;
+use crate::comments::CommentGroup;
 use crate::constant::ClassishConstant;
 use crate::functions::AbstractConstructor;
 use crate::functions::AbstractMethod;
 use crate::identifiers::SimpleIdentifier;
-use crate::node::Node;
+use crate::spanned::Spanned;
 use crate::utils::CommaSeparated;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InterfaceMember {
     Constant(ClassishConstant),       // `public const FOO = 123;`
     Constructor(AbstractConstructor), // `public function __construct(): void;`
     Method(AbstractMethod),           // `public function foo(): void;`
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
-impl Node for InterfaceMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for InterfaceMember {
+    fn span(&self) -> Span {
         match self {
-            InterfaceMember::Constant(constant) => vec![constant],
-            InterfaceMember::Constructor(constructor) => vec![constructor],
-            InterfaceMember::Method(method) => vec![method],
+            InterfaceMember::Constant(member) => member.span(),
+            InterfaceMember::Constructor(member) => member.span(),
+            InterfaceMember::Method(member) => member.span(),
+            InterfaceMember::Error(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceExtends {
     pub extends: Span,                             // `extends`
     pub parents: CommaSeparated<SimpleIdentifier>, // `Foo`, `Bar`
 }
 
-impl Node for InterfaceExtends {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.parents.children()
+impl Spanned for InterfaceExtends {
+    fn span(&self) -> Span {
+        self.extends.join(self.parents.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceBody {
     pub left_brace: Span,              // `{`
     pub members: Vec<InterfaceMember>, // `public const FOO = 123;`, `public function foo(): void;`
+    pub comments: CommentGroup,        // after the last member
     pub right_brace: Span,             // `}`
 }
 
-impl Node for InterfaceBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.members
-            .iter_mut()
-            .map(|member| member as &mut dyn Node)
-            .collect()
+impl Spanned for InterfaceBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceStatement {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub interface: Span,                   // `interface`
     pub name: SimpleIdentifier,            // `Foo`
@@ -65,12 +68,13 @@ pub struct InterfaceStatement {
     pub body: InterfaceBody,               // `{ ... }`
 }
 
-impl Node for InterfaceStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
-        if let Some(extends) = &mut self.extends {
-            children.push(extends);
-        }
-        children
+impl Spanned for InterfaceStatement {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.interface, Spanned::span);
+
+        start.join(self.body.span())
     }
 }
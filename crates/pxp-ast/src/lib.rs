@@ -1,3 +1,5 @@
+#![allow(clippy::large_enum_variant)]
+
 use crate::arguments::ArgumentPlaceholder;
 use crate::arguments::{ArgumentList, SingleArgument};
 use crate::classes::AnonymousClassExpression;
@@ -30,6 +32,8 @@ use crate::operators::AssignmentOperationExpression;
 use crate::operators::BitwiseOperationExpression;
 use crate::operators::ComparisonOperationExpression;
 use crate::operators::LogicalOperationExpression;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use crate::traits::TraitStatement;
 use crate::try_block::TryStatement;
 use crate::utils::CommaSeparated;
@@ -48,6 +52,7 @@ pub mod data_type;
 pub mod declares;
 pub mod downcast;
 pub mod enums;
+pub mod fold;
 pub mod functions;
 pub mod goto;
 pub mod identifiers;
@@ -58,25 +63,53 @@ pub mod modifiers;
 pub mod namespaces;
 pub mod node;
 pub mod operators;
+pub mod parents;
 pub mod properties;
+pub mod query;
+pub mod spanned;
 pub mod traits;
 pub mod traverser;
 pub mod try_block;
 pub mod utils;
 pub mod variables;
+pub mod visitor;
+pub mod visitor_mut;
+
+mod node_impls;
+mod spanned_impls;
 
 pub type Block = Vec<Statement>;
 
+// A block is only ever a node of its own when it's the whole program, everywhere else its
+// statements are the children of whatever it's in.
 impl Node for Block {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.iter_mut().map(|s| s as &mut dyn Node).collect()
+    fn kind(&self) -> &'static str {
+        "Program"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        self.iter_mut()
+            .map(|statement| ("statements", statement as &mut dyn Node))
+            .collect()
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.iter()
+            .map(|statement| ("statements", statement as &dyn Node))
+            .collect()
+    }
+}
+
+impl Spanned for Block {
+    fn span(&self) -> Span {
+        span_of(self).unwrap_or_default()
     }
 }
 
 pub type Program = Block;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UseKind {
     Normal,
     Function,
@@ -84,51 +117,63 @@ pub enum UseKind {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticVar {
     pub var: Variable,
     pub default: Option<Expression>,
 }
 
-impl Node for StaticVar {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.var];
-        if let Some(default) = &mut self.default {
-            children.push(default);
-        }
-        children
+impl Spanned for StaticVar {
+    fn span(&self) -> Span {
+        self.var.span().join(self.default.as_ref().map_or(self.var.span(), Spanned::span))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ending {
     Semicolon(Span),
     CloseTag(Span),
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl Spanned for Ending {
+    fn span(&self) -> Span {
+        match self {
+            Ending::Semicolon(span) => *span,
+            Ending::CloseTag(span) => *span,
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HaltCompilerStatement {
+    pub span: Span,
     pub content: Option<ByteString>,
 }
 
-impl Node for HaltCompilerStatement {}
+impl Spanned for HaltCompilerStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticStatement {
+    pub r#static: Span,
     pub vars: Vec<StaticVar>,
+    pub semicolon: Span,
 }
 
-impl Node for StaticStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.vars.iter_mut().map(|v| v as &mut dyn Node).collect()
+impl Spanned for StaticStatement {
+    fn span(&self) -> Span {
+        self.r#static.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchStatement {
     pub switch: Span,
     pub left_parenthesis: Span,
@@ -137,77 +182,73 @@ pub struct SwitchStatement {
     pub cases: Vec<Case>,
 }
 
-impl Node for SwitchStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.condition];
-        children.extend(self.cases.iter_mut().map(|c| c as &mut dyn Node));
-        children
+impl Spanned for SwitchStatement {
+    fn span(&self) -> Span {
+        self.switch.join(span_of(&self.cases).unwrap_or(self.right_parenthesis))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EchoStatement {
     pub echo: Span,
     pub values: Vec<Expression>,
     pub ending: Ending,
 }
 
-impl Node for EchoStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.values.iter_mut().map(|v| v as &mut dyn Node).collect()
+impl Spanned for EchoStatement {
+    fn span(&self) -> Span {
+        self.echo.join(self.ending.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReturnStatement {
     pub r#return: Span,
     pub value: Option<Expression>,
     pub ending: Ending,
 }
 
-impl Node for ReturnStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        if let Some(value) = &mut self.value {
-            vec![value]
-        } else {
-            vec![]
-        }
+impl Spanned for ReturnStatement {
+    fn span(&self) -> Span {
+        self.r#return.join(self.ending.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UseStatement {
+    pub r#use: Span,
     pub kind: UseKind,
     pub uses: Vec<Use>,
+    pub semicolon: Span,
 }
 
-impl Node for UseStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.uses.iter_mut().map(|u| u as &mut dyn Node).collect()
+impl Spanned for UseStatement {
+    fn span(&self) -> Span {
+        self.r#use.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupUseStatement {
+    pub r#use: Span,
     pub prefix: SimpleIdentifier,
     pub kind: UseKind,
     pub uses: Vec<Use>,
+    pub semicolon: Span,
 }
 
-impl Node for GroupUseStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.prefix];
-        children.extend(self.uses.iter_mut().map(|u| u as &mut dyn Node));
-        children
+impl Spanned for GroupUseStatement {
+    fn span(&self) -> Span {
+        self.r#use.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Statement {
     FullOpeningTag(FullOpeningTagStatement),
     ShortOpeningTag(ShortOpeningTagStatement),
@@ -245,125 +286,158 @@ pub enum Statement {
     Global(GlobalStatement),
     Declare(DeclareStatement),
     Noop(Span),
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InlineHtmlStatement {
+    pub span: Span,
     pub html: ByteString,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl Spanned for InlineHtmlStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FullOpeningTagStatement {
     pub span: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl Spanned for FullOpeningTagStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShortOpeningTagStatement {
     pub span: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl Spanned for ShortOpeningTagStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EchoOpeningTagStatement {
     pub span: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl Spanned for EchoOpeningTagStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClosingTagStatement {
     pub span: Span,
 }
 
-impl Node for Statement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ClosingTagStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl Spanned for Statement {
+    fn span(&self) -> Span {
         match self {
-            Statement::Label(statement) => vec![statement],
-            Statement::Goto(statement) => vec![statement],
-            Statement::HaltCompiler(statement) => vec![statement],
-            Statement::Static(statement) => vec![statement],
-            Statement::DoWhile(statement) => vec![statement],
-            Statement::While(statement) => vec![statement],
-            Statement::For(statement) => vec![statement],
-            Statement::Foreach(statement) => vec![statement],
-            Statement::Break(statement) => vec![statement],
-            Statement::Continue(statement) => vec![statement],
-            Statement::Constant(statement) => vec![statement],
-            Statement::Function(statement) => vec![statement],
-            Statement::Class(statement) => vec![statement],
-            Statement::Trait(statement) => vec![statement],
-            Statement::Interface(statement) => vec![statement],
-            Statement::If(statement) => vec![statement],
-            Statement::Switch(statement) => vec![statement],
-            Statement::Echo(statement) => vec![statement],
-            Statement::Expression(statement) => vec![statement],
-            Statement::Return(statement) => vec![statement],
-            Statement::Namespace(statement) => vec![statement],
-            Statement::Use(statement) => vec![statement],
-            Statement::GroupUse(statement) => vec![statement],
-            Statement::Comment(statement) => vec![statement],
-            Statement::Try(statement) => vec![statement],
-            Statement::UnitEnum(statement) => vec![statement],
-            Statement::BackedEnum(statement) => vec![statement],
-            Statement::Block(statement) => vec![statement],
-            Statement::Global(statement) => vec![statement],
-            Statement::Declare(statement) => vec![statement],
-            _ => vec![],
+            Statement::FullOpeningTag(statement) => statement.span(),
+            Statement::ShortOpeningTag(statement) => statement.span(),
+            Statement::EchoOpeningTag(statement) => statement.span(),
+            Statement::ClosingTag(statement) => statement.span(),
+            Statement::InlineHtml(statement) => statement.span(),
+            Statement::Label(statement) => statement.span(),
+            Statement::Goto(statement) => statement.span(),
+            Statement::HaltCompiler(statement) => statement.span(),
+            Statement::Static(statement) => statement.span(),
+            Statement::DoWhile(statement) => statement.span(),
+            Statement::While(statement) => statement.span(),
+            Statement::For(statement) => statement.span(),
+            Statement::Foreach(statement) => statement.span(),
+            Statement::Break(statement) => statement.span(),
+            Statement::Continue(statement) => statement.span(),
+            Statement::Constant(statement) => statement.span(),
+            Statement::Function(statement) => statement.span(),
+            Statement::Class(statement) => statement.span(),
+            Statement::Trait(statement) => statement.span(),
+            Statement::Interface(statement) => statement.span(),
+            Statement::If(statement) => statement.span(),
+            Statement::Switch(statement) => statement.span(),
+            Statement::Echo(statement) => statement.span(),
+            Statement::Expression(statement) => statement.span(),
+            Statement::Return(statement) => statement.span(),
+            Statement::Namespace(statement) => statement.span(),
+            Statement::Use(statement) => statement.span(),
+            Statement::GroupUse(statement) => statement.span(),
+            Statement::Comment(statement) => statement.span(),
+            Statement::Try(statement) => statement.span(),
+            Statement::UnitEnum(statement) => statement.span(),
+            Statement::BackedEnum(statement) => statement.span(),
+            Statement::Block(statement) => statement.span(),
+            Statement::Global(statement) => statement.span(),
+            Statement::Declare(statement) => statement.span(),
+            Statement::Noop(span)
+            | Statement::Error(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpressionStatement {
     pub expression: Expression,
     pub ending: Ending,
 }
 
-impl Node for ExpressionStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.expression]
+impl Spanned for ExpressionStatement {
+    fn span(&self) -> Span {
+        self.expression.span().join(self.ending.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GlobalStatement {
     pub global: Span,
     pub variables: Vec<Variable>,
 }
 
-impl Node for GlobalStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.variables
-            .iter_mut()
-            .map(|v| v as &mut dyn Node)
-            .collect()
+impl Spanned for GlobalStatement {
+    fn span(&self) -> Span {
+        self.global.join(span_of(&self.variables).unwrap_or(self.global))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlockStatement {
     pub left_brace: Span,
     pub statements: Vec<Statement>,
     pub right_brace: Span,
 }
 
-impl Node for BlockStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.statements
-            .iter_mut()
-            .map(|s| s as &mut dyn Node)
-            .collect()
+impl Spanned for BlockStatement {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 // See https://www.php.net/manual/en/language.types.type-juggling.php#language.types.typecasting for more info.
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CastKind {
     Int,
     Bool,
@@ -374,9 +448,11 @@ pub enum CastKind {
     Unset,
 }
 
-impl From<TokenKind> for CastKind {
-    fn from(kind: TokenKind) -> Self {
-        match kind {
+impl TryFrom<TokenKind> for CastKind {
+    type Error = TokenKind;
+
+    fn try_from(kind: TokenKind) -> Result<Self, Self::Error> {
+        Ok(match kind {
             TokenKind::StringCast | TokenKind::BinaryCast => Self::String,
             TokenKind::ObjectCast => Self::Object,
             TokenKind::BoolCast | TokenKind::BooleanCast => Self::Bool,
@@ -384,59 +460,50 @@ impl From<TokenKind> for CastKind {
             TokenKind::FloatCast | TokenKind::DoubleCast | TokenKind::RealCast => Self::Float,
             TokenKind::UnsetCast => Self::Unset,
             TokenKind::ArrayCast => Self::Array,
-            _ => unreachable!(),
-        }
+            kind => return Err(kind),
+        })
     }
 }
 
-impl From<&TokenKind> for CastKind {
-    fn from(kind: &TokenKind) -> Self {
-        kind.clone().into()
+impl TryFrom<&TokenKind> for CastKind {
+    type Error = TokenKind;
+
+    fn try_from(kind: &TokenKind) -> Result<Self, Self::Error> {
+        kind.clone().try_into()
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Case {
+    pub span: Span,
     pub condition: Option<Expression>,
     pub body: Block,
 }
 
-impl Node for Case {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(condition) = &mut self.condition {
-            children.push(condition);
-        }
-        children.extend(
-            self.body
-                .iter_mut()
-                .map(|statement| statement as &mut dyn Node)
-                .collect::<Vec<&mut dyn Node>>(),
-        );
-        children
+impl Spanned for Case {
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Use {
+    pub span: Span,
     pub name: SimpleIdentifier,
     pub alias: Option<SimpleIdentifier>,
     pub kind: Option<UseKind>,
 }
 
-impl Node for Use {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
-        if let Some(alias) = &mut self.alias {
-            children.push(alias);
-        }
-        children
+impl Spanned for Use {
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EvalExpression {
     pub eval: Span,
     // eval
@@ -444,6 +511,7 @@ pub struct EvalExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmptyExpression {
     pub empty: Span,
     // empty
@@ -451,6 +519,7 @@ pub struct EmptyExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DieExpression {
     pub die: Span,
     // die
@@ -458,6 +527,7 @@ pub struct DieExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExitExpression {
     pub exit: Span,
     // exit
@@ -465,6 +535,7 @@ pub struct ExitExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IssetExpression {
     pub isset: Span,
     // isset
@@ -472,6 +543,7 @@ pub struct IssetExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnsetExpression {
     pub unset: Span,
     // unset
@@ -479,6 +551,7 @@ pub struct UnsetExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrintExpression {
     pub print: Span,
     // print
@@ -488,6 +561,7 @@ pub struct PrintExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConcatExpression {
     pub left: Box<Expression>,
     pub dot: Span,
@@ -495,6 +569,7 @@ pub struct ConcatExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InstanceofExpression {
     pub left: Box<Expression>,
     pub instanceof: Span,
@@ -502,12 +577,14 @@ pub struct InstanceofExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferenceExpression {
     pub ampersand: Span,
     pub right: Box<Expression>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParenthesizedExpression {
     pub start: Span,
     pub expr: Box<Expression>,
@@ -515,62 +592,70 @@ pub struct ParenthesizedExpression {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ErrorSuppressExpression {
     pub at: Span,
     pub expr: Box<Expression>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncludeExpression {
     pub include: Span,
     pub path: Box<Expression>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncludeOnceExpression {
     pub include_once: Span,
     pub path: Box<Expression>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequireExpression {
     pub require: Span,
     pub path: Box<Expression>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequireOnceExpression {
     pub require_once: Span,
     pub path: Box<Expression>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionCallExpression {
     pub target: Box<Expression>,
     // `foo`
     pub arguments: ArgumentList, // `(1, 2, 3)`
 }
 
-impl Node for FunctionCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.arguments]
+impl Spanned for FunctionCallExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.arguments.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionClosureCreationExpression {
     pub target: Box<Expression>,
     // `foo`
     pub placeholder: ArgumentPlaceholder, // `(...)`
 }
 
-impl Node for FunctionClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut()]
+impl Spanned for FunctionClosureCreationExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.placeholder.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodCallExpression {
     pub target: Box<Expression>,
     // `$foo`
@@ -581,17 +666,14 @@ pub struct MethodCallExpression {
     pub arguments: ArgumentList, // `(1, 2, 3)`
 }
 
-impl Node for MethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![
-            self.target.as_mut(),
-            self.method.as_mut(),
-            &mut self.arguments,
-        ]
+impl Spanned for MethodCallExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.arguments.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodClosureCreationExpression {
     pub target: Box<Expression>,
     // `$foo`
@@ -602,13 +684,14 @@ pub struct MethodClosureCreationExpression {
     pub placeholder: ArgumentPlaceholder, // `(...)`
 }
 
-impl Node for MethodClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), self.method.as_mut()]
+impl Spanned for MethodClosureCreationExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.placeholder.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NullsafeMethodCallExpression {
     pub target: Box<Expression>,
     // `$foo`
@@ -619,17 +702,14 @@ pub struct NullsafeMethodCallExpression {
     pub arguments: ArgumentList, // `(1, 2, 3)`
 }
 
-impl Node for NullsafeMethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![
-            self.target.as_mut(),
-            self.method.as_mut(),
-            &mut self.arguments,
-        ]
+impl Spanned for NullsafeMethodCallExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.arguments.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticMethodCallExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -640,13 +720,14 @@ pub struct StaticMethodCallExpression {
     pub arguments: ArgumentList, // `(1, 2, 3)`
 }
 
-impl Node for StaticMethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.method, &mut self.arguments]
+impl Spanned for StaticMethodCallExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.arguments.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticVariableMethodCallExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -657,13 +738,14 @@ pub struct StaticVariableMethodCallExpression {
     pub arguments: ArgumentList, // `(1, 2, 3)`
 }
 
-impl Node for StaticVariableMethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.method, &mut self.arguments]
+impl Spanned for StaticVariableMethodCallExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.arguments.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticMethodClosureCreationExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -674,13 +756,14 @@ pub struct StaticMethodClosureCreationExpression {
     pub placeholder: ArgumentPlaceholder, // `(...)`
 }
 
-impl Node for StaticMethodClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.method]
+impl Spanned for StaticMethodClosureCreationExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.placeholder.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticVariableMethodClosureCreationExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -691,13 +774,14 @@ pub struct StaticVariableMethodClosureCreationExpression {
     pub placeholder: ArgumentPlaceholder, // `(...)`
 }
 
-impl Node for StaticVariableMethodClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.method]
+impl Spanned for StaticVariableMethodClosureCreationExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.placeholder.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PropertyFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -706,13 +790,14 @@ pub struct PropertyFetchExpression {
     pub property: Box<Expression>, // `bar`
 }
 
-impl Node for PropertyFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), self.property.as_mut()]
+impl Spanned for PropertyFetchExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.property.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NullsafePropertyFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -721,13 +806,14 @@ pub struct NullsafePropertyFetchExpression {
     pub property: Box<Expression>, // `bar`
 }
 
-impl Node for NullsafePropertyFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), self.property.as_mut()]
+impl Spanned for NullsafePropertyFetchExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.property.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StaticPropertyFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -736,13 +822,14 @@ pub struct StaticPropertyFetchExpression {
     pub property: Variable, // `$bar`
 }
 
-impl Node for StaticPropertyFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.property]
+impl Spanned for StaticPropertyFetchExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.property.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -751,13 +838,14 @@ pub struct ConstantFetchExpression {
     pub constant: Identifier, // `bar`
 }
 
-impl Node for ConstantFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut(), &mut self.constant]
+impl Spanned for ConstantFetchExpression {
+    fn span(&self) -> Span {
+        self.target.span().join(self.constant.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShortArrayExpression {
     pub start: Span,
     // `[`
@@ -766,13 +854,14 @@ pub struct ShortArrayExpression {
     pub end: Span, // `]`
 }
 
-impl Node for ShortArrayExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.items]
+impl Spanned for ShortArrayExpression {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArrayExpression {
     pub array: Span,
     // `array`
@@ -783,13 +872,14 @@ pub struct ArrayExpression {
     pub end: Span, // `)`
 }
 
-impl Node for ArrayExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.items]
+impl Spanned for ArrayExpression {
+    fn span(&self) -> Span {
+        self.array.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListExpression {
     pub list: Span,
     // `list`
@@ -800,13 +890,14 @@ pub struct ListExpression {
     pub end: Span, // `)`
 }
 
-impl Node for ListExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.items.iter_mut().map(|i| i as &mut dyn Node).collect()
+impl Spanned for ListExpression {
+    fn span(&self) -> Span {
+        self.list.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NewExpression {
     pub new: Span,
     // `new`
@@ -815,75 +906,89 @@ pub struct NewExpression {
     pub arguments: Option<ArgumentList>, // `(1, 2, 3)`
 }
 
-impl Node for NewExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![self.target.as_mut()];
-        if let Some(arguments) = &mut self.arguments {
-            children.push(arguments);
-        }
-        children
+impl Spanned for NewExpression {
+    fn span(&self) -> Span {
+        let target = self.target.span();
+
+        // The arguments of an anonymous class come before its body.
+        let end = match &self.arguments {
+            Some(arguments) if arguments.span().end.offset > target.end.offset => arguments.span(),
+            _ => target,
+        };
+
+        self.new.join(end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterpolatedStringExpression {
+    pub span: Span,
     pub parts: Vec<StringPart>,
 }
 
-impl Node for InterpolatedStringExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.parts
-            .iter_mut()
-            .map(|part| part as &mut dyn Node)
-            .collect()
+impl Spanned for InterpolatedStringExpression {
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HeredocExpression {
+    pub span: Span,
     pub label: ByteString,
     pub parts: Vec<StringPart>,
 }
 
-impl Node for HeredocExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.parts
-            .iter_mut()
-            .map(|part| part as &mut dyn Node)
-            .collect()
+impl Spanned for HeredocExpression {
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NowdocExpression {
+    pub span: Span,
     pub label: ByteString,
     pub value: ByteString,
 }
 
-impl Node for NowdocExpression {}
+impl Spanned for NowdocExpression {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShellExecExpression {
+    pub span: Span,
     pub parts: Vec<StringPart>,
 }
 
-impl Node for ShellExecExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.parts
-            .iter_mut()
-            .map(|part| part as &mut dyn Node)
-            .collect()
+impl Spanned for ShellExecExpression {
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolExpression {
+    pub span: Span,
     pub value: bool,
 }
 
-impl Node for BoolExpression {}
+impl Spanned for BoolExpression {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArrayIndexExpression {
     pub array: Box<Expression>,
     pub left_bracket: Span,
@@ -891,17 +996,14 @@ pub struct ArrayIndexExpression {
     pub right_bracket: Span,
 }
 
-impl Node for ArrayIndexExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(index) = &mut self.index {
-            children.push(index.as_mut());
-        }
-        children
+impl Spanned for ArrayIndexExpression {
+    fn span(&self) -> Span {
+        self.array.span().join(self.right_bracket)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShortTernaryExpression {
     pub condition: Box<Expression>,
     // `foo()`
@@ -910,13 +1012,14 @@ pub struct ShortTernaryExpression {
     pub r#else: Box<Expression>, // `bar()`
 }
 
-impl Node for ShortTernaryExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.condition.as_mut(), self.r#else.as_mut()]
+impl Spanned for ShortTernaryExpression {
+    fn span(&self) -> Span {
+        self.condition.span().join(self.r#else.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TernaryExpression {
     pub condition: Box<Expression>,
     // `foo()`
@@ -929,41 +1032,41 @@ pub struct TernaryExpression {
     pub r#else: Box<Expression>, // `baz()`
 }
 
-impl Node for TernaryExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![
-            self.condition.as_mut(),
-            self.then.as_mut(),
-            self.r#else.as_mut(),
-        ]
+impl Spanned for TernaryExpression {
+    fn span(&self) -> Span {
+        self.condition.span().join(self.r#else.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoalesceExpression {
     pub lhs: Box<Expression>,
     pub double_question: Span,
     pub rhs: Box<Expression>,
 }
 
-impl Node for CoalesceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.lhs.as_mut(), self.rhs.as_mut()]
+impl Spanned for CoalesceExpression {
+    fn span(&self) -> Span {
+        self.lhs.span().join(self.rhs.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CloneExpression {
+    pub clone: Span,
     pub target: Box<Expression>,
 }
 
-impl Node for CloneExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.target.as_mut()]
+impl Spanned for CloneExpression {
+    fn span(&self) -> Span {
+        self.clone.join(self.target.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MatchExpression {
     pub keyword: Span,
     pub left_parenthesis: Span,
@@ -975,78 +1078,69 @@ pub struct MatchExpression {
     pub right_brace: Span,
 }
 
-impl Node for MatchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![self.condition.as_mut()];
-        if let Some(default) = &mut self.default {
-            children.push(default.as_mut());
-        }
-        children.extend(
-            self.arms
-                .iter_mut()
-                .map(|arm| arm as &mut dyn Node)
-                .collect::<Vec<&mut dyn Node>>(),
-        );
-        children
+impl Spanned for MatchExpression {
+    fn span(&self) -> Span {
+        self.keyword.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThrowExpression {
+    pub throw: Span,
     pub value: Box<Expression>,
 }
 
-impl Node for ThrowExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.value.as_mut()]
+impl Spanned for ThrowExpression {
+    fn span(&self) -> Span {
+        self.throw.join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct YieldExpression {
+    pub r#yield: Span,
     pub key: Option<Box<Expression>>,
     pub value: Option<Box<Expression>>,
 }
 
-impl Node for YieldExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(key) = &mut self.key {
-            children.push(key.as_mut());
-        }
-        if let Some(value) = &mut self.value {
-            children.push(value.as_mut());
-        }
-        children
+impl Spanned for YieldExpression {
+    fn span(&self) -> Span {
+        self.r#yield.join(self.value.as_ref().map_or(self.r#yield, Spanned::span))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct YieldFromExpression {
+    pub r#yield: Span,
+    pub from: Span,
     pub value: Box<Expression>,
 }
 
-impl Node for YieldFromExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.value.as_mut()]
+impl Spanned for YieldFromExpression {
+    fn span(&self) -> Span {
+        self.r#yield.join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CastExpression {
     pub cast: Span,
     pub kind: CastKind,
     pub value: Box<Expression>,
 }
 
-impl Node for CastExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.value.as_mut()]
+impl Spanned for CastExpression {
+    fn span(&self) -> Span {
+        self.cast.join(self.value.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expression {
     // eval("$a = 1")
     Eval(EvalExpression),
@@ -1117,11 +1211,11 @@ pub enum Expression {
     // `foo()::bar` or `foo()::{$name}`
     ConstantFetch(ConstantFetchExpression),
     // `static`
-    Static,
+    Static(Span),
     // `self`
-    Self_,
+    Self_(Span),
     // `parent`
-    Parent,
+    Parent(Span),
     // `[1, 2, 3]`
     ShortArray(ShortArrayExpression),
     // `array(1, 2, 3)`
@@ -1149,7 +1243,7 @@ pub enum Expression {
     // `$foo[0]`
     ArrayIndex(ArrayIndexExpression),
     // `null`
-    Null,
+    Null(Span),
     // `__DIR__`, etc
     MagicConstant(MagicConstantExpression),
     // `foo() ?: bar()`
@@ -1171,227 +1265,217 @@ pub enum Expression {
     // `(int) "1"`, etc
     Cast(CastExpression),
     // ;
-    Noop,
+    Noop(Span),
+    // `$a = ;`, an expression that should have been there but wasn't.
+    Missing(Span),
 }
 
-impl Node for EvalExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.argument.as_mut()]
+impl Spanned for EvalExpression {
+    fn span(&self) -> Span {
+        self.eval.join(self.argument.span())
     }
 }
 
-impl Node for EmptyExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.argument.as_mut()]
+impl Spanned for EmptyExpression {
+    fn span(&self) -> Span {
+        self.empty.join(self.argument.span())
     }
 }
 
-impl Node for DieExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        if let Some(argument) = &mut self.argument {
-            vec![argument.as_mut()]
-        } else {
-            vec![]
-        }
+impl Spanned for DieExpression {
+    fn span(&self) -> Span {
+        self.die.join(self.argument.as_ref().map_or(self.die, Spanned::span))
     }
 }
 
-impl Node for ExitExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        if let Some(argument) = &mut self.argument {
-            vec![argument.as_mut()]
-        } else {
-            vec![]
-        }
+impl Spanned for ExitExpression {
+    fn span(&self) -> Span {
+        self.exit.join(self.argument.as_ref().map_or(self.exit, Spanned::span))
     }
 }
 
-impl Node for IssetExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.arguments]
+impl Spanned for IssetExpression {
+    fn span(&self) -> Span {
+        self.isset.join(self.arguments.span())
     }
 }
 
-impl Node for UnsetExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.arguments]
+impl Spanned for UnsetExpression {
+    fn span(&self) -> Span {
+        self.unset.join(self.arguments.span())
     }
 }
 
-impl Node for PrintExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        if let Some(argument) = &mut self.argument {
-            vec![argument.as_mut()]
-        } else if let Some(value) = &mut self.value {
-            vec![value.as_mut()]
-        } else {
-            vec![]
-        }
+impl Spanned for PrintExpression {
+    fn span(&self) -> Span {
+        let end = self
+            .value
+            .as_ref()
+            .map(Spanned::span)
+            .or_else(|| self.argument.as_ref().map(Spanned::span))
+            .unwrap_or(self.print);
+
+        self.print.join(end)
     }
 }
 
-impl Node for ConcatExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.left.as_mut(), self.right.as_mut()]
+impl Spanned for ConcatExpression {
+    fn span(&self) -> Span {
+        self.left.span().join(self.right.span())
     }
 }
 
-impl Node for InstanceofExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.left.as_mut(), self.right.as_mut()]
+impl Spanned for InstanceofExpression {
+    fn span(&self) -> Span {
+        self.left.span().join(self.right.span())
     }
 }
 
-impl Node for ReferenceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.right.as_mut()]
+impl Spanned for ReferenceExpression {
+    fn span(&self) -> Span {
+        self.ampersand.join(self.right.span())
     }
 }
 
-impl Node for ParenthesizedExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.expr.as_mut()]
+impl Spanned for ParenthesizedExpression {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
 
-impl Node for ErrorSuppressExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.expr.as_mut()]
+impl Spanned for ErrorSuppressExpression {
+    fn span(&self) -> Span {
+        self.at.join(self.expr.span())
     }
 }
 
-impl Node for IncludeExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.path.as_mut()]
+impl Spanned for IncludeExpression {
+    fn span(&self) -> Span {
+        self.include.join(self.path.span())
     }
 }
 
-impl Node for IncludeOnceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.path.as_mut()]
+impl Spanned for IncludeOnceExpression {
+    fn span(&self) -> Span {
+        self.include_once.join(self.path.span())
     }
 }
 
-impl Node for RequireExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.path.as_mut()]
+impl Spanned for RequireExpression {
+    fn span(&self) -> Span {
+        self.require.join(self.path.span())
     }
 }
 
-impl Node for RequireOnceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.path.as_mut()]
+impl Spanned for RequireOnceExpression {
+    fn span(&self) -> Span {
+        self.require_once.join(self.path.span())
     }
 }
 
-impl Node for Expression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Expression {
+    fn span(&self) -> Span {
         match self {
-            Expression::Eval(expression) => vec![expression],
-            Expression::Empty(expression) => vec![expression],
-            Expression::Die(expression) => vec![expression],
-            Expression::Exit(expression) => vec![expression],
-            Expression::Isset(expression) => vec![expression],
-            Expression::Unset(expression) => vec![expression],
-            Expression::Print(expression) => vec![expression],
-            Expression::Literal(literal) => vec![literal],
-            Expression::ArithmeticOperation(operation) => vec![operation],
-            Expression::AssignmentOperation(operation) => vec![operation],
-            Expression::BitwiseOperation(operation) => vec![operation],
-            Expression::ComparisonOperation(operation) => vec![operation],
-            Expression::LogicalOperation(operation) => vec![operation],
-            Expression::Concat(expression) => vec![expression],
-            Expression::Instanceof(expression) => vec![expression],
-            Expression::Reference(expression) => vec![expression],
-            Expression::Parenthesized(expression) => vec![expression],
-            Expression::ErrorSuppress(expression) => vec![expression],
-            Expression::Identifier(identifier) => vec![identifier],
-            Expression::Variable(variable) => vec![variable],
-            Expression::Include(expression) => vec![expression],
-            Expression::IncludeOnce(expression) => vec![expression],
-            Expression::Require(expression) => vec![expression],
-            Expression::RequireOnce(expression) => vec![expression],
-            Expression::FunctionCall(expression) => vec![expression],
-            Expression::FunctionClosureCreation(expression) => vec![expression],
-            Expression::MethodCall(expression) => vec![expression],
-            Expression::MethodClosureCreation(expression) => vec![expression],
-            Expression::NullsafeMethodCall(expression) => vec![expression],
-            Expression::StaticMethodCall(expression) => vec![expression],
-            Expression::StaticVariableMethodCall(expression) => vec![expression],
-            Expression::StaticMethodClosureCreation(expression) => vec![expression],
-            Expression::StaticVariableMethodClosureCreation(expression) => vec![expression],
-            Expression::PropertyFetch(expression) => vec![expression],
-            Expression::NullsafePropertyFetch(expression) => vec![expression],
-            Expression::StaticPropertyFetch(expression) => vec![expression],
-            Expression::ConstantFetch(expression) => vec![expression],
-            Expression::Static => vec![],
-            Expression::Self_ => vec![],
-            Expression::Parent => vec![],
-            Expression::ShortArray(expression) => vec![expression],
-            Expression::Array(expression) => vec![expression],
-            Expression::List(expression) => vec![expression],
-            Expression::Closure(expression) => vec![expression],
-            Expression::ArrowFunction(expression) => vec![expression],
-            Expression::New(expression) => vec![expression],
-            Expression::InterpolatedString(expression) => vec![expression],
-            Expression::Heredoc(expression) => vec![expression],
-            Expression::Nowdoc(expression) => vec![expression],
-            Expression::ShellExec(expression) => vec![expression],
-            Expression::AnonymousClass(expression) => vec![expression],
-            Expression::Bool(_) => vec![],
-            Expression::ArrayIndex(expression) => vec![expression],
-            Expression::Null => vec![],
-            Expression::MagicConstant(constant) => vec![constant],
-            Expression::ShortTernary(expression) => vec![expression],
-            Expression::Ternary(expression) => vec![expression],
-            Expression::Coalesce(expression) => vec![expression],
-            Expression::Clone(expression) => vec![expression],
-            Expression::Match(expression) => vec![expression],
-            Expression::Throw(expression) => vec![expression],
-            Expression::Yield(expression) => vec![expression],
-            Expression::YieldFrom(expression) => vec![expression],
-            Expression::Cast(expression) => vec![expression],
-            Expression::Noop => vec![],
+            Expression::Eval(expression) => expression.span(),
+            Expression::Empty(expression) => expression.span(),
+            Expression::Die(expression) => expression.span(),
+            Expression::Exit(expression) => expression.span(),
+            Expression::Isset(expression) => expression.span(),
+            Expression::Unset(expression) => expression.span(),
+            Expression::Print(expression) => expression.span(),
+            Expression::Literal(expression) => expression.span(),
+            Expression::ArithmeticOperation(expression) => expression.span(),
+            Expression::AssignmentOperation(expression) => expression.span(),
+            Expression::BitwiseOperation(expression) => expression.span(),
+            Expression::ComparisonOperation(expression) => expression.span(),
+            Expression::LogicalOperation(expression) => expression.span(),
+            Expression::Concat(expression) => expression.span(),
+            Expression::Instanceof(expression) => expression.span(),
+            Expression::Reference(expression) => expression.span(),
+            Expression::Parenthesized(expression) => expression.span(),
+            Expression::ErrorSuppress(expression) => expression.span(),
+            Expression::Identifier(expression) => expression.span(),
+            Expression::Variable(expression) => expression.span(),
+            Expression::Include(expression) => expression.span(),
+            Expression::IncludeOnce(expression) => expression.span(),
+            Expression::Require(expression) => expression.span(),
+            Expression::RequireOnce(expression) => expression.span(),
+            Expression::FunctionCall(expression) => expression.span(),
+            Expression::FunctionClosureCreation(expression) => expression.span(),
+            Expression::MethodCall(expression) => expression.span(),
+            Expression::MethodClosureCreation(expression) => expression.span(),
+            Expression::NullsafeMethodCall(expression) => expression.span(),
+            Expression::StaticMethodCall(expression) => expression.span(),
+            Expression::StaticVariableMethodCall(expression) => expression.span(),
+            Expression::StaticMethodClosureCreation(expression) => expression.span(),
+            Expression::StaticVariableMethodClosureCreation(expression) => expression.span(),
+            Expression::PropertyFetch(expression) => expression.span(),
+            Expression::NullsafePropertyFetch(expression) => expression.span(),
+            Expression::StaticPropertyFetch(expression) => expression.span(),
+            Expression::ConstantFetch(expression) => expression.span(),
+            Expression::ShortArray(expression) => expression.span(),
+            Expression::Array(expression) => expression.span(),
+            Expression::List(expression) => expression.span(),
+            Expression::Closure(expression) => expression.span(),
+            Expression::ArrowFunction(expression) => expression.span(),
+            Expression::New(expression) => expression.span(),
+            Expression::InterpolatedString(expression) => expression.span(),
+            Expression::Heredoc(expression) => expression.span(),
+            Expression::Nowdoc(expression) => expression.span(),
+            Expression::ShellExec(expression) => expression.span(),
+            Expression::AnonymousClass(expression) => expression.span(),
+            Expression::Bool(expression) => expression.span(),
+            Expression::ArrayIndex(expression) => expression.span(),
+            Expression::MagicConstant(expression) => expression.span(),
+            Expression::ShortTernary(expression) => expression.span(),
+            Expression::Ternary(expression) => expression.span(),
+            Expression::Coalesce(expression) => expression.span(),
+            Expression::Clone(expression) => expression.span(),
+            Expression::Match(expression) => expression.span(),
+            Expression::Throw(expression) => expression.span(),
+            Expression::Yield(expression) => expression.span(),
+            Expression::YieldFrom(expression) => expression.span(),
+            Expression::Cast(expression) => expression.span(),
+            Expression::Static(span)
+            | Expression::Self_(span)
+            | Expression::Parent(span)
+            | Expression::Null(span)
+            | Expression::Noop(span)
+            | Expression::Missing(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DefaultMatchArm {
     pub keyword: Span,      // `default`
     pub double_arrow: Span, // `=>`
     pub body: Expression,   // `foo()`
 }
 
-impl Node for DefaultMatchArm {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.body]
+impl Spanned for DefaultMatchArm {
+    fn span(&self) -> Span {
+        self.keyword.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MatchArm {
     pub conditions: Vec<Expression>,
     pub arrow: Span,
     pub body: Expression,
 }
 
-impl Node for MatchArm {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = self
-            .conditions
-            .iter_mut()
-            .map(|condition| condition as &mut dyn Node)
-            .collect();
-        children.push(&mut self.body);
-        children
+impl Spanned for MatchArm {
+    fn span(&self) -> Span {
+        span_of(&self.conditions).unwrap_or(self.arrow).join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MagicConstantExpression {
     Directory(Span),
     File(Span),
@@ -1404,52 +1488,67 @@ pub enum MagicConstantExpression {
     CompilerHaltOffset(Span),
 }
 
-impl Node for MagicConstantExpression {
-    //
+impl Spanned for MagicConstantExpression {
+    fn span(&self) -> Span {
+        match self {
+            MagicConstantExpression::Directory(span) => *span,
+            MagicConstantExpression::File(span) => *span,
+            MagicConstantExpression::Line(span) => *span,
+            MagicConstantExpression::Class(span) => *span,
+            MagicConstantExpression::Function(span) => *span,
+            MagicConstantExpression::Method(span) => *span,
+            MagicConstantExpression::Namespace(span) => *span,
+            MagicConstantExpression::Trait(span) => *span,
+            MagicConstantExpression::CompilerHaltOffset(span) => *span,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StringPart {
     Literal(LiteralStringPart),
     Expression(ExpressionStringPart),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiteralStringPart {
+    pub span: Span,
     pub value: ByteString,
 }
 
-impl Node for LiteralStringPart {
-    //
+impl Spanned for LiteralStringPart {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpressionStringPart {
     pub expression: Box<Expression>,
 }
 
-impl Node for ExpressionStringPart {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.expression.as_mut()]
+impl Spanned for ExpressionStringPart {
+    fn span(&self) -> Span {
+        self.expression.span()
     }
 }
 
-impl Node for StringPart {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for StringPart {
+    fn span(&self) -> Span {
         match self {
-            StringPart::Literal(part) => vec![part],
-            StringPart::Expression(part) => vec![part],
+            StringPart::Literal(part) => part.span(),
+            StringPart::Expression(part) => part.span(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrayItem {
-    Skipped,
+    Skipped(Span),
     Value {
         value: Expression, // `$foo`
     },
@@ -1474,35 +1573,23 @@ pub enum ArrayItem {
     },
 }
 
-impl Node for ArrayItem {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ArrayItem {
+    fn span(&self) -> Span {
         match self {
-            ArrayItem::Skipped => vec![],
-            ArrayItem::Value { value } => vec![value],
-            ArrayItem::ReferencedValue {
-                ampersand: _,
-                value,
-            } => vec![value],
-            ArrayItem::SpreadValue { ellipsis: _, value } => vec![value],
-            ArrayItem::KeyValue {
-                key,
-                double_arrow: _,
-                value,
-            } => vec![key, value],
-            ArrayItem::ReferencedKeyValue {
-                key,
-                double_arrow: _,
-                ampersand: _,
-                value,
-            } => vec![key, value],
+            ArrayItem::Skipped(span) => *span,
+            ArrayItem::Value { value } => value.span(),
+            ArrayItem::ReferencedValue { ampersand, value } => ampersand.join(value.span()),
+            ArrayItem::SpreadValue { ellipsis, value } => ellipsis.join(value.span()),
+            ArrayItem::KeyValue { key, value, .. }
+            | ArrayItem::ReferencedKeyValue { key, value, .. } => key.span().join(value.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ListEntry {
-    Skipped,
+    Skipped(Span),
     Value {
         value: Expression, // `$foo`
     },
@@ -1513,16 +1600,12 @@ pub enum ListEntry {
     },
 }
 
-impl Node for ListEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ListEntry {
+    fn span(&self) -> Span {
         match self {
-            ListEntry::Skipped => vec![],
-            ListEntry::Value { value } => vec![value],
-            ListEntry::KeyValue {
-                key,
-                double_arrow: _,
-                value,
-            } => vec![key, value],
+            ListEntry::Skipped(span) => *span,
+            ListEntry::Value { value } => value.span(),
+            ListEntry::KeyValue { key, value, .. } => key.span().join(value.span()),
         }
     }
 }
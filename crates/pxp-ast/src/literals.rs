@@ -1,27 +1,27 @@
-use crate::node::Node;
+use crate::spanned::Spanned;
 use pxp_bytestring::ByteString;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Literal {
     String(LiteralString),
     Integer(LiteralInteger),
     Float(LiteralFloat),
 }
 
-impl Node for Literal {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Literal {
+    fn span(&self) -> Span {
         match self {
-            Literal::String(literal) => vec![literal],
-            Literal::Integer(literal) => vec![literal],
-            Literal::Float(literal) => vec![literal],
+            Literal::String(literal) => literal.span(),
+            Literal::Integer(literal) => literal.span(),
+            Literal::Float(literal) => literal.span(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiteralString {
     pub value: ByteString,
     pub span: Span,
@@ -29,33 +29,93 @@ pub struct LiteralString {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LiteralStringKind {
     SingleQuoted,
     DoubleQuoted,
 }
 
-impl Node for LiteralString {
-    //
+impl Spanned for LiteralString {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiteralInteger {
-    pub value: ByteString,
+    pub value: i64,
     pub span: Span,
 }
 
-impl Node for LiteralInteger {
-    //
+impl Spanned for LiteralInteger {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiteralFloat {
-    pub value: ByteString,
+    #[cfg_attr(feature = "serde", serde(with = "float"))]
+    pub value: f64,
     pub span: Span,
 }
 
-impl Node for LiteralFloat {
-    //
+// Float literals can never be NaN, so equality is always reflexive.
+impl Eq for LiteralFloat {}
+
+// Floats that are too big, such as `1e999`, are infinite, which can't be written as a number in
+// formats like JSON, so values that aren't finite are written the way PHP prints them instead.
+#[cfg(feature = "serde")]
+mod float {
+    pub fn serialize<S: serde::Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        match *value {
+            value if value.is_finite() => serializer.serialize_f64(value),
+            value if value.is_nan() => serializer.serialize_str("NAN"),
+            f64::INFINITY => serializer.serialize_str("INF"),
+            _ => serializer.serialize_str("-INF"),
+        }
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = f64;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a number, `INF`, `-INF` or `NAN`")
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<f64, E> {
+                Ok(value)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<f64, E> {
+                Ok(value as f64)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<f64, E> {
+                Ok(value as f64)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<f64, E> {
+                match value {
+                    "INF" => Ok(f64::INFINITY),
+                    "-INF" => Ok(f64::NEG_INFINITY),
+                    "NAN" => Ok(f64::NAN),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl Spanned for LiteralFloat {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -1,5 +1,5 @@
 use crate::literals::LiteralInteger;
-use crate::node::Node;
+use crate::spanned::Spanned;
 use crate::utils::CommaSeparated;
 use crate::Ending;
 use crate::Expression;
@@ -7,7 +7,7 @@ use crate::Statement;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForeachStatement {
     pub foreach: Span,                      // `foreach`
     pub left_parenthesis: Span,             // `(`
@@ -16,14 +16,14 @@ pub struct ForeachStatement {
     pub body: ForeachStatementBody,         // `{ ... }`
 }
 
-impl Node for ForeachStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.iterator, &mut self.body]
+impl Spanned for ForeachStatement {
+    fn span(&self) -> Span {
+        self.foreach.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForeachStatementIterator {
     // `*expression* as &$var`
     Value {
@@ -43,26 +43,21 @@ pub enum ForeachStatementIterator {
     },
 }
 
-impl Node for ForeachStatementIterator {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ForeachStatementIterator {
+    fn span(&self) -> Span {
         match self {
             ForeachStatementIterator::Value {
                 expression, value, ..
-            } => {
-                vec![expression, value]
             }
-            ForeachStatementIterator::KeyAndValue {
-                expression,
-                key,
-                value,
-                ..
-            } => vec![expression, key, value],
+            | ForeachStatementIterator::KeyAndValue {
+                expression, value, ..
+            } => expression.span().join(value.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForeachStatementBody {
     Statement {
         statement: Box<Statement>,
@@ -75,19 +70,17 @@ pub enum ForeachStatementBody {
     },
 }
 
-impl Node for ForeachStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ForeachStatementBody {
+    fn span(&self) -> Span {
         match self {
-            ForeachStatementBody::Statement { statement } => vec![statement.as_mut()],
-            ForeachStatementBody::Block { statements, .. } => {
-                statements.iter_mut().map(|s| s as &mut dyn Node).collect()
-            }
+            ForeachStatementBody::Statement { statement } => statement.span(),
+            ForeachStatementBody::Block { colon, ending, .. } => colon.join(ending.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForStatement {
     pub r#for: Span,                    // `for`
     pub left_parenthesis: Span,         // `(`
@@ -96,14 +89,14 @@ pub struct ForStatement {
     pub body: ForStatementBody,         // `{ ... }`
 }
 
-impl Node for ForStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.iterator, &mut self.body]
+impl Spanned for ForStatement {
+    fn span(&self) -> Span {
+        self.r#for.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForStatementIterator {
     pub initializations: CommaSeparated<Expression>, // `*expression*;`
     pub initializations_semicolon: Span,             // `;`
@@ -112,23 +105,25 @@ pub struct ForStatementIterator {
     pub r#loop: CommaSeparated<Expression>,          // `*expression*`
 }
 
-impl Node for ForStatementIterator {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children = vec![];
-        children.extend(
-            self.initializations
-                .inner
-                .iter_mut()
-                .map(|x| x as &mut dyn Node),
-        );
-        children.extend(self.conditions.inner.iter_mut().map(|x| x as &mut dyn Node));
-        children.extend(self.r#loop.inner.iter_mut().map(|x| x as &mut dyn Node));
-        children
+impl Spanned for ForStatementIterator {
+    fn span(&self) -> Span {
+        let start = self
+            .initializations
+            .inner
+            .first()
+            .map_or(self.initializations_semicolon, Spanned::span);
+        let end = self
+            .r#loop
+            .inner
+            .last()
+            .map_or(self.conditions_semicolon, Spanned::span);
+
+        start.join(end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForStatementBody {
     Statement {
         statement: Box<Statement>,
@@ -141,19 +136,17 @@ pub enum ForStatementBody {
     },
 }
 
-impl Node for ForStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ForStatementBody {
+    fn span(&self) -> Span {
         match self {
-            ForStatementBody::Statement { statement } => vec![statement.as_mut()],
-            ForStatementBody::Block { statements, .. } => {
-                statements.iter_mut().map(|x| x as &mut dyn Node).collect()
-            }
+            ForStatementBody::Statement { statement } => statement.span(),
+            ForStatementBody::Block { colon, ending, .. } => colon.join(ending.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoWhileStatement {
     pub r#do: Span,              // `do`
     pub body: Box<Statement>,    // `{ ... }`
@@ -164,14 +157,14 @@ pub struct DoWhileStatement {
     pub semicolon: Span,         // `;`
 }
 
-impl Node for DoWhileStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.body.as_mut(), &mut self.condition]
+impl Spanned for DoWhileStatement {
+    fn span(&self) -> Span {
+        self.r#do.join(self.semicolon)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhileStatement {
     pub r#while: Span,            // `while`
     pub left_parenthesis: Span,   // `(`
@@ -180,14 +173,14 @@ pub struct WhileStatement {
     pub body: WhileStatementBody, // `{ ... }`
 }
 
-impl Node for WhileStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.condition, &mut self.body]
+impl Spanned for WhileStatement {
+    fn span(&self) -> Span {
+        self.r#while.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WhileStatementBody {
     Statement {
         statement: Box<Statement>,
@@ -200,19 +193,17 @@ pub enum WhileStatementBody {
     },
 }
 
-impl Node for WhileStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for WhileStatementBody {
+    fn span(&self) -> Span {
         match self {
-            WhileStatementBody::Statement { statement } => vec![statement.as_mut()],
-            WhileStatementBody::Block { statements, .. } => {
-                statements.iter_mut().map(|s| s as &mut dyn Node).collect()
-            }
+            WhileStatementBody::Statement { statement } => statement.span(),
+            WhileStatementBody::Block { colon, ending, .. } => colon.join(ending.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Level {
     Literal(LiteralInteger),
     Parenthesized {
@@ -222,45 +213,43 @@ pub enum Level {
     },
 }
 
-impl Node for Level {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Level {
+    fn span(&self) -> Span {
         match self {
-            Level::Literal(literal) => vec![literal],
-            Level::Parenthesized { level, .. } => level.children(),
+            Level::Literal(literal) => literal.span(),
+            Level::Parenthesized {
+                left_parenthesis,
+                right_parenthesis,
+                ..
+            } => left_parenthesis.join(*right_parenthesis),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BreakStatement {
     pub r#break: Span,        // `break`
     pub level: Option<Level>, // `3`
     pub ending: Ending,       // `;` or `?>`
 }
 
-impl Node for BreakStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        match &mut self.level {
-            Some(level) => vec![level],
-            None => vec![],
-        }
+impl Spanned for BreakStatement {
+    fn span(&self) -> Span {
+        self.r#break.join(self.ending.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContinueStatement {
     pub r#continue: Span,     // `continue`
     pub level: Option<Level>, // `2`
     pub ending: Ending,       // `;` or `?>`
 }
 
-impl Node for ContinueStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        match &mut self.level {
-            Some(level) => vec![level],
-            None => vec![],
-        }
+impl Spanned for ContinueStatement {
+    fn span(&self) -> Span {
+        self.r#continue.join(self.ending.span())
     }
 }
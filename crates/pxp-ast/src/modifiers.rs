@@ -1,7 +1,9 @@
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Visibility {
     Public,
     Protected,
@@ -9,15 +11,25 @@ pub enum Visibility {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VisibilityModifier {
     Public(Span),
     Protected(Span),
     Private(Span),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+impl Spanned for VisibilityModifier {
+    fn span(&self) -> Span {
+        match self {
+            VisibilityModifier::Public(span) => *span,
+            VisibilityModifier::Protected(span) => *span,
+            VisibilityModifier::Private(span) => *span,
+        }
+    }
+}
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PromotedPropertyModifier {
     Public(Span),
     Protected(Span),
@@ -25,8 +37,8 @@ pub enum PromotedPropertyModifier {
     Readonly(Span),
 }
 
-impl PromotedPropertyModifier {
-    pub fn span(&self) -> Span {
+impl Spanned for PromotedPropertyModifier {
+    fn span(&self) -> Span {
         match self {
             PromotedPropertyModifier::Public(span) => *span,
             PromotedPropertyModifier::Protected(span) => *span,
@@ -48,11 +60,18 @@ impl std::fmt::Display for PromotedPropertyModifier {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct PromotedPropertyModifierGroup {
     pub modifiers: Vec<PromotedPropertyModifier>,
 }
 
+impl Spanned for PromotedPropertyModifierGroup {
+    fn span(&self) -> Span {
+        span_of(&self.modifiers).unwrap_or_default()
+    }
+}
+
 impl PromotedPropertyModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
@@ -84,7 +103,7 @@ impl PromotedPropertyModifierGroup {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropertyModifier {
     Public(Span),
     Protected(Span),
@@ -93,8 +112,8 @@ pub enum PropertyModifier {
     Readonly(Span),
 }
 
-impl PropertyModifier {
-    pub fn span(&self) -> Span {
+impl Spanned for PropertyModifier {
+    fn span(&self) -> Span {
         match self {
             PropertyModifier::Public(span) => *span,
             PropertyModifier::Protected(span) => *span,
@@ -106,11 +125,18 @@ impl PropertyModifier {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct PropertyModifierGroup {
     pub modifiers: Vec<PropertyModifier>,
 }
 
+impl Spanned for PropertyModifierGroup {
+    fn span(&self) -> Span {
+        span_of(&self.modifiers).unwrap_or_default()
+    }
+}
+
 impl PropertyModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
@@ -154,7 +180,7 @@ impl PropertyModifierGroup {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MethodModifier {
     Final(Span),
     Static(Span),
@@ -164,8 +190,8 @@ pub enum MethodModifier {
     Private(Span),
 }
 
-impl MethodModifier {
-    pub fn span(&self) -> Span {
+impl Spanned for MethodModifier {
+    fn span(&self) -> Span {
         match self {
             MethodModifier::Final(span) => *span,
             MethodModifier::Static(span) => *span,
@@ -178,11 +204,18 @@ impl MethodModifier {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct MethodModifierGroup {
     pub modifiers: Vec<MethodModifier>,
 }
 
+impl Spanned for MethodModifierGroup {
+    fn span(&self) -> Span {
+        span_of(&self.modifiers).unwrap_or_default()
+    }
+}
+
 impl MethodModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
@@ -226,19 +259,36 @@ impl MethodModifierGroup {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClassModifier {
     Final(Span),
     Abstract(Span),
     Readonly(Span),
 }
 
+impl Spanned for ClassModifier {
+    fn span(&self) -> Span {
+        match self {
+            ClassModifier::Final(span) => *span,
+            ClassModifier::Abstract(span) => *span,
+            ClassModifier::Readonly(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct ClassModifierGroup {
     pub modifiers: Vec<ClassModifier>,
 }
 
+impl Spanned for ClassModifierGroup {
+    fn span(&self) -> Span {
+        span_of(&self.modifiers).unwrap_or_default()
+    }
+}
+
 impl ClassModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
@@ -250,6 +300,12 @@ impl ClassModifierGroup {
             .any(|modifier| matches!(modifier, ClassModifier::Final { .. }))
     }
 
+    pub fn get_readonly(&self) -> Option<&ClassModifier> {
+        self.modifiers
+            .iter()
+            .find(|modifier| matches!(modifier, ClassModifier::Readonly { .. }))
+    }
+
     pub fn has_readonly(&self) -> bool {
         self.modifiers
             .iter()
@@ -264,7 +320,7 @@ impl ClassModifierGroup {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConstantModifier {
     Final(Span),
     Public(Span),
@@ -272,12 +328,30 @@ pub enum ConstantModifier {
     Private(Span),
 }
 
+impl Spanned for ConstantModifier {
+    fn span(&self) -> Span {
+        match self {
+            ConstantModifier::Final(span) => *span,
+            ConstantModifier::Public(span) => *span,
+            ConstantModifier::Protected(span) => *span,
+            ConstantModifier::Private(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct ConstantModifierGroup {
     pub modifiers: Vec<ConstantModifier>,
 }
 
+impl Spanned for ConstantModifierGroup {
+    fn span(&self) -> Span {
+        span_of(&self.modifiers).unwrap_or_default()
+    }
+}
+
 impl ConstantModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
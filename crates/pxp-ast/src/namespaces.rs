@@ -1,10 +1,11 @@
 use crate::identifiers::SimpleIdentifier;
-use crate::node::Node;
 use crate::Statement;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnbracedNamespace {
     pub start: Span,                // `namespace`
     pub name: SimpleIdentifier,     // `Foo`
@@ -12,67 +13,52 @@ pub struct UnbracedNamespace {
     pub statements: Vec<Statement>, // `*statements*`
 }
 
-impl Node for UnbracedNamespace {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children = vec![&mut self.name as &mut dyn Node];
-        children.extend(
-            self.statements
-                .iter_mut()
-                .map(|s| s as &mut dyn Node)
-                .collect::<Vec<&mut dyn Node>>(),
-        );
-        children
+impl Spanned for UnbracedNamespace {
+    fn span(&self) -> Span {
+        self.start.join(span_of(&self.statements).unwrap_or(self.end))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BracedNamespace {
     pub namespace: Span,                // `namespace`
     pub name: Option<SimpleIdentifier>, // `Foo`
     pub body: BracedNamespaceBody,      // `{ *statements* }`
 }
 
-impl Node for BracedNamespace {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(name) = &mut self.name {
-            children.push(name);
-        }
-        children.push(&mut self.body);
-        children
+impl Spanned for BracedNamespace {
+    fn span(&self) -> Span {
+        self.namespace.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BracedNamespaceBody {
     pub start: Span,                // `{`
     pub end: Span,                  // `}`
     pub statements: Vec<Statement>, // `*statements*`
 }
 
-impl Node for BracedNamespaceBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.statements
-            .iter_mut()
-            .map(|s| s as &mut dyn Node)
-            .collect()
+impl Spanned for BracedNamespaceBody {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NamespaceStatement {
     Unbraced(UnbracedNamespace), // `namespace Foo; *statements*`
     Braced(BracedNamespace),     // `namespace Foo { *statements* }`
 }
 
-impl Node for NamespaceStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for NamespaceStatement {
+    fn span(&self) -> Span {
         match self {
-            NamespaceStatement::Unbraced(namespace) => vec![namespace],
-            NamespaceStatement::Braced(namespace) => vec![namespace],
+            NamespaceStatement::Unbraced(namespace) => namespace.span(),
+            NamespaceStatement::Braced(namespace) => namespace.span(),
         }
     }
 }
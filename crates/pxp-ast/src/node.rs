@@ -1,7 +1,26 @@
 use std::any::Any;
 
-pub trait Node: Any {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+use crate::spanned::Spanned;
+
+pub trait Node: Any + Spanned {
+    /// The name of the type of node, such as `ClassStatement`, which doesn't change between
+    /// builds the way `std::any::type_name` can.
+    fn kind(&self) -> &'static str;
+
+    /// The nodes directly below this one, along with the name of the field each one is in.
+    /// Fields of tuple variants are named after their position, such as `0`, and a field that
+    /// holds a list of nodes appears once for each of them.
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
         vec![]
     }
+
+    /// The same as `fields`, for when there's only a shared reference to the node.
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
+    }
+
+    /// The nodes directly below this one, in the order they're declared in.
+    fn children(&mut self) -> Vec<&mut dyn Node> {
+        self.fields().into_iter().map(|(_, child)| child).collect()
+    }
 }
@@ -0,0 +1,4976 @@
+// This file is generated by pxp-codegen, run `just codegen` to update it.
+
+use crate::arguments::Argument;
+use crate::arguments::ArgumentList;
+use crate::arguments::ArgumentPlaceholder;
+use crate::arguments::NamedArgument;
+use crate::arguments::PositionalArgument;
+use crate::arguments::SingleArgument;
+use crate::attributes::Attribute;
+use crate::attributes::AttributeGroup;
+use crate::classes::AnonymousClassBody;
+use crate::classes::AnonymousClassExpression;
+use crate::classes::AnonymousClassMember;
+use crate::classes::ClassBody;
+use crate::classes::ClassExtends;
+use crate::classes::ClassImplements;
+use crate::classes::ClassMember;
+use crate::classes::ClassStatement;
+use crate::comments::Comment;
+use crate::comments::CommentGroup;
+use crate::constant::ClassishConstant;
+use crate::constant::ConstantEntry;
+use crate::constant::ConstantStatement;
+use crate::control_flow::IfStatement;
+use crate::control_flow::IfStatementBody;
+use crate::control_flow::IfStatementElse;
+use crate::control_flow::IfStatementElseBlock;
+use crate::control_flow::IfStatementElseIf;
+use crate::control_flow::IfStatementElseIfBlock;
+use crate::data_type::Type;
+use crate::declares::DeclareBody;
+use crate::declares::DeclareEntry;
+use crate::declares::DeclareEntryGroup;
+use crate::declares::DeclareStatement;
+use crate::enums::BackedEnumBody;
+use crate::enums::BackedEnumCase;
+use crate::enums::BackedEnumMember;
+use crate::enums::BackedEnumStatement;
+use crate::enums::BackedEnumType;
+use crate::enums::UnitEnumBody;
+use crate::enums::UnitEnumCase;
+use crate::enums::UnitEnumMember;
+use crate::enums::UnitEnumStatement;
+use crate::functions::AbstractConstructor;
+use crate::functions::AbstractMethod;
+use crate::functions::ArrowFunctionExpression;
+use crate::functions::ClosureExpression;
+use crate::functions::ClosureUse;
+use crate::functions::ClosureUseVariable;
+use crate::functions::ConcreteConstructor;
+use crate::functions::ConcreteMethod;
+use crate::functions::ConstructorParameter;
+use crate::functions::ConstructorParameterList;
+use crate::functions::FunctionBody;
+use crate::functions::FunctionParameter;
+use crate::functions::FunctionParameterList;
+use crate::functions::FunctionStatement;
+use crate::functions::MethodBody;
+use crate::functions::ReturnType;
+use crate::goto::GotoStatement;
+use crate::goto::LabelStatement;
+use crate::identifiers::DynamicIdentifier;
+use crate::identifiers::Identifier;
+use crate::identifiers::SimpleIdentifier;
+use crate::interfaces::InterfaceBody;
+use crate::interfaces::InterfaceExtends;
+use crate::interfaces::InterfaceMember;
+use crate::interfaces::InterfaceStatement;
+use crate::literals::Literal;
+use crate::literals::LiteralFloat;
+use crate::literals::LiteralInteger;
+use crate::literals::LiteralString;
+use crate::loops::BreakStatement;
+use crate::loops::ContinueStatement;
+use crate::loops::DoWhileStatement;
+use crate::loops::ForStatement;
+use crate::loops::ForStatementBody;
+use crate::loops::ForStatementIterator;
+use crate::loops::ForeachStatement;
+use crate::loops::ForeachStatementBody;
+use crate::loops::ForeachStatementIterator;
+use crate::loops::Level;
+use crate::loops::WhileStatement;
+use crate::loops::WhileStatementBody;
+use crate::modifiers::ClassModifier;
+use crate::modifiers::ClassModifierGroup;
+use crate::modifiers::ConstantModifier;
+use crate::modifiers::ConstantModifierGroup;
+use crate::modifiers::MethodModifier;
+use crate::modifiers::MethodModifierGroup;
+use crate::modifiers::PromotedPropertyModifier;
+use crate::modifiers::PromotedPropertyModifierGroup;
+use crate::modifiers::PropertyModifier;
+use crate::modifiers::PropertyModifierGroup;
+use crate::modifiers::VisibilityModifier;
+use crate::namespaces::BracedNamespace;
+use crate::namespaces::BracedNamespaceBody;
+use crate::namespaces::NamespaceStatement;
+use crate::namespaces::UnbracedNamespace;
+use crate::node::Node;
+use crate::operators::ArithmeticOperationExpression;
+use crate::operators::AssignmentOperationExpression;
+use crate::operators::BitwiseOperationExpression;
+use crate::operators::ComparisonOperationExpression;
+use crate::operators::LogicalOperationExpression;
+use crate::properties::Property;
+use crate::properties::PropertyEntry;
+use crate::properties::VariableProperty;
+use crate::traits::TraitBody;
+use crate::traits::TraitMember;
+use crate::traits::TraitStatement;
+use crate::traits::TraitUsage;
+use crate::traits::TraitUsageAdaptation;
+use crate::try_block::CatchBlock;
+use crate::try_block::CatchType;
+use crate::try_block::FinallyBlock;
+use crate::try_block::TryStatement;
+use crate::variables::BracedVariableVariable;
+use crate::variables::SimpleVariable;
+use crate::variables::Variable;
+use crate::variables::VariableVariable;
+use crate::ArrayExpression;
+use crate::ArrayIndexExpression;
+use crate::ArrayItem;
+use crate::BlockStatement;
+use crate::BoolExpression;
+use crate::Case;
+use crate::CastExpression;
+use crate::CloneExpression;
+use crate::ClosingTagStatement;
+use crate::CoalesceExpression;
+use crate::ConcatExpression;
+use crate::ConstantFetchExpression;
+use crate::DefaultMatchArm;
+use crate::DieExpression;
+use crate::EchoOpeningTagStatement;
+use crate::EchoStatement;
+use crate::EmptyExpression;
+use crate::Ending;
+use crate::ErrorSuppressExpression;
+use crate::EvalExpression;
+use crate::ExitExpression;
+use crate::Expression;
+use crate::ExpressionStatement;
+use crate::ExpressionStringPart;
+use crate::FullOpeningTagStatement;
+use crate::FunctionCallExpression;
+use crate::FunctionClosureCreationExpression;
+use crate::GlobalStatement;
+use crate::GroupUseStatement;
+use crate::HaltCompilerStatement;
+use crate::HeredocExpression;
+use crate::IncludeExpression;
+use crate::IncludeOnceExpression;
+use crate::InlineHtmlStatement;
+use crate::InstanceofExpression;
+use crate::InterpolatedStringExpression;
+use crate::IssetExpression;
+use crate::ListEntry;
+use crate::ListExpression;
+use crate::LiteralStringPart;
+use crate::MagicConstantExpression;
+use crate::MatchArm;
+use crate::MatchExpression;
+use crate::MethodCallExpression;
+use crate::MethodClosureCreationExpression;
+use crate::NewExpression;
+use crate::NowdocExpression;
+use crate::NullsafeMethodCallExpression;
+use crate::NullsafePropertyFetchExpression;
+use crate::ParenthesizedExpression;
+use crate::PrintExpression;
+use crate::PropertyFetchExpression;
+use crate::ReferenceExpression;
+use crate::RequireExpression;
+use crate::RequireOnceExpression;
+use crate::ReturnStatement;
+use crate::ShellExecExpression;
+use crate::ShortArrayExpression;
+use crate::ShortOpeningTagStatement;
+use crate::ShortTernaryExpression;
+use crate::Statement;
+use crate::StaticMethodCallExpression;
+use crate::StaticMethodClosureCreationExpression;
+use crate::StaticPropertyFetchExpression;
+use crate::StaticStatement;
+use crate::StaticVar;
+use crate::StaticVariableMethodCallExpression;
+use crate::StaticVariableMethodClosureCreationExpression;
+use crate::StringPart;
+use crate::SwitchStatement;
+use crate::TernaryExpression;
+use crate::ThrowExpression;
+use crate::UnsetExpression;
+use crate::Use;
+use crate::UseStatement;
+use crate::YieldExpression;
+use crate::YieldFromExpression;
+
+impl Node for AbstractConstructor {
+    fn kind(&self) -> &'static str {
+        "AbstractConstructor"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        fields.push(("name", &mut self.name));
+        fields.push(("parameters", &mut self.parameters));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        fields.push(("name", &self.name));
+        fields.push(("parameters", &self.parameters));
+
+        fields
+    }
+}
+
+impl Node for AbstractMethod {
+    fn kind(&self) -> &'static str {
+        "AbstractMethod"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        fields.push(("name", &mut self.name));
+        fields.push(("parameters", &mut self.parameters));
+        if let Some(item) = &mut self.return_type {
+            fields.push(("return_type", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        fields.push(("name", &self.name));
+        fields.push(("parameters", &self.parameters));
+        if let Some(item) = &self.return_type {
+            fields.push(("return_type", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for AnonymousClassBody {
+    fn kind(&self) -> &'static str {
+        "AnonymousClassBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &mut self.comments));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &self.comments));
+
+        fields
+    }
+}
+
+impl Node for AnonymousClassExpression {
+    fn kind(&self) -> &'static str {
+        "AnonymousClassExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        if let Some(item) = &mut self.extends {
+            fields.push(("extends", item));
+        }
+        if let Some(item) = &mut self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        if let Some(item) = &self.extends {
+            fields.push(("extends", item));
+        }
+        if let Some(item) = &self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for AnonymousClassMember {
+    fn kind(&self) -> &'static str {
+        "AnonymousClassMember"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            AnonymousClassMember::Constant(node) => vec![("0", node)],
+            AnonymousClassMember::TraitUsage(node) => vec![("0", node)],
+            AnonymousClassMember::Property(node) => vec![("0", node)],
+            AnonymousClassMember::VariableProperty(node) => vec![("0", node)],
+            AnonymousClassMember::ConcreteMethod(node) => vec![("0", node)],
+            AnonymousClassMember::ConcreteConstructor(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            AnonymousClassMember::Constant(node) => vec![("0", node)],
+            AnonymousClassMember::TraitUsage(node) => vec![("0", node)],
+            AnonymousClassMember::Property(node) => vec![("0", node)],
+            AnonymousClassMember::VariableProperty(node) => vec![("0", node)],
+            AnonymousClassMember::ConcreteMethod(node) => vec![("0", node)],
+            AnonymousClassMember::ConcreteConstructor(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for Argument {
+    fn kind(&self) -> &'static str {
+        "Argument"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Argument::Positional(node) => vec![("0", node)],
+            Argument::Named(node) => vec![("0", node)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Argument::Positional(node) => vec![("0", node)],
+            Argument::Named(node) => vec![("0", node)],
+        }
+    }
+}
+
+impl Node for ArgumentList {
+    fn kind(&self) -> &'static str {
+        "ArgumentList"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.arguments {
+            fields.push(("arguments", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.arguments {
+            fields.push(("arguments", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ArgumentPlaceholder {
+    fn kind(&self) -> &'static str {
+        "ArgumentPlaceholder"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("comments", &mut self.comments)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("comments", &self.comments)]
+    }
+}
+
+impl Node for ArithmeticOperationExpression {
+    fn kind(&self) -> &'static str {
+        "ArithmeticOperationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ArithmeticOperationExpression::Addition { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::Subtraction { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::Multiplication { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::Division { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::Modulo { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::Negative { right, .. } => vec![("right", &mut **right)],
+            ArithmeticOperationExpression::Positive { right, .. } => vec![("right", &mut **right)],
+            ArithmeticOperationExpression::PreIncrement { right, .. } => {
+                vec![("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::PostIncrement { left, .. } => {
+                vec![("left", &mut **left)]
+            }
+            ArithmeticOperationExpression::PreDecrement { right, .. } => {
+                vec![("right", &mut **right)]
+            }
+            ArithmeticOperationExpression::PostDecrement { left, .. } => {
+                vec![("left", &mut **left)]
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ArithmeticOperationExpression::Addition { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ArithmeticOperationExpression::Subtraction { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ArithmeticOperationExpression::Multiplication { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ArithmeticOperationExpression::Division { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ArithmeticOperationExpression::Modulo { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ArithmeticOperationExpression::Negative { right, .. } => vec![("right", &**right)],
+            ArithmeticOperationExpression::Positive { right, .. } => vec![("right", &**right)],
+            ArithmeticOperationExpression::PreIncrement { right, .. } => vec![("right", &**right)],
+            ArithmeticOperationExpression::PostIncrement { left, .. } => vec![("left", &**left)],
+            ArithmeticOperationExpression::PreDecrement { right, .. } => vec![("right", &**right)],
+            ArithmeticOperationExpression::PostDecrement { left, .. } => vec![("left", &**left)],
+        }
+    }
+}
+
+impl Node for ArrayExpression {
+    fn kind(&self) -> &'static str {
+        "ArrayExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.items {
+            fields.push(("items", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.items {
+            fields.push(("items", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ArrayIndexExpression {
+    fn kind(&self) -> &'static str {
+        "ArrayIndexExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("array", &mut *self.array)];
+        if let Some(item) = &mut self.index {
+            fields.push(("index", &mut **item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("array", &*self.array)];
+        if let Some(item) = &self.index {
+            fields.push(("index", &**item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ArrayItem {
+    fn kind(&self) -> &'static str {
+        "ArrayItem"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ArrayItem::Value { value } => vec![("value", value)],
+            ArrayItem::ReferencedValue { value, .. } => vec![("value", value)],
+            ArrayItem::SpreadValue { value, .. } => vec![("value", value)],
+            ArrayItem::KeyValue { key, value, .. } => vec![("key", key), ("value", value)],
+            ArrayItem::ReferencedKeyValue { key, value, .. } => {
+                vec![("key", key), ("value", value)]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ArrayItem::Value { value } => vec![("value", value)],
+            ArrayItem::ReferencedValue { value, .. } => vec![("value", value)],
+            ArrayItem::SpreadValue { value, .. } => vec![("value", value)],
+            ArrayItem::KeyValue { key, value, .. } => vec![("key", key), ("value", value)],
+            ArrayItem::ReferencedKeyValue { key, value, .. } => {
+                vec![("key", key), ("value", value)]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for ArrowFunctionExpression {
+    fn kind(&self) -> &'static str {
+        "ArrowFunctionExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("parameters", &mut self.parameters));
+        if let Some(item) = &mut self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &mut *self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("parameters", &self.parameters));
+        if let Some(item) = &self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &*self.body));
+
+        fields
+    }
+}
+
+impl Node for AssignmentOperationExpression {
+    fn kind(&self) -> &'static str {
+        "AssignmentOperationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            AssignmentOperationExpression::Assign { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Addition { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Subtraction { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Multiplication { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Division { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Modulo { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Exponentiation { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Concat { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::BitwiseAnd { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::BitwiseOr { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::BitwiseXor { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::LeftShift { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::RightShift { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            AssignmentOperationExpression::Coalesce { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            AssignmentOperationExpression::Assign { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Addition { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Subtraction { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Multiplication { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Division { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Modulo { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Exponentiation { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Concat { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::BitwiseAnd { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::BitwiseOr { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::BitwiseXor { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::LeftShift { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::RightShift { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            AssignmentOperationExpression::Coalesce { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+        }
+    }
+}
+
+impl Node for Attribute {
+    fn kind(&self) -> &'static str {
+        "Attribute"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("name", &mut self.name)];
+        if let Some(item) = &mut self.arguments {
+            fields.push(("arguments", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("name", &self.name)];
+        if let Some(item) = &self.arguments {
+            fields.push(("arguments", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for AttributeGroup {
+    fn kind(&self) -> &'static str {
+        "AttributeGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for BackedEnumBody {
+    fn kind(&self) -> &'static str {
+        "BackedEnumBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &mut self.comments));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &self.comments));
+
+        fields
+    }
+}
+
+impl Node for BackedEnumCase {
+    fn kind(&self) -> &'static str {
+        "BackedEnumCase"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &mut self.name));
+        fields.push(("value", &mut self.value));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &self.name));
+        fields.push(("value", &self.value));
+
+        fields
+    }
+}
+
+impl Node for BackedEnumMember {
+    fn kind(&self) -> &'static str {
+        "BackedEnumMember"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            BackedEnumMember::Case(node) => vec![("0", node)],
+            BackedEnumMember::Method(node) => vec![("0", node)],
+            BackedEnumMember::Constant(node) => vec![("0", node)],
+            BackedEnumMember::TraitUsage(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            BackedEnumMember::Case(node) => vec![("0", node)],
+            BackedEnumMember::Method(node) => vec![("0", node)],
+            BackedEnumMember::Constant(node) => vec![("0", node)],
+            BackedEnumMember::TraitUsage(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for BackedEnumStatement {
+    fn kind(&self) -> &'static str {
+        "BackedEnumStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &mut self.name));
+        fields.push(("backed_type", &mut self.backed_type));
+        for item in &mut self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &self.name));
+        fields.push(("backed_type", &self.backed_type));
+        for item in &self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for BackedEnumType {
+    fn kind(&self) -> &'static str {
+        "BackedEnumType"
+    }
+}
+
+impl Node for BitwiseOperationExpression {
+    fn kind(&self) -> &'static str {
+        "BitwiseOperationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            BitwiseOperationExpression::And { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            BitwiseOperationExpression::Or { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            BitwiseOperationExpression::Xor { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            BitwiseOperationExpression::LeftShift { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            BitwiseOperationExpression::RightShift { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            BitwiseOperationExpression::Not { right, .. } => vec![("right", &mut **right)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            BitwiseOperationExpression::And { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            BitwiseOperationExpression::Or { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            BitwiseOperationExpression::Xor { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            BitwiseOperationExpression::LeftShift { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            BitwiseOperationExpression::RightShift { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            BitwiseOperationExpression::Not { right, .. } => vec![("right", &**right)],
+        }
+    }
+}
+
+impl Node for BlockStatement {
+    fn kind(&self) -> &'static str {
+        "BlockStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for BoolExpression {
+    fn kind(&self) -> &'static str {
+        "BoolExpression"
+    }
+}
+
+impl Node for BracedNamespace {
+    fn kind(&self) -> &'static str {
+        "BracedNamespace"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.name {
+            fields.push(("name", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.name {
+            fields.push(("name", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for BracedNamespaceBody {
+    fn kind(&self) -> &'static str {
+        "BracedNamespaceBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for BracedVariableVariable {
+    fn kind(&self) -> &'static str {
+        "BracedVariableVariable"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("variable", &mut *self.variable)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("variable", &*self.variable)]
+    }
+}
+
+impl Node for BreakStatement {
+    fn kind(&self) -> &'static str {
+        "BreakStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.level {
+            fields.push(("level", item));
+        }
+        fields.push(("ending", &mut self.ending));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.level {
+            fields.push(("level", item));
+        }
+        fields.push(("ending", &self.ending));
+
+        fields
+    }
+}
+
+impl Node for Case {
+    fn kind(&self) -> &'static str {
+        "Case"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.condition {
+            fields.push(("condition", item));
+        }
+        for item in &mut self.body {
+            fields.push(("body", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.condition {
+            fields.push(("condition", item));
+        }
+        for item in &self.body {
+            fields.push(("body", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for CastExpression {
+    fn kind(&self) -> &'static str {
+        "CastExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("value", &mut *self.value)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("value", &*self.value)]
+    }
+}
+
+impl Node for CatchBlock {
+    fn kind(&self) -> &'static str {
+        "CatchBlock"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("types", &mut self.types)];
+        if let Some(item) = &mut self.var {
+            fields.push(("var", item));
+        }
+        for item in &mut self.body {
+            fields.push(("body", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("types", &self.types)];
+        if let Some(item) = &self.var {
+            fields.push(("var", item));
+        }
+        for item in &self.body {
+            fields.push(("body", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for CatchType {
+    fn kind(&self) -> &'static str {
+        "CatchType"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            CatchType::Identifier { identifier } => vec![("identifier", identifier)],
+            CatchType::Union { identifiers } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in identifiers {
+                    fields.push(("identifiers", item));
+                }
+
+                fields
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            CatchType::Identifier { identifier } => vec![("identifier", identifier)],
+            CatchType::Union { identifiers } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in identifiers {
+                    fields.push(("identifiers", item));
+                }
+
+                fields
+            }
+        }
+    }
+}
+
+impl Node for ClassBody {
+    fn kind(&self) -> &'static str {
+        "ClassBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &mut self.comments));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &self.comments));
+
+        fields
+    }
+}
+
+impl Node for ClassExtends {
+    fn kind(&self) -> &'static str {
+        "ClassExtends"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("parent", &mut self.parent)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("parent", &self.parent)]
+    }
+}
+
+impl Node for ClassImplements {
+    fn kind(&self) -> &'static str {
+        "ClassImplements"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.interfaces {
+            fields.push(("interfaces", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.interfaces {
+            fields.push(("interfaces", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ClassMember {
+    fn kind(&self) -> &'static str {
+        "ClassMember"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ClassMember::Constant(node) => vec![("0", node)],
+            ClassMember::TraitUsage(node) => vec![("0", node)],
+            ClassMember::Property(node) => vec![("0", node)],
+            ClassMember::VariableProperty(node) => vec![("0", node)],
+            ClassMember::AbstractMethod(node) => vec![("0", node)],
+            ClassMember::AbstractConstructor(node) => vec![("0", node)],
+            ClassMember::ConcreteMethod(node) => vec![("0", node)],
+            ClassMember::ConcreteConstructor(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ClassMember::Constant(node) => vec![("0", node)],
+            ClassMember::TraitUsage(node) => vec![("0", node)],
+            ClassMember::Property(node) => vec![("0", node)],
+            ClassMember::VariableProperty(node) => vec![("0", node)],
+            ClassMember::AbstractMethod(node) => vec![("0", node)],
+            ClassMember::AbstractConstructor(node) => vec![("0", node)],
+            ClassMember::ConcreteMethod(node) => vec![("0", node)],
+            ClassMember::ConcreteConstructor(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for ClassModifier {
+    fn kind(&self) -> &'static str {
+        "ClassModifier"
+    }
+}
+
+impl Node for ClassModifierGroup {
+    fn kind(&self) -> &'static str {
+        "ClassModifierGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ClassStatement {
+    fn kind(&self) -> &'static str {
+        "ClassStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        fields.push(("name", &mut self.name));
+        if let Some(item) = &mut self.extends {
+            fields.push(("extends", item));
+        }
+        if let Some(item) = &mut self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        fields.push(("name", &self.name));
+        if let Some(item) = &self.extends {
+            fields.push(("extends", item));
+        }
+        if let Some(item) = &self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for ClassishConstant {
+    fn kind(&self) -> &'static str {
+        "ClassishConstant"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        for item in &mut self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        for item in &self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for CloneExpression {
+    fn kind(&self) -> &'static str {
+        "CloneExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("target", &mut *self.target)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("target", &*self.target)]
+    }
+}
+
+impl Node for ClosingTagStatement {
+    fn kind(&self) -> &'static str {
+        "ClosingTagStatement"
+    }
+}
+
+impl Node for ClosureExpression {
+    fn kind(&self) -> &'static str {
+        "ClosureExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("parameters", &mut self.parameters));
+        if let Some(item) = &mut self.uses {
+            fields.push(("uses", item));
+        }
+        if let Some(item) = &mut self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("parameters", &self.parameters));
+        if let Some(item) = &self.uses {
+            fields.push(("uses", item));
+        }
+        if let Some(item) = &self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for ClosureUse {
+    fn kind(&self) -> &'static str {
+        "ClosureUse"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.variables {
+            fields.push(("variables", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.variables {
+            fields.push(("variables", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ClosureUseVariable {
+    fn kind(&self) -> &'static str {
+        "ClosureUseVariable"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("comments", &mut self.comments),
+            ("variable", &mut self.variable),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("comments", &self.comments), ("variable", &self.variable)]
+    }
+}
+
+impl Node for CoalesceExpression {
+    fn kind(&self) -> &'static str {
+        "CoalesceExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("lhs", &mut *self.lhs), ("rhs", &mut *self.rhs)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("lhs", &*self.lhs), ("rhs", &*self.rhs)]
+    }
+}
+
+impl Node for Comment {
+    fn kind(&self) -> &'static str {
+        "Comment"
+    }
+}
+
+impl Node for CommentGroup {
+    fn kind(&self) -> &'static str {
+        "CommentGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.comments {
+            fields.push(("comments", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.comments {
+            fields.push(("comments", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ComparisonOperationExpression {
+    fn kind(&self) -> &'static str {
+        "ComparisonOperationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ComparisonOperationExpression::Equal { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::Identical { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::NotEqual { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::AngledNotEqual { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::NotIdentical { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::LessThan { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::GreaterThan { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::LessThanOrEqual { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            ComparisonOperationExpression::Spaceship { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ComparisonOperationExpression::Equal { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::Identical { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::NotEqual { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::AngledNotEqual { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::NotIdentical { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::LessThan { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::GreaterThan { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::LessThanOrEqual { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            ComparisonOperationExpression::Spaceship { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+        }
+    }
+}
+
+impl Node for ConcatExpression {
+    fn kind(&self) -> &'static str {
+        "ConcatExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("left", &mut *self.left), ("right", &mut *self.right)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("left", &*self.left), ("right", &*self.right)]
+    }
+}
+
+impl Node for ConcreteConstructor {
+    fn kind(&self) -> &'static str {
+        "ConcreteConstructor"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        fields.push(("name", &mut self.name));
+        fields.push(("parameters", &mut self.parameters));
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        fields.push(("name", &self.name));
+        fields.push(("parameters", &self.parameters));
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for ConcreteMethod {
+    fn kind(&self) -> &'static str {
+        "ConcreteMethod"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        fields.push(("name", &mut self.name));
+        fields.push(("parameters", &mut self.parameters));
+        if let Some(item) = &mut self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        fields.push(("name", &self.name));
+        fields.push(("parameters", &self.parameters));
+        if let Some(item) = &self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for ConstantEntry {
+    fn kind(&self) -> &'static str {
+        "ConstantEntry"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("name", &mut self.name), ("value", &mut self.value)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("name", &self.name), ("value", &self.value)]
+    }
+}
+
+impl Node for ConstantFetchExpression {
+    fn kind(&self) -> &'static str {
+        "ConstantFetchExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("constant", &mut self.constant),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("target", &*self.target), ("constant", &self.constant)]
+    }
+}
+
+impl Node for ConstantModifier {
+    fn kind(&self) -> &'static str {
+        "ConstantModifier"
+    }
+}
+
+impl Node for ConstantModifierGroup {
+    fn kind(&self) -> &'static str {
+        "ConstantModifierGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ConstantStatement {
+    fn kind(&self) -> &'static str {
+        "ConstantStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ConstructorParameter {
+    fn kind(&self) -> &'static str {
+        "ConstructorParameter"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("comments", &mut self.comments));
+        fields.push(("name", &mut self.name));
+        if let Some(item) = &mut self.data_type {
+            fields.push(("data_type", item));
+        }
+        if let Some(item) = &mut self.default {
+            fields.push(("default", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("comments", &self.comments));
+        fields.push(("name", &self.name));
+        if let Some(item) = &self.data_type {
+            fields.push(("data_type", item));
+        }
+        if let Some(item) = &self.default {
+            fields.push(("default", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+
+        fields
+    }
+}
+
+impl Node for ConstructorParameterList {
+    fn kind(&self) -> &'static str {
+        "ConstructorParameterList"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.parameters {
+            fields.push(("parameters", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.parameters {
+            fields.push(("parameters", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ContinueStatement {
+    fn kind(&self) -> &'static str {
+        "ContinueStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.level {
+            fields.push(("level", item));
+        }
+        fields.push(("ending", &mut self.ending));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.level {
+            fields.push(("level", item));
+        }
+        fields.push(("ending", &self.ending));
+
+        fields
+    }
+}
+
+impl Node for DeclareBody {
+    fn kind(&self) -> &'static str {
+        "DeclareBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            DeclareBody::Braced { statements, .. } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+
+                fields
+            }
+            DeclareBody::Expression { expression, .. } => vec![("expression", expression)],
+            DeclareBody::Block { statements, .. } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+
+                fields
+            }
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            DeclareBody::Braced { statements, .. } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+
+                fields
+            }
+            DeclareBody::Expression { expression, .. } => vec![("expression", expression)],
+            DeclareBody::Block { statements, .. } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+
+                fields
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for DeclareEntry {
+    fn kind(&self) -> &'static str {
+        "DeclareEntry"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("key", &mut self.key), ("value", &mut self.value)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("key", &self.key), ("value", &self.value)]
+    }
+}
+
+impl Node for DeclareEntryGroup {
+    fn kind(&self) -> &'static str {
+        "DeclareEntryGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for DeclareStatement {
+    fn kind(&self) -> &'static str {
+        "DeclareStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("entries", &mut self.entries), ("body", &mut self.body)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("entries", &self.entries), ("body", &self.body)]
+    }
+}
+
+impl Node for DefaultMatchArm {
+    fn kind(&self) -> &'static str {
+        "DefaultMatchArm"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("body", &mut self.body)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("body", &self.body)]
+    }
+}
+
+impl Node for DieExpression {
+    fn kind(&self) -> &'static str {
+        "DieExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.argument {
+            fields.push(("argument", &mut **item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.argument {
+            fields.push(("argument", &**item));
+        }
+
+        fields
+    }
+}
+
+impl Node for DoWhileStatement {
+    fn kind(&self) -> &'static str {
+        "DoWhileStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("body", &mut *self.body),
+            ("condition", &mut self.condition),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("body", &*self.body), ("condition", &self.condition)]
+    }
+}
+
+impl Node for DynamicIdentifier {
+    fn kind(&self) -> &'static str {
+        "DynamicIdentifier"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("expr", &mut *self.expr)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expr", &*self.expr)]
+    }
+}
+
+impl Node for EchoOpeningTagStatement {
+    fn kind(&self) -> &'static str {
+        "EchoOpeningTagStatement"
+    }
+}
+
+impl Node for EchoStatement {
+    fn kind(&self) -> &'static str {
+        "EchoStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.values {
+            fields.push(("values", item));
+        }
+        fields.push(("ending", &mut self.ending));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.values {
+            fields.push(("values", item));
+        }
+        fields.push(("ending", &self.ending));
+
+        fields
+    }
+}
+
+impl Node for EmptyExpression {
+    fn kind(&self) -> &'static str {
+        "EmptyExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("argument", &mut *self.argument)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("argument", &*self.argument)]
+    }
+}
+
+impl Node for Ending {
+    fn kind(&self) -> &'static str {
+        "Ending"
+    }
+}
+
+impl Node for ErrorSuppressExpression {
+    fn kind(&self) -> &'static str {
+        "ErrorSuppressExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("expr", &mut *self.expr)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expr", &*self.expr)]
+    }
+}
+
+impl Node for EvalExpression {
+    fn kind(&self) -> &'static str {
+        "EvalExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("argument", &mut *self.argument)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("argument", &*self.argument)]
+    }
+}
+
+impl Node for ExitExpression {
+    fn kind(&self) -> &'static str {
+        "ExitExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.argument {
+            fields.push(("argument", &mut **item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.argument {
+            fields.push(("argument", &**item));
+        }
+
+        fields
+    }
+}
+
+impl Node for Expression {
+    fn kind(&self) -> &'static str {
+        "Expression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Expression::Eval(node) => vec![("0", node)],
+            Expression::Empty(node) => vec![("0", node)],
+            Expression::Die(node) => vec![("0", node)],
+            Expression::Exit(node) => vec![("0", node)],
+            Expression::Isset(node) => vec![("0", node)],
+            Expression::Unset(node) => vec![("0", node)],
+            Expression::Print(node) => vec![("0", node)],
+            Expression::Literal(node) => vec![("0", node)],
+            Expression::ArithmeticOperation(node) => vec![("0", node)],
+            Expression::AssignmentOperation(node) => vec![("0", node)],
+            Expression::BitwiseOperation(node) => vec![("0", node)],
+            Expression::ComparisonOperation(node) => vec![("0", node)],
+            Expression::LogicalOperation(node) => vec![("0", node)],
+            Expression::Concat(node) => vec![("0", node)],
+            Expression::Instanceof(node) => vec![("0", node)],
+            Expression::Reference(node) => vec![("0", node)],
+            Expression::Parenthesized(node) => vec![("0", node)],
+            Expression::ErrorSuppress(node) => vec![("0", node)],
+            Expression::Identifier(node) => vec![("0", node)],
+            Expression::Variable(node) => vec![("0", node)],
+            Expression::Include(node) => vec![("0", node)],
+            Expression::IncludeOnce(node) => vec![("0", node)],
+            Expression::Require(node) => vec![("0", node)],
+            Expression::RequireOnce(node) => vec![("0", node)],
+            Expression::FunctionCall(node) => vec![("0", node)],
+            Expression::FunctionClosureCreation(node) => vec![("0", node)],
+            Expression::MethodCall(node) => vec![("0", node)],
+            Expression::MethodClosureCreation(node) => vec![("0", node)],
+            Expression::NullsafeMethodCall(node) => vec![("0", node)],
+            Expression::StaticMethodCall(node) => vec![("0", node)],
+            Expression::StaticVariableMethodCall(node) => vec![("0", node)],
+            Expression::StaticMethodClosureCreation(node) => vec![("0", node)],
+            Expression::StaticVariableMethodClosureCreation(node) => vec![("0", node)],
+            Expression::PropertyFetch(node) => vec![("0", node)],
+            Expression::NullsafePropertyFetch(node) => vec![("0", node)],
+            Expression::StaticPropertyFetch(node) => vec![("0", node)],
+            Expression::ConstantFetch(node) => vec![("0", node)],
+            Expression::ShortArray(node) => vec![("0", node)],
+            Expression::Array(node) => vec![("0", node)],
+            Expression::List(node) => vec![("0", node)],
+            Expression::Closure(node) => vec![("0", node)],
+            Expression::ArrowFunction(node) => vec![("0", node)],
+            Expression::New(node) => vec![("0", node)],
+            Expression::InterpolatedString(node) => vec![("0", node)],
+            Expression::Heredoc(node) => vec![("0", node)],
+            Expression::Nowdoc(node) => vec![("0", node)],
+            Expression::ShellExec(node) => vec![("0", node)],
+            Expression::AnonymousClass(node) => vec![("0", node)],
+            Expression::Bool(node) => vec![("0", node)],
+            Expression::ArrayIndex(node) => vec![("0", node)],
+            Expression::MagicConstant(node) => vec![("0", node)],
+            Expression::ShortTernary(node) => vec![("0", node)],
+            Expression::Ternary(node) => vec![("0", node)],
+            Expression::Coalesce(node) => vec![("0", node)],
+            Expression::Clone(node) => vec![("0", node)],
+            Expression::Match(node) => vec![("0", node)],
+            Expression::Throw(node) => vec![("0", node)],
+            Expression::Yield(node) => vec![("0", node)],
+            Expression::YieldFrom(node) => vec![("0", node)],
+            Expression::Cast(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Expression::Eval(node) => vec![("0", node)],
+            Expression::Empty(node) => vec![("0", node)],
+            Expression::Die(node) => vec![("0", node)],
+            Expression::Exit(node) => vec![("0", node)],
+            Expression::Isset(node) => vec![("0", node)],
+            Expression::Unset(node) => vec![("0", node)],
+            Expression::Print(node) => vec![("0", node)],
+            Expression::Literal(node) => vec![("0", node)],
+            Expression::ArithmeticOperation(node) => vec![("0", node)],
+            Expression::AssignmentOperation(node) => vec![("0", node)],
+            Expression::BitwiseOperation(node) => vec![("0", node)],
+            Expression::ComparisonOperation(node) => vec![("0", node)],
+            Expression::LogicalOperation(node) => vec![("0", node)],
+            Expression::Concat(node) => vec![("0", node)],
+            Expression::Instanceof(node) => vec![("0", node)],
+            Expression::Reference(node) => vec![("0", node)],
+            Expression::Parenthesized(node) => vec![("0", node)],
+            Expression::ErrorSuppress(node) => vec![("0", node)],
+            Expression::Identifier(node) => vec![("0", node)],
+            Expression::Variable(node) => vec![("0", node)],
+            Expression::Include(node) => vec![("0", node)],
+            Expression::IncludeOnce(node) => vec![("0", node)],
+            Expression::Require(node) => vec![("0", node)],
+            Expression::RequireOnce(node) => vec![("0", node)],
+            Expression::FunctionCall(node) => vec![("0", node)],
+            Expression::FunctionClosureCreation(node) => vec![("0", node)],
+            Expression::MethodCall(node) => vec![("0", node)],
+            Expression::MethodClosureCreation(node) => vec![("0", node)],
+            Expression::NullsafeMethodCall(node) => vec![("0", node)],
+            Expression::StaticMethodCall(node) => vec![("0", node)],
+            Expression::StaticVariableMethodCall(node) => vec![("0", node)],
+            Expression::StaticMethodClosureCreation(node) => vec![("0", node)],
+            Expression::StaticVariableMethodClosureCreation(node) => vec![("0", node)],
+            Expression::PropertyFetch(node) => vec![("0", node)],
+            Expression::NullsafePropertyFetch(node) => vec![("0", node)],
+            Expression::StaticPropertyFetch(node) => vec![("0", node)],
+            Expression::ConstantFetch(node) => vec![("0", node)],
+            Expression::ShortArray(node) => vec![("0", node)],
+            Expression::Array(node) => vec![("0", node)],
+            Expression::List(node) => vec![("0", node)],
+            Expression::Closure(node) => vec![("0", node)],
+            Expression::ArrowFunction(node) => vec![("0", node)],
+            Expression::New(node) => vec![("0", node)],
+            Expression::InterpolatedString(node) => vec![("0", node)],
+            Expression::Heredoc(node) => vec![("0", node)],
+            Expression::Nowdoc(node) => vec![("0", node)],
+            Expression::ShellExec(node) => vec![("0", node)],
+            Expression::AnonymousClass(node) => vec![("0", node)],
+            Expression::Bool(node) => vec![("0", node)],
+            Expression::ArrayIndex(node) => vec![("0", node)],
+            Expression::MagicConstant(node) => vec![("0", node)],
+            Expression::ShortTernary(node) => vec![("0", node)],
+            Expression::Ternary(node) => vec![("0", node)],
+            Expression::Coalesce(node) => vec![("0", node)],
+            Expression::Clone(node) => vec![("0", node)],
+            Expression::Match(node) => vec![("0", node)],
+            Expression::Throw(node) => vec![("0", node)],
+            Expression::Yield(node) => vec![("0", node)],
+            Expression::YieldFrom(node) => vec![("0", node)],
+            Expression::Cast(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for ExpressionStatement {
+    fn kind(&self) -> &'static str {
+        "ExpressionStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("expression", &mut self.expression),
+            ("ending", &mut self.ending),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expression", &self.expression), ("ending", &self.ending)]
+    }
+}
+
+impl Node for ExpressionStringPart {
+    fn kind(&self) -> &'static str {
+        "ExpressionStringPart"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("expression", &mut *self.expression)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expression", &*self.expression)]
+    }
+}
+
+impl Node for FinallyBlock {
+    fn kind(&self) -> &'static str {
+        "FinallyBlock"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.body {
+            fields.push(("body", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.body {
+            fields.push(("body", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ForStatement {
+    fn kind(&self) -> &'static str {
+        "ForStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("iterator", &mut self.iterator), ("body", &mut self.body)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("iterator", &self.iterator), ("body", &self.body)]
+    }
+}
+
+impl Node for ForStatementBody {
+    fn kind(&self) -> &'static str {
+        "ForStatementBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ForStatementBody::Statement { statement } => vec![("statement", &mut **statement)],
+            ForStatementBody::Block {
+                statements, ending, ..
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ForStatementBody::Statement { statement } => vec![("statement", &**statement)],
+            ForStatementBody::Block {
+                statements, ending, ..
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+}
+
+impl Node for ForStatementIterator {
+    fn kind(&self) -> &'static str {
+        "ForStatementIterator"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.initializations {
+            fields.push(("initializations", item));
+        }
+        for item in &mut self.conditions {
+            fields.push(("conditions", item));
+        }
+        for item in &mut self.r#loop {
+            fields.push(("r#loop", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.initializations {
+            fields.push(("initializations", item));
+        }
+        for item in &self.conditions {
+            fields.push(("conditions", item));
+        }
+        for item in &self.r#loop {
+            fields.push(("r#loop", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ForeachStatement {
+    fn kind(&self) -> &'static str {
+        "ForeachStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("iterator", &mut self.iterator), ("body", &mut self.body)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("iterator", &self.iterator), ("body", &self.body)]
+    }
+}
+
+impl Node for ForeachStatementBody {
+    fn kind(&self) -> &'static str {
+        "ForeachStatementBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ForeachStatementBody::Statement { statement } => vec![("statement", &mut **statement)],
+            ForeachStatementBody::Block {
+                statements, ending, ..
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ForeachStatementBody::Statement { statement } => vec![("statement", &**statement)],
+            ForeachStatementBody::Block {
+                statements, ending, ..
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+}
+
+impl Node for ForeachStatementIterator {
+    fn kind(&self) -> &'static str {
+        "ForeachStatementIterator"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ForeachStatementIterator::Value {
+                expression, value, ..
+            } => vec![("expression", expression), ("value", value)],
+            ForeachStatementIterator::KeyAndValue {
+                expression,
+                key,
+                value,
+                ..
+            } => vec![("expression", expression), ("key", key), ("value", value)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ForeachStatementIterator::Value {
+                expression, value, ..
+            } => vec![("expression", expression), ("value", value)],
+            ForeachStatementIterator::KeyAndValue {
+                expression,
+                key,
+                value,
+                ..
+            } => vec![("expression", expression), ("key", key), ("value", value)],
+        }
+    }
+}
+
+impl Node for FullOpeningTagStatement {
+    fn kind(&self) -> &'static str {
+        "FullOpeningTagStatement"
+    }
+}
+
+impl Node for FunctionBody {
+    fn kind(&self) -> &'static str {
+        "FunctionBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for FunctionCallExpression {
+    fn kind(&self) -> &'static str {
+        "FunctionCallExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("arguments", &mut self.arguments),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("target", &*self.target), ("arguments", &self.arguments)]
+    }
+}
+
+impl Node for FunctionClosureCreationExpression {
+    fn kind(&self) -> &'static str {
+        "FunctionClosureCreationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("placeholder", &mut self.placeholder),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("placeholder", &self.placeholder),
+        ]
+    }
+}
+
+impl Node for FunctionParameter {
+    fn kind(&self) -> &'static str {
+        "FunctionParameter"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> =
+            vec![("comments", &mut self.comments), ("name", &mut self.name)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        if let Some(item) = &mut self.data_type {
+            fields.push(("data_type", item));
+        }
+        if let Some(item) = &mut self.default {
+            fields.push(("default", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> =
+            vec![("comments", &self.comments), ("name", &self.name)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        if let Some(item) = &self.data_type {
+            fields.push(("data_type", item));
+        }
+        if let Some(item) = &self.default {
+            fields.push(("default", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for FunctionParameterList {
+    fn kind(&self) -> &'static str {
+        "FunctionParameterList"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.parameters {
+            fields.push(("parameters", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.parameters {
+            fields.push(("parameters", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for FunctionStatement {
+    fn kind(&self) -> &'static str {
+        "FunctionStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &mut self.name));
+        fields.push(("parameters", &mut self.parameters));
+        if let Some(item) = &mut self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &self.name));
+        fields.push(("parameters", &self.parameters));
+        if let Some(item) = &self.return_type {
+            fields.push(("return_type", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for GlobalStatement {
+    fn kind(&self) -> &'static str {
+        "GlobalStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.variables {
+            fields.push(("variables", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.variables {
+            fields.push(("variables", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for GotoStatement {
+    fn kind(&self) -> &'static str {
+        "GotoStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("comments", &mut self.comments), ("label", &mut self.label)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("comments", &self.comments), ("label", &self.label)]
+    }
+}
+
+impl Node for GroupUseStatement {
+    fn kind(&self) -> &'static str {
+        "GroupUseStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("prefix", &mut self.prefix)];
+        for item in &mut self.uses {
+            fields.push(("uses", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("prefix", &self.prefix)];
+        for item in &self.uses {
+            fields.push(("uses", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for HaltCompilerStatement {
+    fn kind(&self) -> &'static str {
+        "HaltCompilerStatement"
+    }
+}
+
+impl Node for HeredocExpression {
+    fn kind(&self) -> &'static str {
+        "HeredocExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.parts {
+            fields.push(("parts", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.parts {
+            fields.push(("parts", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for Identifier {
+    fn kind(&self) -> &'static str {
+        "Identifier"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Identifier::SimpleIdentifier(node) => vec![("0", node)],
+            Identifier::DynamicIdentifier(node) => vec![("0", node)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Identifier::SimpleIdentifier(node) => vec![("0", node)],
+            Identifier::DynamicIdentifier(node) => vec![("0", node)],
+        }
+    }
+}
+
+impl Node for IfStatement {
+    fn kind(&self) -> &'static str {
+        "IfStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("condition", &mut self.condition), ("body", &mut self.body)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("condition", &self.condition), ("body", &self.body)]
+    }
+}
+
+impl Node for IfStatementBody {
+    fn kind(&self) -> &'static str {
+        "IfStatementBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> =
+                    vec![("statement", &mut **statement)];
+                for item in elseifs {
+                    fields.push(("elseifs", item));
+                }
+                if let Some(item) = r#else {
+                    fields.push(("r#else", item));
+                }
+
+                fields
+            }
+            IfStatementBody::Block {
+                statements,
+                elseifs,
+                r#else,
+                ending,
+                ..
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                for item in elseifs {
+                    fields.push(("elseifs", item));
+                }
+                if let Some(item) = r#else {
+                    fields.push(("r#else", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = vec![("statement", &**statement)];
+                for item in elseifs {
+                    fields.push(("elseifs", item));
+                }
+                if let Some(item) = r#else {
+                    fields.push(("r#else", item));
+                }
+
+                fields
+            }
+            IfStatementBody::Block {
+                statements,
+                elseifs,
+                r#else,
+                ending,
+                ..
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                for item in elseifs {
+                    fields.push(("elseifs", item));
+                }
+                if let Some(item) = r#else {
+                    fields.push(("r#else", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+}
+
+impl Node for IfStatementElse {
+    fn kind(&self) -> &'static str {
+        "IfStatementElse"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("statement", &mut *self.statement)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("statement", &*self.statement)]
+    }
+}
+
+impl Node for IfStatementElseBlock {
+    fn kind(&self) -> &'static str {
+        "IfStatementElseBlock"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for IfStatementElseIf {
+    fn kind(&self) -> &'static str {
+        "IfStatementElseIf"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("condition", &mut self.condition),
+            ("statement", &mut *self.statement),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("condition", &self.condition),
+            ("statement", &*self.statement),
+        ]
+    }
+}
+
+impl Node for IfStatementElseIfBlock {
+    fn kind(&self) -> &'static str {
+        "IfStatementElseIfBlock"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> =
+            vec![("condition", &mut self.condition)];
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("condition", &self.condition)];
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for IncludeExpression {
+    fn kind(&self) -> &'static str {
+        "IncludeExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("path", &mut *self.path)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("path", &*self.path)]
+    }
+}
+
+impl Node for IncludeOnceExpression {
+    fn kind(&self) -> &'static str {
+        "IncludeOnceExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("path", &mut *self.path)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("path", &*self.path)]
+    }
+}
+
+impl Node for InlineHtmlStatement {
+    fn kind(&self) -> &'static str {
+        "InlineHtmlStatement"
+    }
+}
+
+impl Node for InstanceofExpression {
+    fn kind(&self) -> &'static str {
+        "InstanceofExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("left", &mut *self.left), ("right", &mut *self.right)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("left", &*self.left), ("right", &*self.right)]
+    }
+}
+
+impl Node for InterfaceBody {
+    fn kind(&self) -> &'static str {
+        "InterfaceBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &mut self.comments));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &self.comments));
+
+        fields
+    }
+}
+
+impl Node for InterfaceExtends {
+    fn kind(&self) -> &'static str {
+        "InterfaceExtends"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.parents {
+            fields.push(("parents", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.parents {
+            fields.push(("parents", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for InterfaceMember {
+    fn kind(&self) -> &'static str {
+        "InterfaceMember"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            InterfaceMember::Constant(node) => vec![("0", node)],
+            InterfaceMember::Constructor(node) => vec![("0", node)],
+            InterfaceMember::Method(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            InterfaceMember::Constant(node) => vec![("0", node)],
+            InterfaceMember::Constructor(node) => vec![("0", node)],
+            InterfaceMember::Method(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for InterfaceStatement {
+    fn kind(&self) -> &'static str {
+        "InterfaceStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &mut self.name));
+        if let Some(item) = &mut self.extends {
+            fields.push(("extends", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &self.name));
+        if let Some(item) = &self.extends {
+            fields.push(("extends", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for InterpolatedStringExpression {
+    fn kind(&self) -> &'static str {
+        "InterpolatedStringExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.parts {
+            fields.push(("parts", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.parts {
+            fields.push(("parts", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for IssetExpression {
+    fn kind(&self) -> &'static str {
+        "IssetExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("arguments", &mut self.arguments)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("arguments", &self.arguments)]
+    }
+}
+
+impl Node for LabelStatement {
+    fn kind(&self) -> &'static str {
+        "LabelStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("comments", &mut self.comments), ("label", &mut self.label)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("comments", &self.comments), ("label", &self.label)]
+    }
+}
+
+impl Node for Level {
+    fn kind(&self) -> &'static str {
+        "Level"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Level::Literal(node) => vec![("0", node)],
+            Level::Parenthesized { level, .. } => vec![("level", &mut **level)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Level::Literal(node) => vec![("0", node)],
+            Level::Parenthesized { level, .. } => vec![("level", &**level)],
+        }
+    }
+}
+
+impl Node for ListEntry {
+    fn kind(&self) -> &'static str {
+        "ListEntry"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            ListEntry::Value { value } => vec![("value", value)],
+            ListEntry::KeyValue { key, value, .. } => vec![("key", key), ("value", value)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            ListEntry::Value { value } => vec![("value", value)],
+            ListEntry::KeyValue { key, value, .. } => vec![("key", key), ("value", value)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for ListExpression {
+    fn kind(&self) -> &'static str {
+        "ListExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.items {
+            fields.push(("items", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.items {
+            fields.push(("items", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for Literal {
+    fn kind(&self) -> &'static str {
+        "Literal"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Literal::String(node) => vec![("0", node)],
+            Literal::Integer(node) => vec![("0", node)],
+            Literal::Float(node) => vec![("0", node)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Literal::String(node) => vec![("0", node)],
+            Literal::Integer(node) => vec![("0", node)],
+            Literal::Float(node) => vec![("0", node)],
+        }
+    }
+}
+
+impl Node for LiteralFloat {
+    fn kind(&self) -> &'static str {
+        "LiteralFloat"
+    }
+}
+
+impl Node for LiteralInteger {
+    fn kind(&self) -> &'static str {
+        "LiteralInteger"
+    }
+}
+
+impl Node for LiteralString {
+    fn kind(&self) -> &'static str {
+        "LiteralString"
+    }
+}
+
+impl Node for LiteralStringPart {
+    fn kind(&self) -> &'static str {
+        "LiteralStringPart"
+    }
+}
+
+impl Node for LogicalOperationExpression {
+    fn kind(&self) -> &'static str {
+        "LogicalOperationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            LogicalOperationExpression::And { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            LogicalOperationExpression::Or { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            LogicalOperationExpression::Not { right, .. } => vec![("right", &mut **right)],
+            LogicalOperationExpression::LogicalAnd { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            LogicalOperationExpression::LogicalOr { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+            LogicalOperationExpression::LogicalXor { left, right, .. } => {
+                vec![("left", &mut **left), ("right", &mut **right)]
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            LogicalOperationExpression::And { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            LogicalOperationExpression::Or { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            LogicalOperationExpression::Not { right, .. } => vec![("right", &**right)],
+            LogicalOperationExpression::LogicalAnd { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            LogicalOperationExpression::LogicalOr { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+            LogicalOperationExpression::LogicalXor { left, right, .. } => {
+                vec![("left", &**left), ("right", &**right)]
+            }
+        }
+    }
+}
+
+impl Node for MagicConstantExpression {
+    fn kind(&self) -> &'static str {
+        "MagicConstantExpression"
+    }
+}
+
+impl Node for MatchArm {
+    fn kind(&self) -> &'static str {
+        "MatchArm"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.conditions {
+            fields.push(("conditions", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.conditions {
+            fields.push(("conditions", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for MatchExpression {
+    fn kind(&self) -> &'static str {
+        "MatchExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> =
+            vec![("condition", &mut *self.condition)];
+        if let Some(item) = &mut self.default {
+            fields.push(("default", &mut **item));
+        }
+        for item in &mut self.arms {
+            fields.push(("arms", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("condition", &*self.condition)];
+        if let Some(item) = &self.default {
+            fields.push(("default", &**item));
+        }
+        for item in &self.arms {
+            fields.push(("arms", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for MethodBody {
+    fn kind(&self) -> &'static str {
+        "MethodBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for MethodCallExpression {
+    fn kind(&self) -> &'static str {
+        "MethodCallExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut *self.method),
+            ("arguments", &mut self.arguments),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &*self.method),
+            ("arguments", &self.arguments),
+        ]
+    }
+}
+
+impl Node for MethodClosureCreationExpression {
+    fn kind(&self) -> &'static str {
+        "MethodClosureCreationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut *self.method),
+            ("placeholder", &mut self.placeholder),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &*self.method),
+            ("placeholder", &self.placeholder),
+        ]
+    }
+}
+
+impl Node for MethodModifier {
+    fn kind(&self) -> &'static str {
+        "MethodModifier"
+    }
+}
+
+impl Node for MethodModifierGroup {
+    fn kind(&self) -> &'static str {
+        "MethodModifierGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for NamedArgument {
+    fn kind(&self) -> &'static str {
+        "NamedArgument"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("comments", &mut self.comments),
+            ("name", &mut self.name),
+            ("value", &mut self.value),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("comments", &self.comments),
+            ("name", &self.name),
+            ("value", &self.value),
+        ]
+    }
+}
+
+impl Node for NamespaceStatement {
+    fn kind(&self) -> &'static str {
+        "NamespaceStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            NamespaceStatement::Unbraced(node) => vec![("0", node)],
+            NamespaceStatement::Braced(node) => vec![("0", node)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            NamespaceStatement::Unbraced(node) => vec![("0", node)],
+            NamespaceStatement::Braced(node) => vec![("0", node)],
+        }
+    }
+}
+
+impl Node for NewExpression {
+    fn kind(&self) -> &'static str {
+        "NewExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("target", &mut *self.target)];
+        if let Some(item) = &mut self.arguments {
+            fields.push(("arguments", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("target", &*self.target)];
+        if let Some(item) = &self.arguments {
+            fields.push(("arguments", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for NowdocExpression {
+    fn kind(&self) -> &'static str {
+        "NowdocExpression"
+    }
+}
+
+impl Node for NullsafeMethodCallExpression {
+    fn kind(&self) -> &'static str {
+        "NullsafeMethodCallExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut *self.method),
+            ("arguments", &mut self.arguments),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &*self.method),
+            ("arguments", &self.arguments),
+        ]
+    }
+}
+
+impl Node for NullsafePropertyFetchExpression {
+    fn kind(&self) -> &'static str {
+        "NullsafePropertyFetchExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("property", &mut *self.property),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("target", &*self.target), ("property", &*self.property)]
+    }
+}
+
+impl Node for ParenthesizedExpression {
+    fn kind(&self) -> &'static str {
+        "ParenthesizedExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("expr", &mut *self.expr)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expr", &*self.expr)]
+    }
+}
+
+impl Node for PositionalArgument {
+    fn kind(&self) -> &'static str {
+        "PositionalArgument"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("comments", &mut self.comments), ("value", &mut self.value)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("comments", &self.comments), ("value", &self.value)]
+    }
+}
+
+impl Node for PrintExpression {
+    fn kind(&self) -> &'static str {
+        "PrintExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.value {
+            fields.push(("value", &mut **item));
+        }
+        if let Some(item) = &mut self.argument {
+            fields.push(("argument", &mut **item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.value {
+            fields.push(("value", &**item));
+        }
+        if let Some(item) = &self.argument {
+            fields.push(("argument", &**item));
+        }
+
+        fields
+    }
+}
+
+impl Node for PromotedPropertyModifier {
+    fn kind(&self) -> &'static str {
+        "PromotedPropertyModifier"
+    }
+}
+
+impl Node for PromotedPropertyModifierGroup {
+    fn kind(&self) -> &'static str {
+        "PromotedPropertyModifierGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for Property {
+    fn kind(&self) -> &'static str {
+        "Property"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &mut self.modifiers));
+        if let Some(item) = &mut self.r#type {
+            fields.push(("r#type", item));
+        }
+        for item in &mut self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("modifiers", &self.modifiers));
+        if let Some(item) = &self.r#type {
+            fields.push(("r#type", item));
+        }
+        for item in &self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for PropertyEntry {
+    fn kind(&self) -> &'static str {
+        "PropertyEntry"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            PropertyEntry::Uninitialized { variable } => vec![("variable", variable)],
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => vec![("variable", variable), ("value", value)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            PropertyEntry::Uninitialized { variable } => vec![("variable", variable)],
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => vec![("variable", variable), ("value", value)],
+        }
+    }
+}
+
+impl Node for PropertyFetchExpression {
+    fn kind(&self) -> &'static str {
+        "PropertyFetchExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("property", &mut *self.property),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("target", &*self.target), ("property", &*self.property)]
+    }
+}
+
+impl Node for PropertyModifier {
+    fn kind(&self) -> &'static str {
+        "PropertyModifier"
+    }
+}
+
+impl Node for PropertyModifierGroup {
+    fn kind(&self) -> &'static str {
+        "PropertyModifierGroup"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.modifiers {
+            fields.push(("modifiers", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ReferenceExpression {
+    fn kind(&self) -> &'static str {
+        "ReferenceExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("right", &mut *self.right)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("right", &*self.right)]
+    }
+}
+
+impl Node for RequireExpression {
+    fn kind(&self) -> &'static str {
+        "RequireExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("path", &mut *self.path)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("path", &*self.path)]
+    }
+}
+
+impl Node for RequireOnceExpression {
+    fn kind(&self) -> &'static str {
+        "RequireOnceExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("path", &mut *self.path)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("path", &*self.path)]
+    }
+}
+
+impl Node for ReturnStatement {
+    fn kind(&self) -> &'static str {
+        "ReturnStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.value {
+            fields.push(("value", item));
+        }
+        fields.push(("ending", &mut self.ending));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.value {
+            fields.push(("value", item));
+        }
+        fields.push(("ending", &self.ending));
+
+        fields
+    }
+}
+
+impl Node for ReturnType {
+    fn kind(&self) -> &'static str {
+        "ReturnType"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("data_type", &mut self.data_type)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("data_type", &self.data_type)]
+    }
+}
+
+impl Node for ShellExecExpression {
+    fn kind(&self) -> &'static str {
+        "ShellExecExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.parts {
+            fields.push(("parts", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.parts {
+            fields.push(("parts", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ShortArrayExpression {
+    fn kind(&self) -> &'static str {
+        "ShortArrayExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.items {
+            fields.push(("items", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.items {
+            fields.push(("items", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for ShortOpeningTagStatement {
+    fn kind(&self) -> &'static str {
+        "ShortOpeningTagStatement"
+    }
+}
+
+impl Node for ShortTernaryExpression {
+    fn kind(&self) -> &'static str {
+        "ShortTernaryExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("condition", &mut *self.condition),
+            ("r#else", &mut *self.r#else),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("condition", &*self.condition), ("r#else", &*self.r#else)]
+    }
+}
+
+impl Node for SimpleIdentifier {
+    fn kind(&self) -> &'static str {
+        "SimpleIdentifier"
+    }
+}
+
+impl Node for SimpleVariable {
+    fn kind(&self) -> &'static str {
+        "SimpleVariable"
+    }
+}
+
+impl Node for SingleArgument {
+    fn kind(&self) -> &'static str {
+        "SingleArgument"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("comments", &mut self.comments),
+            ("argument", &mut self.argument),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("comments", &self.comments), ("argument", &self.argument)]
+    }
+}
+
+impl Node for Statement {
+    fn kind(&self) -> &'static str {
+        "Statement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Statement::FullOpeningTag(node) => vec![("0", node)],
+            Statement::ShortOpeningTag(node) => vec![("0", node)],
+            Statement::EchoOpeningTag(node) => vec![("0", node)],
+            Statement::ClosingTag(node) => vec![("0", node)],
+            Statement::InlineHtml(node) => vec![("0", node)],
+            Statement::Label(node) => vec![("0", node)],
+            Statement::Goto(node) => vec![("0", node)],
+            Statement::HaltCompiler(node) => vec![("0", node)],
+            Statement::Static(node) => vec![("0", node)],
+            Statement::DoWhile(node) => vec![("0", node)],
+            Statement::While(node) => vec![("0", node)],
+            Statement::For(node) => vec![("0", node)],
+            Statement::Foreach(node) => vec![("0", node)],
+            Statement::Break(node) => vec![("0", node)],
+            Statement::Continue(node) => vec![("0", node)],
+            Statement::Constant(node) => vec![("0", node)],
+            Statement::Function(node) => vec![("0", node)],
+            Statement::Class(node) => vec![("0", node)],
+            Statement::Trait(node) => vec![("0", node)],
+            Statement::Interface(node) => vec![("0", node)],
+            Statement::If(node) => vec![("0", node)],
+            Statement::Switch(node) => vec![("0", node)],
+            Statement::Echo(node) => vec![("0", node)],
+            Statement::Expression(node) => vec![("0", node)],
+            Statement::Return(node) => vec![("0", node)],
+            Statement::Namespace(node) => vec![("0", node)],
+            Statement::Use(node) => vec![("0", node)],
+            Statement::GroupUse(node) => vec![("0", node)],
+            Statement::Comment(node) => vec![("0", node)],
+            Statement::Try(node) => vec![("0", node)],
+            Statement::UnitEnum(node) => vec![("0", node)],
+            Statement::BackedEnum(node) => vec![("0", node)],
+            Statement::Block(node) => vec![("0", node)],
+            Statement::Global(node) => vec![("0", node)],
+            Statement::Declare(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Statement::FullOpeningTag(node) => vec![("0", node)],
+            Statement::ShortOpeningTag(node) => vec![("0", node)],
+            Statement::EchoOpeningTag(node) => vec![("0", node)],
+            Statement::ClosingTag(node) => vec![("0", node)],
+            Statement::InlineHtml(node) => vec![("0", node)],
+            Statement::Label(node) => vec![("0", node)],
+            Statement::Goto(node) => vec![("0", node)],
+            Statement::HaltCompiler(node) => vec![("0", node)],
+            Statement::Static(node) => vec![("0", node)],
+            Statement::DoWhile(node) => vec![("0", node)],
+            Statement::While(node) => vec![("0", node)],
+            Statement::For(node) => vec![("0", node)],
+            Statement::Foreach(node) => vec![("0", node)],
+            Statement::Break(node) => vec![("0", node)],
+            Statement::Continue(node) => vec![("0", node)],
+            Statement::Constant(node) => vec![("0", node)],
+            Statement::Function(node) => vec![("0", node)],
+            Statement::Class(node) => vec![("0", node)],
+            Statement::Trait(node) => vec![("0", node)],
+            Statement::Interface(node) => vec![("0", node)],
+            Statement::If(node) => vec![("0", node)],
+            Statement::Switch(node) => vec![("0", node)],
+            Statement::Echo(node) => vec![("0", node)],
+            Statement::Expression(node) => vec![("0", node)],
+            Statement::Return(node) => vec![("0", node)],
+            Statement::Namespace(node) => vec![("0", node)],
+            Statement::Use(node) => vec![("0", node)],
+            Statement::GroupUse(node) => vec![("0", node)],
+            Statement::Comment(node) => vec![("0", node)],
+            Statement::Try(node) => vec![("0", node)],
+            Statement::UnitEnum(node) => vec![("0", node)],
+            Statement::BackedEnum(node) => vec![("0", node)],
+            Statement::Block(node) => vec![("0", node)],
+            Statement::Global(node) => vec![("0", node)],
+            Statement::Declare(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for StaticMethodCallExpression {
+    fn kind(&self) -> &'static str {
+        "StaticMethodCallExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut self.method),
+            ("arguments", &mut self.arguments),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &self.method),
+            ("arguments", &self.arguments),
+        ]
+    }
+}
+
+impl Node for StaticMethodClosureCreationExpression {
+    fn kind(&self) -> &'static str {
+        "StaticMethodClosureCreationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut self.method),
+            ("placeholder", &mut self.placeholder),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &self.method),
+            ("placeholder", &self.placeholder),
+        ]
+    }
+}
+
+impl Node for StaticPropertyFetchExpression {
+    fn kind(&self) -> &'static str {
+        "StaticPropertyFetchExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("property", &mut self.property),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("target", &*self.target), ("property", &self.property)]
+    }
+}
+
+impl Node for StaticStatement {
+    fn kind(&self) -> &'static str {
+        "StaticStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.vars {
+            fields.push(("vars", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.vars {
+            fields.push(("vars", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for StaticVar {
+    fn kind(&self) -> &'static str {
+        "StaticVar"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("var", &mut self.var)];
+        if let Some(item) = &mut self.default {
+            fields.push(("default", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("var", &self.var)];
+        if let Some(item) = &self.default {
+            fields.push(("default", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for StaticVariableMethodCallExpression {
+    fn kind(&self) -> &'static str {
+        "StaticVariableMethodCallExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut self.method),
+            ("arguments", &mut self.arguments),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &self.method),
+            ("arguments", &self.arguments),
+        ]
+    }
+}
+
+impl Node for StaticVariableMethodClosureCreationExpression {
+    fn kind(&self) -> &'static str {
+        "StaticVariableMethodClosureCreationExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("target", &mut *self.target),
+            ("method", &mut self.method),
+            ("placeholder", &mut self.placeholder),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("target", &*self.target),
+            ("method", &self.method),
+            ("placeholder", &self.placeholder),
+        ]
+    }
+}
+
+impl Node for StringPart {
+    fn kind(&self) -> &'static str {
+        "StringPart"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            StringPart::Literal(node) => vec![("0", node)],
+            StringPart::Expression(node) => vec![("0", node)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            StringPart::Literal(node) => vec![("0", node)],
+            StringPart::Expression(node) => vec![("0", node)],
+        }
+    }
+}
+
+impl Node for SwitchStatement {
+    fn kind(&self) -> &'static str {
+        "SwitchStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> =
+            vec![("condition", &mut self.condition)];
+        for item in &mut self.cases {
+            fields.push(("cases", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("condition", &self.condition)];
+        for item in &self.cases {
+            fields.push(("cases", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for TernaryExpression {
+    fn kind(&self) -> &'static str {
+        "TernaryExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![
+            ("condition", &mut *self.condition),
+            ("then", &mut *self.then),
+            ("r#else", &mut *self.r#else),
+        ]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("condition", &*self.condition),
+            ("then", &*self.then),
+            ("r#else", &*self.r#else),
+        ]
+    }
+}
+
+impl Node for ThrowExpression {
+    fn kind(&self) -> &'static str {
+        "ThrowExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("value", &mut *self.value)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("value", &*self.value)]
+    }
+}
+
+impl Node for TraitBody {
+    fn kind(&self) -> &'static str {
+        "TraitBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &mut self.comments));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &self.comments));
+
+        fields
+    }
+}
+
+impl Node for TraitMember {
+    fn kind(&self) -> &'static str {
+        "TraitMember"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            TraitMember::Constant(node) => vec![("0", node)],
+            TraitMember::TraitUsage(node) => vec![("0", node)],
+            TraitMember::Property(node) => vec![("0", node)],
+            TraitMember::VariableProperty(node) => vec![("0", node)],
+            TraitMember::AbstractMethod(node) => vec![("0", node)],
+            TraitMember::AbstractConstructor(node) => vec![("0", node)],
+            TraitMember::ConcreteMethod(node) => vec![("0", node)],
+            TraitMember::ConcreteConstructor(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            TraitMember::Constant(node) => vec![("0", node)],
+            TraitMember::TraitUsage(node) => vec![("0", node)],
+            TraitMember::Property(node) => vec![("0", node)],
+            TraitMember::VariableProperty(node) => vec![("0", node)],
+            TraitMember::AbstractMethod(node) => vec![("0", node)],
+            TraitMember::AbstractConstructor(node) => vec![("0", node)],
+            TraitMember::ConcreteMethod(node) => vec![("0", node)],
+            TraitMember::ConcreteConstructor(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for TraitStatement {
+    fn kind(&self) -> &'static str {
+        "TraitStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> =
+            vec![("comments", &mut self.comments), ("name", &mut self.name)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> =
+            vec![("comments", &self.comments), ("name", &self.name)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for TraitUsage {
+    fn kind(&self) -> &'static str {
+        "TraitUsage"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.traits {
+            fields.push(("traits", item));
+        }
+        for item in &mut self.adaptations {
+            fields.push(("adaptations", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.traits {
+            fields.push(("traits", item));
+        }
+        for item in &self.adaptations {
+            fields.push(("adaptations", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for TraitUsageAdaptation {
+    fn kind(&self) -> &'static str {
+        "TraitUsageAdaptation"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                visibility,
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                if let Some(item) = r#trait {
+                    fields.push(("r#trait", item));
+                }
+                fields.push(("method", method));
+                fields.push(("alias", alias));
+                if let Some(item) = visibility {
+                    fields.push(("visibility", item));
+                }
+
+                fields
+            }
+            TraitUsageAdaptation::Visibility {
+                r#trait,
+                method,
+                visibility,
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                if let Some(item) = r#trait {
+                    fields.push(("r#trait", item));
+                }
+                fields.push(("method", method));
+                fields.push(("visibility", visibility));
+
+                fields
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                if let Some(item) = r#trait {
+                    fields.push(("r#trait", item));
+                }
+                fields.push(("method", method));
+                for item in insteadof {
+                    fields.push(("insteadof", item));
+                }
+
+                fields
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                visibility,
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                if let Some(item) = r#trait {
+                    fields.push(("r#trait", item));
+                }
+                fields.push(("method", method));
+                fields.push(("alias", alias));
+                if let Some(item) = visibility {
+                    fields.push(("visibility", item));
+                }
+
+                fields
+            }
+            TraitUsageAdaptation::Visibility {
+                r#trait,
+                method,
+                visibility,
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                if let Some(item) = r#trait {
+                    fields.push(("r#trait", item));
+                }
+                fields.push(("method", method));
+                fields.push(("visibility", visibility));
+
+                fields
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                if let Some(item) = r#trait {
+                    fields.push(("r#trait", item));
+                }
+                fields.push(("method", method));
+                for item in insteadof {
+                    fields.push(("insteadof", item));
+                }
+
+                fields
+            }
+        }
+    }
+}
+
+impl Node for TryStatement {
+    fn kind(&self) -> &'static str {
+        "TryStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.body {
+            fields.push(("body", item));
+        }
+        for item in &mut self.catches {
+            fields.push(("catches", item));
+        }
+        if let Some(item) = &mut self.finally {
+            fields.push(("finally", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.body {
+            fields.push(("body", item));
+        }
+        for item in &self.catches {
+            fields.push(("catches", item));
+        }
+        if let Some(item) = &self.finally {
+            fields.push(("finally", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for Type {
+    fn kind(&self) -> &'static str {
+        "Type"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Type::Nullable(_, node) => vec![("1", &mut **node)],
+            Type::Union(node) => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in node {
+                    fields.push(("0", item));
+                }
+
+                fields
+            }
+            Type::Intersection(node) => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in node {
+                    fields.push(("0", item));
+                }
+
+                fields
+            }
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Type::Nullable(_, node) => vec![("1", &**node)],
+            Type::Union(node) => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in node {
+                    fields.push(("0", item));
+                }
+
+                fields
+            }
+            Type::Intersection(node) => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in node {
+                    fields.push(("0", item));
+                }
+
+                fields
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for UnbracedNamespace {
+    fn kind(&self) -> &'static str {
+        "UnbracedNamespace"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("name", &mut self.name)];
+        for item in &mut self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("name", &self.name)];
+        for item in &self.statements {
+            fields.push(("statements", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for UnitEnumBody {
+    fn kind(&self) -> &'static str {
+        "UnitEnumBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &mut self.comments));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.members {
+            fields.push(("members", item));
+        }
+        fields.push(("comments", &self.comments));
+
+        fields
+    }
+}
+
+impl Node for UnitEnumCase {
+    fn kind(&self) -> &'static str {
+        "UnitEnumCase"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &mut self.name));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &self.name));
+
+        fields
+    }
+}
+
+impl Node for UnitEnumMember {
+    fn kind(&self) -> &'static str {
+        "UnitEnumMember"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            UnitEnumMember::Case(node) => vec![("0", node)],
+            UnitEnumMember::Method(node) => vec![("0", node)],
+            UnitEnumMember::Constant(node) => vec![("0", node)],
+            UnitEnumMember::TraitUsage(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            UnitEnumMember::Case(node) => vec![("0", node)],
+            UnitEnumMember::Method(node) => vec![("0", node)],
+            UnitEnumMember::Constant(node) => vec![("0", node)],
+            UnitEnumMember::TraitUsage(node) => vec![("0", node)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Node for UnitEnumStatement {
+    fn kind(&self) -> &'static str {
+        "UnitEnumStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &mut self.name));
+        for item in &mut self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &mut self.body));
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        fields.push(("name", &self.name));
+        for item in &self.implements {
+            fields.push(("implements", item));
+        }
+        fields.push(("body", &self.body));
+
+        fields
+    }
+}
+
+impl Node for UnsetExpression {
+    fn kind(&self) -> &'static str {
+        "UnsetExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("arguments", &mut self.arguments)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("arguments", &self.arguments)]
+    }
+}
+
+impl Node for Use {
+    fn kind(&self) -> &'static str {
+        "Use"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("name", &mut self.name)];
+        if let Some(item) = &mut self.alias {
+            fields.push(("alias", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("name", &self.name)];
+        if let Some(item) = &self.alias {
+            fields.push(("alias", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for UseStatement {
+    fn kind(&self) -> &'static str {
+        "UseStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        for item in &mut self.uses {
+            fields.push(("uses", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        for item in &self.uses {
+            fields.push(("uses", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for Variable {
+    fn kind(&self) -> &'static str {
+        "Variable"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            Variable::SimpleVariable(node) => vec![("0", node)],
+            Variable::VariableVariable(node) => vec![("0", node)],
+            Variable::BracedVariableVariable(node) => vec![("0", node)],
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            Variable::SimpleVariable(node) => vec![("0", node)],
+            Variable::VariableVariable(node) => vec![("0", node)],
+            Variable::BracedVariableVariable(node) => vec![("0", node)],
+        }
+    }
+}
+
+impl Node for VariableProperty {
+    fn kind(&self) -> &'static str {
+        "VariableProperty"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = vec![("comments", &mut self.comments)];
+        for item in &mut self.attributes {
+            fields.push(("attributes", item));
+        }
+        if let Some(item) = &mut self.r#type {
+            fields.push(("r#type", item));
+        }
+        for item in &mut self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = vec![("comments", &self.comments)];
+        for item in &self.attributes {
+            fields.push(("attributes", item));
+        }
+        if let Some(item) = &self.r#type {
+            fields.push(("r#type", item));
+        }
+        for item in &self.entries {
+            fields.push(("entries", item));
+        }
+
+        fields
+    }
+}
+
+impl Node for VariableVariable {
+    fn kind(&self) -> &'static str {
+        "VariableVariable"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("variable", &mut *self.variable)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("variable", &*self.variable)]
+    }
+}
+
+impl Node for VisibilityModifier {
+    fn kind(&self) -> &'static str {
+        "VisibilityModifier"
+    }
+}
+
+impl Node for WhileStatement {
+    fn kind(&self) -> &'static str {
+        "WhileStatement"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("condition", &mut self.condition), ("body", &mut self.body)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("condition", &self.condition), ("body", &self.body)]
+    }
+}
+
+impl Node for WhileStatementBody {
+    fn kind(&self) -> &'static str {
+        "WhileStatementBody"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        match self {
+            WhileStatementBody::Statement { statement } => vec![("statement", &mut **statement)],
+            WhileStatementBody::Block {
+                statements, ending, ..
+            } => {
+                let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        match self {
+            WhileStatementBody::Statement { statement } => vec![("statement", &**statement)],
+            WhileStatementBody::Block {
+                statements, ending, ..
+            } => {
+                let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+                for item in statements {
+                    fields.push(("statements", item));
+                }
+                fields.push(("ending", ending));
+
+                fields
+            }
+        }
+    }
+}
+
+impl Node for YieldExpression {
+    fn kind(&self) -> &'static str {
+        "YieldExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        let mut fields: Vec<(&'static str, &mut dyn Node)> = Vec::new();
+        if let Some(item) = &mut self.key {
+            fields.push(("key", &mut **item));
+        }
+        if let Some(item) = &mut self.value {
+            fields.push(("value", &mut **item));
+        }
+
+        fields
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut fields: Vec<(&'static str, &dyn Node)> = Vec::new();
+        if let Some(item) = &self.key {
+            fields.push(("key", &**item));
+        }
+        if let Some(item) = &self.value {
+            fields.push(("value", &**item));
+        }
+
+        fields
+    }
+}
+
+impl Node for YieldFromExpression {
+    fn kind(&self) -> &'static str {
+        "YieldFromExpression"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        vec![("value", &mut *self.value)]
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("value", &*self.value)]
+    }
+}
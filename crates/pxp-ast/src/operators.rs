@@ -1,9 +1,9 @@
-use crate::node::Node;
 use crate::Expression;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArithmeticOperationExpression {
     Addition {
         left: Box<Expression>,
@@ -61,39 +61,27 @@ pub enum ArithmeticOperationExpression {
     },
 }
 
-impl Node for ArithmeticOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ArithmeticOperationExpression {
+    fn span(&self) -> Span {
         match self {
-            ArithmeticOperationExpression::Addition { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ArithmeticOperationExpression::Subtraction { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ArithmeticOperationExpression::Multiplication { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ArithmeticOperationExpression::Division { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ArithmeticOperationExpression::Modulo { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ArithmeticOperationExpression::Negative { right, .. } => vec![right.as_mut()],
-            ArithmeticOperationExpression::Positive { right, .. } => vec![right.as_mut()],
-            ArithmeticOperationExpression::PreIncrement { right, .. } => vec![right.as_mut()],
-            ArithmeticOperationExpression::PostIncrement { left, .. } => vec![left.as_mut()],
-            ArithmeticOperationExpression::PreDecrement { right, .. } => vec![right.as_mut()],
-            ArithmeticOperationExpression::PostDecrement { left, .. } => vec![left.as_mut()],
+            ArithmeticOperationExpression::Addition { left, right, .. }
+            | ArithmeticOperationExpression::Subtraction { left, right, .. }
+            | ArithmeticOperationExpression::Multiplication { left, right, .. }
+            | ArithmeticOperationExpression::Division { left, right, .. }
+            | ArithmeticOperationExpression::Modulo { left, right, .. }
+            | ArithmeticOperationExpression::Exponentiation { left, right, .. } => left.span().join(right.span()),
+            ArithmeticOperationExpression::Negative { minus, right } => minus.join(right.span()),
+            ArithmeticOperationExpression::Positive { plus, right } => plus.join(right.span()),
+            ArithmeticOperationExpression::PreIncrement { increment, right } => increment.join(right.span()),
+            ArithmeticOperationExpression::PreDecrement { decrement, right } => decrement.join(right.span()),
+            ArithmeticOperationExpression::PostIncrement { left, increment } => left.span().join(*increment),
+            ArithmeticOperationExpression::PostDecrement { left, decrement } => left.span().join(*decrement),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssignmentOperationExpression {
     Assign {
         left: Box<Expression>,
@@ -236,57 +224,29 @@ impl AssignmentOperationExpression {
     }
 }
 
-impl Node for AssignmentOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for AssignmentOperationExpression {
+    fn span(&self) -> Span {
         match self {
-            AssignmentOperationExpression::Assign { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Addition { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Subtraction { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Multiplication { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Division { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Modulo { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Exponentiation { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Concat { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::BitwiseAnd { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::BitwiseOr { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::BitwiseXor { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::LeftShift { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::RightShift { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            AssignmentOperationExpression::Coalesce { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
+            AssignmentOperationExpression::Assign { left, right, .. }
+            | AssignmentOperationExpression::Addition { left, right, .. }
+            | AssignmentOperationExpression::Subtraction { left, right, .. }
+            | AssignmentOperationExpression::Multiplication { left, right, .. }
+            | AssignmentOperationExpression::Division { left, right, .. }
+            | AssignmentOperationExpression::Modulo { left, right, .. }
+            | AssignmentOperationExpression::Exponentiation { left, right, .. }
+            | AssignmentOperationExpression::Concat { left, right, .. }
+            | AssignmentOperationExpression::BitwiseAnd { left, right, .. }
+            | AssignmentOperationExpression::BitwiseOr { left, right, .. }
+            | AssignmentOperationExpression::BitwiseXor { left, right, .. }
+            | AssignmentOperationExpression::LeftShift { left, right, .. }
+            | AssignmentOperationExpression::RightShift { left, right, .. }
+            | AssignmentOperationExpression::Coalesce { left, right, .. } => left.span().join(right.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BitwiseOperationExpression {
     And {
         left: Box<Expression>,
@@ -319,31 +279,21 @@ pub enum BitwiseOperationExpression {
     },
 }
 
-impl Node for BitwiseOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for BitwiseOperationExpression {
+    fn span(&self) -> Span {
         match self {
-            BitwiseOperationExpression::And { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            BitwiseOperationExpression::Or { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            BitwiseOperationExpression::Xor { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            BitwiseOperationExpression::LeftShift { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            BitwiseOperationExpression::RightShift { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            BitwiseOperationExpression::Not { right, .. } => vec![right.as_mut()],
+            BitwiseOperationExpression::And { left, right, .. }
+            | BitwiseOperationExpression::Or { left, right, .. }
+            | BitwiseOperationExpression::Xor { left, right, .. }
+            | BitwiseOperationExpression::LeftShift { left, right, .. }
+            | BitwiseOperationExpression::RightShift { left, right, .. } => left.span().join(right.span()),
+            BitwiseOperationExpression::Not { not, right } => not.join(right.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ComparisonOperationExpression {
     Equal {
         left: Box<Expression>,
@@ -397,45 +347,25 @@ pub enum ComparisonOperationExpression {
     },
 }
 
-impl Node for ComparisonOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for ComparisonOperationExpression {
+    fn span(&self) -> Span {
         match self {
-            ComparisonOperationExpression::Equal { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::Identical { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::NotEqual { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::AngledNotEqual { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::NotIdentical { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::LessThan { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::GreaterThan { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::LessThanOrEqual { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            ComparisonOperationExpression::Spaceship { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
+            ComparisonOperationExpression::Equal { left, right, .. }
+            | ComparisonOperationExpression::Identical { left, right, .. }
+            | ComparisonOperationExpression::NotEqual { left, right, .. }
+            | ComparisonOperationExpression::AngledNotEqual { left, right, .. }
+            | ComparisonOperationExpression::NotIdentical { left, right, .. }
+            | ComparisonOperationExpression::LessThan { left, right, .. }
+            | ComparisonOperationExpression::GreaterThan { left, right, .. }
+            | ComparisonOperationExpression::LessThanOrEqual { left, right, .. }
+            | ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. }
+            | ComparisonOperationExpression::Spaceship { left, right, .. } => left.span().join(right.span()),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LogicalOperationExpression {
     And {
         left: Box<Expression>,
@@ -468,25 +398,15 @@ pub enum LogicalOperationExpression {
     },
 }
 
-impl Node for LogicalOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for LogicalOperationExpression {
+    fn span(&self) -> Span {
         match self {
-            LogicalOperationExpression::And { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            LogicalOperationExpression::Or { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            LogicalOperationExpression::Not { right, .. } => vec![right.as_mut()],
-            LogicalOperationExpression::LogicalAnd { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            LogicalOperationExpression::LogicalOr { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
-            LogicalOperationExpression::LogicalXor { left, right, .. } => {
-                vec![left.as_mut(), right.as_mut()]
-            }
+            LogicalOperationExpression::And { left, right, .. }
+            | LogicalOperationExpression::Or { left, right, .. }
+            | LogicalOperationExpression::LogicalAnd { left, right, .. }
+            | LogicalOperationExpression::LogicalOr { left, right, .. }
+            | LogicalOperationExpression::LogicalXor { left, right, .. } => left.span().join(right.span()),
+            LogicalOperationExpression::Not { bang, right } => bang.join(right.span()),
         }
     }
 }
@@ -0,0 +1,173 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::node::Node;
+use pxp_span::Span;
+
+/// Identifies a node by its position in a pre-order walk of the tree it was indexed from, so
+/// indexing the same tree twice gives every node the same id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub(crate) usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    parent: Option<NodeId>,
+    type_id: TypeId,
+    span: Span,
+}
+
+/// An index of every node in a tree, recording where it is in the source and which node it
+/// belongs to.
+///
+/// Nodes are found by their address, so the index goes stale once the tree is changed or moved.
+#[derive(Debug)]
+pub struct ParentMap {
+    entries: Vec<Entry>,
+    ids: HashMap<(usize, TypeId), NodeId>,
+}
+
+impl ParentMap {
+    pub fn new(root: &mut dyn Node) -> Self {
+        let mut map = Self {
+            entries: Vec::new(),
+            ids: HashMap::new(),
+        };
+
+        map.insert(root, None);
+        map
+    }
+
+    fn insert(&mut self, node: &mut dyn Node, parent: Option<NodeId>) {
+        let id = NodeId(self.entries.len());
+        // A struct and its first field can share an address, so the type is part of the key.
+        let type_id = (*node).type_id();
+
+        self.entries.push(Entry {
+            parent,
+            type_id,
+            span: node.span(),
+        });
+        self.ids.insert((address(node), type_id), id);
+
+        for child in node.children() {
+            self.insert(child, Some(id));
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    /// Every id in the map, in pre-order.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.entries.len()).map(NodeId)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The id of a node from the indexed tree.
+    pub fn id(&self, node: &dyn Node) -> Option<NodeId> {
+        self.ids.get(&(address(node), node.type_id())).copied()
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.entries[id.0].parent
+    }
+
+    /// The parent of a node, then its parent, and so on up to the root.
+    pub fn ancestors(&self, id: NodeId) -> Ancestors<'_> {
+        Ancestors {
+            map: self,
+            next: self.parent(id),
+        }
+    }
+
+    pub fn span(&self, id: NodeId) -> Span {
+        self.entries[id.0].span
+    }
+
+    /// The type of a node, for comparing with `TypeId::of`.
+    pub fn type_id(&self, id: NodeId) -> TypeId {
+        self.entries[id.0].type_id
+    }
+
+    pub fn is<T: Node>(&self, id: NodeId) -> bool {
+        self.entries[id.0].type_id == TypeId::of::<T>()
+    }
+
+    /// The closest ancestor of a node that is a `T`, such as the class a method is declared in.
+    pub fn enclosing<T: Node>(&self, id: NodeId) -> Option<NodeId> {
+        self.ancestors(id).find(|ancestor| self.is::<T>(*ancestor))
+    }
+
+    /// The innermost node that covers the given byte offset.
+    pub fn node_at_offset(&self, offset: usize) -> Option<NodeId> {
+        // Children come after their parents, so the last match is the most deeply nested one.
+        self.entries
+            .iter()
+            .rposition(|entry| entry.span.start.offset <= offset && offset < entry.span.end.offset)
+            .map(NodeId)
+    }
+
+    /// Finds the node with the given id in the tree that was indexed.
+    pub fn get<'a>(&self, root: &'a mut dyn Node, id: NodeId) -> Option<&'a mut dyn Node> {
+        if id.0 >= self.entries.len() {
+            return None;
+        }
+
+        find(root, id)
+    }
+}
+
+/// Finds the node with the given id in a tree, walking it in the same order a `ParentMap` does.
+pub fn find(root: &mut dyn Node, id: NodeId) -> Option<&mut dyn Node> {
+    find_from(root, id.0, &mut 0)
+}
+
+fn find_from<'a>(node: &'a mut dyn Node, target: usize, next: &mut usize) -> Option<&'a mut dyn Node> {
+    if *next == target {
+        return Some(node);
+    }
+
+    *next += 1;
+
+    for child in node.children() {
+        if let Some(found) = find_from(child, target, next) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+fn address(node: &dyn Node) -> usize {
+    node as *const dyn Node as *const () as usize
+}
+
+pub struct Ancestors<'a> {
+    map: &'a ParentMap,
+    next: Option<NodeId>,
+}
+
+impl Iterator for Ancestors<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.next?;
+        self.next = self.map.parent(id);
+
+        Some(id)
+    }
+}
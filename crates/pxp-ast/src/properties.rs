@@ -1,14 +1,17 @@
 use crate::attributes::AttributeGroup;
+use crate::comments::CommentGroup;
 use crate::data_type::Type;
 use crate::modifiers::PropertyModifierGroup;
-use crate::node::Node;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use crate::variables::SimpleVariable;
 use crate::Expression;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Property {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
 
     pub modifiers: PropertyModifierGroup,
@@ -17,49 +20,47 @@ pub struct Property {
     pub end: Span,
 }
 
-impl Node for Property {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(r#type) = &mut self.r#type {
-            children.push(r#type);
-        }
-        children.extend(
-            self.entries
-                .iter_mut()
-                .map(|e| e as &mut dyn Node)
-                .collect::<Vec<&mut dyn Node>>(),
-        );
-        children
+impl Spanned for Property {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.modifiers.modifiers.first().map(Spanned::span))
+            .or_else(|| self.r#type.as_ref().map(Spanned::span))
+            .or_else(|| span_of(&self.entries))
+            .unwrap_or(self.end);
+
+        start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariableProperty {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
     pub r#type: Option<Type>,
     pub entries: Vec<PropertyEntry>,
     pub end: Span,
 }
 
-impl Node for VariableProperty {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
-        if let Some(r#type) = &mut self.r#type {
-            children.push(r#type);
-        }
-        children.extend(
-            self.entries
-                .iter_mut()
-                .map(|e| e as &mut dyn Node)
-                .collect::<Vec<&mut dyn Node>>(),
-        );
-        children
+impl Spanned for VariableProperty {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map(Spanned::span)
+            .or_else(|| self.r#type.as_ref().map(Spanned::span))
+            .or_else(|| span_of(&self.entries))
+            .unwrap_or(self.end);
+
+        start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropertyEntry {
     Uninitialized {
         variable: SimpleVariable,
@@ -71,13 +72,13 @@ pub enum PropertyEntry {
     },
 }
 
-impl Node for PropertyEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for PropertyEntry {
+    fn span(&self) -> Span {
         match self {
-            PropertyEntry::Uninitialized { variable } => vec![variable],
+            PropertyEntry::Uninitialized { variable } => variable.span,
             PropertyEntry::Initialized {
                 variable, value, ..
-            } => vec![variable, value],
+            } => variable.span.join(value.span()),
         }
     }
 }
@@ -0,0 +1,355 @@
+//! Finding nodes with selectors that work like CSS selectors, but over the kinds and fields of
+//! nodes rather than elements and attributes.
+//!
+//! A selector is a list of kinds, such as `ClassStatement ConcreteMethod`, which matches every
+//! `ConcreteMethod` anywhere inside a `ClassStatement`. Putting `>` between two kinds only
+//! matches direct children instead, bearing in mind that enums such as `Statement` and
+//! `Expression` are nodes of their own. `*` matches any kind of node.
+//!
+//! Kinds are the names of the types in the tree, which are sometimes more specific than the
+//! syntax they come from: a constructor is a `ConcreteConstructor`, for example. Where that's
+//! the case, the more general kind matches both, so `ConcreteMethod` matches constructors too.
+//!
+//! Each kind can be followed by conditions on its fields, which are named the same way as in
+//! `Node::fields`:
+//!
+//! - `[name]` matches nodes that have a `name`.
+//! - `[name="__construct"]` matches nodes with a `name` whose source code is `__construct`.
+//!
+//! Only fields that hold nodes can be matched on, so to find a variable by its name, match on
+//! the identifier or variable that holds it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pxp_ast::query::Selector;
+//! use pxp_ast::Program;
+//!
+//! fn raw_queries(program: &Program, source: &[u8]) -> usize {
+//!     let selector = Selector::parse(r#"StaticMethodCallExpression[target="DB"][method="raw"]"#);
+//!
+//!     selector.unwrap().find(program, source).len()
+//! }
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use crate::node::Node;
+use crate::parents::NodeId;
+use pxp_span::Span;
+
+// Kinds that also match nodes of a more specific kind, which is the same syntax in PHP.
+const ALIASES: &[(&str, &str)] = &[
+    ("AbstractMethod", "AbstractConstructor"),
+    ("ConcreteMethod", "ConcreteConstructor"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    /// How this step relates to the one before it, which doesn't matter for the first one.
+    combinator: Combinator,
+    /// The kind of node to match, or `None` for `*`.
+    kind: Option<String>,
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Condition {
+    field: String,
+    value: Option<String>,
+}
+
+/// A node that matched a selector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The same id the node has in a `ParentMap` of the tree that was searched.
+    pub id: NodeId,
+    pub kind: &'static str,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    pub message: String,
+    /// The byte offset in the selector that the error is at.
+    pub offset: usize,
+}
+
+impl Display for SelectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        let mut parser = Parser {
+            input: selector,
+            offset: 0,
+        };
+        let mut steps = Vec::new();
+
+        parser.whitespace();
+
+        while !parser.is_done() {
+            let combinator = if steps.is_empty() {
+                Combinator::Descendant
+            } else if parser.eat('>') {
+                parser.whitespace();
+                Combinator::Child
+            } else {
+                Combinator::Descendant
+            };
+
+            let (kind, conditions) = parser.compound()?;
+            steps.push(Step {
+                combinator,
+                kind,
+                conditions,
+            });
+
+            // Whitespace is what separates steps that aren't separated by `>`.
+            if !parser.whitespace() && !parser.is_done() && !parser.peek(">") {
+                return Err(parser.error("expected whitespace or `>`"));
+            }
+        }
+
+        if steps.is_empty() {
+            return Err(parser.error("expected a kind of node"));
+        }
+
+        Ok(Self { steps })
+    }
+
+    /// Every node below `root` that matches, including `root` itself, in the order they appear
+    /// in the tree. `source` is the code that was parsed, which is needed to compare fields
+    /// with values.
+    pub fn find(&self, root: &dyn Node, source: &[u8]) -> Vec<Match> {
+        let mut finder = Finder {
+            selector: self,
+            source,
+            ancestors: Vec::new(),
+            next: 0,
+            matches: Vec::new(),
+        };
+
+        finder.walk(root);
+        finder.matches
+    }
+}
+
+struct Finder<'a> {
+    selector: &'a Selector,
+    source: &'a [u8],
+    // Which steps each node from the root down to the current one matches on its own.
+    ancestors: Vec<Vec<bool>>,
+    next: usize,
+    matches: Vec<Match>,
+}
+
+impl<'a> Finder<'a> {
+    fn walk(&mut self, node: &dyn Node) {
+        let id = NodeId(self.next);
+        self.next += 1;
+
+        let steps = self
+            .selector
+            .steps
+            .iter()
+            .map(|step| self.matches_step(step, node))
+            .collect();
+        self.ancestors.push(steps);
+
+        let last = self.selector.steps.len() - 1;
+        if self.matches_from(last, self.ancestors.len() - 1) {
+            self.matches.push(Match {
+                id,
+                kind: node.kind(),
+                span: node.span(),
+            });
+        }
+
+        for (_, child) in node.fields_ref() {
+            self.walk(child);
+        }
+
+        self.ancestors.pop();
+    }
+
+    fn matches_step(&self, step: &Step, node: &dyn Node) -> bool {
+        if step.kind.as_ref().is_some_and(|kind| !matches_kind(kind, node.kind())) {
+            return false;
+        }
+
+        if step.conditions.is_empty() {
+            return true;
+        }
+
+        let fields = node.fields_ref();
+
+        step.conditions.iter().all(|condition| {
+            fields.iter().any(|(field, child)| {
+                *field == condition.field
+                    && condition.value.as_ref().is_none_or(|value| {
+                        let span = child.span();
+                        let text = self.source.get(span.start.offset..span.end.offset);
+
+                        text == Some(value.as_bytes())
+                    })
+            })
+        })
+    }
+
+    // Whether the node at `depth` matches the steps up to and including `step`, working back
+    // through its ancestors for the steps before it.
+    fn matches_from(&self, step: usize, depth: usize) -> bool {
+        if !self.ancestors[depth][step] {
+            return false;
+        }
+
+        if step == 0 {
+            return true;
+        }
+
+        match self.selector.steps[step].combinator {
+            Combinator::Child => depth > 0 && self.matches_from(step - 1, depth - 1),
+            Combinator::Descendant => {
+                (0..depth).rev().any(|depth| self.matches_from(step - 1, depth))
+            }
+        }
+    }
+}
+
+fn matches_kind(kind: &str, actual: &str) -> bool {
+    kind == actual || ALIASES.contains(&(kind, actual))
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn compound(&mut self) -> Result<(Option<String>, Vec<Condition>), SelectorError> {
+        let kind = if self.eat('*') {
+            None
+        } else {
+            Some(self.name("a kind of node or `*`")?)
+        };
+
+        let mut conditions = Vec::new();
+
+        while self.eat('[') {
+            self.whitespace();
+            let field = self.name("a field")?;
+            self.whitespace();
+
+            let value = if self.eat('=') {
+                self.whitespace();
+                let value = self.value()?;
+                self.whitespace();
+                Some(value)
+            } else {
+                None
+            };
+
+            if !self.eat(']') {
+                return Err(self.error("expected `]`"));
+            }
+
+            conditions.push(Condition { field, value });
+        }
+
+        Ok((kind, conditions))
+    }
+
+    fn name(&mut self, expected: &str) -> Result<String, SelectorError> {
+        let rest = &self.input[self.offset..];
+        let length = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+
+        if length == 0 {
+            return Err(self.error(&format!("expected {}", expected)));
+        }
+
+        self.offset += length;
+
+        Ok(rest[..length].to_string())
+    }
+
+    // A quoted string, where `\` escapes the next character, or a name.
+    fn value(&mut self) -> Result<String, SelectorError> {
+        let quote = self.input[self.offset..].chars().next();
+        let Some(quote) = quote.filter(|c| *c == '"' || *c == '\'') else {
+            return self.name("a value");
+        };
+
+        let start = self.offset;
+        let mut value = String::new();
+        let mut chars = self.input[self.offset + 1..].char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, c)) => value.push(c),
+                    None => break,
+                },
+                c if c == quote => {
+                    self.offset += index + 2;
+
+                    return Ok(value);
+                }
+                c => value.push(c),
+            }
+        }
+
+        self.offset = start;
+
+        Err(self.error("unterminated string"))
+    }
+
+    // Skips whitespace, returning whether there was any.
+    fn whitespace(&mut self) -> bool {
+        let rest = &self.input[self.offset..];
+        let trimmed = rest.trim_start();
+        self.offset += rest.len() - trimmed.len();
+
+        rest.len() != trimmed.len()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.input[self.offset..].starts_with(c) {
+            self.offset += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self, s: &str) -> bool {
+        self.input[self.offset..].starts_with(s)
+    }
+
+    fn is_done(&self) -> bool {
+        self.offset == self.input.len()
+    }
+
+    fn error(&self, message: &str) -> SelectorError {
+        SelectorError {
+            message: message.to_string(),
+            offset: self.offset,
+        }
+    }
+}
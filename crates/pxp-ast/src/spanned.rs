@@ -0,0 +1,61 @@
+use pxp_span::Span;
+
+/// A node that knows where it is in the source.
+///
+/// A node's span runs from the start of its first token to the end of its last one. Leading
+/// comments aren't part of it, and a group that can be empty, such as a list of modifiers, has
+/// `Span::default()` when there's nothing in it.
+pub trait Spanned {
+    fn span(&self) -> Span;
+}
+
+impl Spanned for Span {
+    fn span(&self) -> Span {
+        *self
+    }
+}
+
+impl<T: Spanned> Spanned for Box<T> {
+    fn span(&self) -> Span {
+        self.as_ref().span()
+    }
+}
+
+// The span covering the first and last of `items`, if there are any.
+pub(crate) fn span_of<T: Spanned>(items: &[T]) -> Option<Span> {
+    Some(items.first()?.span().join(items.last()?.span()))
+}
+
+/// A node whose spans can all be changed in place, such as to move it after the source in
+/// front of it was edited.
+pub trait SpannedMut {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span));
+}
+
+impl SpannedMut for Span {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(self);
+    }
+}
+
+impl<T: SpannedMut> SpannedMut for Box<T> {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.as_mut().for_each_span_mut(f);
+    }
+}
+
+impl<T: SpannedMut> SpannedMut for Option<T> {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        if let Some(inner) = self {
+            inner.for_each_span_mut(f);
+        }
+    }
+}
+
+impl<T: SpannedMut> SpannedMut for Vec<T> {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        for item in self {
+            item.for_each_span_mut(f);
+        }
+    }
+}
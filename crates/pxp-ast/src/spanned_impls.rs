@@ -0,0 +1,3019 @@
+// This file is generated by pxp-codegen, run `just codegen` to update it.
+
+use crate::arguments::Argument;
+use crate::arguments::ArgumentList;
+use crate::arguments::ArgumentPlaceholder;
+use crate::arguments::NamedArgument;
+use crate::arguments::PositionalArgument;
+use crate::arguments::SingleArgument;
+use crate::attributes::Attribute;
+use crate::attributes::AttributeGroup;
+use crate::classes::AnonymousClassBody;
+use crate::classes::AnonymousClassExpression;
+use crate::classes::AnonymousClassMember;
+use crate::classes::ClassBody;
+use crate::classes::ClassExtends;
+use crate::classes::ClassImplements;
+use crate::classes::ClassMember;
+use crate::classes::ClassStatement;
+use crate::comments::Comment;
+use crate::comments::CommentGroup;
+use crate::constant::ClassishConstant;
+use crate::constant::ConstantEntry;
+use crate::constant::ConstantStatement;
+use crate::control_flow::IfStatement;
+use crate::control_flow::IfStatementBody;
+use crate::control_flow::IfStatementElse;
+use crate::control_flow::IfStatementElseBlock;
+use crate::control_flow::IfStatementElseIf;
+use crate::control_flow::IfStatementElseIfBlock;
+use crate::data_type::Type;
+use crate::declares::DeclareBody;
+use crate::declares::DeclareEntry;
+use crate::declares::DeclareEntryGroup;
+use crate::declares::DeclareStatement;
+use crate::enums::BackedEnumBody;
+use crate::enums::BackedEnumCase;
+use crate::enums::BackedEnumMember;
+use crate::enums::BackedEnumStatement;
+use crate::enums::BackedEnumType;
+use crate::enums::UnitEnumBody;
+use crate::enums::UnitEnumCase;
+use crate::enums::UnitEnumMember;
+use crate::enums::UnitEnumStatement;
+use crate::functions::AbstractConstructor;
+use crate::functions::AbstractMethod;
+use crate::functions::ArrowFunctionExpression;
+use crate::functions::ClosureExpression;
+use crate::functions::ClosureUse;
+use crate::functions::ClosureUseVariable;
+use crate::functions::ConcreteConstructor;
+use crate::functions::ConcreteMethod;
+use crate::functions::ConstructorParameter;
+use crate::functions::ConstructorParameterList;
+use crate::functions::FunctionBody;
+use crate::functions::FunctionParameter;
+use crate::functions::FunctionParameterList;
+use crate::functions::FunctionStatement;
+use crate::functions::MethodBody;
+use crate::functions::ReturnType;
+use crate::goto::GotoStatement;
+use crate::goto::LabelStatement;
+use crate::identifiers::DynamicIdentifier;
+use crate::identifiers::Identifier;
+use crate::identifiers::SimpleIdentifier;
+use crate::interfaces::InterfaceBody;
+use crate::interfaces::InterfaceExtends;
+use crate::interfaces::InterfaceMember;
+use crate::interfaces::InterfaceStatement;
+use crate::literals::Literal;
+use crate::literals::LiteralFloat;
+use crate::literals::LiteralInteger;
+use crate::literals::LiteralString;
+use crate::loops::BreakStatement;
+use crate::loops::ContinueStatement;
+use crate::loops::DoWhileStatement;
+use crate::loops::ForStatement;
+use crate::loops::ForStatementBody;
+use crate::loops::ForStatementIterator;
+use crate::loops::ForeachStatement;
+use crate::loops::ForeachStatementBody;
+use crate::loops::ForeachStatementIterator;
+use crate::loops::Level;
+use crate::loops::WhileStatement;
+use crate::loops::WhileStatementBody;
+use crate::modifiers::ClassModifier;
+use crate::modifiers::ClassModifierGroup;
+use crate::modifiers::ConstantModifier;
+use crate::modifiers::ConstantModifierGroup;
+use crate::modifiers::MethodModifier;
+use crate::modifiers::MethodModifierGroup;
+use crate::modifiers::PromotedPropertyModifier;
+use crate::modifiers::PromotedPropertyModifierGroup;
+use crate::modifiers::PropertyModifier;
+use crate::modifiers::PropertyModifierGroup;
+use crate::modifiers::VisibilityModifier;
+use crate::namespaces::BracedNamespace;
+use crate::namespaces::BracedNamespaceBody;
+use crate::namespaces::NamespaceStatement;
+use crate::namespaces::UnbracedNamespace;
+use crate::operators::ArithmeticOperationExpression;
+use crate::operators::AssignmentOperationExpression;
+use crate::operators::BitwiseOperationExpression;
+use crate::operators::ComparisonOperationExpression;
+use crate::operators::LogicalOperationExpression;
+use crate::properties::Property;
+use crate::properties::PropertyEntry;
+use crate::properties::VariableProperty;
+use crate::spanned::SpannedMut;
+use crate::traits::TraitBody;
+use crate::traits::TraitMember;
+use crate::traits::TraitStatement;
+use crate::traits::TraitUsage;
+use crate::traits::TraitUsageAdaptation;
+use crate::try_block::CatchBlock;
+use crate::try_block::CatchType;
+use crate::try_block::FinallyBlock;
+use crate::try_block::TryStatement;
+use crate::variables::BracedVariableVariable;
+use crate::variables::SimpleVariable;
+use crate::variables::Variable;
+use crate::variables::VariableVariable;
+use crate::ArrayExpression;
+use crate::ArrayIndexExpression;
+use crate::ArrayItem;
+use crate::BlockStatement;
+use crate::BoolExpression;
+use crate::Case;
+use crate::CastExpression;
+use crate::CloneExpression;
+use crate::ClosingTagStatement;
+use crate::CoalesceExpression;
+use crate::ConcatExpression;
+use crate::ConstantFetchExpression;
+use crate::DefaultMatchArm;
+use crate::DieExpression;
+use crate::EchoOpeningTagStatement;
+use crate::EchoStatement;
+use crate::EmptyExpression;
+use crate::Ending;
+use crate::ErrorSuppressExpression;
+use crate::EvalExpression;
+use crate::ExitExpression;
+use crate::Expression;
+use crate::ExpressionStatement;
+use crate::ExpressionStringPart;
+use crate::FullOpeningTagStatement;
+use crate::FunctionCallExpression;
+use crate::FunctionClosureCreationExpression;
+use crate::GlobalStatement;
+use crate::GroupUseStatement;
+use crate::HaltCompilerStatement;
+use crate::HeredocExpression;
+use crate::IncludeExpression;
+use crate::IncludeOnceExpression;
+use crate::InlineHtmlStatement;
+use crate::InstanceofExpression;
+use crate::InterpolatedStringExpression;
+use crate::IssetExpression;
+use crate::ListEntry;
+use crate::ListExpression;
+use crate::LiteralStringPart;
+use crate::MagicConstantExpression;
+use crate::MatchArm;
+use crate::MatchExpression;
+use crate::MethodCallExpression;
+use crate::MethodClosureCreationExpression;
+use crate::NewExpression;
+use crate::NowdocExpression;
+use crate::NullsafeMethodCallExpression;
+use crate::NullsafePropertyFetchExpression;
+use crate::ParenthesizedExpression;
+use crate::PrintExpression;
+use crate::PropertyFetchExpression;
+use crate::ReferenceExpression;
+use crate::RequireExpression;
+use crate::RequireOnceExpression;
+use crate::ReturnStatement;
+use crate::ShellExecExpression;
+use crate::ShortArrayExpression;
+use crate::ShortOpeningTagStatement;
+use crate::ShortTernaryExpression;
+use crate::Statement;
+use crate::StaticMethodCallExpression;
+use crate::StaticMethodClosureCreationExpression;
+use crate::StaticPropertyFetchExpression;
+use crate::StaticStatement;
+use crate::StaticVar;
+use crate::StaticVariableMethodCallExpression;
+use crate::StaticVariableMethodClosureCreationExpression;
+use crate::StringPart;
+use crate::SwitchStatement;
+use crate::TernaryExpression;
+use crate::ThrowExpression;
+use crate::UnsetExpression;
+use crate::Use;
+use crate::UseStatement;
+use crate::YieldExpression;
+use crate::YieldFromExpression;
+use pxp_span::Span;
+
+impl SpannedMut for AbstractConstructor {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        f(&mut self.function);
+        self.ampersand.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for AbstractMethod {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        f(&mut self.function);
+        self.ampersand.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        self.return_type.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for AnonymousClassBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.members.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for AnonymousClassExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.class);
+        self.extends.for_each_span_mut(f);
+        self.implements.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for AnonymousClassMember {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            AnonymousClassMember::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            AnonymousClassMember::TraitUsage(node) => {
+                node.for_each_span_mut(f);
+            }
+            AnonymousClassMember::Property(node) => {
+                node.for_each_span_mut(f);
+            }
+            AnonymousClassMember::VariableProperty(node) => {
+                node.for_each_span_mut(f);
+            }
+            AnonymousClassMember::ConcreteMethod(node) => {
+                node.for_each_span_mut(f);
+            }
+            AnonymousClassMember::ConcreteConstructor(node) => {
+                node.for_each_span_mut(f);
+            }
+            AnonymousClassMember::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for Argument {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Argument::Positional(node) => {
+                node.for_each_span_mut(f);
+            }
+            Argument::Named(node) => {
+                node.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ArgumentList {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_parenthesis);
+        self.arguments.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+    }
+}
+
+impl SpannedMut for ArgumentPlaceholder {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_parenthesis);
+        f(&mut self.ellipsis);
+        f(&mut self.right_parenthesis);
+    }
+}
+
+impl SpannedMut for ArithmeticOperationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ArithmeticOperationExpression::Addition { left, plus, right } => {
+                left.for_each_span_mut(f);
+                f(plus);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Subtraction { left, minus, right } => {
+                left.for_each_span_mut(f);
+                f(minus);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Multiplication {
+                left,
+                asterisk,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(asterisk);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Division { left, slash, right } => {
+                left.for_each_span_mut(f);
+                f(slash);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Modulo {
+                left,
+                percent,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(percent);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Exponentiation { left, pow, right } => {
+                left.for_each_span_mut(f);
+                f(pow);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Negative { minus, right } => {
+                f(minus);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::Positive { plus, right } => {
+                f(plus);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::PreIncrement { increment, right } => {
+                f(increment);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::PostIncrement { left, increment } => {
+                left.for_each_span_mut(f);
+                f(increment);
+            }
+            ArithmeticOperationExpression::PreDecrement { decrement, right } => {
+                f(decrement);
+                right.for_each_span_mut(f);
+            }
+            ArithmeticOperationExpression::PostDecrement { left, decrement } => {
+                left.for_each_span_mut(f);
+                f(decrement);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ArrayExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.array);
+        f(&mut self.start);
+        self.items.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for ArrayIndexExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.array.for_each_span_mut(f);
+        f(&mut self.left_bracket);
+        self.index.for_each_span_mut(f);
+        f(&mut self.right_bracket);
+    }
+}
+
+impl SpannedMut for ArrayItem {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ArrayItem::Skipped(span) => {
+                f(span);
+            }
+            ArrayItem::Value { value } => {
+                value.for_each_span_mut(f);
+            }
+            ArrayItem::ReferencedValue { ampersand, value } => {
+                f(ampersand);
+                value.for_each_span_mut(f);
+            }
+            ArrayItem::SpreadValue { ellipsis, value } => {
+                f(ellipsis);
+                value.for_each_span_mut(f);
+            }
+            ArrayItem::KeyValue {
+                key,
+                double_arrow,
+                value,
+            } => {
+                key.for_each_span_mut(f);
+                f(double_arrow);
+                value.for_each_span_mut(f);
+            }
+            ArrayItem::ReferencedKeyValue {
+                key,
+                double_arrow,
+                ampersand,
+                value,
+            } => {
+                key.for_each_span_mut(f);
+                f(double_arrow);
+                f(ampersand);
+                value.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ArrowFunctionExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.r#static.for_each_span_mut(f);
+        self.ampersand.for_each_span_mut(f);
+        f(&mut self.r#fn);
+        self.attributes.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        self.return_type.for_each_span_mut(f);
+        f(&mut self.double_arrow);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for AssignmentOperationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            AssignmentOperationExpression::Assign {
+                left,
+                equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Addition {
+                left,
+                plus_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(plus_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Subtraction {
+                left,
+                minus_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(minus_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Multiplication {
+                left,
+                asterisk_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(asterisk_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Division {
+                left,
+                slash_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(slash_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Modulo {
+                left,
+                percent_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(percent_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Exponentiation {
+                left,
+                pow_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(pow_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Concat {
+                left,
+                dot_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(dot_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::BitwiseAnd {
+                left,
+                ampersand_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(ampersand_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::BitwiseOr {
+                left,
+                pipe_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(pipe_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::BitwiseXor {
+                left,
+                caret_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(caret_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::LeftShift {
+                left,
+                left_shift_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(left_shift_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::RightShift {
+                left,
+                right_shift_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(right_shift_equals);
+                right.for_each_span_mut(f);
+            }
+            AssignmentOperationExpression::Coalesce {
+                left,
+                coalesce_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(coalesce_equals);
+                right.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for Attribute {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        f(&mut self.end);
+        self.name.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for AttributeGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        f(&mut self.end);
+        self.members.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for BackedEnumBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.members.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for BackedEnumCase {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.case);
+        self.name.for_each_span_mut(f);
+        f(&mut self.equals);
+        self.value.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for BackedEnumMember {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            BackedEnumMember::Case(node) => {
+                node.for_each_span_mut(f);
+            }
+            BackedEnumMember::Method(node) => {
+                node.for_each_span_mut(f);
+            }
+            BackedEnumMember::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            BackedEnumMember::TraitUsage(node) => {
+                node.for_each_span_mut(f);
+            }
+            BackedEnumMember::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for BackedEnumStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.r#enum);
+        self.name.for_each_span_mut(f);
+        self.backed_type.for_each_span_mut(f);
+        self.implements.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for BackedEnumType {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            BackedEnumType::String(node0, node1) => {
+                f(node0);
+                f(node1);
+            }
+            BackedEnumType::Int(node0, node1) => {
+                f(node0);
+                f(node1);
+            }
+        }
+    }
+}
+
+impl SpannedMut for BitwiseOperationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            BitwiseOperationExpression::And { left, and, right } => {
+                left.for_each_span_mut(f);
+                f(and);
+                right.for_each_span_mut(f);
+            }
+            BitwiseOperationExpression::Or { left, or, right } => {
+                left.for_each_span_mut(f);
+                f(or);
+                right.for_each_span_mut(f);
+            }
+            BitwiseOperationExpression::Xor { left, xor, right } => {
+                left.for_each_span_mut(f);
+                f(xor);
+                right.for_each_span_mut(f);
+            }
+            BitwiseOperationExpression::LeftShift {
+                left,
+                left_shift,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(left_shift);
+                right.for_each_span_mut(f);
+            }
+            BitwiseOperationExpression::RightShift {
+                left,
+                right_shift,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(right_shift);
+                right.for_each_span_mut(f);
+            }
+            BitwiseOperationExpression::Not { not, right } => {
+                f(not);
+                right.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for BlockStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.statements.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for BoolExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for BracedNamespace {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.namespace);
+        self.name.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for BracedNamespaceBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        f(&mut self.end);
+        self.statements.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for BracedVariableVariable {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        self.variable.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for BreakStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#break);
+        self.level.for_each_span_mut(f);
+        self.ending.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Case {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+        self.condition.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for CastExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.cast);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for CatchBlock {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        f(&mut self.end);
+        self.types.for_each_span_mut(f);
+        self.var.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for CatchType {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            CatchType::Identifier { identifier } => {
+                identifier.for_each_span_mut(f);
+            }
+            CatchType::Union { identifiers } => {
+                identifiers.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ClassBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.members.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for ClassExtends {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.extends);
+        self.parent.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ClassImplements {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.implements);
+        self.interfaces.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ClassMember {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ClassMember::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::TraitUsage(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::Property(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::VariableProperty(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::AbstractMethod(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::AbstractConstructor(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::ConcreteMethod(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::ConcreteConstructor(node) => {
+                node.for_each_span_mut(f);
+            }
+            ClassMember::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ClassModifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ClassModifier::Final(span) => {
+                f(span);
+            }
+            ClassModifier::Abstract(span) => {
+                f(span);
+            }
+            ClassModifier::Readonly(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ClassModifierGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.modifiers.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ClassStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        f(&mut self.class);
+        self.name.for_each_span_mut(f);
+        self.extends.for_each_span_mut(f);
+        self.implements.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ClassishConstant {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        f(&mut self.r#const);
+        self.entries.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for CloneExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.clone);
+        self.target.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ClosingTagStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for ClosureExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.r#static.for_each_span_mut(f);
+        f(&mut self.function);
+        self.ampersand.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        self.uses.for_each_span_mut(f);
+        self.return_type.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ClosureUse {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.r#use);
+        f(&mut self.left_parenthesis);
+        self.variables.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+    }
+}
+
+impl SpannedMut for ClosureUseVariable {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.ampersand.for_each_span_mut(f);
+        self.variable.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for CoalesceExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.lhs.for_each_span_mut(f);
+        f(&mut self.double_question);
+        self.rhs.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Comment {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for CommentGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ComparisonOperationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ComparisonOperationExpression::Equal {
+                left,
+                double_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(double_equals);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::Identical {
+                left,
+                triple_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(triple_equals);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::NotEqual {
+                left,
+                bang_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(bang_equals);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::AngledNotEqual {
+                left,
+                angled_left_right,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(angled_left_right);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::NotIdentical {
+                left,
+                bang_double_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(bang_double_equals);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::LessThan {
+                left,
+                less_than,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(less_than);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::GreaterThan {
+                left,
+                greater_than,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(greater_than);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::LessThanOrEqual {
+                left,
+                less_than_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(less_than_equals);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::GreaterThanOrEqual {
+                left,
+                greater_than_equals,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(greater_than_equals);
+                right.for_each_span_mut(f);
+            }
+            ComparisonOperationExpression::Spaceship {
+                left,
+                spaceship,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(spaceship);
+                right.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ConcatExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.left.for_each_span_mut(f);
+        f(&mut self.dot);
+        self.right.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConcreteConstructor {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        f(&mut self.function);
+        self.ampersand.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConcreteMethod {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        f(&mut self.function);
+        self.ampersand.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        self.return_type.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConstantEntry {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.name.for_each_span_mut(f);
+        f(&mut self.equals);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConstantFetchExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.double_colon);
+        self.constant.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConstantModifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ConstantModifier::Final(span) => {
+                f(span);
+            }
+            ConstantModifier::Public(span) => {
+                f(span);
+            }
+            ConstantModifier::Protected(span) => {
+                f(span);
+            }
+            ConstantModifier::Private(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ConstantModifierGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.modifiers.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConstantStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.r#const);
+        self.entries.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for ConstructorParameter {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.attributes.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        self.ampersand.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.data_type.for_each_span_mut(f);
+        self.ellipsis.for_each_span_mut(f);
+        self.default.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ConstructorParameterList {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_parenthesis);
+        self.parameters.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+    }
+}
+
+impl SpannedMut for ContinueStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#continue);
+        self.level.for_each_span_mut(f);
+        self.ending.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for DeclareBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            DeclareBody::Noop { semicolon } => {
+                f(semicolon);
+            }
+            DeclareBody::Braced {
+                left_brace,
+                statements,
+                right_brace,
+            } => {
+                f(left_brace);
+                statements.for_each_span_mut(f);
+                f(right_brace);
+            }
+            DeclareBody::Expression {
+                expression,
+                semicolon,
+            } => {
+                expression.for_each_span_mut(f);
+                f(semicolon);
+            }
+            DeclareBody::Block {
+                colon,
+                statements,
+                end,
+            } => {
+                f(colon);
+                statements.for_each_span_mut(f);
+                f(&mut end.0);
+                f(&mut end.1);
+            }
+        }
+    }
+}
+
+impl SpannedMut for DeclareEntry {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.key.for_each_span_mut(f);
+        f(&mut self.equals);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for DeclareEntryGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_parenthesis);
+        f(&mut self.right_parenthesis);
+        self.entries.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for DeclareStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.declare);
+        self.entries.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for DefaultMatchArm {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.keyword);
+        f(&mut self.double_arrow);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for DieExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.die);
+        self.argument.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for DoWhileStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#do);
+        self.body.for_each_span_mut(f);
+        f(&mut self.r#while);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for DynamicIdentifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        self.expr.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for EchoOpeningTagStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for EchoStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.echo);
+        self.values.for_each_span_mut(f);
+        self.ending.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for EmptyExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.empty);
+        self.argument.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Ending {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Ending::Semicolon(span) => {
+                f(span);
+            }
+            Ending::CloseTag(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ErrorSuppressExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.at);
+        self.expr.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for EvalExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.eval);
+        self.argument.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ExitExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.exit);
+        self.argument.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Expression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Expression::Eval(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Empty(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Die(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Exit(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Isset(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Unset(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Print(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Literal(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ArithmeticOperation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::AssignmentOperation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::BitwiseOperation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ComparisonOperation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::LogicalOperation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Concat(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Instanceof(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Reference(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Parenthesized(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ErrorSuppress(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Identifier(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Variable(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Include(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::IncludeOnce(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Require(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::RequireOnce(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::FunctionCall(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::FunctionClosureCreation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::MethodCall(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::MethodClosureCreation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::NullsafeMethodCall(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::StaticMethodCall(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::StaticVariableMethodCall(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::StaticMethodClosureCreation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::StaticVariableMethodClosureCreation(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::PropertyFetch(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::NullsafePropertyFetch(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::StaticPropertyFetch(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ConstantFetch(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Static(span) => {
+                f(span);
+            }
+            Expression::Self_(span) => {
+                f(span);
+            }
+            Expression::Parent(span) => {
+                f(span);
+            }
+            Expression::ShortArray(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Array(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::List(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Closure(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ArrowFunction(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::New(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::InterpolatedString(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Heredoc(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Nowdoc(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ShellExec(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::AnonymousClass(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Bool(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ArrayIndex(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Null(span) => {
+                f(span);
+            }
+            Expression::MagicConstant(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::ShortTernary(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Ternary(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Coalesce(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Clone(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Match(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Throw(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Yield(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::YieldFrom(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Cast(node) => {
+                node.for_each_span_mut(f);
+            }
+            Expression::Noop(span) => {
+                f(span);
+            }
+            Expression::Missing(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ExpressionStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.expression.for_each_span_mut(f);
+        self.ending.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ExpressionStringPart {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.expression.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for FinallyBlock {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        f(&mut self.end);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ForStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#for);
+        f(&mut self.left_parenthesis);
+        self.iterator.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ForStatementBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ForStatementBody::Statement { statement } => {
+                statement.for_each_span_mut(f);
+            }
+            ForStatementBody::Block {
+                colon,
+                statements,
+                endfor,
+                ending,
+            } => {
+                f(colon);
+                statements.for_each_span_mut(f);
+                f(endfor);
+                ending.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ForStatementIterator {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.initializations.for_each_span_mut(f);
+        f(&mut self.initializations_semicolon);
+        self.conditions.for_each_span_mut(f);
+        f(&mut self.conditions_semicolon);
+        self.r#loop.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ForeachStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.foreach);
+        f(&mut self.left_parenthesis);
+        self.iterator.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ForeachStatementBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ForeachStatementBody::Statement { statement } => {
+                statement.for_each_span_mut(f);
+            }
+            ForeachStatementBody::Block {
+                colon,
+                statements,
+                endforeach,
+                ending,
+            } => {
+                f(colon);
+                statements.for_each_span_mut(f);
+                f(endforeach);
+                ending.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ForeachStatementIterator {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ForeachStatementIterator::Value {
+                expression,
+                r#as,
+                ampersand,
+                value,
+            } => {
+                expression.for_each_span_mut(f);
+                f(r#as);
+                ampersand.for_each_span_mut(f);
+                value.for_each_span_mut(f);
+            }
+            ForeachStatementIterator::KeyAndValue {
+                expression,
+                r#as,
+                ampersand,
+                key,
+                double_arrow,
+                value,
+            } => {
+                expression.for_each_span_mut(f);
+                f(r#as);
+                ampersand.for_each_span_mut(f);
+                key.for_each_span_mut(f);
+                f(double_arrow);
+                value.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for FullOpeningTagStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for FunctionBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_brace);
+        self.statements.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for FunctionCallExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for FunctionClosureCreationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        self.placeholder.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for FunctionParameter {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.data_type.for_each_span_mut(f);
+        self.ellipsis.for_each_span_mut(f);
+        self.default.for_each_span_mut(f);
+        self.ampersand.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for FunctionParameterList {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_parenthesis);
+        self.parameters.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+    }
+}
+
+impl SpannedMut for FunctionStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.function);
+        self.ampersand.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        self.parameters.for_each_span_mut(f);
+        self.return_type.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for GlobalStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.global);
+        self.variables.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for GotoStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.keyword);
+        self.label.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for GroupUseStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#use);
+        self.prefix.for_each_span_mut(f);
+        self.uses.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for HaltCompilerStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for HeredocExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+        self.parts.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Identifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Identifier::SimpleIdentifier(node) => {
+                node.for_each_span_mut(f);
+            }
+            Identifier::DynamicIdentifier(node) => {
+                node.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for IfStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#if);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IfStatementBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                statement.for_each_span_mut(f);
+                elseifs.for_each_span_mut(f);
+                r#else.for_each_span_mut(f);
+            }
+            IfStatementBody::Block {
+                colon,
+                statements,
+                elseifs,
+                r#else,
+                endif,
+                ending,
+            } => {
+                f(colon);
+                statements.for_each_span_mut(f);
+                elseifs.for_each_span_mut(f);
+                r#else.for_each_span_mut(f);
+                f(endif);
+                ending.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for IfStatementElse {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#else);
+        self.statement.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IfStatementElseBlock {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#else);
+        f(&mut self.colon);
+        self.statements.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IfStatementElseIf {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.elseif);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        self.statement.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IfStatementElseIfBlock {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.elseif);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        f(&mut self.colon);
+        self.statements.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IncludeExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.include);
+        self.path.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IncludeOnceExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.include_once);
+        self.path.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for InlineHtmlStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for InstanceofExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.left.for_each_span_mut(f);
+        f(&mut self.instanceof);
+        self.right.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for InterfaceBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.members.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for InterfaceExtends {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.extends);
+        self.parents.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for InterfaceMember {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            InterfaceMember::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            InterfaceMember::Constructor(node) => {
+                node.for_each_span_mut(f);
+            }
+            InterfaceMember::Method(node) => {
+                node.for_each_span_mut(f);
+            }
+            InterfaceMember::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for InterfaceStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.interface);
+        self.name.for_each_span_mut(f);
+        self.extends.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for InterpolatedStringExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+        self.parts.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for IssetExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.isset);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for LabelStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.label.for_each_span_mut(f);
+        f(&mut self.colon);
+    }
+}
+
+impl SpannedMut for Level {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Level::Literal(node) => {
+                node.for_each_span_mut(f);
+            }
+            Level::Parenthesized {
+                left_parenthesis,
+                level,
+                right_parenthesis,
+            } => {
+                f(left_parenthesis);
+                level.for_each_span_mut(f);
+                f(right_parenthesis);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ListEntry {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ListEntry::Skipped(span) => {
+                f(span);
+            }
+            ListEntry::Value { value } => {
+                value.for_each_span_mut(f);
+            }
+            ListEntry::KeyValue {
+                key,
+                double_arrow,
+                value,
+            } => {
+                key.for_each_span_mut(f);
+                f(double_arrow);
+                value.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for ListExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.list);
+        f(&mut self.start);
+        self.items.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for Literal {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Literal::String(node) => {
+                node.for_each_span_mut(f);
+            }
+            Literal::Integer(node) => {
+                node.for_each_span_mut(f);
+            }
+            Literal::Float(node) => {
+                node.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for LiteralFloat {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for LiteralInteger {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for LiteralString {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for LiteralStringPart {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for LogicalOperationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LogicalOperationExpression::And {
+                left,
+                double_ampersand,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(double_ampersand);
+                right.for_each_span_mut(f);
+            }
+            LogicalOperationExpression::Or {
+                left,
+                double_pipe,
+                right,
+            } => {
+                left.for_each_span_mut(f);
+                f(double_pipe);
+                right.for_each_span_mut(f);
+            }
+            LogicalOperationExpression::Not { bang, right } => {
+                f(bang);
+                right.for_each_span_mut(f);
+            }
+            LogicalOperationExpression::LogicalAnd { left, and, right } => {
+                left.for_each_span_mut(f);
+                f(and);
+                right.for_each_span_mut(f);
+            }
+            LogicalOperationExpression::LogicalOr { left, or, right } => {
+                left.for_each_span_mut(f);
+                f(or);
+                right.for_each_span_mut(f);
+            }
+            LogicalOperationExpression::LogicalXor { left, xor, right } => {
+                left.for_each_span_mut(f);
+                f(xor);
+                right.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for MagicConstantExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            MagicConstantExpression::Directory(span) => {
+                f(span);
+            }
+            MagicConstantExpression::File(span) => {
+                f(span);
+            }
+            MagicConstantExpression::Line(span) => {
+                f(span);
+            }
+            MagicConstantExpression::Class(span) => {
+                f(span);
+            }
+            MagicConstantExpression::Function(span) => {
+                f(span);
+            }
+            MagicConstantExpression::Method(span) => {
+                f(span);
+            }
+            MagicConstantExpression::Namespace(span) => {
+                f(span);
+            }
+            MagicConstantExpression::Trait(span) => {
+                f(span);
+            }
+            MagicConstantExpression::CompilerHaltOffset(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for MatchArm {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.conditions.for_each_span_mut(f);
+        f(&mut self.arrow);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for MatchExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.keyword);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        f(&mut self.left_brace);
+        self.default.for_each_span_mut(f);
+        self.arms.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for MethodBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_brace);
+        self.statements.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for MethodCallExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.arrow);
+        self.method.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for MethodClosureCreationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.arrow);
+        self.method.for_each_span_mut(f);
+        self.placeholder.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for MethodModifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            MethodModifier::Final(span) => {
+                f(span);
+            }
+            MethodModifier::Static(span) => {
+                f(span);
+            }
+            MethodModifier::Abstract(span) => {
+                f(span);
+            }
+            MethodModifier::Public(span) => {
+                f(span);
+            }
+            MethodModifier::Protected(span) => {
+                f(span);
+            }
+            MethodModifier::Private(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for MethodModifierGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.modifiers.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for NamedArgument {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.name.for_each_span_mut(f);
+        f(&mut self.colon);
+        self.ellipsis.for_each_span_mut(f);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for NamespaceStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            NamespaceStatement::Unbraced(node) => {
+                node.for_each_span_mut(f);
+            }
+            NamespaceStatement::Braced(node) => {
+                node.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for NewExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.new);
+        self.target.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for NowdocExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for NullsafeMethodCallExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.question_arrow);
+        self.method.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for NullsafePropertyFetchExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.question_arrow);
+        self.property.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ParenthesizedExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        self.expr.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for PositionalArgument {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.ellipsis.for_each_span_mut(f);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for PrintExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.print);
+        self.value.for_each_span_mut(f);
+        self.argument.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for PromotedPropertyModifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            PromotedPropertyModifier::Public(span) => {
+                f(span);
+            }
+            PromotedPropertyModifier::Protected(span) => {
+                f(span);
+            }
+            PromotedPropertyModifier::Private(span) => {
+                f(span);
+            }
+            PromotedPropertyModifier::Readonly(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for PromotedPropertyModifierGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.modifiers.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Property {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.modifiers.for_each_span_mut(f);
+        self.r#type.for_each_span_mut(f);
+        self.entries.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for PropertyEntry {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            PropertyEntry::Uninitialized { variable } => {
+                variable.for_each_span_mut(f);
+            }
+            PropertyEntry::Initialized {
+                variable,
+                equals,
+                value,
+            } => {
+                variable.for_each_span_mut(f);
+                f(equals);
+                value.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for PropertyFetchExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.arrow);
+        self.property.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for PropertyModifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            PropertyModifier::Public(span) => {
+                f(span);
+            }
+            PropertyModifier::Protected(span) => {
+                f(span);
+            }
+            PropertyModifier::Private(span) => {
+                f(span);
+            }
+            PropertyModifier::Static(span) => {
+                f(span);
+            }
+            PropertyModifier::Readonly(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for PropertyModifierGroup {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.modifiers.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ReferenceExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.ampersand);
+        self.right.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for RequireExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.require);
+        self.path.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for RequireOnceExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.require_once);
+        self.path.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ReturnStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#return);
+        self.value.for_each_span_mut(f);
+        self.ending.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ReturnType {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.colon);
+        self.data_type.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ShellExecExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+        self.parts.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ShortArrayExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        self.items.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for ShortOpeningTagStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for ShortTernaryExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.condition.for_each_span_mut(f);
+        f(&mut self.question_colon);
+        self.r#else.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for SimpleIdentifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for SimpleVariable {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+    }
+}
+
+impl SpannedMut for SingleArgument {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.left_parenthesis);
+        self.argument.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+    }
+}
+
+impl SpannedMut for Statement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Statement::FullOpeningTag(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::ShortOpeningTag(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::EchoOpeningTag(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::ClosingTag(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::InlineHtml(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Label(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Goto(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::HaltCompiler(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Static(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::DoWhile(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::While(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::For(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Foreach(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Break(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Continue(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Function(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Class(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Trait(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Interface(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::If(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Switch(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Echo(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Expression(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Return(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Namespace(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Use(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::GroupUse(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Comment(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Try(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::UnitEnum(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::BackedEnum(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Block(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Global(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Declare(node) => {
+                node.for_each_span_mut(f);
+            }
+            Statement::Noop(span) => {
+                f(span);
+            }
+            Statement::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for StaticMethodCallExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.double_colon);
+        self.method.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for StaticMethodClosureCreationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.double_colon);
+        self.method.for_each_span_mut(f);
+        self.placeholder.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for StaticPropertyFetchExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.double_colon);
+        self.property.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for StaticStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#static);
+        self.vars.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for StaticVar {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.var.for_each_span_mut(f);
+        self.default.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for StaticVariableMethodCallExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.double_colon);
+        self.method.for_each_span_mut(f);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for StaticVariableMethodClosureCreationExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.target.for_each_span_mut(f);
+        f(&mut self.double_colon);
+        self.method.for_each_span_mut(f);
+        self.placeholder.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for StringPart {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            StringPart::Literal(node) => {
+                node.for_each_span_mut(f);
+            }
+            StringPart::Expression(node) => {
+                node.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for SwitchStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.switch);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        self.cases.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for TernaryExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.condition.for_each_span_mut(f);
+        f(&mut self.question);
+        self.then.for_each_span_mut(f);
+        f(&mut self.colon);
+        self.r#else.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for ThrowExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.throw);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for TraitBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.members.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for TraitMember {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TraitMember::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::TraitUsage(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::Property(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::VariableProperty(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::AbstractMethod(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::AbstractConstructor(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::ConcreteMethod(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::ConcreteConstructor(node) => {
+                node.for_each_span_mut(f);
+            }
+            TraitMember::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for TraitStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        f(&mut self.r#trait);
+        self.name.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for TraitUsage {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#use);
+        self.traits.for_each_span_mut(f);
+        self.adaptations.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for TraitUsageAdaptation {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                visibility,
+            } => {
+                r#trait.for_each_span_mut(f);
+                method.for_each_span_mut(f);
+                alias.for_each_span_mut(f);
+                visibility.for_each_span_mut(f);
+            }
+            TraitUsageAdaptation::Visibility {
+                r#trait,
+                method,
+                visibility,
+            } => {
+                r#trait.for_each_span_mut(f);
+                method.for_each_span_mut(f);
+                visibility.for_each_span_mut(f);
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                r#trait.for_each_span_mut(f);
+                method.for_each_span_mut(f);
+                insteadof.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for TryStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        f(&mut self.end);
+        self.body.for_each_span_mut(f);
+        self.catches.for_each_span_mut(f);
+        self.finally.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Type {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Type::Named(span, _) => {
+                f(span);
+            }
+            Type::Nullable(node0, node1) => {
+                f(node0);
+                node1.for_each_span_mut(f);
+            }
+            Type::Union(node) => {
+                node.for_each_span_mut(f);
+            }
+            Type::Intersection(node) => {
+                node.for_each_span_mut(f);
+            }
+            Type::Void(span) => {
+                f(span);
+            }
+            Type::Null(span) => {
+                f(span);
+            }
+            Type::True(span) => {
+                f(span);
+            }
+            Type::False(span) => {
+                f(span);
+            }
+            Type::Never(span) => {
+                f(span);
+            }
+            Type::Float(span) => {
+                f(span);
+            }
+            Type::Boolean(span) => {
+                f(span);
+            }
+            Type::Integer(span) => {
+                f(span);
+            }
+            Type::String(span) => {
+                f(span);
+            }
+            Type::Array(span) => {
+                f(span);
+            }
+            Type::Object(span) => {
+                f(span);
+            }
+            Type::Mixed(span) => {
+                f(span);
+            }
+            Type::Callable(span) => {
+                f(span);
+            }
+            Type::Iterable(span) => {
+                f(span);
+            }
+            Type::StaticReference(span) => {
+                f(span);
+            }
+            Type::SelfReference(span) => {
+                f(span);
+            }
+            Type::ParentReference(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for UnbracedNamespace {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.start);
+        self.name.for_each_span_mut(f);
+        f(&mut self.end);
+        self.statements.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for UnitEnumBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.left_brace);
+        self.members.for_each_span_mut(f);
+        self.comments.for_each_span_mut(f);
+        f(&mut self.right_brace);
+    }
+}
+
+impl SpannedMut for UnitEnumCase {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.start);
+        self.name.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for UnitEnumMember {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            UnitEnumMember::Case(node) => {
+                node.for_each_span_mut(f);
+            }
+            UnitEnumMember::Method(node) => {
+                node.for_each_span_mut(f);
+            }
+            UnitEnumMember::Constant(node) => {
+                node.for_each_span_mut(f);
+            }
+            UnitEnumMember::TraitUsage(node) => {
+                node.for_each_span_mut(f);
+            }
+            UnitEnumMember::Error(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for UnitEnumStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        f(&mut self.r#enum);
+        self.name.for_each_span_mut(f);
+        self.implements.for_each_span_mut(f);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for UnsetExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.unset);
+        self.arguments.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for Use {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+        self.name.for_each_span_mut(f);
+        self.alias.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for UseStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#use);
+        self.uses.for_each_span_mut(f);
+        f(&mut self.semicolon);
+    }
+}
+
+impl SpannedMut for Variable {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Variable::SimpleVariable(node) => {
+                node.for_each_span_mut(f);
+            }
+            Variable::VariableVariable(node) => {
+                node.for_each_span_mut(f);
+            }
+            Variable::BracedVariableVariable(node) => {
+                node.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for VariableProperty {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.comments.for_each_span_mut(f);
+        self.attributes.for_each_span_mut(f);
+        self.r#type.for_each_span_mut(f);
+        self.entries.for_each_span_mut(f);
+        f(&mut self.end);
+    }
+}
+
+impl SpannedMut for VariableVariable {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.span);
+        self.variable.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for VisibilityModifier {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            VisibilityModifier::Public(span) => {
+                f(span);
+            }
+            VisibilityModifier::Protected(span) => {
+                f(span);
+            }
+            VisibilityModifier::Private(span) => {
+                f(span);
+            }
+        }
+    }
+}
+
+impl SpannedMut for WhileStatement {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#while);
+        f(&mut self.left_parenthesis);
+        self.condition.for_each_span_mut(f);
+        f(&mut self.right_parenthesis);
+        self.body.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for WhileStatementBody {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            WhileStatementBody::Statement { statement } => {
+                statement.for_each_span_mut(f);
+            }
+            WhileStatementBody::Block {
+                colon,
+                statements,
+                endwhile,
+                ending,
+            } => {
+                f(colon);
+                statements.for_each_span_mut(f);
+                f(endwhile);
+                ending.for_each_span_mut(f);
+            }
+        }
+    }
+}
+
+impl SpannedMut for YieldExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#yield);
+        self.key.for_each_span_mut(f);
+        self.value.for_each_span_mut(f);
+    }
+}
+
+impl SpannedMut for YieldFromExpression {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(&mut self.r#yield);
+        f(&mut self.from);
+        self.value.for_each_span_mut(f);
+    }
+}
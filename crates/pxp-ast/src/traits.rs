@@ -1,4 +1,5 @@
 use crate::attributes::AttributeGroup;
+use crate::comments::CommentGroup;
 use crate::constant::ClassishConstant;
 use crate::functions::AbstractConstructor;
 use crate::functions::AbstractMethod;
@@ -6,13 +7,14 @@ use crate::functions::ConcreteConstructor;
 use crate::functions::ConcreteMethod;
 use crate::identifiers::SimpleIdentifier;
 use crate::modifiers::VisibilityModifier;
-use crate::node::Node;
 use crate::properties::Property;
 use crate::properties::VariableProperty;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TraitMember {
     Constant(ClassishConstant),
     TraitUsage(TraitUsage),
@@ -22,71 +24,85 @@ pub enum TraitMember {
     AbstractConstructor(AbstractConstructor),
     ConcreteMethod(ConcreteMethod),
     ConcreteConstructor(ConcreteConstructor),
+    // Tokens that were skipped over because they couldn't be parsed.
+    Error(Span),
 }
 
-impl Node for TraitMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for TraitMember {
+    fn span(&self) -> Span {
         match self {
-            TraitMember::Constant(constant) => vec![constant],
-            TraitMember::TraitUsage(usage) => vec![usage],
-            TraitMember::Property(property) => vec![property],
-            TraitMember::VariableProperty(property) => vec![property],
-            TraitMember::AbstractMethod(method) => vec![method],
-            TraitMember::AbstractConstructor(constructor) => vec![constructor],
-            TraitMember::ConcreteMethod(method) => vec![method],
-            TraitMember::ConcreteConstructor(constructor) => vec![constructor],
+            TraitMember::Constant(member) => member.span(),
+            TraitMember::TraitUsage(member) => member.span(),
+            TraitMember::Property(member) => member.span(),
+            TraitMember::VariableProperty(member) => member.span(),
+            TraitMember::AbstractMethod(member) => member.span(),
+            TraitMember::AbstractConstructor(member) => member.span(),
+            TraitMember::ConcreteMethod(member) => member.span(),
+            TraitMember::ConcreteConstructor(member) => member.span(),
+            TraitMember::Error(span) => *span,
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraitBody {
     pub left_brace: Span,
     pub members: Vec<TraitMember>,
+    pub comments: CommentGroup,
     pub right_brace: Span,
 }
 
-impl Node for TraitBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.members
-            .iter_mut()
-            .map(|member| member as &mut dyn Node)
-            .collect()
+impl Spanned for TraitBody {
+    fn span(&self) -> Span {
+        self.left_brace.join(self.right_brace)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraitStatement {
+    pub comments: CommentGroup,
     pub r#trait: Span,
     pub name: SimpleIdentifier,
     pub attributes: Vec<AttributeGroup>,
     pub body: TraitBody,
 }
 
-impl Node for TraitStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.body]
+impl Spanned for TraitStatement {
+    fn span(&self) -> Span {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.r#trait, Spanned::span);
+
+        start.join(self.body.span())
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraitUsage {
     pub r#use: Span,
     pub traits: Vec<SimpleIdentifier>,
     pub adaptations: Vec<TraitUsageAdaptation>,
 }
 
-impl Node for TraitUsage {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.traits.iter_mut().map(|t| t as &mut dyn Node).collect()
+impl Spanned for TraitUsage {
+    fn span(&self) -> Span {
+        let end = self
+            .adaptations
+            .last()
+            .map(Spanned::span)
+            .or_else(|| span_of(&self.traits))
+            .unwrap_or(self.r#use);
+
+        self.r#use.join(end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TraitUsageAdaptation {
     Alias {
         r#trait: Option<SimpleIdentifier>,
@@ -105,3 +121,30 @@ pub enum TraitUsageAdaptation {
         insteadof: Vec<SimpleIdentifier>,
     },
 }
+
+impl Spanned for TraitUsageAdaptation {
+    fn span(&self) -> Span {
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                ..
+            } => r#trait.as_ref().unwrap_or(method).span.join(alias.span),
+            TraitUsageAdaptation::Visibility {
+                r#trait,
+                method,
+                visibility,
+            } => r#trait.as_ref().unwrap_or(method).span.join(visibility.span()),
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                let start = r#trait.as_ref().unwrap_or(method).span;
+
+                start.join(span_of(insteadof).unwrap_or(method.span))
+            }
+        }
+    }
+}
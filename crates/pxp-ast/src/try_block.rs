@@ -1,30 +1,29 @@
 use crate::identifiers::SimpleIdentifier;
-use crate::node::Node;
 use crate::Block;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
 use pxp_span::Span;
 
 use super::variables::SimpleVariable;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CatchType {
     Identifier { identifier: SimpleIdentifier },
     Union { identifiers: Vec<SimpleIdentifier> },
 }
 
-impl Node for CatchType {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for CatchType {
+    fn span(&self) -> Span {
         match self {
-            CatchType::Identifier { identifier } => vec![identifier],
-            CatchType::Union { identifiers } => {
-                identifiers.iter_mut().map(|i| i as &mut dyn Node).collect()
-            }
+            CatchType::Identifier { identifier } => identifier.span,
+            CatchType::Union { identifiers } => span_of(identifiers).unwrap_or_default(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TryStatement {
     pub start: Span,
     pub end: Span,
@@ -33,21 +32,14 @@ pub struct TryStatement {
     pub finally: Option<FinallyBlock>,
 }
 
-impl Node for TryStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.body];
-        for catch in &mut self.catches {
-            children.push(catch);
-        }
-        if let Some(finally) = &mut self.finally {
-            children.push(finally);
-        }
-        children
+impl Spanned for TryStatement {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CatchBlock {
     pub start: Span,
     pub end: Span,
@@ -56,27 +48,22 @@ pub struct CatchBlock {
     pub body: Block,
 }
 
-impl Node for CatchBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children = vec![&mut self.types as &mut dyn Node];
-        if let Some(var) = &mut self.var {
-            children.push(var as &mut dyn Node);
-        }
-        children.push(&mut self.body as &mut dyn Node);
-        children
+impl Spanned for CatchBlock {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FinallyBlock {
     pub start: Span,
     pub end: Span,
     pub body: Block,
 }
 
-impl Node for FinallyBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.body as &mut dyn Node]
+impl Spanned for FinallyBlock {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
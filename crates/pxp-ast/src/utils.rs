@@ -2,10 +2,13 @@ use std::slice::Iter;
 use std::slice::IterMut;
 
 use crate::node::Node;
+use crate::spanned::span_of;
+use crate::spanned::Spanned;
+use crate::spanned::SpannedMut;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommaSeparated<T> {
     pub inner: Vec<T>,
     pub commas: Vec<Span>, // `,`
@@ -22,8 +25,35 @@ impl<T> CommaSeparated<T> {
 }
 
 impl<T: Node> Node for CommaSeparated<T> {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.inner.iter_mut().map(|x| x as &mut dyn Node).collect()
+    fn kind(&self) -> &'static str {
+        "CommaSeparated"
+    }
+
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {
+        self.inner
+            .iter_mut()
+            .map(|x| ("inner", x as &mut dyn Node))
+            .collect()
+    }
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.inner
+            .iter()
+            .map(|x| ("inner", x as &dyn Node))
+            .collect()
+    }
+}
+
+impl<T: Spanned> Spanned for CommaSeparated<T> {
+    fn span(&self) -> Span {
+        span_of(&self.inner).unwrap_or_default()
+    }
+}
+
+impl<T: SpannedMut> SpannedMut for CommaSeparated<T> {
+    fn for_each_span_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.inner.for_each_span_mut(f);
+        self.commas.for_each_span_mut(f);
     }
 }
 
@@ -35,3 +65,21 @@ impl<T> IntoIterator for CommaSeparated<T> {
         self.inner.into_iter()
     }
 }
+
+impl<'a, T> IntoIterator for &'a CommaSeparated<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut CommaSeparated<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
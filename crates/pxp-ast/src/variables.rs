@@ -1,63 +1,65 @@
 use std::fmt::Display;
 
-use crate::node::Node;
 use crate::Expression;
+use crate::spanned::Spanned;
 use pxp_bytestring::ByteString;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Variable {
     SimpleVariable(SimpleVariable),
     VariableVariable(VariableVariable),
     BracedVariableVariable(BracedVariableVariable),
 }
 
-impl Node for Variable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+impl Spanned for Variable {
+    fn span(&self) -> Span {
         match self {
-            Variable::SimpleVariable(variable) => variable.children(),
-            Variable::VariableVariable(variable) => variable.children(),
-            Variable::BracedVariableVariable(variable) => variable.children(),
+            Variable::SimpleVariable(variable) => variable.span(),
+            Variable::VariableVariable(variable) => variable.span(),
+            Variable::BracedVariableVariable(variable) => variable.span(),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleVariable {
     pub span: Span,
     pub name: ByteString,
 }
 
-impl Node for SimpleVariable {
-    //
+impl Spanned for SimpleVariable {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariableVariable {
     pub span: Span,
     pub variable: Box<Variable>,
 }
 
-impl Node for VariableVariable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.variable.as_mut()]
+impl Spanned for VariableVariable {
+    fn span(&self) -> Span {
+        self.span
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BracedVariableVariable {
     pub start: Span,
     pub variable: Box<Expression>,
     pub end: Span,
 }
 
-impl Node for BracedVariableVariable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![self.variable.as_mut()]
+impl Spanned for BracedVariableVariable {
+    fn span(&self) -> Span {
+        self.start.join(self.end)
     }
 }
 
@@ -0,0 +1,3967 @@
+// This file is generated by pxp-codegen, run `just codegen` to update it.
+
+//! Walking the syntax tree without changing it, with a method for each type of node.
+//!
+//! Each `visit_*` method walks the children of its node by calling the `walk_*` function of
+//! the same name, so a visitor only has to override the methods for the nodes it cares about.
+//! Overriding one and not calling `walk_*` leaves out everything below that node.
+//!
+//! `enter` and `leave` are called around every node that's walked, which is useful for
+//! keeping track of where the walk is. Returning `Walk::Skip` from `enter` leaves out the
+//! children of the node.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pxp_ast::visitor::{walk_method_call_expression, Visitor};
+//! use pxp_ast::{MethodCallExpression, Program};
+//!
+//! struct MethodCalls(usize);
+//!
+//! impl Visitor for MethodCalls {
+//!     fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+//!         self.0 += 1;
+//!         walk_method_call_expression(self, node);
+//!     }
+//! }
+//!
+//! fn method_calls(program: &Program) -> usize {
+//!     let mut visitor = MethodCalls(0);
+//!
+//!     for statement in program {
+//!         visitor.visit_statement(statement);
+//!     }
+//!
+//!     visitor.0
+//! }
+//! ```
+
+use crate::arguments::Argument;
+use crate::arguments::ArgumentList;
+use crate::arguments::ArgumentPlaceholder;
+use crate::arguments::NamedArgument;
+use crate::arguments::PositionalArgument;
+use crate::arguments::SingleArgument;
+use crate::attributes::Attribute;
+use crate::attributes::AttributeGroup;
+use crate::classes::AnonymousClassBody;
+use crate::classes::AnonymousClassExpression;
+use crate::classes::AnonymousClassMember;
+use crate::classes::ClassBody;
+use crate::classes::ClassExtends;
+use crate::classes::ClassImplements;
+use crate::classes::ClassMember;
+use crate::classes::ClassStatement;
+use crate::comments::Comment;
+use crate::comments::CommentGroup;
+use crate::constant::ClassishConstant;
+use crate::constant::ConstantEntry;
+use crate::constant::ConstantStatement;
+use crate::control_flow::IfStatement;
+use crate::control_flow::IfStatementBody;
+use crate::control_flow::IfStatementElse;
+use crate::control_flow::IfStatementElseBlock;
+use crate::control_flow::IfStatementElseIf;
+use crate::control_flow::IfStatementElseIfBlock;
+use crate::data_type::Type;
+use crate::declares::DeclareBody;
+use crate::declares::DeclareEntry;
+use crate::declares::DeclareEntryGroup;
+use crate::declares::DeclareStatement;
+use crate::enums::BackedEnumBody;
+use crate::enums::BackedEnumCase;
+use crate::enums::BackedEnumMember;
+use crate::enums::BackedEnumStatement;
+use crate::enums::BackedEnumType;
+use crate::enums::UnitEnumBody;
+use crate::enums::UnitEnumCase;
+use crate::enums::UnitEnumMember;
+use crate::enums::UnitEnumStatement;
+use crate::functions::AbstractConstructor;
+use crate::functions::AbstractMethod;
+use crate::functions::ArrowFunctionExpression;
+use crate::functions::ClosureExpression;
+use crate::functions::ClosureUse;
+use crate::functions::ClosureUseVariable;
+use crate::functions::ConcreteConstructor;
+use crate::functions::ConcreteMethod;
+use crate::functions::ConstructorParameter;
+use crate::functions::ConstructorParameterList;
+use crate::functions::FunctionBody;
+use crate::functions::FunctionParameter;
+use crate::functions::FunctionParameterList;
+use crate::functions::FunctionStatement;
+use crate::functions::MethodBody;
+use crate::functions::ReturnType;
+use crate::goto::GotoStatement;
+use crate::goto::LabelStatement;
+use crate::identifiers::DynamicIdentifier;
+use crate::identifiers::Identifier;
+use crate::identifiers::SimpleIdentifier;
+use crate::interfaces::InterfaceBody;
+use crate::interfaces::InterfaceExtends;
+use crate::interfaces::InterfaceMember;
+use crate::interfaces::InterfaceStatement;
+use crate::literals::Literal;
+use crate::literals::LiteralFloat;
+use crate::literals::LiteralInteger;
+use crate::literals::LiteralString;
+use crate::loops::BreakStatement;
+use crate::loops::ContinueStatement;
+use crate::loops::DoWhileStatement;
+use crate::loops::ForStatement;
+use crate::loops::ForStatementBody;
+use crate::loops::ForStatementIterator;
+use crate::loops::ForeachStatement;
+use crate::loops::ForeachStatementBody;
+use crate::loops::ForeachStatementIterator;
+use crate::loops::Level;
+use crate::loops::WhileStatement;
+use crate::loops::WhileStatementBody;
+use crate::modifiers::ClassModifier;
+use crate::modifiers::ClassModifierGroup;
+use crate::modifiers::ConstantModifier;
+use crate::modifiers::ConstantModifierGroup;
+use crate::modifiers::MethodModifier;
+use crate::modifiers::MethodModifierGroup;
+use crate::modifiers::PromotedPropertyModifier;
+use crate::modifiers::PromotedPropertyModifierGroup;
+use crate::modifiers::PropertyModifier;
+use crate::modifiers::PropertyModifierGroup;
+use crate::modifiers::VisibilityModifier;
+use crate::namespaces::BracedNamespace;
+use crate::namespaces::BracedNamespaceBody;
+use crate::namespaces::NamespaceStatement;
+use crate::namespaces::UnbracedNamespace;
+use crate::node::Node;
+use crate::operators::ArithmeticOperationExpression;
+use crate::operators::AssignmentOperationExpression;
+use crate::operators::BitwiseOperationExpression;
+use crate::operators::ComparisonOperationExpression;
+use crate::operators::LogicalOperationExpression;
+use crate::properties::Property;
+use crate::properties::PropertyEntry;
+use crate::properties::VariableProperty;
+use crate::traits::TraitBody;
+use crate::traits::TraitMember;
+use crate::traits::TraitStatement;
+use crate::traits::TraitUsage;
+use crate::traits::TraitUsageAdaptation;
+use crate::try_block::CatchBlock;
+use crate::try_block::CatchType;
+use crate::try_block::FinallyBlock;
+use crate::try_block::TryStatement;
+use crate::variables::BracedVariableVariable;
+use crate::variables::SimpleVariable;
+use crate::variables::Variable;
+use crate::variables::VariableVariable;
+use crate::ArrayExpression;
+use crate::ArrayIndexExpression;
+use crate::ArrayItem;
+use crate::BlockStatement;
+use crate::BoolExpression;
+use crate::Case;
+use crate::CastExpression;
+use crate::CloneExpression;
+use crate::ClosingTagStatement;
+use crate::CoalesceExpression;
+use crate::ConcatExpression;
+use crate::ConstantFetchExpression;
+use crate::DefaultMatchArm;
+use crate::DieExpression;
+use crate::EchoOpeningTagStatement;
+use crate::EchoStatement;
+use crate::EmptyExpression;
+use crate::Ending;
+use crate::ErrorSuppressExpression;
+use crate::EvalExpression;
+use crate::ExitExpression;
+use crate::Expression;
+use crate::ExpressionStatement;
+use crate::ExpressionStringPart;
+use crate::FullOpeningTagStatement;
+use crate::FunctionCallExpression;
+use crate::FunctionClosureCreationExpression;
+use crate::GlobalStatement;
+use crate::GroupUseStatement;
+use crate::HaltCompilerStatement;
+use crate::HeredocExpression;
+use crate::IncludeExpression;
+use crate::IncludeOnceExpression;
+use crate::InlineHtmlStatement;
+use crate::InstanceofExpression;
+use crate::InterpolatedStringExpression;
+use crate::IssetExpression;
+use crate::ListEntry;
+use crate::ListExpression;
+use crate::LiteralStringPart;
+use crate::MagicConstantExpression;
+use crate::MatchArm;
+use crate::MatchExpression;
+use crate::MethodCallExpression;
+use crate::MethodClosureCreationExpression;
+use crate::NewExpression;
+use crate::NowdocExpression;
+use crate::NullsafeMethodCallExpression;
+use crate::NullsafePropertyFetchExpression;
+use crate::ParenthesizedExpression;
+use crate::PrintExpression;
+use crate::PropertyFetchExpression;
+use crate::ReferenceExpression;
+use crate::RequireExpression;
+use crate::RequireOnceExpression;
+use crate::ReturnStatement;
+use crate::ShellExecExpression;
+use crate::ShortArrayExpression;
+use crate::ShortOpeningTagStatement;
+use crate::ShortTernaryExpression;
+use crate::Statement;
+use crate::StaticMethodCallExpression;
+use crate::StaticMethodClosureCreationExpression;
+use crate::StaticPropertyFetchExpression;
+use crate::StaticStatement;
+use crate::StaticVar;
+use crate::StaticVariableMethodCallExpression;
+use crate::StaticVariableMethodClosureCreationExpression;
+use crate::StringPart;
+use crate::SwitchStatement;
+use crate::TernaryExpression;
+use crate::ThrowExpression;
+use crate::UnsetExpression;
+use crate::Use;
+use crate::UseStatement;
+use crate::YieldExpression;
+use crate::YieldFromExpression;
+
+/// What to do once a node has been entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Walk {
+    /// Walk the children of the node.
+    Continue,
+    /// Leave out everything below the node.
+    Skip,
+}
+
+/// Walks the syntax tree, see the [module documentation](self).
+pub trait Visitor {
+    /// Called when the walk gets to a node, before any of its children.
+    fn enter(&mut self, _node: &dyn Node) -> Walk {
+        Walk::Continue
+    }
+
+    /// Called when the walk is done with a node, even if its children were skipped.
+    fn leave(&mut self, _node: &dyn Node) {}
+
+    fn visit_abstract_constructor(&mut self, node: &AbstractConstructor) {
+        walk_abstract_constructor(self, node);
+    }
+
+    fn visit_abstract_method(&mut self, node: &AbstractMethod) {
+        walk_abstract_method(self, node);
+    }
+
+    fn visit_anonymous_class_body(&mut self, node: &AnonymousClassBody) {
+        walk_anonymous_class_body(self, node);
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        walk_anonymous_class_expression(self, node);
+    }
+
+    fn visit_anonymous_class_member(&mut self, node: &AnonymousClassMember) {
+        walk_anonymous_class_member(self, node);
+    }
+
+    fn visit_argument(&mut self, node: &Argument) {
+        walk_argument(self, node);
+    }
+
+    fn visit_argument_list(&mut self, node: &ArgumentList) {
+        walk_argument_list(self, node);
+    }
+
+    fn visit_argument_placeholder(&mut self, node: &ArgumentPlaceholder) {
+        walk_argument_placeholder(self, node);
+    }
+
+    fn visit_arithmetic_operation_expression(&mut self, node: &ArithmeticOperationExpression) {
+        walk_arithmetic_operation_expression(self, node);
+    }
+
+    fn visit_array_expression(&mut self, node: &ArrayExpression) {
+        walk_array_expression(self, node);
+    }
+
+    fn visit_array_index_expression(&mut self, node: &ArrayIndexExpression) {
+        walk_array_index_expression(self, node);
+    }
+
+    fn visit_array_item(&mut self, node: &ArrayItem) {
+        walk_array_item(self, node);
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        walk_arrow_function_expression(self, node);
+    }
+
+    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
+        walk_assignment_operation_expression(self, node);
+    }
+
+    fn visit_attribute(&mut self, node: &Attribute) {
+        walk_attribute(self, node);
+    }
+
+    fn visit_attribute_group(&mut self, node: &AttributeGroup) {
+        walk_attribute_group(self, node);
+    }
+
+    fn visit_backed_enum_body(&mut self, node: &BackedEnumBody) {
+        walk_backed_enum_body(self, node);
+    }
+
+    fn visit_backed_enum_case(&mut self, node: &BackedEnumCase) {
+        walk_backed_enum_case(self, node);
+    }
+
+    fn visit_backed_enum_member(&mut self, node: &BackedEnumMember) {
+        walk_backed_enum_member(self, node);
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        walk_backed_enum_statement(self, node);
+    }
+
+    fn visit_backed_enum_type(&mut self, node: &BackedEnumType) {
+        walk_backed_enum_type(self, node);
+    }
+
+    fn visit_bitwise_operation_expression(&mut self, node: &BitwiseOperationExpression) {
+        walk_bitwise_operation_expression(self, node);
+    }
+
+    fn visit_block_statement(&mut self, node: &BlockStatement) {
+        walk_block_statement(self, node);
+    }
+
+    fn visit_bool_expression(&mut self, node: &BoolExpression) {
+        walk_bool_expression(self, node);
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        walk_braced_namespace(self, node);
+    }
+
+    fn visit_braced_namespace_body(&mut self, node: &BracedNamespaceBody) {
+        walk_braced_namespace_body(self, node);
+    }
+
+    fn visit_braced_variable_variable(&mut self, node: &BracedVariableVariable) {
+        walk_braced_variable_variable(self, node);
+    }
+
+    fn visit_break_statement(&mut self, node: &BreakStatement) {
+        walk_break_statement(self, node);
+    }
+
+    fn visit_case(&mut self, node: &Case) {
+        walk_case(self, node);
+    }
+
+    fn visit_cast_expression(&mut self, node: &CastExpression) {
+        walk_cast_expression(self, node);
+    }
+
+    fn visit_catch_block(&mut self, node: &CatchBlock) {
+        walk_catch_block(self, node);
+    }
+
+    fn visit_catch_type(&mut self, node: &CatchType) {
+        walk_catch_type(self, node);
+    }
+
+    fn visit_class_body(&mut self, node: &ClassBody) {
+        walk_class_body(self, node);
+    }
+
+    fn visit_class_extends(&mut self, node: &ClassExtends) {
+        walk_class_extends(self, node);
+    }
+
+    fn visit_class_implements(&mut self, node: &ClassImplements) {
+        walk_class_implements(self, node);
+    }
+
+    fn visit_class_member(&mut self, node: &ClassMember) {
+        walk_class_member(self, node);
+    }
+
+    fn visit_class_modifier(&mut self, node: &ClassModifier) {
+        walk_class_modifier(self, node);
+    }
+
+    fn visit_class_modifier_group(&mut self, node: &ClassModifierGroup) {
+        walk_class_modifier_group(self, node);
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        walk_class_statement(self, node);
+    }
+
+    fn visit_classish_constant(&mut self, node: &ClassishConstant) {
+        walk_classish_constant(self, node);
+    }
+
+    fn visit_clone_expression(&mut self, node: &CloneExpression) {
+        walk_clone_expression(self, node);
+    }
+
+    fn visit_closing_tag_statement(&mut self, node: &ClosingTagStatement) {
+        walk_closing_tag_statement(self, node);
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        walk_closure_expression(self, node);
+    }
+
+    fn visit_closure_use(&mut self, node: &ClosureUse) {
+        walk_closure_use(self, node);
+    }
+
+    fn visit_closure_use_variable(&mut self, node: &ClosureUseVariable) {
+        walk_closure_use_variable(self, node);
+    }
+
+    fn visit_coalesce_expression(&mut self, node: &CoalesceExpression) {
+        walk_coalesce_expression(self, node);
+    }
+
+    fn visit_comment(&mut self, node: &Comment) {
+        walk_comment(self, node);
+    }
+
+    fn visit_comment_group(&mut self, node: &CommentGroup) {
+        walk_comment_group(self, node);
+    }
+
+    fn visit_comparison_operation_expression(&mut self, node: &ComparisonOperationExpression) {
+        walk_comparison_operation_expression(self, node);
+    }
+
+    fn visit_concat_expression(&mut self, node: &ConcatExpression) {
+        walk_concat_expression(self, node);
+    }
+
+    fn visit_concrete_constructor(&mut self, node: &ConcreteConstructor) {
+        walk_concrete_constructor(self, node);
+    }
+
+    fn visit_concrete_method(&mut self, node: &ConcreteMethod) {
+        walk_concrete_method(self, node);
+    }
+
+    fn visit_constant_entry(&mut self, node: &ConstantEntry) {
+        walk_constant_entry(self, node);
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) {
+        walk_constant_fetch_expression(self, node);
+    }
+
+    fn visit_constant_modifier(&mut self, node: &ConstantModifier) {
+        walk_constant_modifier(self, node);
+    }
+
+    fn visit_constant_modifier_group(&mut self, node: &ConstantModifierGroup) {
+        walk_constant_modifier_group(self, node);
+    }
+
+    fn visit_constant_statement(&mut self, node: &ConstantStatement) {
+        walk_constant_statement(self, node);
+    }
+
+    fn visit_constructor_parameter(&mut self, node: &ConstructorParameter) {
+        walk_constructor_parameter(self, node);
+    }
+
+    fn visit_constructor_parameter_list(&mut self, node: &ConstructorParameterList) {
+        walk_constructor_parameter_list(self, node);
+    }
+
+    fn visit_continue_statement(&mut self, node: &ContinueStatement) {
+        walk_continue_statement(self, node);
+    }
+
+    fn visit_declare_body(&mut self, node: &DeclareBody) {
+        walk_declare_body(self, node);
+    }
+
+    fn visit_declare_entry(&mut self, node: &DeclareEntry) {
+        walk_declare_entry(self, node);
+    }
+
+    fn visit_declare_entry_group(&mut self, node: &DeclareEntryGroup) {
+        walk_declare_entry_group(self, node);
+    }
+
+    fn visit_declare_statement(&mut self, node: &DeclareStatement) {
+        walk_declare_statement(self, node);
+    }
+
+    fn visit_default_match_arm(&mut self, node: &DefaultMatchArm) {
+        walk_default_match_arm(self, node);
+    }
+
+    fn visit_die_expression(&mut self, node: &DieExpression) {
+        walk_die_expression(self, node);
+    }
+
+    fn visit_do_while_statement(&mut self, node: &DoWhileStatement) {
+        walk_do_while_statement(self, node);
+    }
+
+    fn visit_dynamic_identifier(&mut self, node: &DynamicIdentifier) {
+        walk_dynamic_identifier(self, node);
+    }
+
+    fn visit_echo_opening_tag_statement(&mut self, node: &EchoOpeningTagStatement) {
+        walk_echo_opening_tag_statement(self, node);
+    }
+
+    fn visit_echo_statement(&mut self, node: &EchoStatement) {
+        walk_echo_statement(self, node);
+    }
+
+    fn visit_empty_expression(&mut self, node: &EmptyExpression) {
+        walk_empty_expression(self, node);
+    }
+
+    fn visit_ending(&mut self, node: &Ending) {
+        walk_ending(self, node);
+    }
+
+    fn visit_error_suppress_expression(&mut self, node: &ErrorSuppressExpression) {
+        walk_error_suppress_expression(self, node);
+    }
+
+    fn visit_eval_expression(&mut self, node: &EvalExpression) {
+        walk_eval_expression(self, node);
+    }
+
+    fn visit_exit_expression(&mut self, node: &ExitExpression) {
+        walk_exit_expression(self, node);
+    }
+
+    fn visit_expression(&mut self, node: &Expression) {
+        walk_expression(self, node);
+    }
+
+    fn visit_expression_statement(&mut self, node: &ExpressionStatement) {
+        walk_expression_statement(self, node);
+    }
+
+    fn visit_expression_string_part(&mut self, node: &ExpressionStringPart) {
+        walk_expression_string_part(self, node);
+    }
+
+    fn visit_finally_block(&mut self, node: &FinallyBlock) {
+        walk_finally_block(self, node);
+    }
+
+    fn visit_for_statement(&mut self, node: &ForStatement) {
+        walk_for_statement(self, node);
+    }
+
+    fn visit_for_statement_body(&mut self, node: &ForStatementBody) {
+        walk_for_statement_body(self, node);
+    }
+
+    fn visit_for_statement_iterator(&mut self, node: &ForStatementIterator) {
+        walk_for_statement_iterator(self, node);
+    }
+
+    fn visit_foreach_statement(&mut self, node: &ForeachStatement) {
+        walk_foreach_statement(self, node);
+    }
+
+    fn visit_foreach_statement_body(&mut self, node: &ForeachStatementBody) {
+        walk_foreach_statement_body(self, node);
+    }
+
+    fn visit_foreach_statement_iterator(&mut self, node: &ForeachStatementIterator) {
+        walk_foreach_statement_iterator(self, node);
+    }
+
+    fn visit_full_opening_tag_statement(&mut self, node: &FullOpeningTagStatement) {
+        walk_full_opening_tag_statement(self, node);
+    }
+
+    fn visit_function_body(&mut self, node: &FunctionBody) {
+        walk_function_body(self, node);
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &FunctionClosureCreationExpression,
+    ) {
+        walk_function_closure_creation_expression(self, node);
+    }
+
+    fn visit_function_parameter(&mut self, node: &FunctionParameter) {
+        walk_function_parameter(self, node);
+    }
+
+    fn visit_function_parameter_list(&mut self, node: &FunctionParameterList) {
+        walk_function_parameter_list(self, node);
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        walk_function_statement(self, node);
+    }
+
+    fn visit_global_statement(&mut self, node: &GlobalStatement) {
+        walk_global_statement(self, node);
+    }
+
+    fn visit_goto_statement(&mut self, node: &GotoStatement) {
+        walk_goto_statement(self, node);
+    }
+
+    fn visit_group_use_statement(&mut self, node: &GroupUseStatement) {
+        walk_group_use_statement(self, node);
+    }
+
+    fn visit_halt_compiler_statement(&mut self, node: &HaltCompilerStatement) {
+        walk_halt_compiler_statement(self, node);
+    }
+
+    fn visit_heredoc_expression(&mut self, node: &HeredocExpression) {
+        walk_heredoc_expression(self, node);
+    }
+
+    fn visit_identifier(&mut self, node: &Identifier) {
+        walk_identifier(self, node);
+    }
+
+    fn visit_if_statement(&mut self, node: &IfStatement) {
+        walk_if_statement(self, node);
+    }
+
+    fn visit_if_statement_body(&mut self, node: &IfStatementBody) {
+        walk_if_statement_body(self, node);
+    }
+
+    fn visit_if_statement_else(&mut self, node: &IfStatementElse) {
+        walk_if_statement_else(self, node);
+    }
+
+    fn visit_if_statement_else_block(&mut self, node: &IfStatementElseBlock) {
+        walk_if_statement_else_block(self, node);
+    }
+
+    fn visit_if_statement_else_if(&mut self, node: &IfStatementElseIf) {
+        walk_if_statement_else_if(self, node);
+    }
+
+    fn visit_if_statement_else_if_block(&mut self, node: &IfStatementElseIfBlock) {
+        walk_if_statement_else_if_block(self, node);
+    }
+
+    fn visit_include_expression(&mut self, node: &IncludeExpression) {
+        walk_include_expression(self, node);
+    }
+
+    fn visit_include_once_expression(&mut self, node: &IncludeOnceExpression) {
+        walk_include_once_expression(self, node);
+    }
+
+    fn visit_inline_html_statement(&mut self, node: &InlineHtmlStatement) {
+        walk_inline_html_statement(self, node);
+    }
+
+    fn visit_instanceof_expression(&mut self, node: &InstanceofExpression) {
+        walk_instanceof_expression(self, node);
+    }
+
+    fn visit_interface_body(&mut self, node: &InterfaceBody) {
+        walk_interface_body(self, node);
+    }
+
+    fn visit_interface_extends(&mut self, node: &InterfaceExtends) {
+        walk_interface_extends(self, node);
+    }
+
+    fn visit_interface_member(&mut self, node: &InterfaceMember) {
+        walk_interface_member(self, node);
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        walk_interface_statement(self, node);
+    }
+
+    fn visit_interpolated_string_expression(&mut self, node: &InterpolatedStringExpression) {
+        walk_interpolated_string_expression(self, node);
+    }
+
+    fn visit_isset_expression(&mut self, node: &IssetExpression) {
+        walk_isset_expression(self, node);
+    }
+
+    fn visit_label_statement(&mut self, node: &LabelStatement) {
+        walk_label_statement(self, node);
+    }
+
+    fn visit_level(&mut self, node: &Level) {
+        walk_level(self, node);
+    }
+
+    fn visit_list_entry(&mut self, node: &ListEntry) {
+        walk_list_entry(self, node);
+    }
+
+    fn visit_list_expression(&mut self, node: &ListExpression) {
+        walk_list_expression(self, node);
+    }
+
+    fn visit_literal(&mut self, node: &Literal) {
+        walk_literal(self, node);
+    }
+
+    fn visit_literal_float(&mut self, node: &LiteralFloat) {
+        walk_literal_float(self, node);
+    }
+
+    fn visit_literal_integer(&mut self, node: &LiteralInteger) {
+        walk_literal_integer(self, node);
+    }
+
+    fn visit_literal_string(&mut self, node: &LiteralString) {
+        walk_literal_string(self, node);
+    }
+
+    fn visit_literal_string_part(&mut self, node: &LiteralStringPart) {
+        walk_literal_string_part(self, node);
+    }
+
+    fn visit_logical_operation_expression(&mut self, node: &LogicalOperationExpression) {
+        walk_logical_operation_expression(self, node);
+    }
+
+    fn visit_magic_constant_expression(&mut self, node: &MagicConstantExpression) {
+        walk_magic_constant_expression(self, node);
+    }
+
+    fn visit_match_arm(&mut self, node: &MatchArm) {
+        walk_match_arm(self, node);
+    }
+
+    fn visit_match_expression(&mut self, node: &MatchExpression) {
+        walk_match_expression(self, node);
+    }
+
+    fn visit_method_body(&mut self, node: &MethodBody) {
+        walk_method_body(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        walk_method_call_expression(self, node);
+    }
+
+    fn visit_method_closure_creation_expression(&mut self, node: &MethodClosureCreationExpression) {
+        walk_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_method_modifier(&mut self, node: &MethodModifier) {
+        walk_method_modifier(self, node);
+    }
+
+    fn visit_method_modifier_group(&mut self, node: &MethodModifierGroup) {
+        walk_method_modifier_group(self, node);
+    }
+
+    fn visit_named_argument(&mut self, node: &NamedArgument) {
+        walk_named_argument(self, node);
+    }
+
+    fn visit_namespace_statement(&mut self, node: &NamespaceStatement) {
+        walk_namespace_statement(self, node);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        walk_new_expression(self, node);
+    }
+
+    fn visit_nowdoc_expression(&mut self, node: &NowdocExpression) {
+        walk_nowdoc_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_property_fetch_expression(&mut self, node: &NullsafePropertyFetchExpression) {
+        walk_nullsafe_property_fetch_expression(self, node);
+    }
+
+    fn visit_parenthesized_expression(&mut self, node: &ParenthesizedExpression) {
+        walk_parenthesized_expression(self, node);
+    }
+
+    fn visit_positional_argument(&mut self, node: &PositionalArgument) {
+        walk_positional_argument(self, node);
+    }
+
+    fn visit_print_expression(&mut self, node: &PrintExpression) {
+        walk_print_expression(self, node);
+    }
+
+    fn visit_promoted_property_modifier(&mut self, node: &PromotedPropertyModifier) {
+        walk_promoted_property_modifier(self, node);
+    }
+
+    fn visit_promoted_property_modifier_group(&mut self, node: &PromotedPropertyModifierGroup) {
+        walk_promoted_property_modifier_group(self, node);
+    }
+
+    fn visit_property(&mut self, node: &Property) {
+        walk_property(self, node);
+    }
+
+    fn visit_property_entry(&mut self, node: &PropertyEntry) {
+        walk_property_entry(self, node);
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &PropertyFetchExpression) {
+        walk_property_fetch_expression(self, node);
+    }
+
+    fn visit_property_modifier(&mut self, node: &PropertyModifier) {
+        walk_property_modifier(self, node);
+    }
+
+    fn visit_property_modifier_group(&mut self, node: &PropertyModifierGroup) {
+        walk_property_modifier_group(self, node);
+    }
+
+    fn visit_reference_expression(&mut self, node: &ReferenceExpression) {
+        walk_reference_expression(self, node);
+    }
+
+    fn visit_require_expression(&mut self, node: &RequireExpression) {
+        walk_require_expression(self, node);
+    }
+
+    fn visit_require_once_expression(&mut self, node: &RequireOnceExpression) {
+        walk_require_once_expression(self, node);
+    }
+
+    fn visit_return_statement(&mut self, node: &ReturnStatement) {
+        walk_return_statement(self, node);
+    }
+
+    fn visit_return_type(&mut self, node: &ReturnType) {
+        walk_return_type(self, node);
+    }
+
+    fn visit_shell_exec_expression(&mut self, node: &ShellExecExpression) {
+        walk_shell_exec_expression(self, node);
+    }
+
+    fn visit_short_array_expression(&mut self, node: &ShortArrayExpression) {
+        walk_short_array_expression(self, node);
+    }
+
+    fn visit_short_opening_tag_statement(&mut self, node: &ShortOpeningTagStatement) {
+        walk_short_opening_tag_statement(self, node);
+    }
+
+    fn visit_short_ternary_expression(&mut self, node: &ShortTernaryExpression) {
+        walk_short_ternary_expression(self, node);
+    }
+
+    fn visit_simple_identifier(&mut self, node: &SimpleIdentifier) {
+        walk_simple_identifier(self, node);
+    }
+
+    fn visit_simple_variable(&mut self, node: &SimpleVariable) {
+        walk_simple_variable(self, node);
+    }
+
+    fn visit_single_argument(&mut self, node: &SingleArgument) {
+        walk_single_argument(self, node);
+    }
+
+    fn visit_statement(&mut self, node: &Statement) {
+        walk_statement(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &StaticMethodClosureCreationExpression,
+    ) {
+        walk_static_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_property_fetch_expression(&mut self, node: &StaticPropertyFetchExpression) {
+        walk_static_property_fetch_expression(self, node);
+    }
+
+    fn visit_static_statement(&mut self, node: &StaticStatement) {
+        walk_static_statement(self, node);
+    }
+
+    fn visit_static_var(&mut self, node: &StaticVar) {
+        walk_static_var(self, node);
+    }
+
+    fn visit_static_variable_method_call_expression(
+        &mut self,
+        node: &StaticVariableMethodCallExpression,
+    ) {
+        walk_static_variable_method_call_expression(self, node);
+    }
+
+    fn visit_static_variable_method_closure_creation_expression(
+        &mut self,
+        node: &StaticVariableMethodClosureCreationExpression,
+    ) {
+        walk_static_variable_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_string_part(&mut self, node: &StringPart) {
+        walk_string_part(self, node);
+    }
+
+    fn visit_switch_statement(&mut self, node: &SwitchStatement) {
+        walk_switch_statement(self, node);
+    }
+
+    fn visit_ternary_expression(&mut self, node: &TernaryExpression) {
+        walk_ternary_expression(self, node);
+    }
+
+    fn visit_throw_expression(&mut self, node: &ThrowExpression) {
+        walk_throw_expression(self, node);
+    }
+
+    fn visit_trait_body(&mut self, node: &TraitBody) {
+        walk_trait_body(self, node);
+    }
+
+    fn visit_trait_member(&mut self, node: &TraitMember) {
+        walk_trait_member(self, node);
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        walk_trait_statement(self, node);
+    }
+
+    fn visit_trait_usage(&mut self, node: &TraitUsage) {
+        walk_trait_usage(self, node);
+    }
+
+    fn visit_trait_usage_adaptation(&mut self, node: &TraitUsageAdaptation) {
+        walk_trait_usage_adaptation(self, node);
+    }
+
+    fn visit_try_statement(&mut self, node: &TryStatement) {
+        walk_try_statement(self, node);
+    }
+
+    fn visit_type(&mut self, node: &Type) {
+        walk_type(self, node);
+    }
+
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        walk_unbraced_namespace(self, node);
+    }
+
+    fn visit_unit_enum_body(&mut self, node: &UnitEnumBody) {
+        walk_unit_enum_body(self, node);
+    }
+
+    fn visit_unit_enum_case(&mut self, node: &UnitEnumCase) {
+        walk_unit_enum_case(self, node);
+    }
+
+    fn visit_unit_enum_member(&mut self, node: &UnitEnumMember) {
+        walk_unit_enum_member(self, node);
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        walk_unit_enum_statement(self, node);
+    }
+
+    fn visit_unset_expression(&mut self, node: &UnsetExpression) {
+        walk_unset_expression(self, node);
+    }
+
+    fn visit_use(&mut self, node: &Use) {
+        walk_use(self, node);
+    }
+
+    fn visit_use_statement(&mut self, node: &UseStatement) {
+        walk_use_statement(self, node);
+    }
+
+    fn visit_variable(&mut self, node: &Variable) {
+        walk_variable(self, node);
+    }
+
+    fn visit_variable_property(&mut self, node: &VariableProperty) {
+        walk_variable_property(self, node);
+    }
+
+    fn visit_variable_variable(&mut self, node: &VariableVariable) {
+        walk_variable_variable(self, node);
+    }
+
+    fn visit_visibility_modifier(&mut self, node: &VisibilityModifier) {
+        walk_visibility_modifier(self, node);
+    }
+
+    fn visit_while_statement(&mut self, node: &WhileStatement) {
+        walk_while_statement(self, node);
+    }
+
+    fn visit_while_statement_body(&mut self, node: &WhileStatementBody) {
+        walk_while_statement_body(self, node);
+    }
+
+    fn visit_yield_expression(&mut self, node: &YieldExpression) {
+        walk_yield_expression(self, node);
+    }
+
+    fn visit_yield_from_expression(&mut self, node: &YieldFromExpression) {
+        walk_yield_from_expression(self, node);
+    }
+}
+
+pub fn walk_abstract_constructor<V: Visitor + ?Sized>(visitor: &mut V, node: &AbstractConstructor) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_method_modifier_group(&node.modifiers);
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_function_parameter_list(&node.parameters);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_abstract_method<V: Visitor + ?Sized>(visitor: &mut V, node: &AbstractMethod) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_method_modifier_group(&node.modifiers);
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_function_parameter_list(&node.parameters);
+        if let Some(item) = &node.return_type {
+            visitor.visit_return_type(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_anonymous_class_body<V: Visitor + ?Sized>(visitor: &mut V, node: &AnonymousClassBody) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_anonymous_class_member(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_anonymous_class_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &AnonymousClassExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        if let Some(item) = &node.extends {
+            visitor.visit_class_extends(item);
+        }
+        if let Some(item) = &node.implements {
+            visitor.visit_class_implements(item);
+        }
+        visitor.visit_anonymous_class_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_anonymous_class_member<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &AnonymousClassMember,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            AnonymousClassMember::Constant(node) => {
+                visitor.visit_classish_constant(node);
+            }
+            AnonymousClassMember::TraitUsage(node) => {
+                visitor.visit_trait_usage(node);
+            }
+            AnonymousClassMember::Property(node) => {
+                visitor.visit_property(node);
+            }
+            AnonymousClassMember::VariableProperty(node) => {
+                visitor.visit_variable_property(node);
+            }
+            AnonymousClassMember::ConcreteMethod(node) => {
+                visitor.visit_concrete_method(node);
+            }
+            AnonymousClassMember::ConcreteConstructor(node) => {
+                visitor.visit_concrete_constructor(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_argument<V: Visitor + ?Sized>(visitor: &mut V, node: &Argument) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Argument::Positional(node) => {
+                visitor.visit_positional_argument(node);
+            }
+            Argument::Named(node) => {
+                visitor.visit_named_argument(node);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_argument_list<V: Visitor + ?Sized>(visitor: &mut V, node: &ArgumentList) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.arguments {
+            visitor.visit_argument(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_argument_placeholder<V: Visitor + ?Sized>(visitor: &mut V, node: &ArgumentPlaceholder) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_arithmetic_operation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArithmeticOperationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ArithmeticOperationExpression::Addition { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Subtraction { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Multiplication { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Division { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Modulo { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Negative { right, .. } => {
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::Positive { right, .. } => {
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::PreIncrement { right, .. } => {
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::PostIncrement { left, .. } => {
+                visitor.visit_expression(left);
+            }
+            ArithmeticOperationExpression::PreDecrement { right, .. } => {
+                visitor.visit_expression(right);
+            }
+            ArithmeticOperationExpression::PostDecrement { left, .. } => {
+                visitor.visit_expression(left);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_array_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ArrayExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.items {
+            visitor.visit_array_item(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_array_index_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayIndexExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.array);
+        if let Some(item) = &node.index {
+            visitor.visit_expression(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_array_item<V: Visitor + ?Sized>(visitor: &mut V, node: &ArrayItem) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ArrayItem::Value { value } => {
+                visitor.visit_expression(value);
+            }
+            ArrayItem::ReferencedValue { value, .. } => {
+                visitor.visit_expression(value);
+            }
+            ArrayItem::SpreadValue { value, .. } => {
+                visitor.visit_expression(value);
+            }
+            ArrayItem::KeyValue { key, value, .. } => {
+                visitor.visit_expression(key);
+                visitor.visit_expression(value);
+            }
+            ArrayItem::ReferencedKeyValue { key, value, .. } => {
+                visitor.visit_expression(key);
+                visitor.visit_expression(value);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_arrow_function_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrowFunctionExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_function_parameter_list(&node.parameters);
+        if let Some(item) = &node.return_type {
+            visitor.visit_return_type(item);
+        }
+        visitor.visit_expression(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_assignment_operation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &AssignmentOperationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            AssignmentOperationExpression::Assign { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Addition { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Subtraction { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Multiplication { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Division { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Modulo { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Exponentiation { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Concat { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::BitwiseAnd { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::BitwiseOr { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::BitwiseXor { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::LeftShift { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::RightShift { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            AssignmentOperationExpression::Coalesce { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_attribute<V: Visitor + ?Sized>(visitor: &mut V, node: &Attribute) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.name);
+        if let Some(item) = &node.arguments {
+            visitor.visit_argument_list(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_attribute_group<V: Visitor + ?Sized>(visitor: &mut V, node: &AttributeGroup) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_attribute(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_backed_enum_body<V: Visitor + ?Sized>(visitor: &mut V, node: &BackedEnumBody) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_backed_enum_member(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_backed_enum_case<V: Visitor + ?Sized>(visitor: &mut V, node: &BackedEnumCase) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_backed_enum_member<V: Visitor + ?Sized>(visitor: &mut V, node: &BackedEnumMember) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            BackedEnumMember::Case(node) => {
+                visitor.visit_backed_enum_case(node);
+            }
+            BackedEnumMember::Method(node) => {
+                visitor.visit_concrete_method(node);
+            }
+            BackedEnumMember::Constant(node) => {
+                visitor.visit_classish_constant(node);
+            }
+            BackedEnumMember::TraitUsage(node) => {
+                visitor.visit_trait_usage(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_backed_enum_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &BackedEnumStatement,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_backed_enum_type(&node.backed_type);
+        for item in &node.implements {
+            visitor.visit_simple_identifier(item);
+        }
+        visitor.visit_backed_enum_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_backed_enum_type<V: Visitor + ?Sized>(visitor: &mut V, node: &BackedEnumType) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_bitwise_operation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &BitwiseOperationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            BitwiseOperationExpression::And { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            BitwiseOperationExpression::Or { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            BitwiseOperationExpression::Xor { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            BitwiseOperationExpression::LeftShift { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            BitwiseOperationExpression::RightShift { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            BitwiseOperationExpression::Not { right, .. } => {
+                visitor.visit_expression(right);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_block_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &BlockStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_bool_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &BoolExpression) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_braced_namespace<V: Visitor + ?Sized>(visitor: &mut V, node: &BracedNamespace) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.name {
+            visitor.visit_simple_identifier(item);
+        }
+        visitor.visit_braced_namespace_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_braced_namespace_body<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &BracedNamespaceBody,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_braced_variable_variable<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &BracedVariableVariable,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.variable);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_break_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &BreakStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.level {
+            visitor.visit_level(item);
+        }
+        visitor.visit_ending(&node.ending);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_case<V: Visitor + ?Sized>(visitor: &mut V, node: &Case) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.condition {
+            visitor.visit_expression(item);
+        }
+        for item in &node.body {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_cast_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &CastExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_catch_block<V: Visitor + ?Sized>(visitor: &mut V, node: &CatchBlock) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_catch_type(&node.types);
+        if let Some(item) = &node.var {
+            visitor.visit_simple_variable(item);
+        }
+        for item in &node.body {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_catch_type<V: Visitor + ?Sized>(visitor: &mut V, node: &CatchType) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            CatchType::Identifier { identifier } => {
+                visitor.visit_simple_identifier(identifier);
+            }
+            CatchType::Union { identifiers } => {
+                for item in identifiers {
+                    visitor.visit_simple_identifier(item);
+                }
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_body<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassBody) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_class_member(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_extends<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassExtends) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.parent);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_implements<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassImplements) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.interfaces {
+            visitor.visit_simple_identifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_member<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassMember) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ClassMember::Constant(node) => {
+                visitor.visit_classish_constant(node);
+            }
+            ClassMember::TraitUsage(node) => {
+                visitor.visit_trait_usage(node);
+            }
+            ClassMember::Property(node) => {
+                visitor.visit_property(node);
+            }
+            ClassMember::VariableProperty(node) => {
+                visitor.visit_variable_property(node);
+            }
+            ClassMember::AbstractMethod(node) => {
+                visitor.visit_abstract_method(node);
+            }
+            ClassMember::AbstractConstructor(node) => {
+                visitor.visit_abstract_constructor(node);
+            }
+            ClassMember::ConcreteMethod(node) => {
+                visitor.visit_concrete_method(node);
+            }
+            ClassMember::ConcreteConstructor(node) => {
+                visitor.visit_concrete_constructor(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_modifier<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassModifier) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_modifier_group<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassModifierGroup) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.modifiers {
+            visitor.visit_class_modifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_class_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_class_modifier_group(&node.modifiers);
+        visitor.visit_simple_identifier(&node.name);
+        if let Some(item) = &node.extends {
+            visitor.visit_class_extends(item);
+        }
+        if let Some(item) = &node.implements {
+            visitor.visit_class_implements(item);
+        }
+        visitor.visit_class_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_classish_constant<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassishConstant) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_constant_modifier_group(&node.modifiers);
+        for item in &node.entries {
+            visitor.visit_constant_entry(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_clone_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &CloneExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_closing_tag_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClosingTagStatement,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_closure_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ClosureExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_function_parameter_list(&node.parameters);
+        if let Some(item) = &node.uses {
+            visitor.visit_closure_use(item);
+        }
+        if let Some(item) = &node.return_type {
+            visitor.visit_return_type(item);
+        }
+        visitor.visit_function_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_closure_use<V: Visitor + ?Sized>(visitor: &mut V, node: &ClosureUse) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.variables {
+            visitor.visit_closure_use_variable(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_closure_use_variable<V: Visitor + ?Sized>(visitor: &mut V, node: &ClosureUseVariable) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_variable(&node.variable);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_coalesce_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &CoalesceExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.lhs);
+        visitor.visit_expression(&node.rhs);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_comment<V: Visitor + ?Sized>(visitor: &mut V, node: &Comment) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_comment_group<V: Visitor + ?Sized>(visitor: &mut V, node: &CommentGroup) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.comments {
+            visitor.visit_comment(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_comparison_operation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ComparisonOperationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ComparisonOperationExpression::Equal { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::Identical { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::NotEqual { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::AngledNotEqual { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::NotIdentical { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::LessThan { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::GreaterThan { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::LessThanOrEqual { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            ComparisonOperationExpression::Spaceship { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_concat_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ConcatExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.left);
+        visitor.visit_expression(&node.right);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_concrete_constructor<V: Visitor + ?Sized>(visitor: &mut V, node: &ConcreteConstructor) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_method_modifier_group(&node.modifiers);
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_constructor_parameter_list(&node.parameters);
+        visitor.visit_method_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_concrete_method<V: Visitor + ?Sized>(visitor: &mut V, node: &ConcreteMethod) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_method_modifier_group(&node.modifiers);
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_function_parameter_list(&node.parameters);
+        if let Some(item) = &node.return_type {
+            visitor.visit_return_type(item);
+        }
+        visitor.visit_method_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_constant_entry<V: Visitor + ?Sized>(visitor: &mut V, node: &ConstantEntry) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_constant_fetch_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstantFetchExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_identifier(&node.constant);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_constant_modifier<V: Visitor + ?Sized>(visitor: &mut V, node: &ConstantModifier) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_constant_modifier_group<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstantModifierGroup,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.modifiers {
+            visitor.visit_constant_modifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_constant_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ConstantStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.entries {
+            visitor.visit_constant_entry(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_constructor_parameter<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstructorParameter,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_variable(&node.name);
+        if let Some(item) = &node.data_type {
+            visitor.visit_type(item);
+        }
+        if let Some(item) = &node.default {
+            visitor.visit_expression(item);
+        }
+        visitor.visit_promoted_property_modifier_group(&node.modifiers);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_constructor_parameter_list<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstructorParameterList,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.parameters {
+            visitor.visit_constructor_parameter(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_continue_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ContinueStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.level {
+            visitor.visit_level(item);
+        }
+        visitor.visit_ending(&node.ending);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_declare_body<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclareBody) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            DeclareBody::Braced { statements, .. } => {
+                for item in statements {
+                    visitor.visit_statement(item);
+                }
+            }
+            DeclareBody::Expression { expression, .. } => {
+                visitor.visit_expression(expression);
+            }
+            DeclareBody::Block { statements, .. } => {
+                for item in statements {
+                    visitor.visit_statement(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_declare_entry<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclareEntry) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.key);
+        visitor.visit_literal(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_declare_entry_group<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclareEntryGroup) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.entries {
+            visitor.visit_declare_entry(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_declare_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclareStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_declare_entry_group(&node.entries);
+        visitor.visit_declare_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_default_match_arm<V: Visitor + ?Sized>(visitor: &mut V, node: &DefaultMatchArm) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_die_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &DieExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.argument {
+            visitor.visit_single_argument(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_do_while_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &DoWhileStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_statement(&node.body);
+        visitor.visit_expression(&node.condition);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_dynamic_identifier<V: Visitor + ?Sized>(visitor: &mut V, node: &DynamicIdentifier) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.expr);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_echo_opening_tag_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &EchoOpeningTagStatement,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_echo_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &EchoStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.values {
+            visitor.visit_expression(item);
+        }
+        visitor.visit_ending(&node.ending);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_empty_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &EmptyExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_single_argument(&node.argument);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_ending<V: Visitor + ?Sized>(visitor: &mut V, node: &Ending) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_error_suppress_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ErrorSuppressExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.expr);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_eval_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &EvalExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_single_argument(&node.argument);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_exit_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ExitExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.argument {
+            visitor.visit_single_argument(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &Expression) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Expression::Eval(node) => {
+                visitor.visit_eval_expression(node);
+            }
+            Expression::Empty(node) => {
+                visitor.visit_empty_expression(node);
+            }
+            Expression::Die(node) => {
+                visitor.visit_die_expression(node);
+            }
+            Expression::Exit(node) => {
+                visitor.visit_exit_expression(node);
+            }
+            Expression::Isset(node) => {
+                visitor.visit_isset_expression(node);
+            }
+            Expression::Unset(node) => {
+                visitor.visit_unset_expression(node);
+            }
+            Expression::Print(node) => {
+                visitor.visit_print_expression(node);
+            }
+            Expression::Literal(node) => {
+                visitor.visit_literal(node);
+            }
+            Expression::ArithmeticOperation(node) => {
+                visitor.visit_arithmetic_operation_expression(node);
+            }
+            Expression::AssignmentOperation(node) => {
+                visitor.visit_assignment_operation_expression(node);
+            }
+            Expression::BitwiseOperation(node) => {
+                visitor.visit_bitwise_operation_expression(node);
+            }
+            Expression::ComparisonOperation(node) => {
+                visitor.visit_comparison_operation_expression(node);
+            }
+            Expression::LogicalOperation(node) => {
+                visitor.visit_logical_operation_expression(node);
+            }
+            Expression::Concat(node) => {
+                visitor.visit_concat_expression(node);
+            }
+            Expression::Instanceof(node) => {
+                visitor.visit_instanceof_expression(node);
+            }
+            Expression::Reference(node) => {
+                visitor.visit_reference_expression(node);
+            }
+            Expression::Parenthesized(node) => {
+                visitor.visit_parenthesized_expression(node);
+            }
+            Expression::ErrorSuppress(node) => {
+                visitor.visit_error_suppress_expression(node);
+            }
+            Expression::Identifier(node) => {
+                visitor.visit_identifier(node);
+            }
+            Expression::Variable(node) => {
+                visitor.visit_variable(node);
+            }
+            Expression::Include(node) => {
+                visitor.visit_include_expression(node);
+            }
+            Expression::IncludeOnce(node) => {
+                visitor.visit_include_once_expression(node);
+            }
+            Expression::Require(node) => {
+                visitor.visit_require_expression(node);
+            }
+            Expression::RequireOnce(node) => {
+                visitor.visit_require_once_expression(node);
+            }
+            Expression::FunctionCall(node) => {
+                visitor.visit_function_call_expression(node);
+            }
+            Expression::FunctionClosureCreation(node) => {
+                visitor.visit_function_closure_creation_expression(node);
+            }
+            Expression::MethodCall(node) => {
+                visitor.visit_method_call_expression(node);
+            }
+            Expression::MethodClosureCreation(node) => {
+                visitor.visit_method_closure_creation_expression(node);
+            }
+            Expression::NullsafeMethodCall(node) => {
+                visitor.visit_nullsafe_method_call_expression(node);
+            }
+            Expression::StaticMethodCall(node) => {
+                visitor.visit_static_method_call_expression(node);
+            }
+            Expression::StaticVariableMethodCall(node) => {
+                visitor.visit_static_variable_method_call_expression(node);
+            }
+            Expression::StaticMethodClosureCreation(node) => {
+                visitor.visit_static_method_closure_creation_expression(node);
+            }
+            Expression::StaticVariableMethodClosureCreation(node) => {
+                visitor.visit_static_variable_method_closure_creation_expression(node);
+            }
+            Expression::PropertyFetch(node) => {
+                visitor.visit_property_fetch_expression(node);
+            }
+            Expression::NullsafePropertyFetch(node) => {
+                visitor.visit_nullsafe_property_fetch_expression(node);
+            }
+            Expression::StaticPropertyFetch(node) => {
+                visitor.visit_static_property_fetch_expression(node);
+            }
+            Expression::ConstantFetch(node) => {
+                visitor.visit_constant_fetch_expression(node);
+            }
+            Expression::ShortArray(node) => {
+                visitor.visit_short_array_expression(node);
+            }
+            Expression::Array(node) => {
+                visitor.visit_array_expression(node);
+            }
+            Expression::List(node) => {
+                visitor.visit_list_expression(node);
+            }
+            Expression::Closure(node) => {
+                visitor.visit_closure_expression(node);
+            }
+            Expression::ArrowFunction(node) => {
+                visitor.visit_arrow_function_expression(node);
+            }
+            Expression::New(node) => {
+                visitor.visit_new_expression(node);
+            }
+            Expression::InterpolatedString(node) => {
+                visitor.visit_interpolated_string_expression(node);
+            }
+            Expression::Heredoc(node) => {
+                visitor.visit_heredoc_expression(node);
+            }
+            Expression::Nowdoc(node) => {
+                visitor.visit_nowdoc_expression(node);
+            }
+            Expression::ShellExec(node) => {
+                visitor.visit_shell_exec_expression(node);
+            }
+            Expression::AnonymousClass(node) => {
+                visitor.visit_anonymous_class_expression(node);
+            }
+            Expression::Bool(node) => {
+                visitor.visit_bool_expression(node);
+            }
+            Expression::ArrayIndex(node) => {
+                visitor.visit_array_index_expression(node);
+            }
+            Expression::MagicConstant(node) => {
+                visitor.visit_magic_constant_expression(node);
+            }
+            Expression::ShortTernary(node) => {
+                visitor.visit_short_ternary_expression(node);
+            }
+            Expression::Ternary(node) => {
+                visitor.visit_ternary_expression(node);
+            }
+            Expression::Coalesce(node) => {
+                visitor.visit_coalesce_expression(node);
+            }
+            Expression::Clone(node) => {
+                visitor.visit_clone_expression(node);
+            }
+            Expression::Match(node) => {
+                visitor.visit_match_expression(node);
+            }
+            Expression::Throw(node) => {
+                visitor.visit_throw_expression(node);
+            }
+            Expression::Yield(node) => {
+                visitor.visit_yield_expression(node);
+            }
+            Expression::YieldFrom(node) => {
+                visitor.visit_yield_from_expression(node);
+            }
+            Expression::Cast(node) => {
+                visitor.visit_cast_expression(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_expression_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ExpressionStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.expression);
+        visitor.visit_ending(&node.ending);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_expression_string_part<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ExpressionStringPart,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.expression);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_finally_block<V: Visitor + ?Sized>(visitor: &mut V, node: &FinallyBlock) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.body {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_for_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ForStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_for_statement_iterator(&node.iterator);
+        visitor.visit_for_statement_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_for_statement_body<V: Visitor + ?Sized>(visitor: &mut V, node: &ForStatementBody) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ForStatementBody::Statement { statement } => {
+                visitor.visit_statement(statement);
+            }
+            ForStatementBody::Block {
+                statements, ending, ..
+            } => {
+                for item in statements {
+                    visitor.visit_statement(item);
+                }
+                visitor.visit_ending(ending);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_for_statement_iterator<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForStatementIterator,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.initializations {
+            visitor.visit_expression(item);
+        }
+        for item in &node.conditions {
+            visitor.visit_expression(item);
+        }
+        for item in &node.r#loop {
+            visitor.visit_expression(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_foreach_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ForeachStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_foreach_statement_iterator(&node.iterator);
+        visitor.visit_foreach_statement_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_foreach_statement_body<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementBody,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ForeachStatementBody::Statement { statement } => {
+                visitor.visit_statement(statement);
+            }
+            ForeachStatementBody::Block {
+                statements, ending, ..
+            } => {
+                for item in statements {
+                    visitor.visit_statement(item);
+                }
+                visitor.visit_ending(ending);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_foreach_statement_iterator<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementIterator,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ForeachStatementIterator::Value {
+                expression, value, ..
+            } => {
+                visitor.visit_expression(expression);
+                visitor.visit_expression(value);
+            }
+            ForeachStatementIterator::KeyAndValue {
+                expression,
+                key,
+                value,
+                ..
+            } => {
+                visitor.visit_expression(expression);
+                visitor.visit_expression(key);
+                visitor.visit_expression(value);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_full_opening_tag_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &FullOpeningTagStatement,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_function_body<V: Visitor + ?Sized>(visitor: &mut V, node: &FunctionBody) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_function_call_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionCallExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_function_closure_creation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionClosureCreationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_argument_placeholder(&node.placeholder);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_function_parameter<V: Visitor + ?Sized>(visitor: &mut V, node: &FunctionParameter) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_variable(&node.name);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        if let Some(item) = &node.data_type {
+            visitor.visit_type(item);
+        }
+        if let Some(item) = &node.default {
+            visitor.visit_expression(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_function_parameter_list<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionParameterList,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.parameters {
+            visitor.visit_function_parameter(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_function_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &FunctionStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_function_parameter_list(&node.parameters);
+        if let Some(item) = &node.return_type {
+            visitor.visit_return_type(item);
+        }
+        visitor.visit_function_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_global_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &GlobalStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.variables {
+            visitor.visit_variable(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_goto_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &GotoStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_identifier(&node.label);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_group_use_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &GroupUseStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.prefix);
+        for item in &node.uses {
+            visitor.visit_use(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_halt_compiler_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &HaltCompilerStatement,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_heredoc_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &HeredocExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.parts {
+            visitor.visit_string_part(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_identifier<V: Visitor + ?Sized>(visitor: &mut V, node: &Identifier) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Identifier::SimpleIdentifier(node) => {
+                visitor.visit_simple_identifier(node);
+            }
+            Identifier::DynamicIdentifier(node) => {
+                visitor.visit_dynamic_identifier(node);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_if_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &IfStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        visitor.visit_if_statement_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_if_statement_body<V: Visitor + ?Sized>(visitor: &mut V, node: &IfStatementBody) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                visitor.visit_statement(statement);
+                for item in elseifs {
+                    visitor.visit_if_statement_else_if(item);
+                }
+                if let Some(item) = r#else {
+                    visitor.visit_if_statement_else(item);
+                }
+            }
+            IfStatementBody::Block {
+                statements,
+                elseifs,
+                r#else,
+                ending,
+                ..
+            } => {
+                for item in statements {
+                    visitor.visit_statement(item);
+                }
+                for item in elseifs {
+                    visitor.visit_if_statement_else_if_block(item);
+                }
+                if let Some(item) = r#else {
+                    visitor.visit_if_statement_else_block(item);
+                }
+                visitor.visit_ending(ending);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_if_statement_else<V: Visitor + ?Sized>(visitor: &mut V, node: &IfStatementElse) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_statement(&node.statement);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_if_statement_else_block<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementElseBlock,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_if_statement_else_if<V: Visitor + ?Sized>(visitor: &mut V, node: &IfStatementElseIf) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        visitor.visit_statement(&node.statement);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_if_statement_else_if_block<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementElseIfBlock,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_include_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &IncludeExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.path);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_include_once_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &IncludeOnceExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.path);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_inline_html_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &InlineHtmlStatement,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_instanceof_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &InstanceofExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.left);
+        visitor.visit_expression(&node.right);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_interface_body<V: Visitor + ?Sized>(visitor: &mut V, node: &InterfaceBody) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_interface_member(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_interface_extends<V: Visitor + ?Sized>(visitor: &mut V, node: &InterfaceExtends) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.parents {
+            visitor.visit_simple_identifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_interface_member<V: Visitor + ?Sized>(visitor: &mut V, node: &InterfaceMember) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            InterfaceMember::Constant(node) => {
+                visitor.visit_classish_constant(node);
+            }
+            InterfaceMember::Constructor(node) => {
+                visitor.visit_abstract_constructor(node);
+            }
+            InterfaceMember::Method(node) => {
+                visitor.visit_abstract_method(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_interface_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &InterfaceStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_simple_identifier(&node.name);
+        if let Some(item) = &node.extends {
+            visitor.visit_interface_extends(item);
+        }
+        visitor.visit_interface_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_interpolated_string_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &InterpolatedStringExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.parts {
+            visitor.visit_string_part(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_isset_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &IssetExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_label_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &LabelStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_identifier(&node.label);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_level<V: Visitor + ?Sized>(visitor: &mut V, node: &Level) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Level::Literal(node) => {
+                visitor.visit_literal_integer(node);
+            }
+            Level::Parenthesized { level, .. } => {
+                visitor.visit_level(level);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_list_entry<V: Visitor + ?Sized>(visitor: &mut V, node: &ListEntry) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            ListEntry::Value { value } => {
+                visitor.visit_expression(value);
+            }
+            ListEntry::KeyValue { key, value, .. } => {
+                visitor.visit_expression(key);
+                visitor.visit_expression(value);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_list_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ListExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.items {
+            visitor.visit_list_entry(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_literal<V: Visitor + ?Sized>(visitor: &mut V, node: &Literal) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Literal::String(node) => {
+                visitor.visit_literal_string(node);
+            }
+            Literal::Integer(node) => {
+                visitor.visit_literal_integer(node);
+            }
+            Literal::Float(node) => {
+                visitor.visit_literal_float(node);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_literal_float<V: Visitor + ?Sized>(visitor: &mut V, node: &LiteralFloat) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_literal_integer<V: Visitor + ?Sized>(visitor: &mut V, node: &LiteralInteger) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_literal_string<V: Visitor + ?Sized>(visitor: &mut V, node: &LiteralString) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_literal_string_part<V: Visitor + ?Sized>(visitor: &mut V, node: &LiteralStringPart) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_logical_operation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &LogicalOperationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            LogicalOperationExpression::And { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            LogicalOperationExpression::Or { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            LogicalOperationExpression::Not { right, .. } => {
+                visitor.visit_expression(right);
+            }
+            LogicalOperationExpression::LogicalAnd { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            LogicalOperationExpression::LogicalOr { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+            LogicalOperationExpression::LogicalXor { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_magic_constant_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &MagicConstantExpression,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_match_arm<V: Visitor + ?Sized>(visitor: &mut V, node: &MatchArm) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.conditions {
+            visitor.visit_expression(item);
+        }
+        visitor.visit_expression(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_match_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &MatchExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        if let Some(item) = &node.default {
+            visitor.visit_default_match_arm(item);
+        }
+        for item in &node.arms {
+            visitor.visit_match_arm(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_method_body<V: Visitor + ?Sized>(visitor: &mut V, node: &MethodBody) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_method_call_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodCallExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_expression(&node.method);
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_method_closure_creation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodClosureCreationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_expression(&node.method);
+        visitor.visit_argument_placeholder(&node.placeholder);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_method_modifier<V: Visitor + ?Sized>(visitor: &mut V, node: &MethodModifier) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_method_modifier_group<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodModifierGroup,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.modifiers {
+            visitor.visit_method_modifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_named_argument<V: Visitor + ?Sized>(visitor: &mut V, node: &NamedArgument) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_identifier(&node.name);
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_namespace_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &NamespaceStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            NamespaceStatement::Unbraced(node) => {
+                visitor.visit_unbraced_namespace(node);
+            }
+            NamespaceStatement::Braced(node) => {
+                visitor.visit_braced_namespace(node);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_new_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &NewExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        if let Some(item) = &node.arguments {
+            visitor.visit_argument_list(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_nowdoc_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &NowdocExpression) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_nullsafe_method_call_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &NullsafeMethodCallExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_expression(&node.method);
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_nullsafe_property_fetch_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &NullsafePropertyFetchExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_expression(&node.property);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_parenthesized_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ParenthesizedExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.expr);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_positional_argument<V: Visitor + ?Sized>(visitor: &mut V, node: &PositionalArgument) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_print_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &PrintExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.value {
+            visitor.visit_expression(item);
+        }
+        if let Some(item) = &node.argument {
+            visitor.visit_single_argument(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_promoted_property_modifier<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &PromotedPropertyModifier,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_promoted_property_modifier_group<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &PromotedPropertyModifierGroup,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.modifiers {
+            visitor.visit_promoted_property_modifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_property<V: Visitor + ?Sized>(visitor: &mut V, node: &Property) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_property_modifier_group(&node.modifiers);
+        if let Some(item) = &node.r#type {
+            visitor.visit_type(item);
+        }
+        for item in &node.entries {
+            visitor.visit_property_entry(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_property_entry<V: Visitor + ?Sized>(visitor: &mut V, node: &PropertyEntry) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            PropertyEntry::Uninitialized { variable } => {
+                visitor.visit_simple_variable(variable);
+            }
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => {
+                visitor.visit_simple_variable(variable);
+                visitor.visit_expression(value);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_property_fetch_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyFetchExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_expression(&node.property);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_property_modifier<V: Visitor + ?Sized>(visitor: &mut V, node: &PropertyModifier) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_property_modifier_group<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyModifierGroup,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.modifiers {
+            visitor.visit_property_modifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_reference_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ReferenceExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.right);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_require_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &RequireExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.path);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_require_once_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &RequireOnceExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.path);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_return_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ReturnStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.value {
+            visitor.visit_expression(item);
+        }
+        visitor.visit_ending(&node.ending);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_return_type<V: Visitor + ?Sized>(visitor: &mut V, node: &ReturnType) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_type(&node.data_type);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_shell_exec_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ShellExecExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.parts {
+            visitor.visit_string_part(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_short_array_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ShortArrayExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.items {
+            visitor.visit_array_item(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_short_opening_tag_statement<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ShortOpeningTagStatement,
+) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_short_ternary_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &ShortTernaryExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        visitor.visit_expression(&node.r#else);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_simple_identifier<V: Visitor + ?Sized>(visitor: &mut V, node: &SimpleIdentifier) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_simple_variable<V: Visitor + ?Sized>(visitor: &mut V, node: &SimpleVariable) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_single_argument<V: Visitor + ?Sized>(visitor: &mut V, node: &SingleArgument) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_argument(&node.argument);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &Statement) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Statement::FullOpeningTag(node) => {
+                visitor.visit_full_opening_tag_statement(node);
+            }
+            Statement::ShortOpeningTag(node) => {
+                visitor.visit_short_opening_tag_statement(node);
+            }
+            Statement::EchoOpeningTag(node) => {
+                visitor.visit_echo_opening_tag_statement(node);
+            }
+            Statement::ClosingTag(node) => {
+                visitor.visit_closing_tag_statement(node);
+            }
+            Statement::InlineHtml(node) => {
+                visitor.visit_inline_html_statement(node);
+            }
+            Statement::Label(node) => {
+                visitor.visit_label_statement(node);
+            }
+            Statement::Goto(node) => {
+                visitor.visit_goto_statement(node);
+            }
+            Statement::HaltCompiler(node) => {
+                visitor.visit_halt_compiler_statement(node);
+            }
+            Statement::Static(node) => {
+                visitor.visit_static_statement(node);
+            }
+            Statement::DoWhile(node) => {
+                visitor.visit_do_while_statement(node);
+            }
+            Statement::While(node) => {
+                visitor.visit_while_statement(node);
+            }
+            Statement::For(node) => {
+                visitor.visit_for_statement(node);
+            }
+            Statement::Foreach(node) => {
+                visitor.visit_foreach_statement(node);
+            }
+            Statement::Break(node) => {
+                visitor.visit_break_statement(node);
+            }
+            Statement::Continue(node) => {
+                visitor.visit_continue_statement(node);
+            }
+            Statement::Constant(node) => {
+                visitor.visit_constant_statement(node);
+            }
+            Statement::Function(node) => {
+                visitor.visit_function_statement(node);
+            }
+            Statement::Class(node) => {
+                visitor.visit_class_statement(node);
+            }
+            Statement::Trait(node) => {
+                visitor.visit_trait_statement(node);
+            }
+            Statement::Interface(node) => {
+                visitor.visit_interface_statement(node);
+            }
+            Statement::If(node) => {
+                visitor.visit_if_statement(node);
+            }
+            Statement::Switch(node) => {
+                visitor.visit_switch_statement(node);
+            }
+            Statement::Echo(node) => {
+                visitor.visit_echo_statement(node);
+            }
+            Statement::Expression(node) => {
+                visitor.visit_expression_statement(node);
+            }
+            Statement::Return(node) => {
+                visitor.visit_return_statement(node);
+            }
+            Statement::Namespace(node) => {
+                visitor.visit_namespace_statement(node);
+            }
+            Statement::Use(node) => {
+                visitor.visit_use_statement(node);
+            }
+            Statement::GroupUse(node) => {
+                visitor.visit_group_use_statement(node);
+            }
+            Statement::Comment(node) => {
+                visitor.visit_comment(node);
+            }
+            Statement::Try(node) => {
+                visitor.visit_try_statement(node);
+            }
+            Statement::UnitEnum(node) => {
+                visitor.visit_unit_enum_statement(node);
+            }
+            Statement::BackedEnum(node) => {
+                visitor.visit_backed_enum_statement(node);
+            }
+            Statement::Block(node) => {
+                visitor.visit_block_statement(node);
+            }
+            Statement::Global(node) => {
+                visitor.visit_global_statement(node);
+            }
+            Statement::Declare(node) => {
+                visitor.visit_declare_statement(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_method_call_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticMethodCallExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_identifier(&node.method);
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_method_closure_creation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticMethodClosureCreationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_identifier(&node.method);
+        visitor.visit_argument_placeholder(&node.placeholder);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_property_fetch_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticPropertyFetchExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_variable(&node.property);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &StaticStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.vars {
+            visitor.visit_static_var(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_var<V: Visitor + ?Sized>(visitor: &mut V, node: &StaticVar) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_variable(&node.var);
+        if let Some(item) = &node.default {
+            visitor.visit_expression(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_variable_method_call_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticVariableMethodCallExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_variable(&node.method);
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_static_variable_method_closure_creation_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticVariableMethodClosureCreationExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.target);
+        visitor.visit_variable(&node.method);
+        visitor.visit_argument_placeholder(&node.placeholder);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_string_part<V: Visitor + ?Sized>(visitor: &mut V, node: &StringPart) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            StringPart::Literal(node) => {
+                visitor.visit_literal_string_part(node);
+            }
+            StringPart::Expression(node) => {
+                visitor.visit_expression_string_part(node);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_switch_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &SwitchStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        for item in &node.cases {
+            visitor.visit_case(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_ternary_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &TernaryExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        visitor.visit_expression(&node.then);
+        visitor.visit_expression(&node.r#else);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_throw_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ThrowExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_trait_body<V: Visitor + ?Sized>(visitor: &mut V, node: &TraitBody) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_trait_member(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_trait_member<V: Visitor + ?Sized>(visitor: &mut V, node: &TraitMember) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            TraitMember::Constant(node) => {
+                visitor.visit_classish_constant(node);
+            }
+            TraitMember::TraitUsage(node) => {
+                visitor.visit_trait_usage(node);
+            }
+            TraitMember::Property(node) => {
+                visitor.visit_property(node);
+            }
+            TraitMember::VariableProperty(node) => {
+                visitor.visit_variable_property(node);
+            }
+            TraitMember::AbstractMethod(node) => {
+                visitor.visit_abstract_method(node);
+            }
+            TraitMember::AbstractConstructor(node) => {
+                visitor.visit_abstract_constructor(node);
+            }
+            TraitMember::ConcreteMethod(node) => {
+                visitor.visit_concrete_method(node);
+            }
+            TraitMember::ConcreteConstructor(node) => {
+                visitor.visit_concrete_constructor(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_trait_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &TraitStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        visitor.visit_simple_identifier(&node.name);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_trait_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_trait_usage<V: Visitor + ?Sized>(visitor: &mut V, node: &TraitUsage) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.traits {
+            visitor.visit_simple_identifier(item);
+        }
+        for item in &node.adaptations {
+            visitor.visit_trait_usage_adaptation(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_trait_usage_adaptation<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsageAdaptation,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                visibility,
+            } => {
+                if let Some(item) = r#trait {
+                    visitor.visit_simple_identifier(item);
+                }
+                visitor.visit_simple_identifier(method);
+                visitor.visit_simple_identifier(alias);
+                if let Some(item) = visibility {
+                    visitor.visit_visibility_modifier(item);
+                }
+            }
+            TraitUsageAdaptation::Visibility {
+                r#trait,
+                method,
+                visibility,
+            } => {
+                if let Some(item) = r#trait {
+                    visitor.visit_simple_identifier(item);
+                }
+                visitor.visit_simple_identifier(method);
+                visitor.visit_visibility_modifier(visibility);
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                if let Some(item) = r#trait {
+                    visitor.visit_simple_identifier(item);
+                }
+                visitor.visit_simple_identifier(method);
+                for item in insteadof {
+                    visitor.visit_simple_identifier(item);
+                }
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_try_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &TryStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.body {
+            visitor.visit_statement(item);
+        }
+        for item in &node.catches {
+            visitor.visit_catch_block(item);
+        }
+        if let Some(item) = &node.finally {
+            visitor.visit_finally_block(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_type<V: Visitor + ?Sized>(visitor: &mut V, node: &Type) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Type::Nullable(_, node) => {
+                visitor.visit_type(node);
+            }
+            Type::Union(node) => {
+                for item in node {
+                    visitor.visit_type(item);
+                }
+            }
+            Type::Intersection(node) => {
+                for item in node {
+                    visitor.visit_type(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_unbraced_namespace<V: Visitor + ?Sized>(visitor: &mut V, node: &UnbracedNamespace) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.name);
+        for item in &node.statements {
+            visitor.visit_statement(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_unit_enum_body<V: Visitor + ?Sized>(visitor: &mut V, node: &UnitEnumBody) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.members {
+            visitor.visit_unit_enum_member(item);
+        }
+        visitor.visit_comment_group(&node.comments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_unit_enum_case<V: Visitor + ?Sized>(visitor: &mut V, node: &UnitEnumCase) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_simple_identifier(&node.name);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_unit_enum_member<V: Visitor + ?Sized>(visitor: &mut V, node: &UnitEnumMember) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            UnitEnumMember::Case(node) => {
+                visitor.visit_unit_enum_case(node);
+            }
+            UnitEnumMember::Method(node) => {
+                visitor.visit_concrete_method(node);
+            }
+            UnitEnumMember::Constant(node) => {
+                visitor.visit_classish_constant(node);
+            }
+            UnitEnumMember::TraitUsage(node) => {
+                visitor.visit_trait_usage(node);
+            }
+            _ => {}
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_unit_enum_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &UnitEnumStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        visitor.visit_simple_identifier(&node.name);
+        for item in &node.implements {
+            visitor.visit_simple_identifier(item);
+        }
+        visitor.visit_unit_enum_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_unset_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &UnsetExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_argument_list(&node.arguments);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_use<V: Visitor + ?Sized>(visitor: &mut V, node: &Use) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_simple_identifier(&node.name);
+        if let Some(item) = &node.alias {
+            visitor.visit_simple_identifier(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_use_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &UseStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        for item in &node.uses {
+            visitor.visit_use(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_variable<V: Visitor + ?Sized>(visitor: &mut V, node: &Variable) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            Variable::SimpleVariable(node) => {
+                visitor.visit_simple_variable(node);
+            }
+            Variable::VariableVariable(node) => {
+                visitor.visit_variable_variable(node);
+            }
+            Variable::BracedVariableVariable(node) => {
+                visitor.visit_braced_variable_variable(node);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_variable_property<V: Visitor + ?Sized>(visitor: &mut V, node: &VariableProperty) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_comment_group(&node.comments);
+        for item in &node.attributes {
+            visitor.visit_attribute_group(item);
+        }
+        if let Some(item) = &node.r#type {
+            visitor.visit_type(item);
+        }
+        for item in &node.entries {
+            visitor.visit_property_entry(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_variable_variable<V: Visitor + ?Sized>(visitor: &mut V, node: &VariableVariable) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_variable(&node.variable);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_visibility_modifier<V: Visitor + ?Sized>(visitor: &mut V, node: &VisibilityModifier) {
+    visitor.enter(node);
+
+    visitor.leave(node);
+}
+
+pub fn walk_while_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &WhileStatement) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.condition);
+        visitor.visit_while_statement_body(&node.body);
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_while_statement_body<V: Visitor + ?Sized>(visitor: &mut V, node: &WhileStatementBody) {
+    if visitor.enter(node) == Walk::Continue {
+        match node {
+            WhileStatementBody::Statement { statement } => {
+                visitor.visit_statement(statement);
+            }
+            WhileStatementBody::Block {
+                statements, ending, ..
+            } => {
+                for item in statements {
+                    visitor.visit_statement(item);
+                }
+                visitor.visit_ending(ending);
+            }
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_yield_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &YieldExpression) {
+    if visitor.enter(node) == Walk::Continue {
+        if let Some(item) = &node.key {
+            visitor.visit_expression(item);
+        }
+        if let Some(item) = &node.value {
+            visitor.visit_expression(item);
+        }
+    }
+
+    visitor.leave(node);
+}
+
+pub fn walk_yield_from_expression<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    node: &YieldFromExpression,
+) {
+    if visitor.enter(node) == Walk::Continue {
+        visitor.visit_expression(&node.value);
+    }
+
+    visitor.leave(node);
+}
@@ -131,6 +131,64 @@ impl DerefMut for ByteString {
     }
 }
 
+/// Serializes bytes as a string when they're valid UTF-8, and as an array of bytes otherwise,
+/// which is how `ByteString` is serialized. Use it with `#[serde(serialize_with = "...")]`.
+#[cfg(feature = "serde")]
+pub fn serialize_bytes<S: serde::Serializer>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match from_utf8(bytes) {
+        Ok(string) => serializer.serialize_str(string),
+        Err(_) => serializer.serialize_bytes(bytes),
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.bytes, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ByteString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = ByteString;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a string or an array of bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<ByteString, E> {
+                Ok(ByteString::from(value))
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<ByteString, E> {
+                Ok(ByteString::from(value))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<ByteString, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+
+                Ok(ByteString::new(bytes))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +205,16 @@ mod tests {
             r#""\x01\x10\x7f\xff""#
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_byte_string_serde() {
+        let json = serde_json::to_string(&ByteString::from("a\"b")).unwrap();
+        assert_eq!(json, r#""a\"b""#);
+        assert_eq!(serde_json::from_str::<ByteString>(&json).unwrap(), ByteString::from("a\"b"));
+
+        let json = serde_json::to_string(&ByteString::from(b"a\xff")).unwrap();
+        assert_eq!(json, "[97,255]");
+        assert_eq!(serde_json::from_str::<ByteString>(&json).unwrap(), ByteString::from(b"a\xff"));
+    }
 }
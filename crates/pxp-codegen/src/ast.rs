@@ -0,0 +1,392 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use syn::{GenericArgument, Item, PathArguments};
+
+/// The types in pxp-ast, as far as the generated code needs to know about them.
+pub struct Ast {
+    /// Every type that's part of the syntax tree, by name. Each of them is a `Node`.
+    pub types: BTreeMap<String, Type>,
+    aliases: HashMap<String, syn::Type>,
+}
+
+pub struct Type {
+    pub name: String,
+    /// The module the type is defined in, such as `classes`, or `None` for the crate root.
+    pub module: Option<String>,
+    pub data: Data,
+}
+
+pub enum Data {
+    Struct(Vec<Field>),
+    Enum(Vec<Variant>),
+}
+
+pub struct Variant {
+    pub name: String,
+    pub fields: Vec<Field>,
+    /// Whether the fields are named, like in `Variant { a, b }`, rather than `Variant(a, b)`.
+    pub named: bool,
+}
+
+pub struct Field {
+    /// The name of the field, or its index for a tuple variant.
+    pub name: String,
+    pub shape: Shape,
+    pub spans: Spans,
+}
+
+/// What a field holds, as far as walking it is concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    Node(String),
+    Box(Box<Shape>),
+    Option(Box<Shape>),
+    Vec(Box<Shape>),
+    CommaSeparated(Box<Shape>),
+    /// Anything without nodes in it, such as a span or a name.
+    Leaf,
+    /// A type that isn't a node, but isn't known to be a leaf either.
+    Unknown(String),
+}
+
+/// Where the spans in a field are, as far as changing them is concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Spans {
+    /// Nothing with a span in it, such as a name.
+    None,
+    Span,
+    /// A tuple, with the spans in each of its elements.
+    Tuple(Vec<Spans>),
+    /// Anything else with spans in it, which implements `SpannedMut`.
+    Nested,
+}
+
+// Types that fields can have without any nodes in them.
+const LEAVES: &[&str] = &[
+    "Span",
+    "ByteString",
+    "bool",
+    "f64",
+    "i64",
+    "CastKind",
+    "CommentFormat",
+    "LiteralStringKind",
+    "UseKind",
+];
+
+// Files that aren't part of the syntax tree itself, or are generated from it.
+const SKIPPED: &[&str] = &[
+    "downcast.rs",
+    "fold.rs",
+    "node.rs",
+    "parents.rs",
+    "query.rs",
+    "spanned.rs",
+    "spanned_impls.rs",
+    "node_impls.rs",
+    "visitor.rs",
+    "visitor_mut.rs",
+];
+
+impl Ast {
+    pub fn read(directory: &Path) -> Self {
+        let mut items = Vec::new();
+        let mut paths = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        for path in paths {
+            let name = path.file_name().unwrap().to_str().unwrap();
+
+            if SKIPPED.contains(&name) {
+                continue;
+            }
+
+            let module = match name.trim_end_matches(".rs") {
+                "lib" => None,
+                module => Some(module.to_string()),
+            };
+            let source = fs::read_to_string(&path).unwrap();
+            items.extend(
+                syn::parse_file(&source)
+                    .unwrap()
+                    .items
+                    .into_iter()
+                    .map(|item| (module.clone(), item)),
+            );
+        }
+
+        let mut spanned = HashSet::new();
+        let mut aliases = HashMap::new();
+
+        for (_, item) in &items {
+            match item {
+                Item::Impl(item) => {
+                    let (Some((_, path, _)), syn::Type::Path(ty)) = (&item.trait_, &*item.self_ty)
+                    else {
+                        continue;
+                    };
+
+                    if path.segments.last().unwrap().ident == "Spanned" {
+                        spanned.insert(ty.path.segments.last().unwrap().ident.to_string());
+                    }
+                }
+                Item::Type(item) => {
+                    aliases.insert(item.ident.to_string(), (*item.ty).clone());
+                }
+                _ => {}
+            }
+        }
+
+        let mut ast = Self {
+            types: BTreeMap::new(),
+            aliases,
+        };
+
+        // Only types that have a span are part of the tree, the rest are things like the kind
+        // of a cast. Generic helpers like `CommaSeparated` are walked through instead.
+        for (module, item) in &items {
+            let (name, data) = match item {
+                Item::Struct(item)
+                    if item.generics.params.is_empty()
+                        && spanned.contains(&item.ident.to_string()) =>
+                {
+                    (item.ident.to_string(), Data::Struct(ast.fields(&item.fields, &spanned)))
+                }
+                Item::Enum(item) if spanned.contains(&item.ident.to_string()) => {
+                    let variants = item
+                        .variants
+                        .iter()
+                        .map(|variant| Variant {
+                            name: variant.ident.to_string(),
+                            fields: ast.fields(&variant.fields, &spanned),
+                            named: matches!(variant.fields, syn::Fields::Named(_)),
+                        })
+                        .collect();
+
+                    (item.ident.to_string(), Data::Enum(variants))
+                }
+                _ => continue,
+            };
+
+            let module = module.clone();
+            ast.types.insert(name.clone(), Type { name, module, data });
+        }
+
+        ast
+    }
+
+    fn fields(&self, fields: &syn::Fields, spanned: &HashSet<String>) -> Vec<Field> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| Field {
+                name: match &field.ident {
+                    Some(ident) => ident.to_string(),
+                    None => index.to_string(),
+                },
+                shape: self.shape(&field.ty, spanned),
+                spans: self.spans(&field.ty, spanned),
+            })
+            .collect()
+    }
+
+    fn spans(&self, ty: &syn::Type, spanned: &HashSet<String>) -> Spans {
+        let ty = match ty {
+            syn::Type::Path(ty) => ty,
+            syn::Type::Tuple(tuple) => {
+                let elements = tuple
+                    .elems
+                    .iter()
+                    .map(|ty| self.spans(ty, spanned))
+                    .collect::<Vec<_>>();
+
+                if elements.iter().all(|spans| *spans == Spans::None) {
+                    return Spans::None;
+                }
+
+                return Spans::Tuple(elements);
+            }
+            _ => return Spans::None,
+        };
+
+        let segment = ty.path.segments.last().unwrap();
+        let name = segment.ident.to_string();
+
+        if let Some(alias) = self.aliases.get(&name) {
+            return self.spans(alias, spanned);
+        }
+
+        if name == "Span" {
+            return Spans::Span;
+        }
+
+        // Containers of anything with a span, such as `Option<Span>`, have spans too.
+        let nested = match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => {
+                arguments.args.iter().any(|argument| match argument {
+                    GenericArgument::Type(ty) => self.spans(ty, spanned) != Spans::None,
+                    _ => false,
+                })
+            }
+            _ => false,
+        };
+
+        if nested || spanned.contains(&name) {
+            Spans::Nested
+        } else {
+            Spans::None
+        }
+    }
+
+    fn shape(&self, ty: &syn::Type, spanned: &HashSet<String>) -> Shape {
+        let ty = match ty {
+            syn::Type::Path(ty) => ty,
+            syn::Type::Tuple(tuple) => {
+                if tuple.elems.iter().all(|ty| self.shape(ty, spanned) == Shape::Leaf) {
+                    return Shape::Leaf;
+                }
+
+                return Shape::Unknown("a tuple with nodes in it".to_string());
+            }
+            _ => return Shape::Unknown("a type that isn't a path".to_string()),
+        };
+
+        let segment = ty.path.segments.last().unwrap();
+        let name = segment.ident.to_string();
+
+        if let Some(alias) = self.aliases.get(&name) {
+            return self.shape(alias, spanned);
+        }
+
+        let argument = match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+                Some(GenericArgument::Type(ty)) => Some(Box::new(self.shape(ty, spanned))),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let shape = match (name.as_str(), argument) {
+            ("Box", Some(inner)) => Shape::Box(inner),
+            ("Option", Some(inner)) => Shape::Option(inner),
+            ("Vec", Some(inner)) => Shape::Vec(inner),
+            ("CommaSeparated", Some(inner)) => Shape::CommaSeparated(inner),
+            (_, None) if LEAVES.contains(&name.as_str()) => Shape::Leaf,
+            (_, None) if spanned.contains(&name) => Shape::Node(name),
+            _ => Shape::Unknown(name),
+        };
+
+        match shape {
+            Shape::Box(inner)
+            | Shape::Option(inner)
+            | Shape::Vec(inner)
+            | Shape::CommaSeparated(inner)
+                if matches!(*inner, Shape::Unknown(_)) =>
+            {
+                *inner
+            }
+            Shape::Unknown(_) => shape,
+            _ if shape.has_nodes() => shape,
+            _ => Shape::Leaf,
+        }
+    }
+}
+
+impl Ast {
+    /// A `use` for every type in the tree.
+    pub fn imports(&self) -> String {
+        let mut imports = String::new();
+
+        for ty in self.types.values() {
+            match &ty.module {
+                Some(module) => writeln!(imports, "use crate::{}::{};", module, ty.name),
+                None => writeln!(imports, "use crate::{};", ty.name),
+            }
+            .unwrap();
+        }
+
+        imports
+    }
+}
+
+impl Variant {
+    /// The fields with nodes in them.
+    pub fn walked(&self) -> Vec<&Field> {
+        self.fields
+            .iter()
+            .filter(|field| field.shape.has_nodes())
+            .collect()
+    }
+
+    /// A pattern for the variant that binds the fields with nodes in them, such as `(_, node)`,
+    /// along with the names they're bound to.
+    pub fn pattern(&self) -> (String, Vec<String>) {
+        let walked = self.walked();
+
+        if self.named {
+            let bindings = walked
+                .iter()
+                .map(|field| field.name.clone())
+                .collect::<Vec<_>>();
+            let rest = if walked.len() < self.fields.len() { ", .." } else { "" };
+
+            return (format!(" {{ {}{} }}", bindings.join(", "), rest), bindings);
+        }
+
+        let mut patterns = Vec::new();
+        let mut bindings = Vec::new();
+
+        for field in &self.fields {
+            if !field.shape.has_nodes() {
+                patterns.push("_".to_string());
+                continue;
+            }
+
+            let binding = if walked.len() == 1 {
+                "node".to_string()
+            } else {
+                format!("node{}", field.name)
+            };
+
+            patterns.push(binding.clone());
+            bindings.push(binding);
+        }
+
+        (format!("({})", patterns.join(", ")), bindings)
+    }
+}
+
+impl Shape {
+    pub fn has_nodes(&self) -> bool {
+        match self {
+            Self::Node(_) => true,
+            Self::Box(inner)
+            | Self::Option(inner)
+            | Self::Vec(inner)
+            | Self::CommaSeparated(inner) => inner.has_nodes(),
+            Self::Leaf | Self::Unknown(_) => false,
+        }
+    }
+}
+
+/// `ClassStatement` becomes `class_statement`.
+pub fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+
+        snake.push(character.to_ascii_lowercase());
+    }
+
+    snake
+}
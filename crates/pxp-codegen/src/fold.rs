@@ -0,0 +1,222 @@
+use std::fmt::Write;
+
+use crate::ast::{snake_case, Ast, Data, Shape, Type};
+
+const DOCS: &str = r#"
+//! Rewriting the syntax tree by taking it apart and putting it back together, with a method for
+//! each type of node.
+//!
+//! Unlike `VisitorMut`, each `fold_*` method takes its node by value and returns whatever should
+//! replace it, so a node can be turned into a different variant of the same type. The default
+//! methods fold the children of the node by calling the `fold_*` function of the same name.
+//!
+//! A statement in a list of statements, such as the body of a function, goes through
+//! `fold_statements`, which can replace it with any number of statements.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pxp_ast::fold::{fold_statement, Fold};
+//! use pxp_ast::{Program, Statement};
+//!
+//! // Removes every `echo` from the program.
+//! struct RemoveEchoes;
+//!
+//! impl Fold for RemoveEchoes {
+//!     fn fold_statements(&mut self, node: Statement) -> Vec<Statement> {
+//!         match node {
+//!             Statement::Echo(_) => vec![],
+//!             node => vec![fold_statement(self, node)],
+//!         }
+//!     }
+//! }
+//!
+//! fn remove_echoes(program: Program) -> Program {
+//!     RemoveEchoes.fold_block(program)
+//! }
+//! ```
+
+use crate::utils::CommaSeparated;
+use crate::Block;
+"#;
+
+const STATEMENTS: &str = r#"
+    /// Folds a list of statements, such as the body of a function or the whole program.
+    fn fold_block(&mut self, node: Block) -> Block {
+        fold_block(self, node)
+    }
+
+    /// Folds a statement that's part of a list of statements into the statements that should
+    /// replace it, which can be none at all.
+    fn fold_statements(&mut self, node: Statement) -> Vec<Statement> {
+        vec![self.fold_statement(node)]
+    }
+"#;
+
+const FOLD_BLOCK: &str = r#"
+pub fn fold_block<F: Fold + ?Sized>(folder: &mut F, node: Block) -> Block {
+    node.into_iter()
+        .flat_map(|statement| folder.fold_statements(statement))
+        .collect()
+}
+"#;
+
+/// Generates `fold.rs`.
+pub fn generate(ast: &Ast) -> String {
+    let mut output = String::from(DOCS);
+    output.push_str(&ast.imports());
+
+    output.push_str(
+        "
+/// Rewrites the syntax tree, see the [module documentation](self).
+pub trait Fold {",
+    );
+    output.push_str(STATEMENTS);
+
+    for ty in ast.types.values() {
+        let snake = snake_case(&ty.name);
+
+        write!(
+            output,
+            "
+    fn fold_{snake}(&mut self, node: {name}) -> {name} {{
+        fold_{snake}(self, node)
+    }}
+",
+            name = ty.name,
+        )
+        .unwrap();
+    }
+
+    output.push_str("}\n");
+    output.push_str(FOLD_BLOCK);
+
+    for ty in ast.types.values() {
+        output.push_str(&function(ty));
+    }
+
+    output
+}
+
+fn function(ty: &Type) -> String {
+    let body = match &ty.data {
+        Data::Struct(fields) if fields.iter().any(|field| field.shape.has_nodes()) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let value = format!("node.{}", field.name);
+                    format!("{}: {},\n", field.name, fold(&field.shape, &value))
+                })
+                .collect::<String>();
+
+            format!("{} {{ {} }}", ty.name, fields)
+        }
+        Data::Struct(_) => "node".to_string(),
+        Data::Enum(variants) => {
+            let mut arms = String::new();
+            let mut skipped = false;
+
+            for variant in variants {
+                if variant.walked().is_empty() {
+                    skipped = true;
+                    continue;
+                }
+
+                let path = format!("{}::{}", ty.name, variant.name);
+
+                if variant.named {
+                    let names = variant
+                        .fields
+                        .iter()
+                        .map(|field| field.name.as_str())
+                        .collect::<Vec<_>>();
+                    let values = variant
+                        .fields
+                        .iter()
+                        .map(|field| match field.shape {
+                            Shape::Leaf => field.name.clone(),
+                            _ => format!("{}: {}", field.name, fold(&field.shape, &field.name)),
+                        })
+                        .collect::<Vec<_>>();
+
+                    writeln!(
+                        arms,
+                        "{path} {{ {} }} => {path} {{ {} }},",
+                        names.join(", "),
+                        values.join(", ")
+                    )
+                    .unwrap();
+                } else {
+                    let bindings = variant
+                        .fields
+                        .iter()
+                        .map(|field| match variant.fields.len() {
+                            1 => "node".to_string(),
+                            _ => format!("node{}", field.name),
+                        })
+                        .collect::<Vec<_>>();
+                    let values = variant
+                        .fields
+                        .iter()
+                        .zip(&bindings)
+                        .map(|(field, binding)| fold(&field.shape, binding))
+                        .collect::<Vec<_>>();
+
+                    writeln!(
+                        arms,
+                        "{path}({}) => {path}({}),",
+                        bindings.join(", "),
+                        values.join(", ")
+                    )
+                    .unwrap();
+                }
+            }
+
+            if arms.is_empty() {
+                "node".to_string()
+            } else {
+                if skipped {
+                    arms.push_str("node => node,\n");
+                }
+
+                format!("match node {{ {} }}", arms)
+            }
+        }
+    };
+
+    let folder = if body == "node" { "_folder" } else { "folder" };
+
+    format!(
+        "
+pub fn fold_{snake}<F: Fold + ?Sized>({folder}: &mut F, node: {name}) -> {name} {{
+    {body}
+}}
+",
+        snake = snake_case(&ty.name),
+        name = ty.name,
+    )
+}
+
+// Folds whatever `value` is, which holds nodes in the given shape.
+fn fold(shape: &Shape, value: &str) -> String {
+    match shape {
+        Shape::Node(name) => format!("folder.fold_{}({})", snake_case(name), value),
+        Shape::Box(inner) => format!("Box::new({})", fold(inner, &format!("*{}", value))),
+        Shape::Option(inner) => format!("{}.map(|item| {})", value, fold(inner, "item")),
+        Shape::Vec(inner) if **inner == Shape::Node("Statement".to_string()) => {
+            format!("folder.fold_block({})", value)
+        }
+        Shape::Vec(inner) => format!(
+            "{}.into_iter().map(|item| {}).collect()",
+            value,
+            fold(inner, "item")
+        ),
+        Shape::CommaSeparated(inner) => format!(
+            "CommaSeparated {{ inner: {value}.inner.into_iter().map(|item| {}).collect(), \
+             commas: {value}.commas }}",
+            fold(inner, "item"),
+        ),
+        Shape::Leaf => value.to_string(),
+        Shape::Unknown(name) => panic!("can't fold {}", name),
+    }
+}
@@ -0,0 +1,98 @@
+//! Generates the code in pxp-ast that follows the shape of the syntax tree, such as the
+//! visitors. Run it with `just codegen` after changing any of the types in the tree.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use ast::Ast;
+
+mod ast;
+mod fold;
+mod node;
+mod spanned;
+mod visitor;
+
+const HEADER: &str = "// This file is generated by pxp-codegen, run `just codegen` to update it.\n";
+
+fn main() {
+    for (path, source) in files() {
+        fs::write(path, source).unwrap();
+    }
+}
+
+// The generated files, along with what should be in them.
+fn files() -> Vec<(PathBuf, String)> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("../pxp-ast/src");
+    let ast = Ast::read(&directory);
+
+    vec![
+        (directory.join("fold.rs"), format(fold::generate(&ast))),
+        (directory.join("node_impls.rs"), format(node::generate(&ast))),
+        (directory.join("spanned_impls.rs"), format(spanned::generate(&ast))),
+        (directory.join("visitor.rs"), format(visitor::generate(&ast, false))),
+        (directory.join("visitor_mut.rs"), format(visitor::generate(&ast, true))),
+    ]
+}
+
+fn format(source: String) -> String {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("rustfmt needs to be installed");
+
+    let mut stdin = rustfmt.stdin.take().unwrap();
+    stdin.write_all(HEADER.as_bytes()).unwrap();
+    stdin.write_all(source.as_bytes()).unwrap();
+    drop(stdin);
+
+    let output = rustfmt.wait_with_output().unwrap();
+    assert!(output.status.success(), "the generated code couldn't be formatted");
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Data, Shape};
+
+    #[test]
+    fn test_generated_code_is_up_to_date() {
+        for (path, source) in files() {
+            assert!(
+                fs::read_to_string(&path).unwrap() == source,
+                "{} is out of date, run `just codegen`",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_field_is_walked_or_a_leaf() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("../pxp-ast/src");
+        let mut unknown = Vec::new();
+
+        for ty in Ast::read(&directory).types.values() {
+            let fields: Vec<_> = match &ty.data {
+                Data::Struct(fields) => fields.iter().collect(),
+                Data::Enum(variants) => variants.iter().flat_map(|v| &v.fields).collect(),
+            };
+
+            for field in fields {
+                if let Shape::Unknown(description) = &field.shape {
+                    unknown.push(format!("{}.{} ({})", ty.name, field.name, description));
+                }
+            }
+        }
+
+        assert!(
+            unknown.is_empty(),
+            "these fields are neither nodes nor in `LEAVES`: {}",
+            unknown.join(", ")
+        );
+    }
+}
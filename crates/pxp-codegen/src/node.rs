@@ -0,0 +1,207 @@
+use std::fmt::Write;
+
+use crate::ast::{Ast, Data, Shape, Type};
+
+/// Generates `node_impls.rs`, which implements `Node` for every type in the tree.
+pub fn generate(ast: &Ast) -> String {
+    let mut output = String::from("\nuse crate::node::Node;\n");
+    output.push_str(&ast.imports());
+
+    for ty in ast.types.values() {
+        output.push_str(&implementation(ty));
+    }
+
+    output
+}
+
+// A field with nodes in it, and where to find it.
+struct Walked<'a> {
+    name: &'a str,
+    shape: &'a Shape,
+    // Either a field of `self`, or a binding that holds a reference to the field.
+    place: String,
+    binding: bool,
+    // `&mut ` for `fields`, or `&` for `fields_ref`.
+    reference: &'static str,
+}
+
+fn implementation(ty: &Type) -> String {
+    let body = fields(ty, "&mut ");
+
+    let fields = if body.is_empty() {
+        body
+    } else {
+        format!(
+            "
+    fn fields(&mut self) -> Vec<(&'static str, &mut dyn Node)> {{
+        {}
+    }}
+
+    fn fields_ref(&self) -> Vec<(&'static str, &dyn Node)> {{
+        {}
+    }}
+",
+            body,
+            fields(ty, "&"),
+        )
+    };
+
+    format!(
+        "
+impl Node for {name} {{
+    fn kind(&self) -> &'static str {{
+        \"{name}\"
+    }}
+{fields}
+}}
+",
+        name = ty.name,
+    )
+}
+
+// The body of `fields` or `fields_ref`, or nothing if the type doesn't have any nodes in it.
+fn fields(ty: &Type, reference: &'static str) -> String {
+    match &ty.data {
+        Data::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .filter(|field| field.shape.has_nodes())
+                .map(|field| Walked {
+                    name: &field.name,
+                    shape: &field.shape,
+                    place: format!("self.{}", field.name),
+                    binding: false,
+                    reference,
+                })
+                .collect::<Vec<_>>();
+
+            if fields.is_empty() {
+                String::new()
+            } else {
+                collect(&fields).0
+            }
+        }
+        Data::Enum(variants) => {
+            let mut arms = String::new();
+            let mut skipped = false;
+
+            for variant in variants {
+                let fields = variant.walked();
+
+                if fields.is_empty() {
+                    skipped = true;
+                    continue;
+                }
+
+                let (pattern, bindings) = variant.pattern();
+                let fields = fields
+                    .iter()
+                    .zip(bindings)
+                    .map(|(field, binding)| Walked {
+                        name: &field.name,
+                        shape: &field.shape,
+                        place: binding,
+                        binding: true,
+                        reference,
+                    })
+                    .collect::<Vec<_>>();
+
+                let (body, expression) = collect(&fields);
+                let body = if expression {
+                    format!("{},", body)
+                } else {
+                    format!("{{ {} }}", body)
+                };
+
+                writeln!(arms, "{}::{}{} => {}", ty.name, variant.name, pattern, body).unwrap();
+            }
+
+            if arms.is_empty() {
+                String::new()
+            } else {
+                if skipped {
+                    arms.push_str("_ => vec![],\n");
+                }
+
+                format!("match self {{ {} }}", arms)
+            }
+        }
+    }
+}
+
+// Code that collects the nodes in the fields into a vector, along with whether it's a single
+// expression rather than a list of statements.
+fn collect(fields: &[Walked]) -> (String, bool) {
+    let direct = fields
+        .iter()
+        .take_while(|field| matches!(field.shape, Shape::Node(_) | Shape::Box(_)))
+        .map(|field| format!("(\"{}\", {})", field.name, reference(field)))
+        .collect::<Vec<_>>();
+
+    if direct.len() == fields.len() {
+        return (format!("vec![{}]", direct.join(", ")), true);
+    }
+
+    let ty = format!("Vec<(&'static str, {}dyn Node)>", fields[0].reference);
+    let mut output = if direct.is_empty() {
+        format!("let mut fields: {} = Vec::new();\n", ty)
+    } else {
+        format!("let mut fields: {} = vec![{}];\n", ty, direct.join(", "))
+    };
+
+    for field in &fields[direct.len()..] {
+        output.push_str(&push(field));
+    }
+
+    output.push_str("\nfields");
+
+    (output, false)
+}
+
+fn push(field: &Walked) -> String {
+    let container = if field.binding {
+        field.place.clone()
+    } else {
+        format!("{}{}", field.reference, field.place)
+    };
+
+    let inner = |shape| Walked {
+        name: field.name,
+        shape,
+        place: "item".to_string(),
+        binding: true,
+        reference: field.reference,
+    };
+
+    match field.shape {
+        Shape::Node(_) | Shape::Box(_) => {
+            format!("fields.push((\"{}\", {}));\n", field.name, reference(field))
+        }
+        Shape::Option(shape) => format!(
+            "if let Some(item) = {} {{ {} }}\n",
+            container,
+            push(&inner(shape))
+        ),
+        Shape::Vec(shape) | Shape::CommaSeparated(shape) => {
+            format!("for item in {} {{ {} }}\n", container, push(&inner(shape)))
+        }
+        _ => panic!("can't walk {:?}", field.shape),
+    }
+}
+
+// A `&mut dyn Node` or `&dyn Node` for a field that holds a node, or a box with a node in it.
+fn reference(field: &Walked) -> String {
+    let reference = field.reference;
+
+    match (field.shape, field.binding) {
+        (Shape::Node(_), true) => field.place.clone(),
+        (Shape::Node(_), false) => format!("{}{}", reference, field.place),
+        (Shape::Box(inner), true) if matches!(**inner, Shape::Node(_)) => {
+            format!("{}**{}", reference, field.place)
+        }
+        (Shape::Box(inner), false) if matches!(**inner, Shape::Node(_)) => {
+            format!("{}*{}", reference, field.place)
+        }
+        _ => panic!("can't walk {:?}", field.shape),
+    }
+}
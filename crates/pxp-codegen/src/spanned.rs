@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use crate::ast::{Ast, Data, Field, Spans, Type};
+
+/// Generates `spanned_impls.rs`, which implements `SpannedMut` for every type in the tree.
+pub fn generate(ast: &Ast) -> String {
+    let mut output = String::from("\nuse crate::spanned::SpannedMut;\nuse pxp_span::Span;\n");
+    output.push_str(&ast.imports());
+
+    for ty in ast.types.values() {
+        output.push_str(&implementation(ty));
+    }
+
+    output
+}
+
+fn implementation(ty: &Type) -> String {
+    let mut used = false;
+    let body = match &ty.data {
+        Data::Struct(fields) => fields
+            .iter()
+            .map(|field| visit(&field.spans, &format!("self.{}", field.name), false))
+            .inspect(|body| used |= !body.is_empty())
+            .collect::<String>(),
+        Data::Enum(variants) => {
+            let mut arms = String::new();
+
+            for variant in variants {
+                let fields = variant
+                    .fields
+                    .iter()
+                    .filter(|field| field.spans != Spans::None)
+                    .collect::<Vec<_>>();
+                let (pattern, bindings) = pattern(&variant.fields, &fields, variant.named);
+                let body = fields
+                    .iter()
+                    .zip(bindings)
+                    .map(|(field, binding)| visit(&field.spans, &binding, true))
+                    .collect::<String>();
+                used |= !body.is_empty();
+
+                writeln!(arms, "{}::{}{} => {{ {} }}", ty.name, variant.name, pattern, body)
+                    .unwrap();
+            }
+
+            format!("match self {{ {} }}", arms)
+        }
+    };
+
+    let f = if used { "f" } else { "_f" };
+
+    format!(
+        "
+impl SpannedMut for {name} {{
+    fn for_each_span_mut(&mut self, {f}: &mut dyn FnMut(&mut Span)) {{
+        {body}
+    }}
+}}
+",
+        name = ty.name,
+    )
+}
+
+// A pattern for a variant that binds the fields with spans in them, along with the names
+// they're bound to.
+fn pattern(all: &[Field], fields: &[&Field], named: bool) -> (String, Vec<String>) {
+    if all.is_empty() {
+        return (String::new(), Vec::new());
+    }
+
+    if named {
+        let bindings = fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect::<Vec<_>>();
+        let rest = match (bindings.is_empty(), fields.len() < all.len()) {
+            (true, _) => "..",
+            (false, true) => ", ..",
+            (false, false) => "",
+        };
+
+        return (format!(" {{ {}{} }}", bindings.join(", "), rest), bindings);
+    }
+
+    let mut patterns = Vec::new();
+    let mut bindings = Vec::new();
+
+    for field in all {
+        let binding = match (&field.spans, fields.len()) {
+            (Spans::None, _) => {
+                patterns.push("_".to_string());
+                continue;
+            }
+            (Spans::Span, 1) => "span".to_string(),
+            (_, 1) => "node".to_string(),
+            _ => format!("node{}", field.name),
+        };
+
+        patterns.push(binding.clone());
+        bindings.push(binding);
+    }
+
+    (format!("({})", patterns.join(", ")), bindings)
+}
+
+// Calls `f` with every span in `place`, which is either a field of `self` or a binding that
+// holds a reference to the field.
+fn visit(spans: &Spans, place: &str, binding: bool) -> String {
+    match spans {
+        Spans::None => String::new(),
+        Spans::Span if binding => format!("f({});\n", place),
+        Spans::Span => format!("f(&mut {});\n", place),
+        Spans::Tuple(elements) => elements
+            .iter()
+            .enumerate()
+            .map(|(index, spans)| visit(spans, &format!("{}.{}", place, index), false))
+            .collect(),
+        Spans::Nested => format!("{}.for_each_span_mut(f);\n", place),
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::Write;
+
+use crate::ast::{snake_case, Ast, Data, Shape, Type};
+
+const VISITOR_DOCS: &str = r#"
+//! Walking the syntax tree without changing it, with a method for each type of node.
+//!
+//! Each `visit_*` method walks the children of its node by calling the `walk_*` function of
+//! the same name, so a visitor only has to override the methods for the nodes it cares about.
+//! Overriding one and not calling `walk_*` leaves out everything below that node.
+//!
+//! `enter` and `leave` are called around every node that's walked, which is useful for
+//! keeping track of where the walk is. Returning `Walk::Skip` from `enter` leaves out the
+//! children of the node.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pxp_ast::visitor::{walk_method_call_expression, Visitor};
+//! use pxp_ast::{MethodCallExpression, Program};
+//!
+//! struct MethodCalls(usize);
+//!
+//! impl Visitor for MethodCalls {
+//!     fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+//!         self.0 += 1;
+//!         walk_method_call_expression(self, node);
+//!     }
+//! }
+//!
+//! fn method_calls(program: &Program) -> usize {
+//!     let mut visitor = MethodCalls(0);
+//!
+//!     for statement in program {
+//!         visitor.visit_statement(statement);
+//!     }
+//!
+//!     visitor.0
+//! }
+//! ```
+
+use crate::node::Node;
+"#;
+
+const WALK: &str = r#"
+/// What to do once a node has been entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Walk {
+    /// Walk the children of the node.
+    Continue,
+    /// Leave out everything below the node.
+    Skip,
+}
+"#;
+
+const VISITOR_MUT_DOCS: &str = r#"
+//! Walking the syntax tree and changing it in place, with a method for each type of node.
+//!
+//! This works the same way as `Visitor`, but every node is handed out as `&mut`.
+
+use crate::node::Node;
+use crate::visitor::Walk;
+"#;
+
+/// Generates `visitor.rs`, or `visitor_mut.rs` when `mutable` is set.
+pub fn generate(ast: &Ast, mutable: bool) -> String {
+    let (reference, name) = if mutable {
+        ("&mut ", "VisitorMut")
+    } else {
+        ("&", "Visitor")
+    };
+
+    let mut output = String::new();
+    output.push_str(if mutable { VISITOR_MUT_DOCS } else { VISITOR_DOCS });
+
+    output.push_str(&ast.imports());
+
+    if !mutable {
+        output.push_str(WALK);
+    }
+
+    write!(
+        output,
+        "
+/// Walks the syntax tree{}, see the [module documentation](self).
+pub trait {name} {{
+    /// Called when the walk gets to a node, before any of its children.
+    fn enter(&mut self, _node: {reference}dyn Node) -> Walk {{
+        Walk::Continue
+    }}
+
+    /// Called when the walk is done with a node, even if its children were skipped.
+    fn leave(&mut self, _node: {reference}dyn Node) {{}}
+",
+        if mutable { " and changes it" } else { "" },
+    )
+    .unwrap();
+
+    for ty in ast.types.values() {
+        let snake = snake_case(&ty.name);
+
+        write!(
+            output,
+            "
+    fn visit_{snake}(&mut self, node: {reference}{}) {{
+        walk_{snake}(self, node);
+    }}
+",
+            ty.name,
+        )
+        .unwrap();
+    }
+
+    output.push_str("}\n");
+
+    for ty in ast.types.values() {
+        output.push_str(&walk(ty, name, reference));
+    }
+
+    output
+}
+
+fn walk(ty: &Type, name: &str, reference: &str) -> String {
+    let children = match &ty.data {
+        Data::Struct(fields) => fields
+            .iter()
+            .filter(|field| field.shape.has_nodes())
+            .map(|field| visit(&field.shape, &format!("{}node.{}", reference, field.name)))
+            .collect(),
+        Data::Enum(variants) => {
+            let mut arms = String::new();
+            let mut skipped = false;
+
+            for variant in variants {
+                let fields = variant.walked();
+
+                if fields.is_empty() {
+                    skipped = true;
+                    continue;
+                }
+
+                let (pattern, bindings) = variant.pattern();
+                let body = fields
+                    .iter()
+                    .zip(&bindings)
+                    .map(|(field, binding)| visit(&field.shape, binding))
+                    .collect::<String>();
+
+                writeln!(arms, "{}::{}{} => {{ {} }}", ty.name, variant.name, pattern, body)
+                    .unwrap();
+            }
+
+            if arms.is_empty() {
+                String::new()
+            } else {
+                if skipped {
+                    arms.push_str("_ => {}\n");
+                }
+
+                format!("match node {{ {} }}", arms)
+            }
+        }
+    };
+
+    let snake = snake_case(&ty.name);
+
+    let body = if children.is_empty() {
+        "visitor.enter(node);\n\nvisitor.leave(node);".to_string()
+    } else {
+        format!(
+            "if visitor.enter(node) == Walk::Continue {{ {} }}\n\nvisitor.leave(node);",
+            children
+        )
+    };
+
+    format!(
+        "
+pub fn walk_{snake}<V: {name} + ?Sized>(visitor: &mut V, node: {reference}{}) {{
+    {body}
+}}
+",
+        ty.name,
+    )
+}
+
+// Visits whatever `expression` refers to, which holds nodes in the given shape.
+fn visit(shape: &Shape, expression: &str) -> String {
+    match shape {
+        Shape::Node(name) => format!("visitor.visit_{}({});\n", snake_case(name), expression),
+        // A reference to a box is turned into a reference to what's in it when it's passed on.
+        Shape::Box(inner) if matches!(**inner, Shape::Node(_)) => visit(inner, expression),
+        Shape::Option(inner) => format!(
+            "if let Some(item) = {} {{ {} }}\n",
+            expression,
+            visit(inner, "item")
+        ),
+        Shape::Vec(inner) | Shape::CommaSeparated(inner) => {
+            format!("for item in {} {{ {} }}\n", expression, visit(inner, "item"))
+        }
+        _ => panic!("can't walk {:?}", shape),
+    }
+}
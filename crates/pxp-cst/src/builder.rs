@@ -0,0 +1,146 @@
+use std::any::TypeId;
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use pxp_ast::node::Node;
+use pxp_ast::parents::NodeId;
+use pxp_ast::parents::ParentMap;
+use pxp_bytestring::ByteString;
+use pxp_token::Token;
+
+use crate::green::GreenElement;
+use crate::green::GreenNode;
+use crate::green::GreenToken;
+use crate::green::SyntaxKind;
+
+struct Piece {
+    kind: SyntaxKind,
+    start: usize,
+    end: usize,
+}
+
+struct Open {
+    id: NodeId,
+    kind: TypeId,
+    end: usize,
+    children: Vec<GreenElement>,
+}
+
+/// Builds the green tree for `input` from its tokens and the AST that was parsed from them.
+///
+/// Every token, and the whitespace between them, goes into the innermost node whose span
+/// covers it, so comments that aren't part of any node end up in the enclosing one. Writing
+/// the tree back out gives `input` byte for byte.
+pub fn build(input: &[u8], tokens: &[Token], root: &mut dyn Node) -> GreenNode {
+    let map = ParentMap::new(root);
+
+    let mut nodes = map
+        .ids()
+        .skip(1)
+        .filter(|id| {
+            let span = map.span(*id);
+            span.start.offset < span.end.offset
+        })
+        .collect::<Vec<_>>();
+    // A parent comes before its children in the map, so it's opened first when they share a
+    // span.
+    nodes.sort_by_key(|id| {
+        let span = map.span(*id);
+        (span.start.offset, Reverse(span.end.offset), *id)
+    });
+
+    let mut stack = vec![Open {
+        id: map.root(),
+        kind: map.type_id(map.root()),
+        end: usize::MAX,
+        children: Vec::new(),
+    }];
+    let mut pending = nodes.into_iter().peekable();
+
+    for piece in pieces(input, tokens) {
+        while stack.len() > 1 && stack[stack.len() - 1].end <= piece.start {
+            close(&mut stack);
+        }
+
+        while let Some(id) = pending.next_if(|id| map.span(*id).start.offset <= piece.start) {
+            let end = map.span(id).end.offset;
+
+            // A node that would stick out of its parent can't be nested in the tree, so its
+            // tokens are left to the parent instead.
+            if end <= piece.start || end > stack[stack.len() - 1].end {
+                continue;
+            }
+
+            stack.push(Open {
+                id,
+                kind: map.type_id(id),
+                end,
+                children: Vec::new(),
+            });
+        }
+
+        let text = ByteString::from(&input[piece.start..piece.end]);
+        stack
+            .last_mut()
+            .unwrap()
+            .children
+            .push(GreenElement::Token(Arc::new(GreenToken::new(piece.kind, text))));
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    let root = stack.pop().unwrap();
+
+    GreenNode::new(root.kind, root.id, root.children)
+}
+
+fn close(stack: &mut Vec<Open>) {
+    let open = stack.pop().unwrap();
+    let node = GreenNode::new(open.kind, open.id, open.children);
+
+    stack
+        .last_mut()
+        .unwrap()
+        .children
+        .push(GreenElement::Node(Arc::new(node)));
+}
+
+// The tokens along with the whitespace between them, covering all of the input.
+fn pieces(input: &[u8], tokens: &[Token]) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut offset = 0;
+
+    for token in tokens {
+        let (start, end) = (token.span.start.offset, token.span.end.offset);
+        if start >= end || start < offset {
+            continue;
+        }
+
+        if offset < start {
+            pieces.push(Piece {
+                kind: SyntaxKind::Whitespace,
+                start: offset,
+                end: start,
+            });
+        }
+
+        pieces.push(Piece {
+            kind: SyntaxKind::Token(token.kind.clone()),
+            start,
+            end,
+        });
+        offset = end;
+    }
+
+    if offset < input.len() {
+        pieces.push(Piece {
+            kind: SyntaxKind::Whitespace,
+            start: offset,
+            end: input.len(),
+        });
+    }
+
+    pieces
+}
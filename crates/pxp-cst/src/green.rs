@@ -0,0 +1,120 @@
+use std::any::TypeId;
+use std::sync::Arc;
+
+use pxp_ast::parents::NodeId;
+use pxp_bytestring::ByteString;
+use pxp_token::TokenKind;
+
+/// What a token in the syntax tree is. Comments keep the token kind the lexer gave them, and
+/// the whitespace between tokens gets a kind of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxKind {
+    Token(TokenKind),
+    Whitespace,
+}
+
+impl SyntaxKind {
+    /// Whether the token is whitespace or a comment, rather than part of the code.
+    pub fn is_trivia(&self) -> bool {
+        matches!(
+            self,
+            Self::Whitespace
+                | Self::Token(
+                    TokenKind::SingleLineComment
+                        | TokenKind::HashMarkComment
+                        | TokenKind::MultiLineComment
+                        | TokenKind::DocumentComment
+                )
+        )
+    }
+}
+
+/// A node that only knows how wide it is, not where it is, so it can be shared between trees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GreenNode {
+    kind: TypeId,
+    id: NodeId,
+    width: usize,
+    children: Vec<GreenElement>,
+}
+
+impl GreenNode {
+    pub fn new(kind: TypeId, id: NodeId, children: Vec<GreenElement>) -> Self {
+        Self {
+            kind,
+            id,
+            width: children.iter().map(GreenElement::width).sum(),
+            children,
+        }
+    }
+
+    /// The type of the AST node this was built from.
+    pub fn kind(&self) -> TypeId {
+        self.kind
+    }
+
+    /// The id of the AST node this was built from.
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn children(&self) -> &[GreenElement] {
+        &self.children
+    }
+
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        for child in &self.children {
+            child.write_to(buffer);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GreenToken {
+    kind: SyntaxKind,
+    text: ByteString,
+}
+
+impl GreenToken {
+    pub fn new(kind: SyntaxKind, text: ByteString) -> Self {
+        Self { kind, text }
+    }
+
+    pub fn kind(&self) -> &SyntaxKind {
+        &self.kind
+    }
+
+    pub fn text(&self) -> &ByteString {
+        &self.text
+    }
+
+    pub fn width(&self) -> usize {
+        self.text.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GreenElement {
+    Node(Arc<GreenNode>),
+    Token(Arc<GreenToken>),
+}
+
+impl GreenElement {
+    pub fn width(&self) -> usize {
+        match self {
+            Self::Node(node) => node.width(),
+            Self::Token(token) => token.width(),
+        }
+    }
+
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::Node(node) => node.write_to(buffer),
+            Self::Token(token) => buffer.extend_from_slice(token.text()),
+        }
+    }
+}
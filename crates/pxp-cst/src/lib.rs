@@ -0,0 +1,60 @@
+//! A lossless concrete syntax tree, built from the tokens of a file and the AST parsed from
+//! them.
+//!
+//! The tree is split in two, in the style of rowan. Green nodes are immutable and only know
+//! their width, while `SyntaxNode`s are cheap handles on top of them that know their parent and
+//! position. Every node records the id its AST node has in a `ParentMap`, so the typed node can
+//! be found from the syntax node and the other way around.
+//!
+//! The tree is built from scratch each time, `incremental::Document` in the parser doesn't
+//! update it after an edit.
+
+use std::sync::Arc;
+
+use pxp_ast::node::Node;
+use pxp_ast::parents;
+use pxp_ast::parents::NodeId;
+use pxp_ast::Program;
+
+pub use crate::builder::build;
+pub use crate::green::GreenElement;
+pub use crate::green::GreenNode;
+pub use crate::green::GreenToken;
+pub use crate::green::SyntaxKind;
+pub use crate::red::SyntaxElement;
+pub use crate::red::SyntaxNode;
+pub use crate::red::SyntaxToken;
+
+mod builder;
+mod green;
+mod red;
+
+/// A program along with the syntax tree for the file it was parsed from.
+#[derive(Debug)]
+pub struct SyntaxTree {
+    pub program: Program,
+    pub root: SyntaxNode,
+}
+
+impl SyntaxTree {
+    pub fn new(program: Program, root: SyntaxNode) -> Self {
+        Self { program, root }
+    }
+
+    /// The AST node that a syntax node was built from.
+    pub fn ast(&mut self, node: &SyntaxNode) -> Option<&mut dyn Node> {
+        parents::find(&mut self.program, node.id())
+    }
+
+    /// The syntax node for the AST node with the given id.
+    pub fn syntax(&self, id: NodeId) -> Option<SyntaxNode> {
+        self.root.descendants().into_iter().find(|node| node.id() == id)
+    }
+}
+
+/// Builds the syntax tree for `input`.
+pub fn tree(input: &[u8], tokens: &[pxp_token::Token], mut program: Program) -> SyntaxTree {
+    let green = build(input, tokens, &mut program);
+
+    SyntaxTree::new(program, SyntaxNode::new_root(Arc::new(green)))
+}
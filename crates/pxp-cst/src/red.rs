@@ -0,0 +1,236 @@
+use std::any::TypeId;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use pxp_ast::node::Node;
+use pxp_ast::parents::NodeId;
+use pxp_bytestring::ByteString;
+
+use crate::green::GreenElement;
+use crate::green::GreenNode;
+use crate::green::GreenToken;
+use crate::green::SyntaxKind;
+
+/// A node in the syntax tree, which unlike a `GreenNode` knows its parent and where it is in
+/// the source.
+#[derive(Clone)]
+pub struct SyntaxNode(Rc<NodeData>);
+
+struct NodeData {
+    green: Arc<GreenNode>,
+    parent: Option<SyntaxNode>,
+    offset: usize,
+}
+
+impl SyntaxNode {
+    pub fn new_root(green: Arc<GreenNode>) -> Self {
+        Self(Rc::new(NodeData {
+            green,
+            parent: None,
+            offset: 0,
+        }))
+    }
+
+    fn new_child(green: Arc<GreenNode>, parent: SyntaxNode, offset: usize) -> Self {
+        Self(Rc::new(NodeData {
+            green,
+            parent: Some(parent),
+            offset,
+        }))
+    }
+
+    pub fn green(&self) -> &Arc<GreenNode> {
+        &self.0.green
+    }
+
+    /// The id of the AST node this was built from, as given to it by a `ParentMap`.
+    pub fn id(&self) -> NodeId {
+        self.0.green.id()
+    }
+
+    /// Whether this was built from a `T`.
+    pub fn is<T: Node>(&self) -> bool {
+        self.0.green.kind() == TypeId::of::<T>()
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.0.parent.clone()
+    }
+
+    /// The parent of the node, then its parent, and so on up to the root.
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        std::iter::successors(self.parent(), SyntaxNode::parent)
+    }
+
+    /// The byte range of the node in the source, including any whitespace and comments in it.
+    pub fn range(&self) -> Range<usize> {
+        self.0.offset..self.0.offset + self.0.green.width()
+    }
+
+    pub fn children_with_tokens(&self) -> Vec<SyntaxElement> {
+        let mut offset = self.0.offset;
+
+        self.0
+            .green
+            .children()
+            .iter()
+            .map(|child| {
+                let element = match child {
+                    GreenElement::Node(node) => {
+                        SyntaxElement::Node(SyntaxNode::new_child(node.clone(), self.clone(), offset))
+                    }
+                    GreenElement::Token(token) => SyntaxElement::Token(SyntaxToken {
+                        green: token.clone(),
+                        parent: self.clone(),
+                        offset,
+                    }),
+                };
+
+                offset += child.width();
+                element
+            })
+            .collect()
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.children_with_tokens()
+            .into_iter()
+            .filter_map(SyntaxElement::into_node)
+    }
+
+    /// The node itself and every node below it, in the order they appear in the source.
+    pub fn descendants(&self) -> Vec<SyntaxNode> {
+        let mut nodes = vec![self.clone()];
+
+        for child in self.children() {
+            nodes.extend(child.descendants());
+        }
+
+        nodes
+    }
+
+    /// Every token below the node, in the order they appear in the source.
+    pub fn tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = Vec::new();
+
+        for child in self.children_with_tokens() {
+            match child {
+                SyntaxElement::Node(node) => tokens.extend(node.tokens()),
+                SyntaxElement::Token(token) => tokens.push(token),
+            }
+        }
+
+        tokens
+    }
+
+    /// The token that covers the given byte offset.
+    pub fn token_at_offset(&self, offset: usize) -> Option<SyntaxToken> {
+        for child in self.children_with_tokens() {
+            if !child.range().contains(&offset) {
+                continue;
+            }
+
+            return match child {
+                SyntaxElement::Node(node) => node.token_at_offset(offset),
+                SyntaxElement::Token(token) => Some(token),
+            };
+        }
+
+        None
+    }
+
+    /// The source text of the node, exactly as it was written.
+    pub fn text(&self) -> ByteString {
+        let mut buffer = Vec::with_capacity(self.0.green.width());
+        self.write_to(&mut buffer);
+
+        ByteString::new(buffer)
+    }
+
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        self.0.green.write_to(buffer);
+    }
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0.green, &other.0.green) && self.0.offset == other.0.offset
+    }
+}
+
+impl Eq for SyntaxNode {}
+
+impl fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxNode")
+            .field("id", &self.id())
+            .field("range", &self.range())
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct SyntaxToken {
+    green: Arc<GreenToken>,
+    parent: SyntaxNode,
+    offset: usize,
+}
+
+impl SyntaxToken {
+    pub fn kind(&self) -> &SyntaxKind {
+        self.green.kind()
+    }
+
+    pub fn text(&self) -> &ByteString {
+        self.green.text()
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.green.width()
+    }
+
+    pub fn parent(&self) -> SyntaxNode {
+        self.parent.clone()
+    }
+}
+
+impl fmt::Debug for SyntaxToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxToken")
+            .field("kind", self.kind())
+            .field("text", self.text())
+            .field("range", &self.range())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken),
+}
+
+impl SyntaxElement {
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            Self::Node(node) => node.range(),
+            Self::Token(token) => token.range(),
+        }
+    }
+
+    pub fn into_node(self) -> Option<SyntaxNode> {
+        match self {
+            Self::Node(node) => Some(node),
+            Self::Token(_) => None,
+        }
+    }
+
+    pub fn into_token(self) -> Option<SyntaxToken> {
+        match self {
+            Self::Node(_) => None,
+            Self::Token(token) => Some(token),
+        }
+    }
+}
@@ -1,12 +1,17 @@
 use std::{env::args, process::exit};
 
+use pxp_lexer::php::PhpToken;
+use pxp_lexer::stream::LazyTokenStream;
 use pxp_lexer::Lexer;
 
 fn main() {
     let args = args().skip(1).collect::<Vec<_>>();
 
     if args.is_empty() {
-        eprintln!("Usage: tokenise <file> [--debug, --times <n>]");
+        eprintln!(concat!(
+            "Usage: tokenise <file> ",
+            "[--debug, --lazy, --trivia, --token-get-all, --json, --times <n>]",
+        ));
         exit(1);
     }
 
@@ -19,17 +24,124 @@ fn main() {
         None => 1
     };
 
+    // Print the tokens as JSON, in the same shape as `json_encode(token_get_all(...))` with the
+    // token IDs swapped for their names.
+    if args.contains(&"--token-get-all".to_string()) {
+        let tokens = lexer.token_get_all(&contents[..]).unwrap();
+        let tokens = tokens
+            .iter()
+            .map(|token| match token {
+                PhpToken::Named { name, text, line } => {
+                    format!("[\"{}\",{},{}]", name, json_string(text), line)
+                }
+                PhpToken::Char(text) => json_string(text),
+            })
+            .collect::<Vec<_>>();
+
+        println!("[{}]", tokens.join(","));
+
+        return;
+    }
+
     let debug = args.contains(&"--debug".to_string());
+    let lazy = args.contains(&"--lazy".to_string());
+    let trivia = args.contains(&"--trivia".to_string());
+
+    if args.contains(&"--json".to_string()) {
+        json(&lexer, &contents, trivia);
+
+        return;
+    }
 
     for i in 0..times {
         if debug {
             println!("Tokenising {} (no. {i})...", file);
         }
 
+        // Pull the tokens through the same bounded stream that the parser uses, so only a few
+        // of them are in memory at a time.
+        if lazy {
+            let mut stream = LazyTokenStream::new(lexer.tokens(&contents[..]));
+
+            loop {
+                if debug {
+                    dbg!(stream.comments(), stream.current());
+                }
+
+                if stream.is_eof() {
+                    break;
+                }
+
+                stream.next();
+            }
+
+            if let Some(error) = stream.take_error() {
+                eprintln!("{}", error);
+                exit(1);
+            }
+
+            continue;
+        }
+
+        if trivia {
+            let tokens = lexer.tokenize_with_trivia(&contents[..]).unwrap();
+
+            if debug {
+                dbg!(tokens);
+            }
+
+            continue;
+        }
+
         let tokens = lexer.tokenize(&contents[..]).unwrap();
 
         if debug {
             dbg!(tokens);
         }
     }
-}
\ No newline at end of file
+}
+
+// Print the tokens as JSON, along with their trivia when `trivia` is set.
+#[cfg(feature = "serde")]
+fn json(lexer: &Lexer, contents: &[u8], trivia: bool) {
+    let json = if trivia {
+        serde_json::to_string(&lexer.tokenize_with_trivia(contents).unwrap())
+    } else {
+        serde_json::to_string(&lexer.tokenize(contents).unwrap())
+    };
+
+    println!("{}", json.unwrap());
+}
+
+#[cfg(not(feature = "serde"))]
+fn json(_: &Lexer, _: &[u8], _: bool) {
+    eprintln!("--json needs tokenise to be built with the `serde` feature");
+    exit(1);
+}
+
+// A JSON string with the given bytes in it, or an array of the bytes when they aren't valid
+// UTF-8, the same way a `ByteString` is serialized, so that nothing is lost.
+fn json_string(bytes: &[u8]) -> String {
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        let bytes = bytes.iter().map(u8::to_string).collect::<Vec<_>>();
+
+        return format!("[{}]", bytes.join(","));
+    };
+
+    let mut string = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            c if c.is_control() => string.push_str(&format!("\\u{:04x}", c as u32)),
+            c => string.push(c),
+        }
+    }
+
+    string.push('"');
+    string
+}
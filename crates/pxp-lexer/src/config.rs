@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+
+/// A version of PHP that the lexer can target.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub enum PhpVersion {
+    Php70,
+    Php71,
+    Php72,
+    Php73,
+    Php74,
+    Php80,
+    Php81,
+    Php82,
+    #[default]
+    Php83,
+}
+
+impl Display for PhpVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let version = match self {
+            Self::Php70 => "7.0",
+            Self::Php71 => "7.1",
+            Self::Php72 => "7.2",
+            Self::Php73 => "7.3",
+            Self::Php74 => "7.4",
+            Self::Php80 => "8.0",
+            Self::Php81 => "8.1",
+            Self::Php82 => "8.2",
+            Self::Php83 => "8.3",
+        };
+
+        write!(f, "{}", version)
+    }
+}
+
+/// Options that change how the lexer tokenizes source code.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LexerConfig {
+    /// The version of PHP being tokenized, which decides the keywords and numeric literal
+    /// syntax that are available.
+    pub php_version: PhpVersion,
+    /// Whether `<?` opens a block of PHP code, just like the `short_open_tag` ini setting.
+    pub short_open_tag: bool,
+}
+
+impl LexerConfig {
+    pub const fn new() -> Self {
+        Self {
+            php_version: PhpVersion::Php83,
+            short_open_tag: true,
+        }
+    }
+}
+
+impl Default for LexerConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
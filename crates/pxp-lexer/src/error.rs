@@ -17,6 +17,7 @@ pub enum SyntaxError {
     InvalidDocIndentation(Span),
     InvalidDocBodyIndentationLevel(usize, Span),
     UnrecognisedToken(u8, Span),
+    InvalidEdit(Span),
 }
 
 impl SyntaxError {
@@ -33,71 +34,57 @@ impl SyntaxError {
             Self::InvalidDocIndentation(span) => *span,
             Self::InvalidDocBodyIndentationLevel(_, span) => *span,
             Self::UnrecognisedToken(_, span) => *span,
+            Self::InvalidEdit(span) => *span,
+        }
+    }
+}
+
+impl SyntaxError {
+    /// What went wrong, without where it happened.
+    pub fn message(&self) -> String {
+        match self {
+            Self::UnexpectedEndOfFile(_) => "unexpected end of file".to_string(),
+            Self::UnexpectedError(_) => "unexpected error".to_string(),
+            Self::UnexpectedCharacter(char, _) => {
+                format!("unexpected character `{:?}`", *char as char)
+            }
+            Self::InvalidHaltCompiler(_) => "invalid halt compiler".to_string(),
+            Self::InvalidOctalEscape(_) => "invalid octal escape".to_string(),
+            Self::InvalidOctalLiteral(_) => "invalid octal literal".to_string(),
+            Self::InvalidUnicodeEscape(_) => "invalid unicode escape".to_string(),
+            Self::UnpredictableState(_) => "Reached an unpredictable state".to_string(),
+            Self::InvalidDocIndentation(_) => {
+                "Invalid indentation - cannot use tabs and spaces".to_string()
+            }
+            Self::InvalidDocBodyIndentationLevel(expected, _) => format!(
+                "Invalid body indentation level - expecting an indentation level of at least {}",
+                expected
+            ),
+            Self::UnrecognisedToken(token, _) => format!("Unrecognised token {}", token),
+            Self::InvalidEdit(_) => "the edit doesn't match the input".to_string(),
         }
     }
 }
 
 impl Display for SyntaxError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let span = self.span();
+
         match self {
-            Self::UnexpectedEndOfFile(span) => write!(
-                f,
-                "Syntax Error: unexpected end of file on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::UnexpectedError(span) => write!(
-                f,
-                "Syntax Error: unexpected error on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::UnexpectedCharacter(char, span) => write!(
-                f,
-                "Syntax Error: unexpected character `{:?}` on line {} column {}",
-                *char as char, span.start.line, span.start.column
-            ),
-            Self::InvalidHaltCompiler(span) => write!(
+            // The indentation of a heredoc is a property of the whole line.
+            Self::InvalidDocIndentation(_) | Self::InvalidDocBodyIndentationLevel(..) => write!(
                 f,
-                "Syntax Error: invalid halt compiler on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::InvalidOctalEscape(span) => write!(
-                f,
-                "Syntax Error: invalid octal escape on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::InvalidOctalLiteral(span) => write!(
-                f,
-                "Syntax Error: invalid octal literal on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::InvalidUnicodeEscape(span) => write!(
-                f,
-                "Syntax Error: invalid unicode escape on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::UnpredictableState(span) => write!(
-                f,
-                "Syntax Error: Reached an unpredictable state on line {} column {}",
-                span.start.line, span.start.column
-            ),
-            Self::InvalidDocIndentation(span) => write!(
-                f,
-                "Syntax Error: Invalid indentation - cannot use tabs and spaces on line {}",
+                "Syntax Error: {} on line {}",
+                self.message(),
                 span.start.line
             ),
-            Self::InvalidDocBodyIndentationLevel(expected, span) => write!(
+            _ => write!(
                 f,
-                "Syntax Error: Invalid body indentation level - expecting an indentation level of at least {} on line {}",
-                expected,
-                span.start.line
-            ),
-            Self::UnrecognisedToken(token, span) => write!(
-                f,
-                "Syntax Error: Unrecognised token {} on line {} column {}",
-                token,
+                "Syntax Error: {} on line {} column {}",
+                self.message(),
                 span.start.line,
                 span.start.column
-            )
+            ),
         }
     }
 }
@@ -0,0 +1,341 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use pxp_span::Position;
+use pxp_span::Span;
+use pxp_token::Token;
+use pxp_token::TokenKind;
+
+use crate::error::SyntaxError;
+use crate::error::SyntaxResult;
+use crate::state::source::Source;
+use crate::state::StackFrame;
+use crate::state::State;
+use crate::Lexer;
+
+// How many tokens there are between two checkpoints.
+const CHECKPOINT_INTERVAL: usize = 32;
+
+/// A replacement of a range of bytes in some source code.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub replacement: Vec<u8>,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, replacement: impl Into<Vec<u8>>) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Apply the edit to the given input, which fails if the range isn't inside of it.
+    pub fn apply(&self, input: &[u8]) -> SyntaxResult<Vec<u8>> {
+        if self.range.start > self.range.end || self.range.end > input.len() {
+            return Err(invalid_edit(input, self.range.start));
+        }
+
+        let mut output =
+            Vec::with_capacity(input.len() - self.range.len() + self.replacement.len());
+
+        output.extend_from_slice(&input[..self.range.start]);
+        output.extend_from_slice(&self.replacement);
+        output.extend_from_slice(&input[self.range.end..]);
+
+        Ok(output)
+    }
+}
+
+/// The tokens of some source code, along with periodic checkpoints of the lexer's state so
+/// that they can be brought up to date after an edit without tokenizing everything again.
+#[derive(Debug, Clone)]
+pub struct CheckpointedTokens<'a> {
+    input: &'a [u8],
+    tokens: Vec<Token<'a>>,
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl<'a> CheckpointedTokens<'a> {
+    pub fn tokens(&self) -> &[Token<'a>] {
+        &self.tokens
+    }
+
+    pub fn into_tokens(self) -> Vec<Token<'a>> {
+        self.tokens
+    }
+}
+
+/// The result of re-tokenizing some source code after an edit.
+#[derive(Debug, Clone)]
+pub struct Relexed<'a> {
+    pub tokens: CheckpointedTokens<'a>,
+    /// The range of tokens that were produced again, everything outside of it was reused.
+    pub changed: Range<usize>,
+    /// Where positions after the edit moved to.
+    pub shift: Shift,
+}
+
+// The state of the lexer right before it produced the token at index `token`.
+#[derive(Debug, Clone)]
+struct Checkpoint {
+    token: usize,
+    position: Position,
+    stack: VecDeque<StackFrame>,
+    // Everything the lexer looked at before reaching this checkpoint, tokens before the
+    // checkpoint can only be reused if none of those bytes were edited.
+    furthest: usize,
+}
+
+impl Lexer {
+    /// Tokenize the given input, keeping enough information around to cheaply update the
+    /// tokens with [`Lexer::relex`] after the input is edited.
+    pub fn tokenize_checkpointed<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &'a B,
+    ) -> SyntaxResult<CheckpointedTokens<'a>> {
+        let input = input.as_ref();
+        let mut state = State::new(Source::new(input));
+        let mut tokens = Vec::new();
+        let mut checkpoints = Vec::new();
+
+        self.lex_checkpointed(&mut state, &mut tokens, &mut checkpoints, |_, _| None::<()>)?;
+
+        Ok(CheckpointedTokens {
+            input,
+            tokens,
+            checkpoints,
+        })
+    }
+
+    /// Update the tokens of `previous` after `edit` was applied to it, producing `input`.
+    ///
+    /// The lexer restarts from the last checkpoint that wasn't affected by the edit and stops
+    /// as soon as its state matches a checkpoint after the edit, reusing the rest of the
+    /// previous tokens.
+    pub fn relex<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        previous: &CheckpointedTokens,
+        edit: &TextEdit,
+        input: &'a B,
+    ) -> SyntaxResult<Relexed<'a>> {
+        let input = input.as_ref();
+
+        // The edit has to be inside of the previous input, and applying it has to produce
+        // something as long as the new input.
+        let valid = edit.range.start <= edit.range.end
+            && edit.range.end <= previous.input.len()
+            && input.len() == previous.input.len() - edit.range.len() + edit.replacement.len();
+
+        if !valid {
+            return Err(invalid_edit(previous.input, edit.range.start));
+        }
+
+        // The first checkpoint is always at the very start, so there's always one to use.
+        let restart = previous
+            .checkpoints
+            .iter()
+            .rposition(|checkpoint| checkpoint.furthest <= edit.range.start)
+            .unwrap_or_default();
+        let checkpoint = &previous.checkpoints[restart];
+
+        // Everything before the checkpoint is untouched, it just needs to point at the new input.
+        let mut tokens = previous.tokens[..checkpoint.token]
+            .iter()
+            .map(|token| Token {
+                kind: token.kind.clone(),
+                span: token.span,
+                value: &input[token.span.start.offset..token.span.end.offset],
+            })
+            .collect::<Vec<_>>();
+        let mut checkpoints = previous.checkpoints[..restart].to_vec();
+
+        let mut state = State {
+            source: Source::starting_at(input, checkpoint.position, checkpoint.furthest),
+            stack: checkpoint.stack.clone(),
+        };
+
+        let old_end = advance(
+            checkpoint.position,
+            &previous.input[checkpoint.position.offset..edit.range.end],
+        );
+        let new_end = advance(
+            checkpoint.position,
+            &input[checkpoint.position.offset..edit.range.start + edit.replacement.len()],
+        );
+        let shift = Shift { old_end, new_end };
+
+        // Only checkpoints after the edit can be used to re-synchronise with the previous tokens.
+        let mut candidates = previous
+            .checkpoints
+            .iter()
+            .skip(restart + 1)
+            .filter(|checkpoint| checkpoint.position.offset > edit.range.end)
+            .peekable();
+
+        let synchronised = self.lex_checkpointed(
+            &mut state,
+            &mut tokens,
+            &mut checkpoints,
+            |state, tokens| {
+                let offset = state.source.position().offset;
+
+                while let Some(candidate) = candidates.peek() {
+                    let position = shift.offset(candidate.position.offset);
+
+                    if position > offset {
+                        return None;
+                    }
+
+                    let candidate = candidates.next()?;
+
+                    if position == offset && candidate.stack == state.stack {
+                        return Some((candidate, tokens));
+                    }
+                }
+
+                None
+            },
+        )?;
+
+        let changed = checkpoint.token..tokens.len();
+
+        if let Some((candidate, from)) = synchronised {
+            let reused = candidate.token;
+
+            tokens.extend(previous.tokens[reused..].iter().map(|token| {
+                let span = shift.span(token.span);
+
+                Token {
+                    kind: token.kind.clone(),
+                    span,
+                    value: &input[span.start.offset..span.end.offset],
+                }
+            }));
+
+            checkpoints.extend(
+                previous
+                    .checkpoints
+                    .iter()
+                    .filter(|checkpoint| checkpoint.token >= reused)
+                    .map(|checkpoint| Checkpoint {
+                        token: checkpoint.token - reused + from,
+                        position: shift.position(checkpoint.position),
+                        stack: checkpoint.stack.clone(),
+                        furthest: shift.offset(checkpoint.furthest),
+                    }),
+            );
+        }
+
+        Ok(Relexed {
+            tokens: CheckpointedTokens {
+                input,
+                tokens,
+                checkpoints,
+            },
+            changed,
+            shift,
+        })
+    }
+
+    // Tokenize until the end of the input, recording checkpoints along the way. `synchronise`
+    // is called before each step and stops tokenizing early by returning something.
+    fn lex_checkpointed<'a, T>(
+        &self,
+        state: &mut State<'a>,
+        tokens: &mut Vec<Token<'a>>,
+        checkpoints: &mut Vec<Checkpoint>,
+        mut synchronise: impl FnMut(&State<'a>, usize) -> Option<T>,
+    ) -> SyntaxResult<Option<T>> {
+        loop {
+            if let Some(result) = synchronise(state, tokens.len()) {
+                return Ok(Some(result));
+            }
+
+            let last = checkpoints.last().map(|checkpoint| checkpoint.token);
+
+            if last.is_none_or(|last| tokens.len() >= last + CHECKPOINT_INTERVAL) {
+                checkpoints.push(Checkpoint {
+                    token: tokens.len(),
+                    position: state.source.position(),
+                    stack: state.stack.clone(),
+                    furthest: state.source.furthest(),
+                });
+            }
+
+            if state.source.eof() || !self.step(state, tokens)? {
+                break;
+            }
+        }
+
+        state.source.start_token();
+
+        tokens.push(Token {
+            kind: TokenKind::Eof,
+            span: state.source.span(),
+            value: &[],
+        });
+
+        Ok(None)
+    }
+}
+
+/// Moves positions that come after an edit to where they are once it has been applied.
+///
+/// Only positions at or after the end of the edit can be moved, anything before it stays where
+/// it is and anything inside it is gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shift {
+    old_end: Position,
+    new_end: Position,
+}
+
+impl Shift {
+    pub fn offset(&self, offset: usize) -> usize {
+        offset - self.old_end.offset + self.new_end.offset
+    }
+
+    pub fn position(&self, position: Position) -> Position {
+        // Only the positions on the same line as the end of the edit move sideways.
+        let column = if position.line == self.old_end.line {
+            position.column - self.old_end.column + self.new_end.column
+        } else {
+            position.column
+        };
+
+        Position::new(
+            self.offset(position.offset),
+            position.line - self.old_end.line + self.new_end.line,
+            column,
+        )
+    }
+
+    pub fn span(&self, span: Span) -> Span {
+        Span::new(self.position(span.start), self.position(span.end))
+    }
+}
+
+// An error for an edit that doesn't match `input`, pointing at where it starts.
+fn invalid_edit(input: &[u8], offset: usize) -> SyntaxError {
+    let position = advance(Position::new(0, 1, 0), &input[..offset.min(input.len())]);
+
+    SyntaxError::InvalidEdit(Span::new(position, position))
+}
+
+// Work out the position after the given bytes, the same way `Source` does.
+fn advance(mut position: Position, bytes: &[u8]) -> Position {
+    for b in bytes {
+        match b {
+            b'\n' => {
+                position.line += 1;
+                position.column = 1;
+            }
+            _ => position.column += 1,
+        }
+
+        position.offset += 1;
+    }
+
+    position
+}
@@ -1,267 +1,333 @@
+use crate::config::LexerConfig;
+use crate::config::PhpVersion;
 use crate::error::SyntaxError;
 use crate::error::SyntaxResult;
+use crate::php::PhpToken;
 use crate::state::source::Source;
 use crate::state::StackFrame;
 use crate::state::State;
+use crate::tokens::Tokens;
 use pxp_bytestring::ByteString;
+use pxp_span::Position;
 use pxp_span::Span;
 use pxp_token::DocStringIndentationKind;
 use pxp_token::DocStringKind;
 use pxp_token::OpenTagKind;
 use pxp_token::Token;
 use pxp_token::TokenKind;
+use pxp_token::TriviaToken;
 
+pub mod config;
 pub mod error;
+pub mod incremental;
 pub mod macros;
+pub mod number;
+pub mod php;
+pub mod printer;
 pub mod state;
 pub mod stream;
+pub mod tokens;
+mod trivia;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub struct Lexer;
+pub struct Lexer {
+    config: LexerConfig,
+}
 
 impl Lexer {
     pub const fn new() -> Self {
-        Self {}
+        Self::with_config(LexerConfig::new())
+    }
+
+    pub const fn with_config(config: LexerConfig) -> Self {
+        Self { config }
+    }
+
+    pub const fn config(&self) -> &LexerConfig {
+        &self.config
+    }
+
+    pub fn tokenize<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &'a B,
+    ) -> SyntaxResult<Vec<Token<'a>>> {
+        self.tokens(input).collect()
+    }
+
+    /// Tokenize the given input as PHP code, as if it came straight after an opening tag.
+    pub fn tokenize_script<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &'a B,
+    ) -> SyntaxResult<Vec<Token<'a>>> {
+        Tokens::scripting(self.clone(), input.as_ref()).collect()
+    }
+
+    /// Lazily tokenize the given input, producing tokens as they are requested.
+    pub fn tokens<'a, B: ?Sized + AsRef<[u8]>>(&self, input: &'a B) -> Tokens<'a> {
+        Tokens::new(self.clone(), input.as_ref())
+    }
+
+    /// Tokenize the given input, keeping whitespace and comments as trivia attached to the
+    /// significant tokens around them.
+    ///
+    /// Writing out every token along with its trivia reproduces the input byte-for-byte.
+    pub fn tokenize_with_trivia<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &'a B,
+    ) -> SyntaxResult<Vec<TriviaToken<'a>>> {
+        trivia::attach(input.as_ref(), self.tokens(input))
     }
 
-    pub fn tokenize<B: ?Sized + AsRef<[u8]>>(&self, input: &B) -> SyntaxResult<Vec<Token>> {
+    /// Tokenize the given input into the same tokens that PHP's `token_get_all()` would return.
+    pub fn token_get_all<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &'a B,
+    ) -> SyntaxResult<Vec<PhpToken<'a>>> {
+        let tokens = self.tokenize(input)?;
+
+        Ok(php::token_get_all(input.as_ref(), &tokens))
+    }
+
+    /// Turn the given tokens back into source code, see [`printer::print`].
+    pub fn print(&self, tokens: &[Token]) -> Vec<u8> {
+        printer::print(self, tokens)
+    }
+
+    /// Tokenize the given input without stopping at the first syntax error.
+    ///
+    /// Any bytes that can't be tokenized are turned into a `TokenKind::Error` token and the
+    /// lexer carries on from the next byte. Every error encountered is returned alongside the
+    /// tokens, and the token stream is always terminated with a `TokenKind::Eof` token.
+    pub fn tokenize_recovering<'a, B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &'a B,
+    ) -> (Vec<Token<'a>>, Vec<SyntaxError>) {
         let mut state = State::new(Source::new(input.as_ref()));
         let mut tokens = Vec::new();
+        let mut errors = Vec::new();
 
         while !state.source.eof() {
-            state.source.start_token();
-
-            match state.frame()? {
-                // The "Initial" state is used to parse inline HTML. It is essentially a catch-all
-                // state that will build up a single token buffer until it encounters an open tag
-                // of some description.
-                StackFrame::Initial => self.initial(&mut state, &mut tokens)?,
-                // The scripting state is entered when an open tag is encountered in the source code.
-                // This tells the lexer to start analysing characters at PHP tokens instead of inline HTML.
-                StackFrame::Scripting => {
-                    self.skip_whitespace(&mut state);
-
-                    // If we have consumed whitespace and then reached the end of the file, we should break.
-                    if state.source.eof() {
-                        break;
+            match self.step(&mut state, &mut tokens) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(error) => {
+                    // Always consume at least one byte so that we're guaranteed to make progress.
+                    if state.source.value().is_empty() {
+                        state.source.next();
                     }
 
-                    tokens.push(self.scripting(&mut state)?);
-                }
-                // The "Halted" state is entered when the `__halt_compiler` token is encountered.
-                // In this state, all the text that follows is no longer parsed as PHP as is collected
-                // into a single "InlineHtml" token (kind of cheating, oh well).
-                StackFrame::Halted => {
                     tokens.push(Token {
-                        kind: TokenKind::InlineHtml,
+                        kind: TokenKind::Error,
                         span: state.source.span(),
-                        value: state.source.read_remaining().into(),
+                        value: state.source.value(),
                     });
-                    break;
-                }
-                // The double quote state is entered when inside a double-quoted string that
-                // contains variables.
-                StackFrame::DoubleQuote => self.double_quote(&mut state, &mut tokens)?,
-                // The shell exec state is entered when inside of a execution string (`).
-                StackFrame::ShellExec => self.shell_exec(&mut state, &mut tokens)?,
-                // The doc string state is entered when tokenizing heredocs and nowdocs.
-                StackFrame::DocString(kind, label, ..) => {
-                    let label = label.clone();
-
-                    match kind {
-                        DocStringKind::Heredoc => self.heredoc(&mut state, &mut tokens, label)?,
-                        DocStringKind::Nowdoc => self.nowdoc(&mut state, &mut tokens, label)?,
-                    }
-                }
-                // LookingForProperty is entered inside double quotes,
-                // backticks, or a heredoc, expecting a variable name.
-                // If one isn't found, it switches to scripting.
-                StackFrame::LookingForVarname => {
-                    if let Some(token) = self.looking_for_varname(&mut state)? {
-                        tokens.push(token);
-                    }
-                }
-                // LookingForProperty is entered inside double quotes,
-                // backticks, or a heredoc, expecting an arrow followed by a
-                // property name.
-                StackFrame::LookingForProperty => {
-                    tokens.push(self.looking_for_property(&mut state)?);
-                }
-                StackFrame::VarOffset => {
-                    if state.source.eof() {
-                        break;
-                    }
 
-                    tokens.push(self.var_offset(&mut state)?);
+                    errors.push(error);
                 }
             }
         }
 
+        state.source.start_token();
+
         tokens.push(Token {
             kind: TokenKind::Eof,
             span: state.source.span(),
-            value: ByteString::default(),
+            value: &[],
         });
 
-        Ok(tokens)
-    }
-
-    fn skip_whitespace(&self, state: &mut State) {
-        while let Some(true) = state.source.current().map(|u: &u8| u.is_ascii_whitespace()) {
-            state.source.next();
-        }
+        (tokens, errors)
     }
 
-    fn read_and_skip_whitespace(&self, state: &mut State) -> Vec<u8> {
-        let mut buffer = Vec::new();
-        while let Some(true) = state.source.current().map(|u: &u8| u.is_ascii_whitespace()) {
-            buffer.push(*state.source.current().unwrap());
-            state.source.next();
-        }
-        buffer
-    }
+    // Tokenize the next chunk of input for the current state, returning `false` once there
+    // is nothing left to tokenize.
+    fn step<'a>(&self, state: &mut State<'a>, tokens: &mut Vec<Token<'a>>) -> SyntaxResult<bool> {
+        state.source.start_token();
 
-    fn initial(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<()> {
-        let mut buffer = Vec::new();
-        while let Some(char) = state.source.current() {
-            if state.source.at_case_insensitive(b"<?php", 5) {
-                let inline_span = state.source.span();
+        match state.frame()? {
+            // The "Initial" state is used to parse inline HTML. It is essentially a catch-all
+            // state that will build up a single token buffer until it encounters an open tag
+            // of some description.
+            StackFrame::Initial => self.initial(state, tokens)?,
+            // The scripting state is entered when an open tag is encountered in the source code.
+            // This tells the lexer to start analysing characters at PHP tokens instead of inline HTML.
+            StackFrame::Scripting => {
+                self.skip_whitespace(state);
 
-                let tag = state.source.read_and_skip(5);
-                let tag_span = state.source.span();
+                // If we have consumed whitespace and then reached the end of the file, we should break.
+                if state.source.eof() {
+                    return Ok(false);
+                }
 
-                state.replace(StackFrame::Scripting);
+                state.source.start_token();
 
-                if !buffer.is_empty() {
-                    tokens.push(Token {
-                        kind: TokenKind::InlineHtml,
-                        span: inline_span,
-                        value: buffer.into(),
-                    });
-                }
+                tokens.push(self.scripting(state)?);
+            }
+            // The "Halted" state is entered when the `__halt_compiler` token is encountered.
+            // In this state, all the text that follows is no longer parsed as PHP as is collected
+            // into a single "InlineHtml" token (kind of cheating, oh well).
+            StackFrame::Halted => {
+                let value = state.source.read_remaining();
+                state.source.skip(value.len());
 
                 tokens.push(Token {
-                    kind: TokenKind::OpenTag(OpenTagKind::Full),
-                    span: tag_span,
-                    value: tag.into(),
+                    kind: TokenKind::InlineHtml,
+                    span: state.source.span(),
+                    value,
                 });
+                return Ok(false);
+            }
+            // The double quote state is entered when inside a double-quoted string that
+            // contains variables.
+            StackFrame::DoubleQuote => self.double_quote(state, tokens)?,
+            // The shell exec state is entered when inside of a execution string (`).
+            StackFrame::ShellExec => self.shell_exec(state, tokens)?,
+            // The doc string state is entered when tokenizing heredocs and nowdocs.
+            StackFrame::DocString(kind, label, ..) => {
+                let (kind, label) = (kind.clone(), label.clone());
 
-                return Ok(());
-            } else if state.source.at_case_insensitive(b"<?=", 3) {
-                let inline_span = state.source.span();
-
-                state.source.start_token();
-                state.source.skip(3);
-
-                let tag_span = state.source.span();
+                self.doc_string(state, tokens, kind, label)?
+            }
+            // LookingForProperty is entered inside double quotes,
+            // backticks, or a heredoc, expecting a variable name.
+            // If one isn't found, it switches to scripting.
+            StackFrame::LookingForVarname => {
+                if let Some(token) = self.looking_for_varname(state)? {
+                    tokens.push(token);
+                }
+            }
+            // LookingForProperty is entered inside double quotes,
+            // backticks, or a heredoc, expecting an arrow followed by a
+            // property name.
+            StackFrame::LookingForProperty => {
+                tokens.push(self.looking_for_property(state)?);
+            }
+            StackFrame::VarOffset => {
+                if state.source.eof() {
+                    return Ok(false);
+                }
 
-                state.replace(StackFrame::Scripting);
+                tokens.push(self.var_offset(state)?);
+            }
+        }
 
-                if !buffer.is_empty() {
-                    tokens.push(Token {
-                        kind: TokenKind::InlineHtml,
-                        span: inline_span,
-                        value: buffer.into(),
-                    });
-                }
+        Ok(true)
+    }
 
-                tokens.push(Token {
-                    kind: TokenKind::OpenTag(OpenTagKind::Echo),
-                    span: tag_span,
-                    value: b"<?=".into(),
-                });
+    // Some keywords were introduced in later versions of PHP, so they're just identifiers before that.
+    fn is_keyword(&self, kind: &TokenKind) -> bool {
+        let version = match kind {
+            TokenKind::Fn => PhpVersion::Php74,
+            TokenKind::Match => PhpVersion::Php80,
+            TokenKind::Enum | TokenKind::Readonly => PhpVersion::Php81,
+            _ => return true,
+        };
 
-                return Ok(());
-            } else if state.source.at_case_insensitive(b"<?", 2) {
-                let inline_span = state.source.span();
+        self.config.php_version >= version
+    }
 
-                state.source.start_token();
-                state.source.skip(2);
-                let tag_span = state.source.span();
+    fn skip_whitespace(&self, state: &mut State) {
+        while let Some(true) = state.source.current().map(|u: &u8| u.is_ascii_whitespace()) {
+            state.source.next();
+        }
+    }
 
-                state.replace(StackFrame::Scripting);
+    fn initial<'a>(&self, state: &mut State<'a>, tokens: &mut Vec<Token<'a>>) -> SyntaxResult<()> {
+        while !state.source.eof() {
+            let kind = if state.source.at_case_insensitive(b"<?php", 5) {
+                Some((OpenTagKind::Full, 5))
+            } else if state.source.at_case_insensitive(b"<?=", 3) {
+                Some((OpenTagKind::Echo, 3))
+            } else if self.config.short_open_tag && state.source.at_case_insensitive(b"<?", 2) {
+                Some((OpenTagKind::Short, 2))
+            } else {
+                None
+            };
 
-                if !buffer.is_empty() {
+            if let Some((kind, length)) = kind {
+                if state.source.span().start.offset != state.source.position().offset {
                     tokens.push(Token {
                         kind: TokenKind::InlineHtml,
-                        span: inline_span,
-                        value: buffer.into(),
+                        span: state.source.span(),
+                        value: state.source.value(),
                     });
                 }
 
+                state.source.start_token();
+                state.source.skip(length);
+                state.replace(StackFrame::Scripting);
+
                 tokens.push(Token {
-                    kind: TokenKind::OpenTag(OpenTagKind::Short),
-                    span: tag_span,
-                    value: b"<?".into(),
+                    kind: TokenKind::OpenTag(kind),
+                    span: state.source.span(),
+                    value: state.source.value(),
                 });
 
                 return Ok(());
             }
 
             state.source.next();
-            buffer.push(*char);
         }
 
         tokens.push(Token {
             kind: TokenKind::InlineHtml,
             span: state.source.span(),
-            value: buffer.into(),
+            value: state.source.value(),
         });
 
         Ok(())
     }
 
-    fn scripting(&self, state: &mut State) -> SyntaxResult<Token> {
-        let (kind, value): (TokenKind, ByteString) = match state.source.read(3) {
+    fn scripting<'a>(&self, state: &mut State<'a>) -> SyntaxResult<Token<'a>> {
+        let kind = match state.source.read(3) {
             [b'!', b'=', b'='] => {
                 state.source.skip(3);
 
-                (TokenKind::BangDoubleEquals, b"!==".into())
+                TokenKind::BangDoubleEquals
             }
             [b'?', b'?', b'='] => {
                 state.source.skip(3);
-                (TokenKind::DoubleQuestionEquals, b"??=".into())
+                TokenKind::DoubleQuestionEquals
             }
             [b'?', b'-', b'>'] => {
                 state.source.skip(3);
-                (TokenKind::QuestionArrow, b"?->".into())
+                TokenKind::QuestionArrow
             }
             [b'=', b'=', b'='] => {
                 state.source.skip(3);
-                (TokenKind::TripleEquals, b"===".into())
+                TokenKind::TripleEquals
             }
             [b'.', b'.', b'.'] => {
                 state.source.skip(3);
-                (TokenKind::Ellipsis, b"...".into())
+                TokenKind::Ellipsis
             }
             [b'`', ..] => {
                 state.source.next();
                 state.replace(StackFrame::ShellExec);
-                (TokenKind::Backtick, b"`".into())
+                TokenKind::Backtick
             }
             [b'@', ..] => {
                 state.source.next();
-                (TokenKind::At, b"@".into())
+                TokenKind::At
             }
             [b'!', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::BangEquals, b"!=".into())
+                TokenKind::BangEquals
             }
             [b'!', ..] => {
                 state.source.next();
-                (TokenKind::Bang, b"!".into())
+                TokenKind::Bang
             }
             [b'&', b'&', ..] => {
                 state.source.skip(2);
-                (TokenKind::BooleanAnd, b"&&".into())
+                TokenKind::BooleanAnd
             }
             [b'&', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::AmpersandEquals, b"&=".into())
+                TokenKind::AmpersandEquals
             }
             [b'&', ..] => {
                 state.source.next();
-                (TokenKind::Ampersand, b"&".into())
+                TokenKind::Ampersand
             }
             [b'?', b'>', ..] => {
                 // This is a close tag, we can enter "Initial" mode again.
@@ -269,31 +335,31 @@ impl Lexer {
 
                 state.replace(StackFrame::Initial);
 
-                (TokenKind::CloseTag, b"?>".into())
+                TokenKind::CloseTag
             }
             [b'?', b'?', ..] => {
                 state.source.skip(2);
-                (TokenKind::DoubleQuestion, b"??".into())
+                TokenKind::DoubleQuestion
             }
             [b'?', b':', ..] => {
                 state.source.skip(2);
-                (TokenKind::QuestionColon, b"?:".into())
+                TokenKind::QuestionColon
             }
             [b'?', ..] => {
                 state.source.next();
-                (TokenKind::Question, b"?".into())
+                TokenKind::Question
             }
             [b'=', b'>', ..] => {
                 state.source.skip(2);
-                (TokenKind::DoubleArrow, b"=>".into())
+                TokenKind::DoubleArrow
             }
             [b'=', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::DoubleEquals, b"==".into())
+                TokenKind::DoubleEquals
             }
             [b'=', ..] => {
                 state.source.next();
-                (TokenKind::Equals, b"=".into())
+                TokenKind::Equals
             }
             // Single quoted string.
             [b'\'', ..] => {
@@ -315,149 +381,107 @@ impl Lexer {
             [b'$', ident_start!(), ..] => self.tokenize_variable(state),
             [b'$', ..] => {
                 state.source.next();
-                (TokenKind::Dollar, b"$".into())
+                TokenKind::Dollar
             }
             [b'.', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::DotEquals, b".=".into())
+                TokenKind::DotEquals
             }
             [b'0'..=b'9', ..] => self.tokenize_number(state)?,
             [b'.', b'0'..=b'9', ..] => self.tokenize_number(state)?,
             [b'.', ..] => {
                 state.source.next();
-                (TokenKind::Dot, b".".into())
+                TokenKind::Dot
             }
             [b'\\', ident_start!(), ..] => {
                 state.source.next();
+                self.read_name(state);
 
-                match self.scripting(state)? {
-                    Token {
-                        kind: TokenKind::Identifier | TokenKind::QualifiedIdentifier,
-                        value,
-                        ..
-                    } => {
-                        let mut bytes = value;
-                        bytes.insert(0, b'\\');
-
-                        (TokenKind::FullyQualifiedIdentifier, bytes)
-                    }
-                    Token {
-                        kind: TokenKind::True,
-                        ..
-                    } => (TokenKind::FullyQualifiedIdentifier, b"\\true".into()),
-                    Token {
-                        kind: TokenKind::False,
-                        ..
-                    } => (TokenKind::FullyQualifiedIdentifier, b"\\false".into()),
-                    Token {
-                        kind: TokenKind::Null,
-                        ..
-                    } => (TokenKind::FullyQualifiedIdentifier, b"\\null".into()),
-                    s => unreachable!("{:?}", s),
-                }
+                TokenKind::FullyQualifiedIdentifier
             }
             [b'\\', ..] => {
                 state.source.next();
-                (TokenKind::NamespaceSeparator, b"\\".into())
+                TokenKind::NamespaceSeparator
             }
             [b'/', b'*', ..] => {
                 state.source.next();
-                let mut buffer = vec![b'/'];
 
                 loop {
                     match state.source.read(2) {
                         [b'*', b'/'] => {
                             state.source.skip(2);
-                            buffer.extend_from_slice(b"*/");
-                            break;
-                        }
-                        &[t, ..] => {
-                            state.source.next();
-                            buffer.push(t);
-                        }
-                        _ => {
                             break;
                         }
+                        [_, ..] => state.source.next(),
+                        _ => break,
                     }
                 }
 
-                if buffer.starts_with(b"/**") {
-                    (TokenKind::DocumentComment, buffer.into())
+                if state.source.value().starts_with(b"/**") {
+                    TokenKind::DocumentComment
                 } else {
-                    (TokenKind::MultiLineComment, buffer.into())
+                    TokenKind::MultiLineComment
                 }
             }
-            [b'#', b'[', ..] => {
+            [b'#', b'[', ..] if self.config.php_version >= PhpVersion::Php80 => {
                 state.source.skip(2);
-                (TokenKind::Attribute, b"#[".into())
+                TokenKind::Attribute
             }
             [ch @ b'/', b'/', ..] | [ch @ b'#', ..] => {
-                let mut buffer = if *ch == b'/' {
+                let kind = if *ch == b'/' {
                     state.source.skip(2);
-                    b"//".to_vec()
+                    TokenKind::SingleLineComment
                 } else {
                     state.source.next();
-                    b"#".to_vec()
+                    TokenKind::HashMarkComment
                 };
 
+                // The trailing new-line isn't part of the comment.
                 while let Some(c) = state.source.current() {
-                    if *c == b'\n' {
-                        state.source.next();
+                    if *c == b'\n' || state.source.read(2) == [b'?', b'>'] {
                         break;
                     }
 
-                    if state.source.read(2) == [b'?', b'>'] {
-                        break;
-                    }
-
-                    buffer.push(*c);
                     state.source.next();
                 }
 
-                if buffer.starts_with(b"#") {
-                    (TokenKind::HashMarkComment, buffer.into())
-                } else {
-                    (TokenKind::SingleLineComment, buffer.into())
-                }
+                kind
             }
             [b'/', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::SlashEquals, b"/=".into())
+                TokenKind::SlashEquals
             }
             [b'/', ..] => {
                 state.source.next();
-                (TokenKind::Slash, b"/".into())
+                TokenKind::Slash
             }
             [b'*', b'*', b'=', ..] => {
                 state.source.skip(3);
-                (TokenKind::PowEquals, b"**=".into())
+                TokenKind::PowEquals
             }
             [b'<', b'<', b'='] => {
                 state.source.skip(3);
 
-                (TokenKind::LeftShiftEquals, b"<<=".into())
+                TokenKind::LeftShiftEquals
             }
             [b'<', b'=', b'>'] => {
                 state.source.skip(3);
-                (TokenKind::Spaceship, b"<=>".into())
+                TokenKind::Spaceship
             }
             [b'>', b'>', b'='] => {
                 state.source.skip(3);
-                (TokenKind::RightShiftEquals, b">>=".into())
+                TokenKind::RightShiftEquals
             }
             [b'<', b'<', b'<'] => {
                 state.source.skip(3);
-                let mut buffer = b"<<<".to_vec();
-                buffer.extend(self.read_and_skip_whitespace(state));
+                self.skip_whitespace(state);
 
                 let doc_string_kind = match state.source.read(1) {
                     [b'\''] => {
-                        buffer.push(b'\'');
                         state.source.next();
                         DocStringKind::Nowdoc
                     }
                     [b'"'] => {
-                        buffer.push(b'"');
                         state.source.next();
                         DocStringKind::Heredoc
                     }
@@ -479,342 +503,192 @@ impl Lexer {
                     }
                 };
 
-                buffer.extend_from_slice(&label);
-
                 if doc_string_kind == DocStringKind::Nowdoc {
                     match state.source.current() {
-                        Some(b'\'') => {
-                            buffer.push(b'\'');
-                            state.source.next();
-                        }
-                        _ => {
-                            // FIXME: this is most likely a bug, what if current is none?
-                            return Err(SyntaxError::UnexpectedCharacter(
-                                *state.source.current().unwrap(),
-                                state.source.span(),
-                            ));
+                        Some(b'\'') => state.source.next(),
+                        Some(c) => {
+                            return Err(SyntaxError::UnexpectedCharacter(*c, state.source.span()))
                         }
+                        None => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
                     };
                 } else if let Some(b'"') = state.source.current() {
-                    buffer.push(b'"');
                     state.source.next();
                 }
 
-                if !matches!(state.source.current(), Some(b'\n')) {
-                    return Err(SyntaxError::UnexpectedCharacter(
-                        *state.source.current().unwrap(),
-                        state.source.span(),
-                    ));
+                match state.source.current() {
+                    Some(b'\n') => {}
+                    Some(c) => {
+                        return Err(SyntaxError::UnexpectedCharacter(*c, state.source.span()))
+                    }
+                    None => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
                 }
 
+                // The new-line following the label is part of the opening token.
                 state.source.next();
                 state.replace(StackFrame::DocString(
                     doc_string_kind.clone(),
-                    label.clone(),
+                    label,
                     DocStringIndentationKind::None,
                     0,
                 ));
 
-                (TokenKind::StartDocString(doc_string_kind), buffer.into())
+                TokenKind::StartDocString(doc_string_kind)
             }
             [b'*', b'*', ..] => {
                 state.source.skip(2);
-                (TokenKind::Pow, b"**".into())
+                TokenKind::Pow
             }
             [b'*', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::AsteriskEquals, b"*=".into())
+                TokenKind::AsteriskEquals
             }
             [b'*', ..] => {
                 state.source.next();
-                (TokenKind::Asterisk, b"*".into())
+                TokenKind::Asterisk
             }
             [b'|', b'|', ..] => {
                 state.source.skip(2);
-                (TokenKind::BooleanOr, b"||".into())
+                TokenKind::BooleanOr
             }
             [b'|', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::PipeEquals, b"|=".into())
+                TokenKind::PipeEquals
             }
             [b'|', ..] => {
                 state.source.next();
-                (TokenKind::Pipe, b"|".into())
+                TokenKind::Pipe
             }
             [b'^', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::CaretEquals, b"^=".into())
+                TokenKind::CaretEquals
             }
             [b'^', ..] => {
                 state.source.next();
-                (TokenKind::Caret, b"^".into())
+                TokenKind::Caret
             }
             [b'{', ..] => {
                 state.source.next();
                 state.enter(StackFrame::Scripting);
-                (TokenKind::LeftBrace, b"{".into())
+                TokenKind::LeftBrace
             }
             [b'}', ..] => {
                 state.source.next();
                 state.exit();
-                (TokenKind::RightBrace, b"}".into())
+                TokenKind::RightBrace
             }
-            [b'(', ..] => {
-                state.source.next();
-                let mut buffer = b"(".to_vec();
-
-                // Inlined so we can add whitespace to the buffer.
-                while let Some(true) = state.source.current().map(|u: &u8| u.is_ascii_whitespace())
-                {
-                    buffer.push(*state.source.current().unwrap());
-                    state.source.next();
+            [b'(', ..] => match self.cast(state) {
+                Some((kind, length)) => {
+                    state.source.skip(length);
+                    kind
                 }
-
-                if state.source.at_case_insensitive(b"int", 3) {
-                    if state.source.at_case_insensitive(b"integer", 7)
-                        && state.source.peek_ignoring_whitespace(7, 1) == [b')']
-                    {
-                        buffer.extend(state.source.read_and_skip(7));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::IntegerCast, buffer.into())
-                    } else if state.source.peek_ignoring_whitespace(3, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(3));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::IntCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"bool", 4) {
-                    if state.source.at_case_insensitive(b"boolean", 7)
-                        && state.source.peek_ignoring_whitespace(7, 1) == [b')']
-                    {
-                        buffer.extend(state.source.read_and_skip(7));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::BooleanCast, buffer.into())
-                    } else if state.source.peek_ignoring_whitespace(4, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(4));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::BoolCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"float", 5) {
-                    if state.source.peek_ignoring_whitespace(5, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(5));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::FloatCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"double", 6) {
-                    if state.source.peek_ignoring_whitespace(6, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(6));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::DoubleCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"real", 4) {
-                    if state.source.peek_ignoring_whitespace(4, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(4));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::RealCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"string", 6) {
-                    if state.source.peek_ignoring_whitespace(6, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(6));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::StringCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"binary", 6) {
-                    if state.source.peek_ignoring_whitespace(6, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(6));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::BinaryCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"array", 5) {
-                    if state.source.peek_ignoring_whitespace(5, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(5));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::ArrayCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"object", 6) {
-                    if state.source.peek_ignoring_whitespace(6, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(6));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::ObjectCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else if state.source.at_case_insensitive(b"unset", 5) {
-                    if state.source.peek_ignoring_whitespace(5, 1) == [b')'] {
-                        buffer.extend(state.source.read_and_skip(5));
-                        buffer.extend(self.read_and_skip_whitespace(state));
-                        buffer.extend(state.source.read_and_skip(1));
-
-                        (TokenKind::UnsetCast, buffer.into())
-                    } else {
-                        (TokenKind::LeftParen, buffer.into())
-                    }
-                } else {
-                    (TokenKind::LeftParen, buffer.into())
+                None => {
+                    state.source.next();
+                    TokenKind::LeftParen
                 }
-            }
+            },
             [b')', ..] => {
                 state.source.next();
-                (TokenKind::RightParen, b")".into())
+                TokenKind::RightParen
             }
             [b';', ..] => {
                 state.source.next();
-                (TokenKind::SemiColon, b";".into())
+                TokenKind::SemiColon
             }
             [b'+', b'+', ..] => {
                 state.source.skip(2);
-                (TokenKind::Increment, b"++".into())
+                TokenKind::Increment
             }
             [b'+', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::PlusEquals, b"+=".into())
+                TokenKind::PlusEquals
             }
             [b'+', ..] => {
                 state.source.next();
-                (TokenKind::Plus, b"+".into())
+                TokenKind::Plus
             }
             [b'%', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::PercentEquals, b"%=".into())
+                TokenKind::PercentEquals
             }
             [b'%', ..] => {
                 state.source.next();
-                (TokenKind::Percent, b"%".into())
+                TokenKind::Percent
             }
             [b'-', b'-', ..] => {
                 state.source.skip(2);
-                (TokenKind::Decrement, b"--".into())
+                TokenKind::Decrement
             }
             [b'-', b'>', ..] => {
                 state.source.skip(2);
-                (TokenKind::Arrow, b"->".into())
+                TokenKind::Arrow
             }
             [b'-', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::MinusEquals, b"-=".into())
+                TokenKind::MinusEquals
             }
             [b'-', ..] => {
                 state.source.next();
-                (TokenKind::Minus, b"-".into())
+                TokenKind::Minus
             }
             [b'<', b'<', ..] => {
                 state.source.skip(2);
-                (TokenKind::LeftShift, b"<<".into())
+                TokenKind::LeftShift
             }
             [b'<', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::LessThanEquals, b"<=".into())
+                TokenKind::LessThanEquals
             }
             [b'<', b'>', ..] => {
                 state.source.skip(2);
-                (TokenKind::AngledLeftRight, b"<>".into())
+                TokenKind::AngledLeftRight
             }
             [b'<', ..] => {
                 state.source.next();
-                (TokenKind::LessThan, b"<".into())
+                TokenKind::LessThan
             }
             [b'>', b'>', ..] => {
                 state.source.skip(2);
-                (TokenKind::RightShift, b">>".into())
+                TokenKind::RightShift
             }
             [b'>', b'=', ..] => {
                 state.source.skip(2);
-                (TokenKind::GreaterThanEquals, b">=".into())
+                TokenKind::GreaterThanEquals
             }
             [b'>', ..] => {
                 state.source.next();
-                (TokenKind::GreaterThan, b">".into())
+                TokenKind::GreaterThan
             }
             [b',', ..] => {
                 state.source.next();
-                (TokenKind::Comma, b",".into())
+                TokenKind::Comma
             }
             [b'[', ..] => {
                 state.source.next();
-                (TokenKind::LeftBracket, b"[".into())
+                TokenKind::LeftBracket
             }
             [b']', ..] => {
                 state.source.next();
-                (TokenKind::RightBracket, b"]".into())
+                TokenKind::RightBracket
             }
             [b':', b':', ..] => {
                 state.source.skip(2);
-                (TokenKind::DoubleColon, b"::".into())
+                TokenKind::DoubleColon
             }
             [b':', ..] => {
                 state.source.next();
-                (TokenKind::Colon, b":".into())
+                TokenKind::Colon
             }
             [b'~', ..] => {
                 state.source.next();
-                (TokenKind::BitwiseNot, b"~".into())
+                TokenKind::BitwiseNot
             }
-            [b @ ident_start!(), ..] => {
-                state.source.next();
-                let mut qualified = false;
-                let mut last_was_slash = false;
-
-                let mut buffer = vec![*b];
-                while let Some(next @ ident!() | next @ b'\\') = state.source.current() {
-                    if matches!(next, ident!()) {
-                        buffer.push(*next);
-                        state.source.next();
-                        last_was_slash = false;
-                        continue;
-                    }
-
-                    if *next == b'\\' && !last_was_slash {
-                        qualified = true;
-                        last_was_slash = true;
-                        buffer.push(*next);
-                        state.source.next();
-                        continue;
-                    }
-
-                    break;
-                }
-
-                if qualified {
-                    (TokenKind::QualifiedIdentifier, buffer.into())
+            [ident_start!(), ..] => {
+                if self.read_name(state) {
+                    TokenKind::QualifiedIdentifier
                 } else {
-                    let kind = identifier_to_keyword(&buffer).unwrap_or(TokenKind::Identifier);
+                    let kind = identifier_to_keyword(state.source.value())
+                        .filter(|kind| self.is_keyword(kind))
+                        .unwrap_or(TokenKind::Identifier);
 
                     if kind == TokenKind::HaltCompiler {
                         match state.source.read(3) {
@@ -826,595 +700,205 @@ impl Lexer {
                         }
                     }
 
-                    (kind, buffer.into())
+                    kind
                 }
             }
-            [b, ..] => unimplemented!(
-                "<scripting> char: {}, line: {}, col: {}",
-                *b as char,
-                state.source.span().start.line,
-                state.source.span().start.column
-            ),
+            &[b, ..] => return Err(SyntaxError::UnrecognisedToken(b, state.source.span())),
             // We should never reach this point since we have the empty checks surrounding
             // the call to this function, but it's better to be safe than sorry.
             [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
         };
 
-        Ok(Token { kind, span: state.source.span(), value })
+        Ok(Token {
+            kind,
+            span: state.source.span(),
+            value: state.source.value(),
+        })
     }
 
-    fn double_quote(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<()> {
-        let mut buffer = Vec::new();
-        let mut buffer_span = state.source.span();
-        
-        let (kind, value, span) = loop {
-            match state.source.read(3) {
-                [b'$', b'{', ..] => {
-                    buffer_span = state.source.span();
-                    state.source.start_token();
-                    state.source.skip(2);
-                    state.enter(StackFrame::LookingForVarname);
-                    break (TokenKind::DollarLeftBrace, b"${".into(), state.source.span());
-                }
-                [b'{', b'$', ..] => {
-                    buffer_span = state.source.span();
-                    state.source.start_token();
-                    // Intentionally only consume the left brace.
-                    state.source.next();
-                    state.enter(StackFrame::Scripting);
-                    break (TokenKind::LeftBrace, b"{".into(), state.source.span());
-                }
-                [b'"', ..] => {
-                    buffer_span = state.source.span();
-                    state.source.start_token();
-                    state.source.next();
-                    state.replace(StackFrame::Scripting);
-                    break (TokenKind::DoubleQuote, b'"'.into(), state.source.span());
-                }
-                &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b'\\', b'n', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\n');
-                }
-                &[b'\\', b'r', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\r');
-                }
-                &[b'\\', b't', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\t');
-                }
-                &[b'\\', b'v', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0b');
-                }
-                &[b'\\', b'e', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x1b');
-                }
-                &[b'\\', b'f', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0c');
-                }
-                &[b'\\', b'x', b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')] => {
-                    state.source.skip(3);
+    fn double_quote<'a>(
+        &self,
+        state: &mut State<'a>,
+        tokens: &mut Vec<Token<'a>>,
+    ) -> SyntaxResult<()> {
+        self.interpolated(state, tokens, b'"', TokenKind::DoubleQuote)
+    }
 
-                    let mut hex = String::from(b as char);
-                    if let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        hex.push(*b as char);
-                    }
+    fn shell_exec<'a>(
+        &self,
+        state: &mut State<'a>,
+        tokens: &mut Vec<Token<'a>>,
+    ) -> SyntaxResult<()> {
+        self.interpolated(state, tokens, b'`', TokenKind::Backtick)
+    }
 
-                    let b = u8::from_str_radix(&hex, 16).unwrap();
-                    buffer.push(b);
+    // Tokenizes the body of a double quoted or shell exec string, up until the next
+    // interpolated variable or expression, or the closing delimiter.
+    fn interpolated<'a>(
+        &self,
+        state: &mut State<'a>,
+        tokens: &mut Vec<Token<'a>>,
+        delimiter: u8,
+        closing: TokenKind,
+    ) -> SyntaxResult<()> {
+        let kind = loop {
+            match state.source.read(2) {
+                [b'$', b'{'] | [b'{', b'$'] | [b'$', ident_start!()] => {
+                    self.string_part(state, tokens);
+                    break self.interpolation(state);
                 }
-                &[b'\\', b'u', b'{'] => {
-                    state.source.skip(3);
-
-                    let mut code_point = String::new();
-                    while let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        code_point.push(*b as char);
-                    }
-
-                    if code_point.is_empty() || state.source.current() != Some(&b'}') {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
+                [b, ..] if *b == delimiter => {
+                    self.string_part(state, tokens);
                     state.source.next();
-
-                    let c = if let Ok(c) = u32::from_str_radix(&code_point, 16) {
-                        c
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    };
-
-                    if let Some(c) = char::from_u32(c) {
-                        let mut tmp = [0; 4];
-                        let bytes = c.encode_utf8(&mut tmp);
-                        buffer.extend(bytes.as_bytes());
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
+                    state.replace(StackFrame::Scripting);
+                    break closing;
                 }
-                &[b'\\', b @ b'0'..=b'7', ..] => {
-                    state.source.skip(2);
+                [b'\\', _] => state.source.skip(2),
+                [_, ..] => state.source.next(),
+                [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
+            }
+        };
 
-                    let mut octal = String::from(b as char);
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
+        tokens.push(Token {
+            kind,
+            span: state.source.span(),
+            value: state.source.value(),
+        });
 
-                    if let Ok(b) = u8::from_str_radix(&octal, 8) {
-                        buffer.push(b);
-                    } else {
-                        return Err(SyntaxError::InvalidOctalEscape(state.source.span()));
-                    }
-                }
-                [b'$', ident_start!(), ..] => {
-                    buffer_span = state.source.span();
-                    state.source.start_token();
-                    let mut var = state.source.read_and_skip(1).to_vec();
-                    var.extend(self.consume_identifier(state));
-
-                    match state.source.read(4) {
-                        [b'[', ..] => state.enter(StackFrame::VarOffset),
-                        [b'-', b'>', ident_start!(), ..] | [b'?', b'-', b'>', ident_start!()] => {
-                            state.enter(StackFrame::LookingForProperty)
-                        }
-                        _ => {}
-                    }
+        Ok(())
+    }
 
-                    break (TokenKind::Variable, var.into(), state.source.span());
+    fn doc_string<'a>(
+        &self,
+        state: &mut State<'a>,
+        tokens: &mut Vec<Token<'a>>,
+        doc_string_kind: DocStringKind,
+        label: ByteString,
+    ) -> SyntaxResult<()> {
+        let kind = loop {
+            // The closing label can only appear at the start of a line, optionally indented.
+            if state.source.previous() == Some(&b'\n') {
+                if let Some(kind) = self.doc_string_end(state, tokens, &label)? {
+                    break kind;
                 }
-                &[b, ..] => {
-                    state.source.next();
-                    buffer.push(b);
+            }
+
+            match state.source.read(2) {
+                [b'$', b'{'] | [b'{', b'$'] | [b'$', ident_start!()]
+                    if doc_string_kind == DocStringKind::Heredoc =>
+                {
+                    self.string_part(state, tokens);
+                    break self.interpolation(state);
                 }
+                [b'\\', _] if doc_string_kind == DocStringKind::Heredoc => state.source.skip(2),
+                [_, ..] => state.source.next(),
                 [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
             }
         };
 
-        if !buffer.is_empty() {
-            tokens.push(Token {
-                kind: TokenKind::StringPart,
-                span: buffer_span,
-                value: buffer.into(),
-            })
-        }
+        tokens.push(Token {
+            kind,
+            span: state.source.span(),
+            value: state.source.value(),
+        });
 
-        tokens.push(Token { kind, span, value });
         Ok(())
     }
 
-    fn shell_exec(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<()> {
-        let span = state.source.span();
-        let mut buffer = Vec::new();
-        let (kind, value) = loop {
-            match state.source.read(2) {
-                [b'$', b'{'] => {
-                    state.source.skip(2);
-                    state.enter(StackFrame::LookingForVarname);
-                    break (TokenKind::DollarLeftBrace, b"${".into());
-                }
-                [b'{', b'$'] => {
-                    // Intentionally only consume the left brace.
-                    state.source.next();
-                    state.enter(StackFrame::Scripting);
-                    break (TokenKind::LeftBrace, b"{".into());
-                }
-                [b'`', ..] => {
-                    state.source.next();
-                    state.replace(StackFrame::Scripting);
-                    break (TokenKind::Backtick, b"`".into());
-                }
-                [b'$', ident_start!()] => {
-                    let mut var = state.source.read_and_skip(1).to_vec();
-                    var.extend(self.consume_identifier(state));
-
-                    match state.source.read(4) {
-                        [b'[', ..] => state.enter(StackFrame::VarOffset),
-                        [b'-', b'>', ident_start!(), ..] | [b'?', b'-', b'>', ident_start!()] => {
-                            state.enter(StackFrame::LookingForProperty)
-                        }
-                        _ => {}
-                    }
+    // Checks for the closing label of a heredoc or nowdoc at the start of the current line,
+    // consuming the indentation and label if it's found.
+    fn doc_string_end<'a>(
+        &self,
+        state: &mut State<'a>,
+        tokens: &mut Vec<Token<'a>>,
+        label: &[u8],
+    ) -> SyntaxResult<Option<TokenKind>> {
+        let mut amount = 0;
+        while let [b' ' | b'\t'] = state.source.peek(amount, 1) {
+            amount += 1;
+        }
 
-                    break (TokenKind::Variable, var.into());
-                }
-                &[b, ..] => {
-                    state.source.next();
-                    buffer.push(b);
-                }
-                [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
-            }
+        if state.source.peek(amount, label.len()) != label
+            || matches!(state.source.peek(amount + label.len(), 1), [ident!()])
+        {
+            return Ok(None);
+        }
+
+        let indentation = state.source.peek(0, amount);
+        let indentation_kind = match indentation.first() {
+            None => DocStringIndentationKind::None,
+            Some(b' ') => DocStringIndentationKind::Space,
+            Some(_) => DocStringIndentationKind::Tab,
         };
 
-        if !buffer.is_empty() {
+        // Mixing spaces and tabs in the indentation of the closing label isn't allowed.
+        if indentation.iter().any(|b| Some(b) != indentation.first()) {
+            state.source.skip(amount);
+            return Err(SyntaxError::InvalidDocIndentation(state.source.span()));
+        }
+
+        self.string_part(state, tokens);
+        state.source.skip(amount + label.len());
+        state.replace(StackFrame::Scripting);
+
+        Ok(Some(TokenKind::EndDocString(indentation_kind, amount)))
+    }
+
+    // Pushes a `StringPart` token for anything consumed since the start of the current
+    // token, and starts a new token.
+    fn string_part<'a>(&self, state: &mut State<'a>, tokens: &mut Vec<Token<'a>>) {
+        if !state.source.value().is_empty() {
             tokens.push(Token {
                 kind: TokenKind::StringPart,
-                span,
-                value: buffer.into(),
-            })
+                span: state.source.span(),
+                value: state.source.value(),
+            });
         }
 
-        tokens.push(Token { kind, span, value });
-
-        Ok(())
+        state.source.start_token();
     }
 
-    fn heredoc(
-        &self,
-        state: &mut State,
-        tokens: &mut Vec<Token>,
-        label: ByteString,
-    ) -> SyntaxResult<()> {
-        let span = state.source.span();
-        let mut buffer: Vec<u8> = Vec::new();
+    // Consumes the start of an interpolated variable or expression inside of a string.
+    fn interpolation(&self, state: &mut State) -> TokenKind {
+        match state.source.read(2) {
+            [b'$', b'{'] => {
+                state.source.skip(2);
+                state.enter(StackFrame::LookingForVarname);
+                TokenKind::DollarLeftBrace
+            }
+            [b'{', b'$'] => {
+                // Intentionally only consume the left brace.
+                state.source.next();
+                state.enter(StackFrame::Scripting);
+                TokenKind::LeftBrace
+            }
+            _ => {
+                let kind = self.tokenize_variable(state);
 
-        let (kind, value) = loop {
-            match state.source.read(3) {
-                [b'$', b'{', ..] => {
-                    state.source.skip(2);
-                    state.enter(StackFrame::LookingForVarname);
-                    break (TokenKind::DollarLeftBrace, b"${".into());
-                }
-                [b'{', b'$', ..] => {
-                    // Intentionally only consume the left brace.
-                    state.source.next();
-                    state.enter(StackFrame::Scripting);
-                    break (TokenKind::LeftBrace, b"{".into());
-                }
-                &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b'\\', b'n', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\n');
-                }
-                &[b'\\', b'r', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\r');
-                }
-                &[b'\\', b't', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\t');
-                }
-                &[b'\\', b'v', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0b');
-                }
-                &[b'\\', b'e', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x1b');
-                }
-                &[b'\\', b'f', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0c');
-                }
-                &[b'\\', b'x', b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')] => {
-                    state.source.skip(3);
-
-                    let mut hex = String::from(b as char);
-                    if let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        hex.push(*b as char);
-                    }
-
-                    let b = u8::from_str_radix(&hex, 16).unwrap();
-                    buffer.push(b);
-                }
-                &[b'\\', b'u', b'{'] => {
-                    state.source.skip(3);
-
-                    let mut code_point = String::new();
-                    while let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        code_point.push(*b as char);
-                    }
-
-                    if code_point.is_empty() || state.source.current() != Some(&b'}') {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                    state.source.next();
-
-                    let c = if let Ok(c) = u32::from_str_radix(&code_point, 16) {
-                        c
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    };
-
-                    if let Some(c) = char::from_u32(c) {
-                        let mut tmp = [0; 4];
-                        let bytes = c.encode_utf8(&mut tmp);
-                        buffer.extend(bytes.as_bytes());
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                }
-                &[b'\\', b @ b'0'..=b'7', ..] => {
-                    state.source.skip(2);
-
-                    let mut octal = String::from(b as char);
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
+                match state.source.read(4) {
+                    [b'[', ..] => state.enter(StackFrame::VarOffset),
+                    [b'-', b'>', ident_start!(), ..] | [b'?', b'-', b'>', ident_start!()] => {
+                        state.enter(StackFrame::LookingForProperty)
                     }
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-
-                    if let Ok(b) = u8::from_str_radix(&octal, 8) {
-                        buffer.push(b);
-                    } else {
-                        return Err(SyntaxError::InvalidOctalEscape(state.source.span()));
-                    }
-                }
-                [b'$', ident_start!(), ..] => {
-                    let mut var = state.source.read_and_skip(1).to_vec();
-                    var.extend(self.consume_identifier(state));
-
-                    match state.source.read(4) {
-                        [b'[', ..] => state.enter(StackFrame::VarOffset),
-                        [b'-', b'>', ident_start!(), ..] | [b'?', b'-', b'>', ident_start!()] => {
-                            state.enter(StackFrame::LookingForProperty)
-                        }
-                        _ => {}
-                    }
-
-                    break (TokenKind::Variable, var.into());
+                    _ => {}
                 }
-                // If we find a new-line, we can start to check if we can see the EndHeredoc token.
-                [b'\n', ..] => {
-                    buffer.push(b'\n');
-                    state.source.next();
-
-                    // Check if we can see the closing label right here.
-                    if state.source.at(&label, label.len()) {
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(DocStringIndentationKind::None, 0),
-                            label,
-                        );
-                    }
-
-                    // Check if there's any whitespace first.
-                    let (whitespace_kind, whitespace_amount) = match state.source.read(1) {
-                        [b' '] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b' '] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Space, amount)
-                        }
-                        [b'\t'] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b'\t'] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Tab, amount)
-                        }
-                        _ => (DocStringIndentationKind::None, 0),
-                    };
-
-                    // We've figured out what type of whitespace was being used
-                    // at the start of the line.
-                    // We should now check for any extra whitespace, of any kind.
-                    let mut extra_whitespace_buffer = Vec::new();
-                    while let [b @ b' ' | b @ b'\t'] = state.source.read(1) {
-                        extra_whitespace_buffer.push(b);
-                        state.source.next();
-                    }
-
-                    // We've consumed all leading whitespace on this line now,
-                    // so let's try to read the label again.
-                    if state.source.at(&label, label.len()) {
-                        // We've found the label, finally! We need to do 1 last
-                        // check to make sure there wasn't a mixture of indentation types.
-                        if whitespace_kind != DocStringIndentationKind::None
-                            && !extra_whitespace_buffer.is_empty()
-                        {
-                            return Err(SyntaxError::InvalidDocIndentation(state.source.span()));
-                        }
-
-                        // If we get here, only 1 type of indentation was found. We can move
-                        // the process along by reading over the label and breaking out
-                        // with the EndHeredoc token, storing the kind and amount of whitespace.
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(whitespace_kind, whitespace_amount),
-                            label,
-                        );
-                    } else {
-                        // We didn't find the label. The buffer still needs to know about
-                        // the whitespace, so let's extend the buffer with the whitespace
-                        // and let the loop run again to handle the rest of the line.
-                        if whitespace_kind != DocStringIndentationKind::None {
-                            let whitespace_char: u8 = whitespace_kind.into();
-                            for _ in 0..whitespace_amount {
-                                buffer.push(whitespace_char);
-                            }
-                        }
-
-                        buffer.extend(extra_whitespace_buffer);
-                    }
-                }
-                &[b, ..] => {
-                    state.source.next();
-                    buffer.push(b);
-                }
-                [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
-            }
-        };
-
-        // Any trailing line breaks should be removed from the final heredoc.
-        if buffer.last() == Some(&b'\n') {
-            buffer.pop();
-        }
-
-        if !buffer.is_empty() {
-            tokens.push(Token {
-                kind: TokenKind::StringPart,
-                span,
-                value: buffer.into(),
-            })
-        }
-
-        tokens.push(Token { kind, span, value });
-
-        Ok(())
-    }
 
-    fn nowdoc(
-        &self,
-        state: &mut State,
-        tokens: &mut Vec<Token>,
-        label: ByteString,
-    ) -> SyntaxResult<()> {
-        let span = state.source.span();
-        let mut buffer: Vec<u8> = Vec::new();
-
-        let (kind, value) = loop {
-            match state.source.read(3) {
-                // If we find a new-line, we can start to check if we can see the EndHeredoc token.
-                [b'\n', ..] => {
-                    buffer.push(b'\n');
-                    state.source.next();
-
-                    // Check if we can see the closing label right here.
-                    if state.source.at(&label, label.len()) {
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(DocStringIndentationKind::None, 0),
-                            label,
-                        );
-                    }
-
-                    // Check if there's any whitespace first.
-                    let (whitespace_kind, whitespace_amount) = match state.source.read(1) {
-                        [b' '] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b' '] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Space, amount)
-                        }
-                        [b'\t'] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b'\t'] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Tab, amount)
-                        }
-                        _ => (DocStringIndentationKind::None, 0),
-                    };
-
-                    // We've figured out what type of whitespace was being used
-                    // at the start of the line.
-                    // We should now check for any extra whitespace, of any kind.
-                    let mut extra_whitespace_buffer = Vec::new();
-                    while let [b @ b' ' | b @ b'\t'] = state.source.read(1) {
-                        extra_whitespace_buffer.push(b);
-                        state.source.next();
-                    }
-
-                    // We've consumed all leading whitespace on this line now,
-                    // so let's try to read the label again.
-                    if state.source.at(&label, label.len()) {
-                        // We've found the label, finally! We need to do 1 last
-                        // check to make sure there wasn't a mixture of indentation types.
-                        if whitespace_kind != DocStringIndentationKind::None
-                            && !extra_whitespace_buffer.is_empty()
-                        {
-                            return Err(SyntaxError::InvalidDocIndentation(state.source.span()));
-                        }
-
-                        // If we get here, only 1 type of indentation was found. We can move
-                        // the process along by reading over the label and breaking out
-                        // with the EndHeredoc token, storing the kind and amount of whitespace.
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(whitespace_kind, whitespace_amount),
-                            label,
-                        );
-                    } else {
-                        // We didn't find the label. The buffer still needs to know about
-                        // the whitespace, so let's extend the buffer with the whitespace
-                        // and let the loop run again to handle the rest of the line.
-                        if whitespace_kind != DocStringIndentationKind::None {
-                            let whitespace_char: u8 = whitespace_kind.into();
-                            for _ in 0..whitespace_amount {
-                                buffer.push(whitespace_char);
-                            }
-                        }
-
-                        buffer.extend(extra_whitespace_buffer);
-                    }
-                }
-                &[b, ..] => {
-                    state.source.next();
-                    buffer.push(b);
-                }
-                [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
+                kind
             }
-        };
-
-        // Any trailing line breaks should be removed from the final heredoc.
-        if buffer.last() == Some(&b'\n') {
-            buffer.pop();
-        }
-
-        if !buffer.is_empty() {
-            tokens.push(Token {
-                kind: TokenKind::StringPart,
-                span,
-                value: buffer.into(),
-            })
         }
-
-        tokens.push(Token { kind, span, value });
-
-        Ok(())
     }
 
-    fn looking_for_varname(&self, state: &mut State) -> SyntaxResult<Option<Token>> {
+    fn looking_for_varname<'a>(&self, state: &mut State<'a>) -> SyntaxResult<Option<Token<'a>>> {
         let identifier = self.peek_identifier(state);
 
         if let Some(ident) = identifier {
             if let [b'[' | b'}'] = state.source.peek(ident.len(), 1) {
-                let ident = ident.to_vec();
-                let span = state.source.span();
                 state.source.skip(ident.len());
                 state.replace(StackFrame::Scripting);
                 return Ok(Some(Token {
                     kind: TokenKind::Identifier,
-                    span,
-                    value: ident.into(),
+                    span: state.source.span(),
+                    value: state.source.value(),
                 }));
             }
         }
@@ -1424,214 +908,166 @@ impl Lexer {
         Ok(None)
     }
 
-    fn looking_for_property(&self, state: &mut State) -> SyntaxResult<Token> {
-        let span = state.source.span();
-        let (kind, value) = match state.source.read(3) {
+    fn looking_for_property<'a>(&self, state: &mut State<'a>) -> SyntaxResult<Token<'a>> {
+        let kind = match state.source.read(3) {
             [b'?', b'-', b'>'] => {
                 state.source.skip(3);
-                (TokenKind::QuestionArrow, b"?->".into())
+                TokenKind::QuestionArrow
             }
             [b'-', b'>', ..] => {
                 state.source.skip(2);
-                (TokenKind::Arrow, b"->".into())
+                TokenKind::Arrow
             }
             &[ident_start!(), ..] => {
-                let buffer = self.consume_identifier(state);
+                self.consume_identifier(state);
+                state.exit();
+                TokenKind::Identifier
+            }
+            // Should be impossible as we already looked ahead this far inside double_quote,
+            // but we bail out of the frame anyway so that we don't get stuck here.
+            &[b, ..] => {
                 state.exit();
-                (TokenKind::Identifier, buffer.into())
+                return Err(SyntaxError::UnexpectedCharacter(b, state.source.span()));
             }
-            // Should be impossible as we already looked ahead this far inside double_quote.
-            _ => unreachable!(),
+            [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
         };
 
-        Ok(Token { kind, span, value })
+        Ok(Token {
+            kind,
+            span: state.source.span(),
+            value: state.source.value(),
+        })
     }
 
-    fn var_offset(&self, state: &mut State) -> SyntaxResult<Token> {
-        let span = state.source.span();
-        let (kind, value) = match state.source.read(2) {
+    fn var_offset<'a>(&self, state: &mut State<'a>) -> SyntaxResult<Token<'a>> {
+        let kind = match state.source.read(2) {
             [b'$', ident_start!()] => self.tokenize_variable(state),
             [b'0'..=b'9', ..] => {
-                // FIXME: all integer literals are allowed, but only decimal integers with no underscores
-                // are actually treated as numbers. Others are treated as strings.
-                // Float literals are not allowed, but that could be handled in the parser.
-                self.tokenize_number(state)?
+                // Only decimal integers are treated as numbers here, anything else is used
+                // as a string key, so the parser decides what to do with the raw digits.
+                while let Some(b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'_') =
+                    state.source.current()
+                {
+                    state.source.next();
+                }
+
+                TokenKind::LiteralInteger
             }
             [b'[', ..] => {
                 state.source.next();
-                (TokenKind::LeftBracket, b"[".into())
+                TokenKind::LeftBracket
             }
             [b'-', ..] => {
                 state.source.next();
-                (TokenKind::Minus, b"-".into())
+                TokenKind::Minus
             }
             [b']', ..] => {
                 state.source.next();
                 state.exit();
-                (TokenKind::RightBracket, b"]".into())
+                TokenKind::RightBracket
             }
             &[ident_start!(), ..] => {
-                let label = self.consume_identifier(state);
-                (TokenKind::Identifier, label.into())
+                self.consume_identifier(state);
+                TokenKind::Identifier
             }
             &[b, ..] => return Err(SyntaxError::UnrecognisedToken(b, state.source.span())),
             [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
         };
-        Ok(Token { kind, span, value })
-    }
 
-    fn tokenize_single_quote_string(
-        &self,
-        state: &mut State,
-    ) -> SyntaxResult<(TokenKind, ByteString)> {
-        let mut buffer = vec![];
+        Ok(Token {
+            kind,
+            span: state.source.span(),
+            value: state.source.value(),
+        })
+    }
 
+    fn tokenize_single_quote_string(&self, state: &mut State) -> SyntaxResult<TokenKind> {
         loop {
             match state.source.read(2) {
                 [b'\'', ..] => {
                     state.source.next();
                     break;
                 }
-                &[b'\\', b @ b'\'' | b @ b'\\'] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b, ..] => {
-                    state.source.next();
-                    buffer.push(b);
-                }
+                [b'\\', _] => state.source.skip(2),
+                [_, ..] => state.source.next(),
                 [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
             }
         }
 
-        Ok((TokenKind::LiteralSingleQuotedString, buffer.into()))
+        Ok(TokenKind::LiteralSingleQuotedString)
     }
 
-    fn tokenize_double_quote_string(
-        &self,
-        state: &mut State,
-    ) -> SyntaxResult<(TokenKind, ByteString)> {
-        state.source.start_token();
-        let mut buffer = vec![];
+    // Double quoted strings without any interpolation are tokenized as a single literal.
+    // Otherwise, only the opening quote is consumed and the rest of the string is
+    // tokenized in the `DoubleQuote` state.
+    fn tokenize_double_quote_string(&self, state: &mut State) -> SyntaxResult<TokenKind> {
+        let mut length = 0;
 
-        let constant = loop {
-            match state.source.read(3) {
+        loop {
+            match state.source.peek(length, 2) {
                 [b'"', ..] => {
-                    state.source.next();
-                    break true;
-                }
-                &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b'\\', b'n', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\n');
-                }
-                &[b'\\', b'r', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\r');
-                }
-                &[b'\\', b't', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\t');
-                }
-                &[b'\\', b'v', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0b');
-                }
-                &[b'\\', b'e', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x1b');
-                }
-                &[b'\\', b'f', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0c');
+                    state.source.skip(length + 1);
+                    return Ok(TokenKind::LiteralDoubleQuotedString);
                 }
-                &[b'\\', b'x', b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')] => {
-                    state.source.skip(3);
-
-                    let mut hex = String::from(b as char);
-                    if let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        hex.push(*b as char);
-                    }
-
-                    let b = u8::from_str_radix(&hex, 16).unwrap();
-                    buffer.push(b);
+                [b'$', ident_start!()] | [b'{', b'$'] | [b'$', b'{'] => {
+                    state.replace(StackFrame::DoubleQuote);
+                    return Ok(TokenKind::DoubleQuote);
                 }
-                &[b'\\', b'u', b'{'] => {
-                    state.source.skip(3);
-
-                    let mut code_point = String::new();
-                    while let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        code_point.push(*b as char);
-                    }
-
-                    if code_point.is_empty() || state.source.current() != Some(&b'}') {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                    state.source.next();
-
-                    let c = if let Ok(c) = u32::from_str_radix(&code_point, 16) {
-                        c
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    };
-
-                    if let Some(c) = char::from_u32(c) {
-                        let mut tmp = [0; 4];
-                        let bytes = c.encode_utf8(&mut tmp);
-                        buffer.extend(bytes.as_bytes());
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
+                [b'\\', _] => length += 2,
+                [_, ..] => length += 1,
+                [] => {
+                    state.source.skip(length);
+                    return Err(SyntaxError::UnexpectedEndOfFile(state.source.span()));
                 }
-                &[b'\\', b @ b'0'..=b'7', ..] => {
-                    state.source.skip(2);
-
-                    let mut octal = String::from(b as char);
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
+            }
+        }
+    }
 
-                    if let Ok(b) = u8::from_str_radix(&octal, 8) {
-                        buffer.push(b);
-                    } else {
-                        return Err(SyntaxError::InvalidOctalEscape(state.source.span()));
-                    }
-                }
-                [b'$', ident_start!(), ..] | [b'{', b'$', ..] | [b'$', b'{', ..] => {
-                    break false;
-                }
-                &[b, ..] => {
-                    state.source.next();
-                    buffer.push(b);
-                }
-                [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
+    // Looks ahead for a cast such as `( int )`, returning the kind of cast and the number
+    // of bytes it spans without consuming anything.
+    fn cast(&self, state: &State) -> Option<(TokenKind, usize)> {
+        let whitespace = |mut i: usize| {
+            while let [b' ' | b'\t' | b'\r' | b'\n'] = state.source.peek(i, 1) {
+                i += 1;
             }
+            i
         };
 
-        Ok(if constant {
-            (TokenKind::LiteralDoubleQuotedString, buffer.into())
-        } else {
-            state.replace(StackFrame::DoubleQuote);
-            (TokenKind::StringPart, buffer.into())
-        })
+        let start = whitespace(1);
+
+        for (name, kind) in [
+            (&b"integer"[..], TokenKind::IntegerCast),
+            (b"int", TokenKind::IntCast),
+            (b"boolean", TokenKind::BooleanCast),
+            (b"bool", TokenKind::BoolCast),
+            (b"float", TokenKind::FloatCast),
+            (b"double", TokenKind::DoubleCast),
+            (b"real", TokenKind::RealCast),
+            (b"string", TokenKind::StringCast),
+            (b"binary", TokenKind::BinaryCast),
+            (b"array", TokenKind::ArrayCast),
+            (b"object", TokenKind::ObjectCast),
+            (b"unset", TokenKind::UnsetCast),
+        ] {
+            if !state
+                .source
+                .peek(start, name.len())
+                .eq_ignore_ascii_case(name)
+            {
+                continue;
+            }
+
+            let end = whitespace(start + name.len());
+
+            if state.source.peek(end, 1) == b")" {
+                return Some((kind, end + 1));
+            }
+        }
+
+        None
     }
 
-    fn peek_identifier<'a>(&'a self, state: &'a State) -> Option<&'a [u8]> {
+    fn peek_identifier<'a>(&self, state: &State<'a>) -> Option<&'a [u8]> {
         let mut size = 0;
 
         if let [ident_start!()] = state.source.read(1) {
@@ -1646,38 +1082,58 @@ impl Lexer {
         }
     }
 
-    fn consume_identifier(&self, state: &mut State) -> Vec<u8> {
-        let ident = self.peek_identifier(state).unwrap().to_vec();
+    // Consumes an identifier that may contain namespace separators, returning whether
+    // or not it was qualified.
+    fn read_name(&self, state: &mut State) -> bool {
+        let mut qualified = false;
+        let mut last_was_slash = false;
+
+        while let Some(next @ ident!() | next @ b'\\') = state.source.current() {
+            if matches!(next, ident!()) {
+                state.source.next();
+                last_was_slash = false;
+                continue;
+            }
+
+            if *next == b'\\' && !last_was_slash {
+                qualified = true;
+                last_was_slash = true;
+                state.source.next();
+                continue;
+            }
+
+            break;
+        }
+
+        qualified
+    }
+
+    fn consume_identifier<'a>(&self, state: &mut State<'a>) -> &'a [u8] {
+        let ident = self.peek_identifier(state).unwrap_or_default();
         state.source.skip(ident.len());
 
         ident
     }
 
-    fn tokenize_variable(&self, state: &mut State) -> (TokenKind, ByteString) {
-        let mut var = state.source.read_and_skip(1).to_vec();
-        var.extend(self.consume_identifier(state));
-        (TokenKind::Variable, var.into())
-    }
+    fn tokenize_variable(&self, state: &mut State) -> TokenKind {
+        state.source.next();
+        self.consume_identifier(state);
 
-    fn tokenize_number(&self, state: &mut State) -> SyntaxResult<(TokenKind, ByteString)> {
-        let mut buffer = Vec::new();
+        TokenKind::Variable
+    }
 
-        let (base, kind) = match state.source.read(2) {
-            [a @ b'0', b @ b'B' | b @ b'b'] => {
-                buffer.push(*a);
-                buffer.push(*b);
+    fn tokenize_number(&self, state: &mut State) -> SyntaxResult<TokenKind> {
+        // A prefix is only recognised when it's followed by a digit, `0b2` is `0` followed by `b2`.
+        let (base, kind) = match state.source.read(3) {
+            [b'0', b'B' | b'b', b'0' | b'1'] => {
                 state.source.skip(2);
                 (2, NumberKind::Int)
             }
-            [a @ b'0', b @ b'O' | b @ b'o'] => {
-                buffer.push(*a);
-                buffer.push(*b);
+            [b'0', b'O' | b'o', b'0'..=b'7'] if self.config.php_version >= PhpVersion::Php81 => {
                 state.source.skip(2);
                 (8, NumberKind::Int)
             }
-            [a @ b'0', b @ b'X' | b @ b'x'] => {
-                buffer.push(*a);
-                buffer.push(*b);
+            [b'0', b'X' | b'x', b] if b.is_ascii_hexdigit() => {
                 state.source.skip(2);
                 (16, NumberKind::Int)
             }
@@ -1687,9 +1143,14 @@ impl Lexer {
         };
 
         if kind != NumberKind::Float {
-            self.read_digits(state, &mut buffer, base);
+            self.read_digits(state, base);
+
             if kind == NumberKind::Int {
-                return parse_int(&buffer);
+                if base == 8 && matches!(state.source.current(), Some(b'8' | b'9')) {
+                    return Err(self.invalid_octal_literal(state));
+                }
+
+                return Ok(self.integer_or_float(state));
             }
         }
 
@@ -1700,47 +1161,77 @@ impl Lexer {
         );
 
         if !is_float {
-            return parse_int(&buffer);
+            if kind == NumberKind::OctalOrFloat
+                && state
+                    .source
+                    .value()
+                    .iter()
+                    .any(|b| matches!(b, b'8' | b'9'))
+            {
+                return Err(self.invalid_octal_literal(state));
+            }
+
+            return Ok(self.integer_or_float(state));
         }
 
         if let Some(b'.') = state.source.current() {
-            buffer.push(b'.');
             state.source.next();
-            self.read_digits(state, &mut buffer, 10);
+            self.read_digits(state, 10);
         }
 
         if let Some(b'e' | b'E') = state.source.current() {
-            buffer.push(b'e');
             state.source.next();
-            if let Some(b @ (b'-' | b'+')) = state.source.current() {
-                buffer.push(*b);
+            if let Some(b'-' | b'+') = state.source.current() {
                 state.source.next();
             }
-            self.read_digits(state, &mut buffer, 10);
+            self.read_digits(state, 10);
         }
 
-        Ok((TokenKind::LiteralFloat, buffer.into()))
+        Ok(TokenKind::LiteralFloat)
+    }
+
+    // PHP turns integer literals that are too large for an integer into floats.
+    fn integer_or_float(&self, state: &State) -> TokenKind {
+        match number::integer(state.source.value()) {
+            Some(_) => TokenKind::LiteralInteger,
+            None => TokenKind::LiteralFloat,
+        }
     }
 
-    fn read_digits(&self, state: &mut State, buffer: &mut Vec<u8>, base: usize) {
+    // Consume the rest of an octal literal, pointing the error at the first digit that isn't valid.
+    fn invalid_octal_literal(&self, state: &mut State) -> SyntaxError {
+        self.read_digits(state, 10);
+
+        let start = state.source.span().start;
+        let index = state
+            .source
+            .value()
+            .iter()
+            .position(|b| matches!(b, b'8' | b'9'))
+            .unwrap_or_default();
+
+        // Numbers never span multiple lines, so the column moves along with the offset.
+        let position = Position::new(start.offset + index, start.line, start.column + index);
+
+        SyntaxError::InvalidOctalLiteral(Span::new(
+            position,
+            Position::new(position.offset + 1, position.line, position.column + 1),
+        ))
+    }
+
+    fn read_digits(&self, state: &mut State, base: usize) {
         if base == 16 {
-            self.read_digits_fn(state, buffer, u8::is_ascii_hexdigit);
+            self.read_digits_fn(state, u8::is_ascii_hexdigit);
         } else {
             let max = b'0' + base as u8;
-            self.read_digits_fn(state, buffer, |b| (b'0'..max).contains(b));
+            self.read_digits_fn(state, |b| (b'0'..max).contains(b));
         };
     }
 
-    fn read_digits_fn<F: Fn(&u8) -> bool>(
-        &self,
-        state: &mut State,
-        buffer: &mut Vec<u8>,
-        is_digit: F,
-    ) {
+    fn read_digits_fn<F: Fn(&u8) -> bool>(&self, state: &mut State, is_digit: F) {
         if let Some(b) = state.source.current() {
             if is_digit(b) {
                 state.source.next();
-                buffer.push(*b);
             } else {
                 return;
             }
@@ -1750,12 +1241,9 @@ impl Lexer {
             match state.source.read(2) {
                 [b, ..] if is_digit(b) => {
                     state.source.next();
-                    buffer.push(*b);
                 }
-                [b'_', b] if is_digit(b) => {
-                    state.source.next();
-                    state.source.next();
-                    buffer.push(*b);
+                [b'_', b] if is_digit(b) && self.config.php_version >= PhpVersion::Php74 => {
+                    state.source.skip(2);
                 }
                 _ => {
                     break;
@@ -1765,12 +1253,6 @@ impl Lexer {
     }
 }
 
-// Parses an integer literal in the given base and converts errors to SyntaxError.
-// It returns a float token instead on overflow.
-fn parse_int(buffer: &[u8]) -> SyntaxResult<(TokenKind, ByteString)> {
-    Ok((TokenKind::LiteralInteger, buffer.into()))
-}
-
 #[inline(always)]
 fn identifier_to_keyword(ident: &[u8]) -> Option<TokenKind> {
     Some(match ident.to_ascii_lowercase().as_slice() {
@@ -1870,3 +1352,376 @@ enum NumberKind {
     IntOrFloat,
     OctalOrFloat,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::incremental::TextEdit;
+    use pxp_token::TriviaKind;
+
+    #[test]
+    fn test_tokenize_script() {
+        let tokens = Lexer::new().tokenize_script("$a ?> <p>").unwrap();
+        let kinds = tokens.iter().map(|token| token.kind.clone()).collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Variable,
+                TokenKind::CloseTag,
+                TokenKind::InlineHtml,
+                TokenKind::Eof
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_recovering() {
+        let (tokens, errors) =
+            Lexer::new().tokenize_recovering("<?php $a = 1 \x01 2; } echo $b \x02;");
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            tokens
+                .iter()
+                .filter(|token| token.kind == TokenKind::Error)
+                .count(),
+            2
+        );
+        assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+        assert!(tokens.iter().any(|token| token.value == b"$b"));
+
+        let (tokens, errors) = Lexer::new().tokenize_recovering("<?php <<<'FOO");
+
+        assert_eq!(
+            errors,
+            vec![SyntaxError::UnexpectedEndOfFile(errors[0].span())]
+        );
+        assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+    }
+
+    #[test]
+    fn test_tokenize_with_trivia() {
+        let input =
+            "<html>\n<?php   // one\n\t/** two */\nfunction  foo() { # three\n  return 1;\n}\n\n";
+        let tokens = Lexer::new().tokenize_with_trivia(input).unwrap();
+
+        let mut output = Vec::new();
+        for token in &tokens {
+            token.write_to(&mut output);
+        }
+
+        assert_eq!(output, input.as_bytes());
+
+        let function = tokens
+            .iter()
+            .find(|token| token.token.kind == TokenKind::Function)
+            .unwrap();
+
+        assert_eq!(
+            function
+                .leading
+                .iter()
+                .map(|trivia| trivia.value)
+                .collect::<Vec<_>>(),
+            vec![&b"\t"[..], b"/** two */", b"\n"]
+        );
+
+        let brace = tokens
+            .iter()
+            .find(|token| token.token.kind == TokenKind::LeftBrace)
+            .unwrap();
+
+        assert_eq!(brace.trailing.len(), 3);
+        assert_eq!(brace.trailing[1].kind, TriviaKind::HashMarkComment);
+
+        let eof = tokens.last().unwrap();
+
+        assert_eq!(eof.token.kind, TokenKind::Eof);
+        assert_eq!(eof.leading.len(), 1);
+        assert_eq!(eof.leading[0].value, b"\n");
+    }
+
+    #[test]
+    fn test_numbers() {
+        let tokens = Lexer::new()
+            .tokenize("<?php 1_000 0x1F 0b101 0o17 017 0.5 1e3 9223372036854775807 9223372036854775808 0b2")
+            .unwrap();
+
+        let numbers = tokens
+            .iter()
+            .map(|token| match token.kind {
+                TokenKind::LiteralInteger => Some(number::integer(token.value).unwrap() as f64),
+                TokenKind::LiteralFloat => Some(number::float(token.value)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            numbers,
+            vec![
+                None,
+                Some(1000.0),
+                Some(31.0),
+                Some(5.0),
+                Some(15.0),
+                Some(15.0),
+                Some(0.5),
+                Some(1000.0),
+                Some(i64::MAX as f64),
+                Some(9223372036854775808.0),
+                Some(0.0),
+                None,
+                None,
+            ]
+        );
+
+        let error = Lexer::new().tokenize("<?php\n  $a = 01238;").unwrap_err();
+
+        assert_eq!(error, SyntaxError::InvalidOctalLiteral(error.span()));
+        assert_eq!(error.span().start.offset, 17);
+        assert_eq!(error.span().end.offset, 18);
+        assert_eq!(error.span().start.column, 12);
+        assert_eq!(error.to_string(), "Syntax Error: invalid octal literal on line 2 column 12");
+    }
+
+    #[test]
+    fn test_config() {
+        let kinds = |lexer: Lexer, input: &str| {
+            lexer
+                .tokenize(input)
+                .unwrap()
+                .iter()
+                .map(|token| token.kind.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let input = "<?php match enum fn readonly #[A]\n1_000 0o7";
+        let lexer = Lexer::with_config(LexerConfig {
+            php_version: PhpVersion::Php73,
+            ..LexerConfig::new()
+        });
+
+        assert_eq!(
+            kinds(lexer, input),
+            vec![
+                TokenKind::OpenTag(OpenTagKind::Full),
+                TokenKind::Identifier,
+                TokenKind::Identifier,
+                TokenKind::Identifier,
+                TokenKind::Identifier,
+                TokenKind::HashMarkComment,
+                TokenKind::LiteralInteger,
+                TokenKind::Identifier,
+                TokenKind::LiteralInteger,
+                TokenKind::Identifier,
+                TokenKind::Eof,
+            ]
+        );
+
+        assert_eq!(
+            kinds(Lexer::new(), input),
+            vec![
+                TokenKind::OpenTag(OpenTagKind::Full),
+                TokenKind::Match,
+                TokenKind::Enum,
+                TokenKind::Fn,
+                TokenKind::Readonly,
+                TokenKind::Attribute,
+                TokenKind::Identifier,
+                TokenKind::RightBracket,
+                TokenKind::LiteralInteger,
+                TokenKind::LiteralInteger,
+                TokenKind::Eof,
+            ]
+        );
+
+        let lexer = Lexer::with_config(LexerConfig {
+            short_open_tag: false,
+            ..LexerConfig::new()
+        });
+
+        assert_eq!(
+            kinds(lexer, "<? echo 1; ?>"),
+            vec![TokenKind::InlineHtml, TokenKind::Eof]
+        );
+    }
+
+    #[test]
+    fn test_token_get_all() {
+        let tokens = Lexer::new()
+            .token_get_all("<?php\nfunction &f() { yield  from \"a{$b}\"; } ?>\nx")
+            .unwrap();
+
+        let named = |name, text: &'static str, line| PhpToken::Named {
+            name,
+            text: text.as_bytes(),
+            line,
+        };
+
+        assert_eq!(
+            tokens,
+            vec![
+                named("T_OPEN_TAG", "<?php\n", 1),
+                named("T_FUNCTION", "function", 2),
+                named("T_WHITESPACE", " ", 2),
+                named("T_AMPERSAND_NOT_FOLLOWED_BY_VAR_OR_VARARG", "&", 2),
+                named("T_STRING", "f", 2),
+                PhpToken::Char(b"("),
+                PhpToken::Char(b")"),
+                named("T_WHITESPACE", " ", 2),
+                PhpToken::Char(b"{"),
+                named("T_WHITESPACE", " ", 2),
+                named("T_YIELD_FROM", "yield  from", 2),
+                named("T_WHITESPACE", " ", 2),
+                PhpToken::Char(b"\""),
+                named("T_ENCAPSED_AND_WHITESPACE", "a", 2),
+                named("T_CURLY_OPEN", "{", 2),
+                named("T_VARIABLE", "$b", 2),
+                PhpToken::Char(b"}"),
+                PhpToken::Char(b"\""),
+                PhpToken::Char(b";"),
+                named("T_WHITESPACE", " ", 2),
+                PhpToken::Char(b"}"),
+                named("T_WHITESPACE", " ", 2),
+                named("T_CLOSE_TAG", "?>\n", 2),
+                named("T_INLINE_HTML", "x", 3),
+            ]
+        );
+
+        // `enum` is only a keyword when it's followed by a name.
+        let tokens = Lexer::new()
+            .token_get_all("<?php enum A {} enum(1); enum /* c */ B {} A::enum; enum implements")
+            .unwrap();
+        let enums = tokens
+            .iter()
+            .filter_map(|token| match token {
+                PhpToken::Named {
+                    name, text: b"enum", ..
+                } => Some(*name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(enums, ["T_ENUM", "T_STRING", "T_ENUM", "T_STRING", "T_STRING"]);
+    }
+
+    #[test]
+    fn test_relex() {
+        let lexer = Lexer::new();
+        let input = format!(
+            "<?php\n{}echo \"a $b\";\n{}",
+            "$a = 1;\n".repeat(50),
+            "$c = 2;\n".repeat(50)
+        );
+        let previous = lexer.tokenize_checkpointed(&input).unwrap();
+
+        let edits = [
+            TextEdit::new(6..6, "$x = 'y';\n"),
+            TextEdit::new(206..208, "\" . $b . \""),
+            TextEdit::new(10..11, "\n\n"),
+            TextEdit::new(input.len()..input.len(), "$d"),
+            TextEdit::new(0..5, ""),
+        ];
+
+        for edit in edits {
+            let edited = edit.apply(input.as_bytes()).unwrap();
+            let relexed = lexer.relex(&previous, &edit, &edited).unwrap();
+
+            assert_eq!(relexed.tokens.tokens(), lexer.tokenize(&edited).unwrap());
+        }
+
+        let edit = TextEdit::new(6..8, "$abc");
+        let edited = edit.apply(input.as_bytes()).unwrap();
+        let relexed = lexer.relex(&previous, &edit, &edited).unwrap();
+
+        // Only the tokens around the edit need to be produced again.
+        assert!(relexed.changed.len() < 64);
+        assert_eq!(
+            relexed.tokens.tokens()[relexed.changed.start + 1].value,
+            b"$abc"
+        );
+
+        // Edits that don't match the input are an error rather than a panic.
+        for edit in [
+            TextEdit::new(6..8, "$a"),
+            TextEdit::new(input.len()..input.len() + 1, ""),
+        ] {
+            let error = lexer.relex(&previous, &edit, &edited).unwrap_err();
+
+            assert!(matches!(error, SyntaxError::InvalidEdit(_)));
+        }
+
+        let error = TextEdit::new(input.len()..input.len() + 1, "").apply(input.as_bytes());
+        assert!(matches!(error, Err(SyntaxError::InvalidEdit(_))));
+    }
+
+    #[test]
+    fn test_lazy_token_stream() {
+        use crate::stream::LazyTokenStream;
+
+        let lexer = Lexer::new();
+        let input = "<?php /* a */ echo 1, 2; // b\n$c = <<<EOT\n  {$d}\n  EOT;";
+        let tokens = lexer.tokenize(input).unwrap();
+
+        let mut stream = LazyTokenStream::new(lexer.tokens(input));
+        let mut streamed = vec![stream.current()];
+        while !stream.is_eof() {
+            stream.next();
+            streamed.push(stream.current());
+        }
+
+        let significant = tokens
+            .iter()
+            .filter(|token| !stream::is_comment(&token.kind))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(streamed, significant);
+
+        // Nothing past the lookahead window can be seen.
+        let stream = LazyTokenStream::with_lookahead(lexer.tokens(input), 1);
+        assert_eq!(stream.lookahead(0).kind, TokenKind::Echo);
+        assert_eq!(stream.lookahead(1).kind, TokenKind::Echo);
+
+        // The lexer failing ends the stream early.
+        let mut stream = LazyTokenStream::new(lexer.tokens("<?php echo 01238;"));
+        stream.next();
+        stream.next();
+        assert!(stream.is_eof());
+        assert_eq!(
+            stream.take_error().unwrap().to_string(),
+            "Syntax Error: invalid octal literal on line 1 column 15"
+        );
+    }
+
+    #[test]
+    fn test_print() {
+        let lexer = Lexer::new();
+        let tokens = lexer
+            .tokenize("<?php\n$a instanceof B; // hi\n$b = $c - -1 . <<<EOT\n  x\n  EOT;\n?>\n<p>")
+            .unwrap();
+
+        assert_eq!(
+            lexer.print(&tokens),
+            b"<?php $a instanceof B;// hi\n$b=$c- -1 .<<<EOT\n  x\n  EOT;?>\n<p>"
+        );
+
+        // Tokens without any text are written out using their only spelling.
+        let tokens = tokens
+            .into_iter()
+            .map(|token| match token.kind {
+                TokenKind::Instanceof | TokenKind::Minus => Token {
+                    kind: TokenKind::Plus,
+                    value: b"",
+                    ..token
+                },
+                _ => token,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lexer.print(&tokens),
+            b"<?php $a+B;// hi\n$b=$c+ +1 .<<<EOT\n  x\n  EOT;?>\n<p>"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+/// Decode the value of an integer literal, e.g. `1_000`, `0x1F`, `0b101`, `0o17` or `017`.
+///
+/// Returns `None` if the value doesn't fit in an `i64`. PHP treats those literals as floats,
+/// so their value should be decoded with [`float`] instead.
+pub fn integer(value: &[u8]) -> Option<i64> {
+    let (radix, digits) = split(value);
+
+    digits.iter().try_fold(0i64, |acc, b| {
+        acc.checked_mul(radix as i64)?.checked_add(digit(*b) as i64)
+    })
+}
+
+/// Decode the value of a float literal, or an integer literal that is too large for an `i64`.
+pub fn float(value: &[u8]) -> f64 {
+    let (radix, digits) = split(value);
+
+    if radix == 10 {
+        return std::str::from_utf8(&digits)
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .unwrap_or_default();
+    }
+
+    digits
+        .iter()
+        .fold(0.0, |acc, b| acc * radix as f64 + digit(*b) as f64)
+}
+
+// Split a numeric literal into its radix and digits, without the prefix and any `_` separators.
+fn split(value: &[u8]) -> (u32, Vec<u8>) {
+    let (radix, digits) = match value {
+        [b'0', b'x' | b'X', digits @ ..] => (16, digits),
+        [b'0', b'b' | b'B', digits @ ..] => (2, digits),
+        [b'0', b'o' | b'O', digits @ ..] => (8, digits),
+        // Legacy octal literals start with a `0`, but so do floats like `0.5` and `0e1`.
+        [b'0', digits @ ..]
+            if !digits.is_empty() && digits.iter().all(|b| b.is_ascii_digit() || *b == b'_') =>
+        {
+            (8, digits)
+        }
+        _ => (10, value),
+    };
+
+    (
+        radix,
+        digits.iter().copied().filter(|b| *b != b'_').collect(),
+    )
+}
+
+fn digit(b: u8) -> u32 {
+    (b as char).to_digit(16).unwrap_or_default()
+}
@@ -0,0 +1,270 @@
+use pxp_token::OpenTagKind;
+use pxp_token::Token;
+use pxp_token::TokenKind;
+
+use crate::stream::is_comment;
+
+/// A token in the same shape as the ones returned by PHP's `token_get_all()` function.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PhpToken<'a> {
+    /// A token with a `T_*` name, along with its text and the line that it starts on.
+    Named {
+        name: &'static str,
+        text: &'a [u8],
+        line: usize,
+    },
+    /// A single character token, which PHP represents as a plain string.
+    Char(&'a [u8]),
+}
+
+impl<'a> PhpToken<'a> {
+    pub fn text(&self) -> &'a [u8] {
+        match self {
+            Self::Named { text, .. } => text,
+            Self::Char(text) => text,
+        }
+    }
+}
+
+/// Convert the tokens of `input` into the tokens that PHP's `token_get_all()` would produce.
+///
+/// PHP has tokens for whitespace and doesn't split tokens quite the same way that we do, e.g.
+/// `yield from` is a single token and the new-line after a `?>` is part of the close tag, so the
+/// original input is needed too.
+pub fn token_get_all<'a>(input: &'a [u8], tokens: &[Token<'a>]) -> Vec<PhpToken<'a>> {
+    let mut converter = Converter {
+        input,
+        output: Vec::new(),
+        offset: 0,
+        line: 1,
+        line_offset: 0,
+    };
+
+    // Whether we're in a string or in code, so that tokens inside of strings can be named
+    // the same way PHP names them.
+    let mut contexts = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = &tokens[i];
+        let next = |n: usize| tokens.get(i + n).map(|token| &token.kind);
+        let in_string = matches!(contexts.last(), Some(Context::String));
+
+        converter.whitespace(token.span.start.offset);
+
+        match &token.kind {
+            TokenKind::Eof => {}
+            TokenKind::OpenTag(OpenTagKind::Full) => {
+                // The full open tag also contains a single whitespace character after it.
+                let end = match &input[token.span.end.offset..] {
+                    [b'\r', b'\n', ..] => token.span.end.offset + 2,
+                    [b' ' | b'\t' | b'\n', ..] => token.span.end.offset + 1,
+                    _ => token.span.end.offset,
+                };
+
+                converter.named("T_OPEN_TAG", end);
+            }
+            TokenKind::CloseTag => {
+                // The close tag also contains the new-line after it, if there is one.
+                let end = match &input[token.span.end.offset..] {
+                    [b'\r', b'\n', ..] => token.span.end.offset + 2,
+                    [b'\n', ..] => token.span.end.offset + 1,
+                    _ => token.span.end.offset,
+                };
+
+                converter.named("T_CLOSE_TAG", end);
+            }
+            TokenKind::Yield
+                if next(1) == Some(&TokenKind::From)
+                    && input[token.span.end.offset..tokens[i + 1].span.start.offset]
+                        .iter()
+                        .all(u8::is_ascii_whitespace) =>
+            {
+                i += 1;
+                converter.named("T_YIELD_FROM", tokens[i].span.end.offset);
+            }
+            TokenKind::HaltCompiler => {
+                // We keep the `();` that has to follow `__halt_compiler` in the same token.
+                let end = token.span.end.offset;
+
+                converter.named(
+                    "T_HALT_COMPILER",
+                    end.saturating_sub(3).max(converter.offset),
+                );
+                converter.chars(end);
+            }
+            TokenKind::QualifiedIdentifier
+                if token.value.len() > 10
+                    && token.value[..10].eq_ignore_ascii_case(b"namespace\\") =>
+            {
+                converter.named("T_NAME_RELATIVE", token.span.end.offset);
+            }
+            TokenKind::Ampersand => {
+                let followed_by_variable = tokens[i + 1..]
+                    .iter()
+                    .find(|token| !is_comment(&token.kind))
+                    .is_some_and(|token| {
+                        matches!(token.kind, TokenKind::Variable | TokenKind::Ellipsis)
+                    });
+
+                converter.named(
+                    if followed_by_variable {
+                        "T_AMPERSAND_FOLLOWED_BY_VAR_OR_VARARG"
+                    } else {
+                        "T_AMPERSAND_NOT_FOLLOWED_BY_VAR_OR_VARARG"
+                    },
+                    token.span.end.offset,
+                );
+            }
+            TokenKind::Enum if !is_enum_keyword(tokens, i) => {
+                converter.named("T_STRING", token.span.end.offset);
+            }
+            TokenKind::DoubleQuote | TokenKind::Backtick => {
+                if in_string {
+                    contexts.pop();
+                } else {
+                    contexts.push(Context::String);
+                }
+
+                converter.chars(token.span.end.offset);
+            }
+            TokenKind::StartDocString(_) => {
+                contexts.push(Context::String);
+                converter.named("T_START_HEREDOC", token.span.end.offset);
+            }
+            TokenKind::EndDocString(..) => {
+                contexts.pop();
+                converter.named("T_END_HEREDOC", token.span.end.offset);
+            }
+            TokenKind::DollarLeftBrace => {
+                contexts.push(Context::Code);
+                converter.named("T_DOLLAR_OPEN_CURLY_BRACES", token.span.end.offset);
+
+                if next(1) == Some(&TokenKind::Identifier)
+                    && matches!(
+                        next(2),
+                        Some(TokenKind::LeftBracket | TokenKind::RightBrace)
+                    )
+                {
+                    i += 1;
+                    converter.whitespace(tokens[i].span.start.offset);
+                    converter.named("T_STRING_VARNAME", tokens[i].span.end.offset);
+                }
+            }
+            TokenKind::LeftBrace if in_string => {
+                contexts.push(Context::Code);
+                converter.named("T_CURLY_OPEN", token.span.end.offset);
+            }
+            TokenKind::LeftBrace => {
+                contexts.push(Context::Code);
+                converter.chars(token.span.end.offset);
+            }
+            TokenKind::RightBrace => {
+                if let Some(Context::Code) = contexts.last() {
+                    contexts.pop();
+                }
+
+                converter.chars(token.span.end.offset);
+            }
+            // Array offsets in simple interpolation, e.g. "$a[0]".
+            TokenKind::LiteralInteger if in_string => {
+                converter.named("T_NUM_STRING", token.span.end.offset);
+            }
+            kind => match kind.php_name() {
+                Some(name) => converter.named(name, token.span.end.offset),
+                None => converter.chars(token.span.end.offset),
+            },
+        }
+
+        i += 1;
+    }
+
+    converter.whitespace(input.len());
+    converter.output
+}
+
+// Whether PHP's scanner would treat the `enum` at `i` as a keyword, which is only when it's
+// followed by whitespace or comments and then a name that doesn't start with `extends` or
+// `implements`. Anywhere else, such as in `enum(...)`, it's a `T_STRING`.
+fn is_enum_keyword(tokens: &[Token], i: usize) -> bool {
+    let separated = tokens.get(i + 1).is_some_and(|next| {
+        next.span.start.offset > tokens[i].span.end.offset || is_comment(&next.kind)
+    });
+    let next = tokens[i + 1..]
+        .iter()
+        .find(|token| !is_comment(&token.kind));
+
+    separated
+        && next.is_some_and(|next| {
+            let starts_with = |prefix: &[u8]| {
+                next.value.len() >= prefix.len()
+                    && next.value[..prefix.len()].eq_ignore_ascii_case(prefix)
+            };
+
+            next.value
+                .first()
+                .is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_' || *b >= 0x80)
+                && !starts_with(b"extends")
+                && !starts_with(b"implements")
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context {
+    String,
+    Code,
+}
+
+struct Converter<'a> {
+    input: &'a [u8],
+    output: Vec<PhpToken<'a>>,
+    // How much of the input has been turned into tokens.
+    offset: usize,
+    // The line number at `line_offset`, which only ever moves forwards.
+    line: usize,
+    line_offset: usize,
+}
+
+impl<'a> Converter<'a> {
+    // Output everything between the current offset and `end` as a named token. Tokens can
+    // overlap with the previous one when it took some trailing bytes, so nothing is output
+    // when there's nothing left of the token.
+    fn named(&mut self, name: &'static str, end: usize) {
+        if end <= self.offset {
+            return;
+        }
+
+        let line = self.line_at(self.offset);
+
+        self.output.push(PhpToken::Named {
+            name,
+            text: &self.input[self.offset..end],
+            line,
+        });
+
+        self.offset = end;
+    }
+
+    // Output every character between the current offset and `end` as single character tokens.
+    fn chars(&mut self, end: usize) {
+        while self.offset < end {
+            self.output
+                .push(PhpToken::Char(&self.input[self.offset..self.offset + 1]));
+            self.offset += 1;
+        }
+    }
+
+    fn whitespace(&mut self, end: usize) {
+        self.named("T_WHITESPACE", end);
+    }
+
+    fn line_at(&mut self, offset: usize) -> usize {
+        self.line += self.input[self.line_offset..offset]
+            .iter()
+            .filter(|b| **b == b'\n')
+            .count();
+        self.line_offset = offset;
+
+        self.line
+    }
+}
@@ -0,0 +1,151 @@
+use pxp_token::DocStringKind;
+use pxp_token::OpenTagKind;
+use pxp_token::Token;
+use pxp_token::TokenKind;
+
+use crate::Lexer;
+
+/// Turn a sequence of tokens back into source code.
+///
+/// Tokens are written out using their original bytes. Tokens without any, e.g. ones that were
+/// created by hand, are written using the only spelling their kind can have. Whitespace is only
+/// added between tokens in code when they would otherwise merge into something else, such as
+/// `$a` and `instanceof`, or when it is required, such as after a `// comment`.
+pub fn print<'a>(lexer: &Lexer, tokens: &[Token<'a>]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut contexts = vec![Context::Html];
+    // The previous token, if it was in code and something could merge with it.
+    let mut previous: Option<(&TokenKind, &'a [u8])> = None;
+
+    for token in tokens {
+        let text = text(token, &contexts);
+
+        if text.is_empty() {
+            continue;
+        }
+
+        if let (Some(Context::Code), Some((kind, before))) = (contexts.last(), previous) {
+            if let Some(separator) = separator(lexer, kind, before, &token.kind, text) {
+                output.push(separator);
+            }
+        }
+
+        // The closing label of a doc string always has to be on a line of its own.
+        if matches!(token.kind, TokenKind::EndDocString(..)) && output.last() != Some(&b'\n') {
+            output.push(b'\n');
+        }
+
+        output.extend_from_slice(text);
+
+        let mut mergeable = true;
+
+        match &token.kind {
+            TokenKind::OpenTag(_) => contexts = vec![Context::Code],
+            TokenKind::CloseTag => contexts = vec![Context::Html],
+            TokenKind::DoubleQuote | TokenKind::Backtick => {
+                if let Some(Context::String(_)) = contexts.last() {
+                    contexts.pop();
+                    mergeable = false;
+                } else {
+                    contexts.push(Context::String(None));
+                }
+            }
+            TokenKind::StartDocString(_) => contexts.push(Context::String(Some(label(text)))),
+            TokenKind::EndDocString(..) => {
+                contexts.pop();
+            }
+            TokenKind::LeftBrace | TokenKind::DollarLeftBrace => contexts.push(Context::Code),
+            TokenKind::RightBrace if contexts.len() > 1 => {
+                if let Some(Context::Code) = contexts.last() {
+                    contexts.pop();
+                }
+            }
+            _ => {}
+        }
+
+        previous = match contexts.last() {
+            Some(Context::Code) if mergeable => Some((&token.kind, text)),
+            _ => None,
+        };
+    }
+
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context<'a> {
+    Html,
+    Code,
+    // The label is only known for doc strings.
+    String(Option<&'a [u8]>),
+}
+
+// Get the text to write out for a token.
+fn text<'a>(token: &Token<'a>, contexts: &[Context<'a>]) -> &'a [u8] {
+    if !token.value.is_empty() {
+        return token.value;
+    }
+
+    match &token.kind {
+        // The lexer keeps the `();` that has to follow `__halt_compiler` in the same token.
+        TokenKind::HaltCompiler => b"__halt_compiler();",
+        TokenKind::StartDocString(DocStringKind::Heredoc) => b"<<<EOT\n",
+        TokenKind::StartDocString(DocStringKind::Nowdoc) => b"<<<'EOT'\n",
+        TokenKind::EndDocString(..) => match contexts.last() {
+            Some(Context::String(Some(label))) => label,
+            _ => b"EOT",
+        },
+        kind => kind.spelling().unwrap_or_default().as_bytes(),
+    }
+}
+
+// Get the label out of the text of a `StartDocString` token, e.g. `<<<"EOT"\n`.
+fn label(text: &[u8]) -> &[u8] {
+    match text.strip_prefix(b"<<<").unwrap_or(text).trim_ascii() {
+        [b'"', label @ .., b'"'] | [b'\'', label @ .., b'\''] => label,
+        label => label,
+    }
+}
+
+// Work out what, if anything, has to go between two tokens in code to keep them apart.
+fn separator(
+    lexer: &Lexer,
+    previous: &TokenKind,
+    before: &[u8],
+    kind: &TokenKind,
+    after: &[u8],
+) -> Option<u8> {
+    match previous {
+        // Single line comments run until the end of the line, or a close tag.
+        TokenKind::SingleLineComment | TokenKind::HashMarkComment
+            if !before.ends_with(b"\n") && kind != &TokenKind::CloseTag =>
+        {
+            Some(b'\n')
+        }
+        TokenKind::SingleLineComment | TokenKind::HashMarkComment => None,
+        // PHP only treats `<?php` as an open tag when it's followed by whitespace.
+        TokenKind::OpenTag(OpenTagKind::Full) => Some(b' '),
+        TokenKind::EndDocString(..) => after
+            .first()
+            .is_some_and(|b| is_identifier_byte(*b))
+            .then_some(b' '),
+        // Tokens merge when tokenizing them together doesn't give back the first one as is,
+        // e.g. `-` followed by `-` or `.` followed by `5`.
+        _ => {
+            let mut input = b"<?php ".to_vec();
+            input.extend_from_slice(before);
+            input.extend_from_slice(after);
+
+            let merges = !matches!(
+                lexer.tokens(&input).nth(1),
+                Some(Ok(Token { value, .. })) if value == before
+            );
+
+            merges.then_some(b' ')
+        }
+    }
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
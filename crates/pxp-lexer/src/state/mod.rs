@@ -10,7 +10,7 @@ use pxp_token::DocStringKind;
 
 pub mod source;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum StackFrame {
     Initial,
     Scripting,
@@ -59,6 +59,10 @@ impl<'a> State<'a> {
     }
 
     pub fn exit(&mut self) {
-        self.stack.pop_back();
+        // The bottom frame is never popped, an unbalanced `}` would otherwise
+        // leave the lexer without any state at all.
+        if self.stack.len() > 1 {
+            self.stack.pop_back();
+        }
     }
 }
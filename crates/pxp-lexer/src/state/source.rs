@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use pxp_span::{Span, Position};
 
 #[derive(Debug)]
@@ -6,18 +8,36 @@ pub struct Source<'a> {
     length: usize,
     position: Position,
     current_token_start_position: Position,
+    // The offset just past the furthest byte that has been looked at, which is beyond the end
+    // of the input once the lexer has checked for the end of the input.
+    furthest: Cell<usize>,
 }
 
 impl<'a> Source<'a> {
     pub fn new(input: &'a [u8]) -> Self {
-        let input = input;
-        let length = input.len();
+        Self::starting_at(input, Position::new(0, 1, 0), 0)
+    }
 
+    /// Create a source that starts at the given position, with the bytes up to `furthest`
+    /// already looked at.
+    pub fn starting_at(input: &'a [u8], position: Position, furthest: usize) -> Self {
         Self {
             input,
-            length,
-            position: Position::new(0, 1, 0),
-            current_token_start_position: Position::new(0, 1, 0),
+            length: input.len(),
+            position,
+            current_token_start_position: position,
+            furthest: Cell::new(furthest.max(position.offset)),
+        }
+    }
+
+    /// Get the offset just past the furthest byte that has been looked at so far.
+    pub fn furthest(&self) -> usize {
+        self.furthest.get()
+    }
+
+    fn look(&self, until: usize) {
+        if until > self.furthest.get() {
+            self.furthest.set(until);
         }
     }
 
@@ -29,11 +49,28 @@ impl<'a> Source<'a> {
         Span::new(self.current_token_start_position, self.position)
     }
 
+    /// Get the bytes consumed since the current token was started.
+    pub fn value(&self) -> &'a [u8] {
+        let until = self.position.offset.min(self.length);
+        let from = self.current_token_start_position.offset.min(until);
+
+        &self.input[from..until]
+    }
+
+    /// Get the byte before the current position.
+    pub fn previous(&self) -> Option<&'a u8> {
+        match self.position.offset {
+            0 => None,
+            offset => self.input.get(offset - 1),
+        }
+    }
+
     pub const fn position(&self) -> Position {
         self.position
     }
 
-    pub const fn eof(&self) -> bool {
+    pub fn eof(&self) -> bool {
+        self.look(self.position.offset + 1);
         self.position.offset >= self.length
     }
 
@@ -66,6 +103,8 @@ impl<'a> Source<'a> {
     }
 
     pub fn current(&self) -> Option<&'a u8> {
+        self.look(self.position.offset + 1);
+
         if self.position.offset >= self.length {
             None
         } else {
@@ -81,6 +120,8 @@ impl<'a> Source<'a> {
 
     #[inline(always)]
     pub fn read_remaining(&self) -> &'a [u8] {
+        self.look(self.length + 1);
+
         &self.input[(if self.position.offset >= self.length {
             self.length
         } else {
@@ -102,6 +143,8 @@ impl<'a> Source<'a> {
 
     pub fn peek(&self, i: usize, n: usize) -> &'a [u8] {
         let from = self.position.offset + i;
+        self.look(from + n);
+
         if from >= self.length {
             return &self.input[self.length..self.length];
         }
@@ -133,7 +176,9 @@ impl<'a> Source<'a> {
         self.peek(i, n)
     }
 
-    const fn to_bound(&self, n: usize) -> (usize, usize) {
+    fn to_bound(&self, n: usize) -> (usize, usize) {
+        self.look(self.position.offset + n);
+
         if self.position.offset >= self.length {
             return (self.length, self.length);
         }
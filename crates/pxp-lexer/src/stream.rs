@@ -1,3 +1,7 @@
+use std::collections::VecDeque;
+
+use crate::error::SyntaxError;
+use crate::tokens::Tokens;
 use pxp_token::Token;
 use pxp_token::TokenKind;
 
@@ -10,26 +14,23 @@ use pxp_ast::comments::CommentGroup;
 /// # Examples
 ///
 /// ```rust
-/// use php_parser_rs::lexer::token::Token;
-/// use php_parser_rs::lexer::token::TokenKind;
-/// use php_parser_rs::lexer::stream::TokenStream;
-///
-/// let tokens = vec![
-///     Token { kind: TokenKind::SingleLineComment("// some class".into()), span: (1, 1) },
-///     Token { kind: TokenKind::Readonly, span: (2, 1) },
-///     Token { kind: TokenKind::Class, span: (2, 10) },
-///     Token { kind: TokenKind::Enum, span: (2, 16) },
-///     Token { kind: TokenKind::LeftBrace, span: (2, 21) },
-///     Token { kind: TokenKind::SingleLineComment("// empty body!".into()), span: (3, 1) },
-///     Token { kind: TokenKind::RightBrace, span: (4, 1) },
-///     Token { kind: TokenKind::Eof, span: (0, 0) },
-/// ];
-///
-/// let mut stream = TokenStream::new(tokens);
+/// use pxp_lexer::Lexer;
+/// use pxp_lexer::stream::TokenStream;
+/// use pxp_token::TokenKind;
+///
+/// let tokens = Lexer::new()
+///     .tokenize("<?php\n// some class\nreadonly class Foo {\n    // empty body!\n}")
+///     .unwrap();
+///
+/// let mut stream = TokenStream::new(&tokens);
+///
+/// assert!(matches!(stream.current().kind, TokenKind::OpenTag(_)));
+///
+/// stream.next();
 ///
 /// assert!(matches!(stream.current().kind, TokenKind::Readonly));
 /// assert!(matches!(stream.peek().kind, TokenKind::Class));
-/// assert!(matches!(stream.lookahead(1).kind, TokenKind::Enum));
+/// assert!(matches!(stream.lookahead(1).kind, TokenKind::Identifier));
 /// assert!(matches!(stream.lookahead(2).kind, TokenKind::LeftBrace));
 /// assert!(matches!(stream.lookahead(3).kind, TokenKind::RightBrace));
 /// assert!(matches!(stream.lookahead(4).kind, TokenKind::Eof));
@@ -50,22 +51,19 @@ use pxp_ast::comments::CommentGroup;
 /// assert!(matches!(stream.current().kind, TokenKind::Eof));
 /// assert!(stream.is_eof());
 ///
-/// assert_eq!(stream.comments(), vec![
-///     Token { kind: TokenKind::SingleLineComment("// some class".into()), span: (1, 1) },
-///     Token { kind: TokenKind::SingleLineComment("// empty body!".into()), span: (3, 1) },
-/// ]);
+/// assert_eq!(stream.comments().comments.len(), 2);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TokenStream<'a> {
-    tokens: &'a [Token],
+    tokens: &'a [Token<'a>],
     length: usize,
-    comments: Vec<&'a Token>,
+    comments: Vec<&'a Token<'a>>,
     cursor: usize,
 }
 
 /// Token stream.
 impl<'a> TokenStream<'a> {
-    pub fn new(tokens: &'a [Token]) -> TokenStream {
+    pub fn new(tokens: &'a [Token]) -> TokenStream<'a> {
         let length = tokens.len();
 
         let mut stream = TokenStream {
@@ -89,7 +87,7 @@ impl<'a> TokenStream<'a> {
     }
 
     /// Get current token.
-    pub const fn current(&self) -> &'a Token {
+    pub const fn current(&self) -> &'a Token<'a> {
         let position = if self.cursor >= self.length {
             self.length - 1
         } else {
@@ -100,7 +98,7 @@ impl<'a> TokenStream<'a> {
     }
 
     /// Get previous token.
-    pub const fn previous(&self) -> &'a Token {
+    pub const fn previous(&self) -> &'a Token<'a> {
         let position = if self.cursor == 0 { 0 } else { self.cursor - 1 };
 
         &self.tokens[position]
@@ -109,14 +107,14 @@ impl<'a> TokenStream<'a> {
     /// Peek next token.
     ///
     /// All comments are skipped.
-    pub const fn peek(&self) -> &'a Token {
+    pub const fn peek(&self) -> &'a Token<'a> {
         self.peek_nth(1)
     }
 
     /// Peek nth+1 token.
     ///
     /// All comments are skipped.
-    pub const fn lookahead(&self, n: usize) -> &'a Token {
+    pub const fn lookahead(&self, n: usize) -> &'a Token<'a> {
         self.peek_nth(n + 1)
     }
 
@@ -124,7 +122,7 @@ impl<'a> TokenStream<'a> {
     ///
     /// All comments are skipped.
     #[inline(always)]
-    const fn peek_nth(&self, n: usize) -> &'a Token {
+    const fn peek_nth(&self, n: usize) -> &'a Token<'a> {
         let mut cursor = self.cursor + 1;
         let mut target = 1;
         loop {
@@ -134,13 +132,7 @@ impl<'a> TokenStream<'a> {
 
             let current = &self.tokens[cursor];
 
-            if matches!(
-                current.kind,
-                TokenKind::SingleLineComment
-                    | TokenKind::MultiLineComment
-                    | TokenKind::HashMarkComment
-                    | TokenKind::DocumentComment
-            ) {
+            if is_comment(&current.kind) {
                 cursor += 1;
                 continue;
             }
@@ -171,48 +163,7 @@ impl<'a> TokenStream<'a> {
         std::mem::swap(&mut self.comments, &mut comments);
 
         CommentGroup {
-            comments: comments
-                .iter()
-                .map(|token| match token {
-                    Token {
-                        kind: TokenKind::SingleLineComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::SingleLine,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::MultiLineComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::MultiLine,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::HashMarkComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::HashMark,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::DocumentComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::Document,
-                        content: value.clone(),
-                    },
-                    _ => unreachable!(),
-                })
-                .collect(),
+            comments: comments.into_iter().map(comment).collect(),
         }
     }
 
@@ -224,13 +175,7 @@ impl<'a> TokenStream<'a> {
 
             let current = &self.tokens[self.cursor];
 
-            if !matches!(
-                current.kind,
-                TokenKind::SingleLineComment
-                    | TokenKind::MultiLineComment
-                    | TokenKind::HashMarkComment
-                    | TokenKind::DocumentComment
-            ) {
+            if !is_comment(&current.kind) {
                 break;
             }
 
@@ -246,8 +191,239 @@ impl<'a> Default for TokenStream<'a> {
     }
 }
 
-impl<'a> From<&'a Vec<Token>> for TokenStream<'a> {
+impl<'a> From<&'a Vec<Token<'a>>> for TokenStream<'a> {
     fn from(tokens: &'a Vec<Token>) -> Self {
         Self::new(tokens.as_slice())
     }
 }
+
+/// A token stream that pulls its tokens from the lexer as they're needed.
+///
+/// Only the current token, the comments preceding it and a bounded window of lookahead
+/// tokens are kept in memory at any one time. The size of the window is fixed when the
+/// stream is created and limits how far `peek` and `lookahead` can see.
+///
+/// # Examples
+///
+/// ```rust
+/// use pxp_lexer::Lexer;
+/// use pxp_lexer::stream::LazyTokenStream;
+/// use pxp_token::TokenKind;
+///
+/// let mut stream = LazyTokenStream::new(Lexer::new().tokens("<?php /* hi */ echo 1;"));
+///
+/// assert!(matches!(stream.current().kind, TokenKind::OpenTag(_)));
+/// assert!(matches!(stream.peek().kind, TokenKind::Echo));
+/// assert!(matches!(stream.lookahead(1).kind, TokenKind::LiteralInteger));
+///
+/// stream.next();
+///
+/// assert!(matches!(stream.current().kind, TokenKind::Echo));
+/// assert_eq!(stream.comments().comments.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct LazyTokenStream<'a> {
+    source: Source<'a>,
+    buffer: VecDeque<Token<'a>>,
+    previous: Option<Token<'a>>,
+    comments: Vec<Token<'a>>,
+    lookahead: usize,
+    error: Option<SyntaxError>,
+}
+
+// Where a `LazyTokenStream` gets its tokens from.
+#[derive(Debug)]
+enum Source<'a> {
+    Lexer(Tokens<'a>),
+    Tokens(std::slice::Iter<'a, Token<'a>>),
+}
+
+impl<'a> LazyTokenStream<'a> {
+    /// The default number of tokens that can be seen past the current token.
+    pub const DEFAULT_LOOKAHEAD: usize = 4;
+
+    pub fn new(tokens: Tokens<'a>) -> LazyTokenStream<'a> {
+        Self::with_lookahead(tokens, Self::DEFAULT_LOOKAHEAD)
+    }
+
+    pub fn with_lookahead(tokens: Tokens<'a>, lookahead: usize) -> LazyTokenStream<'a> {
+        Self::from_source(Source::Lexer(tokens), lookahead)
+    }
+
+    fn from_source(source: Source<'a>, lookahead: usize) -> LazyTokenStream<'a> {
+        let mut stream = LazyTokenStream {
+            source,
+            buffer: VecDeque::new(),
+            previous: None,
+            comments: vec![],
+            lookahead,
+            error: None,
+        };
+
+        stream.collect_comments();
+        stream.fill();
+
+        stream
+    }
+
+    /// Move cursor to next token.
+    ///
+    /// Comments are collected.
+    pub fn next(&mut self) {
+        if !self.is_eof() {
+            self.previous = self.buffer.pop_front();
+        }
+
+        self.collect_comments();
+        self.fill();
+    }
+
+    /// Get current token.
+    pub fn current(&self) -> Token<'a> {
+        // The buffer always holds at least one token, the last of which is `Eof`.
+        self.buffer[0].clone()
+    }
+
+    /// Get previous token, which can be a comment.
+    pub fn previous(&self) -> Token<'a> {
+        self.previous.clone().unwrap_or_else(|| self.current())
+    }
+
+    /// Peek next token.
+    ///
+    /// All comments are skipped.
+    pub fn peek(&self) -> Token<'a> {
+        self.peek_nth(1).clone()
+    }
+
+    /// Peek nth+1 token.
+    ///
+    /// All comments are skipped. Looking further ahead than the stream's lookahead window
+    /// returns the last buffered token.
+    pub fn lookahead(&self, n: usize) -> Token<'a> {
+        self.peek_nth(n + 1).clone()
+    }
+
+    fn peek_nth(&self, n: usize) -> &Token<'a> {
+        self.buffer
+            .iter()
+            .skip(1)
+            .filter(|token| !is_comment(&token.kind))
+            .nth(n - 1)
+            .unwrap_or_else(|| &self.buffer[self.buffer.len() - 1])
+    }
+
+    /// Check if current token is EOF.
+    pub fn is_eof(&self) -> bool {
+        self.buffer[0].kind == TokenKind::Eof
+    }
+
+    /// Get all comments.
+    pub fn comments(&mut self) -> CommentGroup {
+        CommentGroup {
+            comments: self.comments.drain(..).map(|token| comment(&token)).collect(),
+        }
+    }
+
+    /// Take the syntax error that stopped the lexer, if there was one.
+    ///
+    /// The stream behaves as if it had reached the end of the file once the lexer fails.
+    pub fn take_error(&mut self) -> Option<SyntaxError> {
+        self.error.take()
+    }
+
+    fn pull(&mut self) -> bool {
+        let token = match &mut self.source {
+            Source::Lexer(tokens) => tokens.next(),
+            Source::Tokens(tokens) => tokens.next().cloned().map(Ok),
+        };
+
+        match token {
+            Some(Ok(token)) => self.buffer.push_back(token),
+            Some(Err(error)) => {
+                self.buffer.push_back(Token {
+                    kind: TokenKind::Eof,
+                    span: error.span(),
+                    value: &[],
+                });
+                self.error = Some(error);
+            }
+            None => return false,
+        }
+
+        true
+    }
+
+    fn fill(&mut self) {
+        let mut significant = self
+            .buffer
+            .iter()
+            .filter(|token| !is_comment(&token.kind))
+            .count();
+
+        while significant <= self.lookahead {
+            if matches!(self.buffer.back(), Some(Token { kind: TokenKind::Eof, .. })) {
+                break;
+            }
+
+            if !self.pull() {
+                break;
+            }
+
+            if !is_comment(&self.buffer[self.buffer.len() - 1].kind) {
+                significant += 1;
+            }
+        }
+
+        if self.buffer.is_empty() {
+            self.buffer.push_back(Token::default());
+        }
+    }
+
+    fn collect_comments(&mut self) {
+        loop {
+            if self.buffer.is_empty() && !self.pull() {
+                break;
+            }
+
+            if !is_comment(&self.buffer[0].kind) {
+                break;
+            }
+
+            self.previous = self.buffer.pop_front();
+            self.comments.extend(self.previous.clone());
+        }
+    }
+}
+
+impl<'a> From<&'a [Token<'a>]> for LazyTokenStream<'a> {
+    fn from(tokens: &'a [Token<'a>]) -> Self {
+        Self::from_source(Source::Tokens(tokens.iter()), Self::DEFAULT_LOOKAHEAD)
+    }
+}
+
+pub const fn is_comment(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::SingleLineComment
+            | TokenKind::MultiLineComment
+            | TokenKind::HashMarkComment
+            | TokenKind::DocumentComment
+    )
+}
+
+fn comment(token: &Token) -> Comment {
+    let format = match token.kind {
+        TokenKind::SingleLineComment => CommentFormat::SingleLine,
+        TokenKind::MultiLineComment => CommentFormat::MultiLine,
+        TokenKind::HashMarkComment => CommentFormat::HashMark,
+        TokenKind::DocumentComment => CommentFormat::Document,
+        _ => unreachable!(),
+    };
+
+    Comment {
+        span: token.span,
+        format,
+        content: token.value.into(),
+    }
+}
@@ -0,0 +1,87 @@
+use pxp_token::Token;
+use pxp_token::TokenKind;
+
+use crate::error::SyntaxResult;
+use crate::state::source::Source;
+use crate::state::StackFrame;
+use crate::state::State;
+use crate::Lexer;
+
+/// A lazy iterator over the tokens in a piece of source code.
+///
+/// Tokens are produced on demand, so only the tokens that haven't been consumed yet are
+/// kept in memory. The iterator finishes with a `TokenKind::Eof` token, or stops after
+/// yielding the first `SyntaxError` it encounters.
+#[derive(Debug)]
+pub struct Tokens<'a> {
+    lexer: Lexer,
+    state: State<'a>,
+    buffer: Vec<Token<'a>>,
+    cursor: usize,
+    finished: bool,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(lexer: Lexer, input: &'a [u8]) -> Self {
+        Self {
+            lexer,
+            state: State::new(Source::new(input)),
+            buffer: Vec::new(),
+            cursor: 0,
+            finished: false,
+        }
+    }
+
+    /// Like [`Tokens::new`], but the input is treated as PHP code from the start instead of
+    /// inline HTML.
+    pub fn scripting(lexer: Lexer, input: &'a [u8]) -> Self {
+        let mut tokens = Self::new(lexer, input);
+        tokens.state.replace(StackFrame::Scripting);
+        tokens
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = SyntaxResult<Token<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.cursor < self.buffer.len() {
+                let token = std::mem::take(&mut self.buffer[self.cursor]);
+                self.cursor += 1;
+
+                return Some(Ok(token));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            self.buffer.clear();
+            self.cursor = 0;
+
+            let more = if self.state.source.eof() {
+                false
+            } else {
+                match self.lexer.step(&mut self.state, &mut self.buffer) {
+                    Ok(more) => more,
+                    Err(error) => {
+                        self.finished = true;
+
+                        return Some(Err(error));
+                    }
+                }
+            };
+
+            if !more || self.state.source.eof() {
+                self.finished = true;
+                self.state.source.start_token();
+                self.buffer.push(Token {
+                    kind: TokenKind::Eof,
+                    span: self.state.source.span(),
+                    value: &[],
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use pxp_span::Position;
+use pxp_span::Span;
+use pxp_token::Token;
+use pxp_token::TokenKind;
+use pxp_token::Trivia;
+use pxp_token::TriviaKind;
+use pxp_token::TriviaToken;
+
+use crate::error::SyntaxResult;
+
+/// Group the given tokens into significant tokens with their surrounding trivia.
+///
+/// The lexer doesn't produce tokens for whitespace, so any gaps between the tokens are
+/// filled in from `input` as `TriviaKind::Whitespace`.
+pub(crate) fn attach<'a>(
+    input: &'a [u8],
+    tokens: impl Iterator<Item = SyntaxResult<Token<'a>>>,
+) -> SyntaxResult<Vec<TriviaToken<'a>>> {
+    let mut builder = Builder {
+        tokens: Vec::new(),
+        leading: Vec::new(),
+        trailing: false,
+    };
+
+    let mut position = Position::new(0, 1, 0);
+
+    for token in tokens {
+        let token = token?;
+
+        if token.span.start.offset > position.offset {
+            builder.push(Trivia {
+                kind: TriviaKind::Whitespace,
+                span: Span::new(position, token.span.start),
+                value: &input[position.offset..token.span.start.offset],
+            });
+        }
+
+        position = token.span.end;
+
+        match trivia_kind(&token.kind) {
+            Some(kind) => builder.push(Trivia {
+                kind,
+                span: token.span,
+                value: token.value,
+            }),
+            None => builder.token(token),
+        }
+    }
+
+    Ok(builder.tokens)
+}
+
+fn trivia_kind(kind: &TokenKind) -> Option<TriviaKind> {
+    match kind {
+        TokenKind::SingleLineComment => Some(TriviaKind::SingleLineComment),
+        TokenKind::HashMarkComment => Some(TriviaKind::HashMarkComment),
+        TokenKind::MultiLineComment => Some(TriviaKind::MultiLineComment),
+        TokenKind::DocumentComment => Some(TriviaKind::DocumentComment),
+        _ => None,
+    }
+}
+
+struct Builder<'a> {
+    tokens: Vec<TriviaToken<'a>>,
+    leading: Vec<Trivia<'a>>,
+    // Whether trivia still belongs to the previous token, i.e. we haven't seen a line break yet.
+    trailing: bool,
+}
+
+impl<'a> Builder<'a> {
+    fn token(&mut self, token: Token<'a>) {
+        self.tokens.push(TriviaToken {
+            leading: std::mem::take(&mut self.leading),
+            token,
+            trailing: Vec::new(),
+        });
+
+        self.trailing = true;
+    }
+
+    fn push(&mut self, trivia: Trivia<'a>) {
+        let previous = match self.tokens.last_mut() {
+            Some(previous) if self.trailing => previous,
+            _ => return self.leading.push(trivia),
+        };
+
+        let line_break = match trivia.kind {
+            TriviaKind::Whitespace => trivia.value.iter().position(|b| *b == b'\n'),
+            _ => None,
+        };
+
+        let Some(line_break) = line_break else {
+            return previous.trailing.push(trivia);
+        };
+
+        // Split the whitespace after the line break, the rest of it leads the next token.
+        let (before, after) = trivia.value.split_at(line_break + 1);
+        let middle = Position::new(
+            trivia.span.start.offset + before.len(),
+            trivia.span.start.line + 1,
+            1,
+        );
+
+        previous.trailing.push(Trivia {
+            kind: TriviaKind::Whitespace,
+            span: Span::new(trivia.span.start, middle),
+            value: before,
+        });
+
+        if !after.is_empty() {
+            self.leading.push(Trivia {
+                kind: TriviaKind::Whitespace,
+                span: Span::new(middle, trivia.span.end),
+                value: after,
+            });
+        }
+
+        self.trailing = false;
+    }
+}
@@ -1,21 +1,119 @@
-use std::{env::args, process::exit};
+use std::{env::args, process::exit, time::Instant};
 
-use pxp_parser::parse;
+use pxp_parser::files::{Files, ParsedFile};
 
 fn main() {
     let args = args().skip(1).collect::<Vec<_>>();
 
     if args.is_empty() {
-        eprintln!("Usage: parse <file> [--debug]");
+        eprintln!(concat!(
+            "Usage: parse <path>... ",
+            "[--debug, --json, --include <glob>, --exclude <glob>, --skip-vendor]",
+        ));
         exit(1);
     }
 
-    let file = args.first().unwrap();
-    let contents = std::fs::read(file).unwrap();
+    let debug = args.contains(&"--debug".to_string());
+    let json = args.contains(&"--json".to_string());
+    let mut files = Files::new().skip_vendor(args.contains(&"--skip-vendor".to_string()));
+    let mut paths = Vec::new();
+    let mut rest = args.iter();
 
-    let ast = parse(&contents[..]).unwrap();
+    while let Some(arg) = rest.next() {
+        let mut pattern = || match rest.next() {
+            Some(pattern) => pattern.clone(),
+            None => {
+                eprintln!("{} needs a glob pattern", arg);
+                exit(1);
+            }
+        };
 
-    if args.contains(&"--debug".to_string()) {
-        dbg!(ast);
+        let result = match arg.as_str() {
+            "--include" => files.clone().include(&pattern()),
+            "--exclude" => files.clone().exclude(&pattern()),
+            "--debug" | "--json" | "--skip-vendor" => continue,
+            _ => {
+                paths.push(arg.clone());
+                continue;
+            }
+        };
+
+        files = result.unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            exit(1);
+        });
+    }
+
+    let files = files.paths(paths);
+
+    let start = Instant::now();
+    let results = files.map(|file| {
+        if debug {
+            if let Ok(program) = &file.result {
+                dbg!(&file.path, program);
+            }
+        }
+
+        let output = json.then(|| to_json(&file));
+
+        (file.path, file.source.len(), file.result.err().map(|error| error.to_string()), output)
+    });
+    let elapsed = start.elapsed().as_secs_f64();
+
+    let mut failures = 0;
+    let mut bytes = 0;
+
+    for (path, size, error, output) in &results {
+        bytes += size;
+
+        if let Some(output) = output {
+            println!("{}", output);
+        }
+
+        if let Some(error) = error {
+            failures += 1;
+
+            if !json {
+                println!("{}: {}", path.display(), error);
+            }
+        }
     }
-}
\ No newline at end of file
+
+    if json {
+        exit(if failures > 0 { 1 } else { 0 });
+    }
+
+    let megabytes = bytes as f64 / 1_000_000.0;
+
+    println!(
+        "Parsed {} files ({:.2} MB) in {:.2}s, {} failed, {:.0} files/s, {:.2} MB/s",
+        results.len(),
+        megabytes,
+        elapsed,
+        failures,
+        results.len() as f64 / elapsed,
+        megabytes / elapsed,
+    );
+
+    if failures > 0 {
+        exit(1);
+    }
+}
+
+// A line of JSON with the path of the file, along with either its syntax tree or the error.
+#[cfg(feature = "serde")]
+fn to_json(file: &ParsedFile) -> String {
+    let path = file.path.display().to_string();
+
+    match &file.result {
+        Ok(program) => serde_json::json!({ "path": path, "program": program }),
+        Err(error) => serde_json::json!({ "path": path, "error": error.to_string() }),
+    }
+    .to_string()
+}
+
+#[cfg(not(feature = "serde"))]
+fn to_json(_: &ParsedFile) -> String {
+    eprintln!("--json needs parse to be built with the `serde` feature");
+    exit(1);
+}
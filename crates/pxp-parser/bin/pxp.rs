@@ -0,0 +1,102 @@
+use std::{env::args, process::exit};
+
+use pxp_ast::query::Selector;
+use pxp_parser::files::Files;
+
+const USAGE: &str = concat!(
+    "Usage: pxp query <selector> <path>... ",
+    "[--include <glob>, --exclude <glob>, --skip-vendor]",
+);
+
+fn main() {
+    let args = args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("query") if args.len() > 2 => query(&args[1], &args[2..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            exit(1);
+        }
+    }
+}
+
+// Prints every node in the files that matches the selector, along with where it is.
+fn query(selector: &str, args: &[String]) {
+    let selector = Selector::parse(selector).unwrap_or_else(|error| {
+        eprintln!("Invalid selector: {}", error);
+        exit(1);
+    });
+
+    let mut files = Files::new().skip_vendor(args.contains(&"--skip-vendor".to_string()));
+    let mut paths = Vec::new();
+    let mut rest = args.iter();
+
+    while let Some(arg) = rest.next() {
+        let mut pattern = || match rest.next() {
+            Some(pattern) => pattern.clone(),
+            None => {
+                eprintln!("{} needs a glob pattern", arg);
+                exit(1);
+            }
+        };
+
+        let result = match arg.as_str() {
+            "--include" => files.clone().include(&pattern()),
+            "--exclude" => files.clone().exclude(&pattern()),
+            "--skip-vendor" => continue,
+            _ => {
+                paths.push(arg.clone());
+                continue;
+            }
+        };
+
+        files = result.unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            exit(1);
+        });
+    }
+
+    let results = files.paths(paths).map(|file| {
+        let path = file.path.display().to_string();
+        let program = match file.result {
+            Ok(program) => program,
+            Err(error) => return Err(format!("{}: {}", path, error)),
+        };
+
+        let lines = selector
+            .find(&program, &file.source)
+            .into_iter()
+            .map(|found| {
+                let (start, end) = (found.span.start, found.span.end);
+                let text = String::from_utf8_lossy(&file.source[start.offset..end.offset]);
+
+                format!(
+                    "{}:{}:{}: {}: {}",
+                    path,
+                    start.line,
+                    start.column,
+                    found.kind,
+                    text.lines().next().unwrap_or("").trim(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(lines)
+    });
+
+    let mut failures = 0;
+
+    for result in results {
+        match result {
+            Ok(lines) => lines.iter().for_each(|line| println!("{}", line)),
+            Err(error) => {
+                failures += 1;
+                eprintln!("{}", error);
+            }
+        }
+    }
+
+    if failures > 0 {
+        exit(1);
+    }
+}
@@ -4,12 +4,14 @@ use pxp_ast::attributes::AttributeGroup;
 use pxp_ast::data_type::Type;
 use pxp_ast::modifiers::PromotedPropertyModifier;
 use pxp_ast::Program;
+use pxp_lexer::config::PhpVersion;
 use pxp_lexer::error::SyntaxError;
 use pxp_span::Span;
 use pxp_token::{Token, TokenKind};
 
 use super::state::State;
 use pxp_ast::identifiers::SimpleIdentifier;
+use pxp_ast::spanned::Spanned;
 use pxp_ast::variables::SimpleVariable;
 
 pub type ParseResult<T> = Result<T, ParseError>;
@@ -96,7 +98,7 @@ pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
             | TokenKind::LiteralFloat
             | TokenKind::LiteralSingleQuotedString
             | TokenKind::LiteralDoubleQuotedString => ("literal".to_string(), false),
-            _ => (format!("token `{}`", found.value), false),
+            _ => (format!("token `{}`", found), false),
         },
     };
 
@@ -190,7 +192,7 @@ pub fn multiple_visibility_modifiers(first: (String, Span), second: (String, Spa
 }
 
 pub fn standalone_type_used_as_nullable(ty: &Type, span: Span) -> ParseError {
-    let type_span = ty.first_span();
+    let type_span = ty.span();
     let type_string = ty.to_string();
 
     ParseError::new(
@@ -204,7 +206,7 @@ pub fn standalone_type_used_as_nullable(ty: &Type, span: Span) -> ParseError {
 }
 
 pub fn standalone_type_used_in_union(ty: &Type, span: Span) -> ParseError {
-    let type_span = ty.first_span();
+    let type_span = ty.span();
     let type_string = ty.to_string();
 
     ParseError::new(
@@ -225,7 +227,7 @@ pub fn standalone_type_used_in_union(ty: &Type, span: Span) -> ParseError {
 }
 
 pub fn standalone_type_used_in_intersection(ty: &Type, span: Span) -> ParseError {
-    let type_span = ty.first_span();
+    let type_span = ty.span();
     let type_string = ty.to_string();
 
     ParseError::new(
@@ -691,7 +693,7 @@ pub fn forbidden_type_used_in_property(
     ty: Type,
 ) -> ParseError {
     let type_string = ty.to_string();
-    let type_span = ty.first_span();
+    let type_span = ty.span();
 
     let error = ParseError::new(
         "E037".to_string(),
@@ -755,7 +757,7 @@ pub fn missing_item_definition_after_attributes(
         },
         _ => ParseErrorAnnotation {
             r#type: ParseErrorAnnotationType::Error,
-            message: format!("expected an item definition, found `{}`", current.value),
+            message: format!("expected an item definition, found `{}`", current),
             position: current.span.start.offset,
             length: current.value.len(),
         },
@@ -889,11 +891,35 @@ pub fn argument_is_required(span: Span, current_span: Span) -> ParseError {
     )
 }
 
+pub fn nesting_too_deep(span: Span) -> ParseError {
+    ParseError::new("E052".to_string(), "code is nested too deeply to be parsed", span)
+        .error("try splitting this up", span.start.offset, span.end.offset - span.start.offset)
+}
+
+pub fn feature_requires_newer_php_version(
+    feature: &str,
+    required: PhpVersion,
+    target: PhpVersion,
+    span: Span,
+) -> ParseError {
+    ParseError::new(
+        "E053".to_string(),
+        format!("{} require PHP {}", feature, required),
+        span,
+    )
+    .error(
+        format!("this is not available in PHP {}", target),
+        span.start.offset,
+        span.end.offset - span.start.offset,
+    )
+}
+
 impl From<SyntaxError> for ParseError {
     fn from(e: SyntaxError) -> Self {
         Self {
             id: "E001".to_string(),
-            message: format!("syntax error, {}", e),
+            // The location is part of how a `ParseError` is displayed, so it's left out here.
+            message: format!("syntax error, {}", e.message()),
             annotations: vec![],
             span: e.span(),
             note: None,
@@ -38,11 +38,16 @@ use pxp_ast::{
     TernaryExpression,
 };
 use pxp_token::DocStringKind;
+use pxp_lexer::config::PhpVersion;
+use pxp_lexer::number;
+use pxp_span::Span;
+use pxp_token::Token;
 use pxp_token::TokenKind;
 
 use pxp_ast::literals::LiteralStringKind;
 use pxp_ast::BoolExpression;
 use pxp_ast::CastExpression;
+use pxp_ast::CastKind;
 use pxp_ast::CloneExpression;
 use pxp_ast::DieExpression;
 use pxp_ast::EmptyExpression;
@@ -75,6 +80,12 @@ fn clone_or_new_precedence(state: &mut State) -> ParseResult<Expression> {
 }
 
 fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expression> {
+    state.nested(|state| operators(state, precedence))
+}
+
+// Parse an operand along with all of the operators that follow it, for as long as they bind
+// at least as tightly as the given precedence.
+fn operators(state: &mut State, precedence: Precedence) -> ParseResult<Expression> {
     let mut left = left(state, &precedence)?;
 
     loop {
@@ -87,7 +98,9 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
         }
 
         if is_postfix(kind) {
-            let lpred = Precedence::postfix(kind);
+            let Some(lpred) = Precedence::postfix(kind) else {
+                return Err(error::unexpected_token(vec![], &current));
+            };
 
             if lpred < precedence {
                 break;
@@ -98,7 +111,9 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
         }
 
         if is_infix(kind) {
-            let rpred = Precedence::infix(kind);
+            let Some(rpred) = Precedence::infix(kind) else {
+                return Err(error::unexpected_token(vec![], &current));
+            };
 
             if rpred < precedence {
                 break;
@@ -109,7 +124,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
             }
 
             if rpred == precedence && matches!(rpred.associativity(), Some(Associativity::Non)) {
-                return Err(error::unexpected_token(vec![], current));
+                return Err(error::unexpected_token(vec![], &current));
             }
 
             state.stream.next();
@@ -129,7 +144,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                         Expression::Ternary(TernaryExpression {
                             condition: Box::new(left),
                             question: span,
-                            then: Box::new(Expression::Noop),
+                            then: Box::new(Expression::Noop(Span::new(span.end, op.span.start))),
                             colon: op.span,
                             r#else: Box::new(r#else),
                         })
@@ -177,7 +192,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                     Expression::Instanceof(InstanceofExpression {
                         left: Box::new(left),
                         instanceof: span,
-                        right: Box::new(Expression::Self_),
+                        right: Box::new(Expression::Self_(op.span)),
                     })
                 }
                 TokenKind::Instanceof if op.kind == TokenKind::Parent => {
@@ -186,7 +201,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                     Expression::Instanceof(InstanceofExpression {
                         left: Box::new(left),
                         instanceof: span,
-                        right: Box::new(Expression::Parent),
+                        right: Box::new(Expression::Parent(op.span)),
                     })
                 }
                 TokenKind::Instanceof if op.kind == TokenKind::Static => {
@@ -195,7 +210,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                     Expression::Instanceof(InstanceofExpression {
                         left: Box::new(left),
                         instanceof: span,
-                        right: Box::new(Expression::Static),
+                        right: Box::new(Expression::Static(op.span)),
                     })
                 }
                 TokenKind::Instanceof if op.kind == TokenKind::Enum => {
@@ -359,13 +374,17 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                                 right,
                             },
                         ),
-                        TokenKind::DoubleQuestionEquals => Expression::AssignmentOperation(
-                            AssignmentOperationExpression::Coalesce {
-                                left,
-                                coalesce_equals: span,
-                                right,
-                            },
-                        ),
+                        TokenKind::DoubleQuestionEquals => {
+                            state.requires(PhpVersion::Php74, "null coalescing assignments", span);
+
+                            Expression::AssignmentOperation(
+                                AssignmentOperationExpression::Coalesce {
+                                    left,
+                                    coalesce_equals: span,
+                                    right,
+                                },
+                            )
+                        }
                         TokenKind::DotEquals => {
                             Expression::AssignmentOperation(AssignmentOperationExpression::Concat {
                                 left,
@@ -523,7 +542,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                             instanceof: span,
                             right,
                         }),
-                        _ => todo!(),
+                        _ => return Err(error::unexpected_token(vec![], &current)),
                     }
                 }
             };
@@ -539,7 +558,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
 
 fn left(state: &mut State, precedence: &Precedence) -> ParseResult<Expression> {
     if state.stream.is_eof() {
-        return Err(error::unexpected_token(vec![], state.stream.current()));
+        return Err(error::unexpected_token(vec![], &state.stream.current()));
     }
 
     attributes(state, precedence)
@@ -592,7 +611,7 @@ expressions! {
             _ => {
                 Err(error::missing_item_definition_after_attributes(
                     &state.attributes,
-                    current,
+                    &current,
                 ))
             }
         }
@@ -623,7 +642,15 @@ expressions! {
         let eval = state.stream.current().span;
         state.stream.next();
 
-        let argument = Box::new(parameters::single_argument(state, true, true).unwrap()?);
+        let argument = match parameters::single_argument(state, true, true)? {
+            Some(argument) => Box::new(argument),
+            None => {
+                return Err(error::unexpected_token(
+                    vec!["`(`".to_string()],
+                    &state.stream.current(),
+                ))
+            }
+        };
 
         Ok(Expression::Eval(EvalExpression { eval, argument }))
     })
@@ -633,7 +660,15 @@ expressions! {
         let empty = state.stream.current().span;
         state.stream.next();
 
-        let argument = Box::new(parameters::single_argument(state, true, true).unwrap()?);
+        let argument = match parameters::single_argument(state, true, true)? {
+            Some(argument) => Box::new(argument),
+            None => {
+                return Err(error::unexpected_token(
+                    vec!["`(`".to_string()],
+                    &state.stream.current(),
+                ))
+            }
+        };
 
         Ok(Expression::Empty(EmptyExpression { empty, argument }))
     })
@@ -643,10 +678,7 @@ expressions! {
         let die = state.stream.current().span;
         state.stream.next();
 
-        let argument = match parameters::single_argument(state, false, true) {
-            Some(arg) => Some(Box::new(arg?)),
-            None => None,
-        };
+        let argument = parameters::single_argument(state, false, true)?.map(Box::new);
 
         Ok(Expression::Die(DieExpression { die, argument }))
     })
@@ -656,10 +688,7 @@ expressions! {
         let exit = state.stream.current().span;
         state.stream.next();
 
-        let argument = match parameters::single_argument(state, false, true) {
-            Some(arg) => Some(Box::new(arg?)),
-            None => None,
-        };
+        let argument = parameters::single_argument(state, false, true)?.map(Box::new);
 
         Ok(Expression::Exit(ExitExpression { exit, argument }))
     })
@@ -690,8 +719,8 @@ expressions! {
         let mut value = None;
         let mut argument = None;
 
-        if let Some(arg) = parameters::single_argument(state, false, true) {
-            argument = Some(Box::new(arg?));
+        if state.stream.current().kind == TokenKind::LeftParen {
+            argument = parameters::single_argument(state, false, true)?.map(Box::new);
         } else {
             value = Some(Box::new(create(state)?));
         }
@@ -731,49 +760,54 @@ expressions! {
 
     #[before(r#yield), current(TokenKind::Throw)]
     throw({
+        let throw = state.stream.current().span;
         state.stream.next();
 
         Ok(Expression::Throw(ThrowExpression {
+            throw,
             value: Box::new(for_precedence(state, Precedence::Lowest)?)
         }))
     })
 
     #[before(clone), current(TokenKind::Yield)]
     r#yield({
+        let r#yield = state.stream.current().span;
         state.stream.next();
         if state.stream.current().kind == TokenKind::SemiColon || state.stream.current().kind == TokenKind::RightParen {
             Ok(Expression::Yield(YieldExpression {
+                r#yield,
                 key: None,
                 value: None,
             }))
         } else {
-            let mut from = false;
+            let mut from = None;
 
             if state.stream.current().kind == TokenKind::From {
+                from = Some(state.stream.current().span);
                 state.stream.next();
-                from = true;
             }
 
             let mut key = None;
             let mut value = Box::new(for_precedence(
                 state,
-                if from {
+                if from.is_some() {
                     Precedence::YieldFrom
                 } else {
                     Precedence::Yield
                 },
             )?);
 
-            if state.stream.current().kind == TokenKind::DoubleArrow && !from {
+            if state.stream.current().kind == TokenKind::DoubleArrow && from.is_none() {
                 state.stream.next();
                 key = Some(value.clone());
                 value = Box::new(for_precedence(state, Precedence::Yield)?);
             }
 
-            if from {
-                Ok(Expression::YieldFrom(YieldFromExpression { value }))
+            if let Some(from) = from {
+                Ok(Expression::YieldFrom(YieldFromExpression { r#yield, from, value }))
             } else {
                 Ok(Expression::Yield(YieldExpression {
+                    r#yield,
                     key,
                     value: Some(value),
                 }))
@@ -783,34 +817,39 @@ expressions! {
 
     #[before(r#true), current(TokenKind::Clone)]
     clone({
+        let clone = state.stream.current().span;
         state.stream.next();
 
         let target = for_precedence(state, Precedence::CloneOrNew)?;
 
         Ok(Expression::Clone(CloneExpression {
+            clone,
             target: Box::new(target),
         }))
     })
 
     #[before(r#false), current(TokenKind::True)]
     r#true({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Bool(BoolExpression { value: true }))
+        Ok(Expression::Bool(BoolExpression { span, value: true }))
     })
 
     #[before(null), current(TokenKind::False)]
     r#false({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Bool(BoolExpression { value: false }))
+        Ok(Expression::Bool(BoolExpression { span, value: false }))
     })
 
     #[before(literal_integer), current(TokenKind::Null)]
     null({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Null)
+        Ok(Expression::Null(span))
     })
 
     #[before(literal_float), current(TokenKind::LiteralInteger)]
@@ -818,16 +857,17 @@ expressions! {
         let current = state.stream.current();
 
         if let TokenKind::LiteralInteger = &current.kind {
+            number_version(state, &current);
             state.stream.next();
 
             Ok(Expression::Literal(Literal::Integer(
                 LiteralInteger {
                     span: current.span,
-                    value: current.value.clone()
+                    value: number::integer(current.value).unwrap_or_default(),
                 }
             )))
         } else {
-            unreachable!("{}:{}", file!(), line!());
+            Err(error::unexpected_token(vec![], &current))
         }
     })
 
@@ -836,16 +876,17 @@ expressions! {
         let current = state.stream.current();
 
         if let TokenKind::LiteralFloat = &current.kind {
+            number_version(state, &current);
             state.stream.next();
 
             Ok(Expression::Literal(
                 Literal::Float(LiteralFloat {
                     span: current.span,
-                    value: current.value.clone()
+                    value: number::float(current.value),
                 })
             ))
         } else {
-            unreachable!("{}:{}", file!(), line!());
+            Err(error::unexpected_token(vec![], &current))
         }
     })
 
@@ -859,7 +900,7 @@ expressions! {
             Ok(Expression::Literal(
                 Literal::String(LiteralString {
                     span: current.span,
-                    value: current.value.clone(),
+                    value: strings::literal_value(&current)?,
                     kind: LiteralStringKind::SingleQuoted,
                 })
             ))
@@ -869,16 +910,16 @@ expressions! {
             Ok(Expression::Literal(
                 Literal::String(LiteralString {
                     span: current.span,
-                    value: current.value.clone(),
+                    value: strings::literal_value(&current)?,
                     kind: LiteralStringKind::DoubleQuoted,
                 })
             ))
         } else {
-            unreachable!("{}:{}", file!(), line!());
+            Err(error::unexpected_token(vec![], &current))
         }
     })
 
-    #[before(heredoc), current(TokenKind::StringPart)]
+    #[before(heredoc), current(TokenKind::DoubleQuote)]
     string_part({
         strings::interpolated(state)
     })
@@ -905,23 +946,26 @@ expressions! {
 
     #[before(self_identifier), current(TokenKind::Static)]
     static_postfix({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        postfix(state, Expression::Static, &TokenKind::DoubleColon)
+        postfix(state, Expression::Static(span), &TokenKind::DoubleColon)
     })
 
     #[before(parent_identifier), current(TokenKind::Self_)]
     self_identifier({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Self_)
+        Ok(Expression::Self_(span))
     })
 
     #[before(left_parenthesis), current(TokenKind::Parent)]
     parent_identifier({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Parent)
+        Ok(Expression::Parent(span))
     })
 
     #[before(r#match), current(TokenKind::LeftParen)]
@@ -963,19 +1007,22 @@ expressions! {
 
         let target = match state.stream.current().kind {
             TokenKind::Self_ => {
+                let span = state.stream.current().span;
                 state.stream.next();
 
-                Expression::Self_
+                Expression::Self_(span)
             }
             TokenKind::Static => {
+                let span = state.stream.current().span;
                 state.stream.next();
 
-                Expression::Static
+                Expression::Static(span)
             }
             TokenKind::Parent => {
+                let span = state.stream.current().span;
                 state.stream.next();
 
-                Expression::Parent
+                Expression::Parent(span)
             }
             TokenKind::Enum => {
                 let span = state.stream.current().span;
@@ -1093,7 +1140,7 @@ expressions! {
             TokenKind::IncludeOnce => Expression::IncludeOnce(IncludeOnceExpression { include_once: span, path }),
             TokenKind::Require => Expression::Require(RequireExpression { require: span, path }),
             TokenKind::RequireOnce => Expression::RequireOnce(RequireOnceExpression { require_once: span, path }),
-            _ => unreachable!()
+            _ => return Err(error::unexpected_token(vec![], &current)),
         })
     })
 
@@ -1107,7 +1154,9 @@ expressions! {
         let current = state.stream.current();
 
         let span = current.span;
-        let kind = current.kind.clone().into();
+        let Ok(kind) = CastKind::try_from(current.kind.clone()) else {
+            return Err(error::unexpected_token(vec![], &current));
+        };
 
         state.stream.next();
 
@@ -1135,7 +1184,7 @@ expressions! {
             TokenKind::Plus => Expression::ArithmeticOperation(ArithmeticOperationExpression::Positive { plus: span, right }),
             TokenKind::Decrement => Expression::ArithmeticOperation(ArithmeticOperationExpression::PreDecrement { decrement: span, right }),
             TokenKind::Increment => Expression::ArithmeticOperation(ArithmeticOperationExpression::PreIncrement { increment: span, right }),
-            _ => unreachable!(),
+            _ => return Err(error::unexpected_token(vec![], &current)),
         };
 
         Ok(expr)
@@ -1189,7 +1238,36 @@ expressions! {
 fn unexpected_token(state: &mut State, _: &Precedence) -> ParseResult<Expression> {
     let current = state.stream.current();
 
-    Err(error::unexpected_token(vec![], current))
+    // When the expression is missing altogether, e.g. `$a = ;`, carry on as if it was there
+    // so that the rest of the statement can still be parsed.
+    if matches!(
+        current.kind,
+        TokenKind::SemiColon
+            | TokenKind::Comma
+            | TokenKind::RightParen
+            | TokenKind::RightBracket
+            | TokenKind::RightBrace
+            | TokenKind::CloseTag
+    ) {
+        state.record(error::unexpected_token(vec![], &current));
+
+        return Ok(Expression::Missing(Span::new(
+            current.span.start,
+            current.span.start,
+        )));
+    }
+
+    Err(error::unexpected_token(vec![], &current))
+}
+
+fn number_version(state: &mut State, token: &Token) {
+    if token.value.contains(&b'_') {
+        state.requires(PhpVersion::Php74, "numeric literal separators", token.span);
+    }
+
+    if matches!(token.value, [b'0', b'o' | b'O', ..]) {
+        state.requires(PhpVersion::Php81, "explicit octal literals", token.span);
+    }
 }
 
 fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Expression> {
@@ -1214,6 +1292,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                 let start = utils::skip(state, TokenKind::LeftParen)?;
                 let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
                 let end = utils::skip(state, TokenKind::RightParen)?;
+                state.requires(PhpVersion::Php81, "first-class callables", ellipsis);
 
                 let placeholder = ArgumentPlaceholder {
                     comments: state.stream.comments(),
@@ -1290,6 +1369,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                     let start = utils::skip(state, TokenKind::LeftParen)?;
                     let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
                     let end = utils::skip(state, TokenKind::RightParen)?;
+                    state.requires(PhpVersion::Php81, "first-class callables", ellipsis);
 
                     let placeholder = ArgumentPlaceholder {
                         comments: state.stream.comments(),
@@ -1319,7 +1399,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                                 },
                             )
                         }
-                        _ => unreachable!(),
+                        _ => return Err(error::unexpected_token(vec![], &current)),
                     }
                 } else {
                     let arguments = parameters::argument_list(state)?;
@@ -1341,7 +1421,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                                 arguments,
                             },
                         ),
-                        _ => unreachable!(),
+                        _ => return Err(error::unexpected_token(vec![], &current)),
                     }
                 }
             } else {
@@ -1360,12 +1440,15 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                             property: variable,
                         })
                     }
-                    _ => unreachable!(),
+                    _ => return Err(error::unexpected_token(vec![], &current)),
                 }
             }
         }
         TokenKind::Arrow | TokenKind::QuestionArrow => {
             let span = state.stream.current().span;
+            if op == &TokenKind::QuestionArrow {
+                state.requires(PhpVersion::Php80, "nullsafe operators", span);
+            }
             state.stream.next();
 
             let property = match state.stream.current().kind {
@@ -1414,6 +1497,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                         let start = utils::skip(state, TokenKind::LeftParen)?;
                         let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
                         let end = utils::skip(state, TokenKind::RightParen)?;
+                        state.requires(PhpVersion::Php81, "first-class callables", ellipsis);
 
                         let placeholder = ArgumentPlaceholder {
                             comments: state.stream.comments(),
@@ -1471,7 +1555,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                 decrement: span,
             })
         }
-        _ => todo!("postfix: {:?}", op),
+        _ => return Err(error::unexpected_token(vec![], &state.stream.current())),
     })
 }
 
@@ -0,0 +1,242 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use pxp_ast::Program;
+use rayon::prelude::*;
+use walkdir::{DirEntry, WalkDir};
+
+use crate::error::ParseErrorStack;
+use crate::options::ParserOptions;
+
+pub use globset::Error as PatternError;
+
+/// A set of files and directories to parse in parallel, such as all of the PHP files in a
+/// project.
+///
+/// Directories are searched for files that match one of the `include` patterns, which is
+/// `**/*.php` when none are given, and that don't match any of the `exclude` patterns. The
+/// patterns are matched against the path relative to the directory that was given. Files that
+/// are given directly are always parsed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pxp_parser::files::Files;
+///
+/// let files = Files::new()
+///     .path("src")
+///     .exclude("**/Tests/**")
+///     .unwrap()
+///     .skip_vendor(true);
+///
+/// for file in files.parse() {
+///     if let Err(error) = file.result {
+///         println!("{}: {}", file.path.display(), error);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Files {
+    paths: Vec<PathBuf>,
+    include: GlobSetBuilder,
+    exclude: GlobSetBuilder,
+    // The sets are built each time a pattern is added, so that any error is returned straight
+    // away. No include patterns means every PHP file is included.
+    included: Option<GlobSet>,
+    excluded: GlobSet,
+    skip_vendor: bool,
+    options: ParserOptions,
+}
+
+/// The result of parsing one of the files.
+#[derive(Debug)]
+pub struct ParsedFile {
+    pub path: PathBuf,
+    /// The contents of the file, or nothing if it couldn't be read.
+    pub source: Vec<u8>,
+    pub result: Result<Program, FileError>,
+}
+
+#[derive(Debug)]
+pub enum FileError {
+    Io(io::Error),
+    Parse(ParseErrorStack),
+}
+
+impl Files {
+    pub fn new() -> Self {
+        Self {
+            paths: Vec::new(),
+            include: GlobSetBuilder::new(),
+            exclude: GlobSetBuilder::new(),
+            included: None,
+            excluded: GlobSet::empty(),
+            skip_vendor: false,
+            options: ParserOptions::new(),
+        }
+    }
+
+    /// Add a file, or a directory to search for files.
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.paths.push(path.into());
+
+        self
+    }
+
+    pub fn paths<P: Into<PathBuf>>(mut self, paths: impl IntoIterator<Item = P>) -> Self {
+        self.paths.extend(paths.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Only parse the files found in directories that match `pattern`, where `*` doesn't match
+    /// across directories and `**` does.
+    pub fn include(mut self, pattern: &str) -> Result<Self, PatternError> {
+        self.include.add(glob(pattern)?);
+        self.included = Some(self.include.build()?);
+
+        Ok(self)
+    }
+
+    /// Leave out files and directories that match `pattern`.
+    pub fn exclude(mut self, pattern: &str) -> Result<Self, PatternError> {
+        self.exclude.add(glob(pattern)?);
+        self.excluded = self.exclude.build()?;
+
+        Ok(self)
+    }
+
+    /// Leave out any directory named `vendor`, which is where Composer puts dependencies.
+    pub fn skip_vendor(mut self, skip_vendor: bool) -> Self {
+        self.skip_vendor = skip_vendor;
+
+        self
+    }
+
+    pub fn options(mut self, options: ParserOptions) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Every file that would be parsed, in the order they're found, along with the errors
+    /// from reading any directories.
+    pub fn files(&self) -> Vec<Result<PathBuf, (PathBuf, io::Error)>> {
+        let mut files = Vec::new();
+
+        for root in &self.paths {
+            if !root.is_dir() {
+                files.push(Ok(root.clone()));
+                continue;
+            }
+
+            let walker = WalkDir::new(root)
+                .sort_by_file_name()
+                .into_iter()
+                .filter_entry(|entry| entry.depth() == 0 || !self.skipped(root, entry));
+
+            for entry in walker {
+                match entry {
+                    Ok(entry) if entry.file_type().is_file() => {
+                        if self.included(root, entry.path()) {
+                            files.push(Ok(entry.into_path()));
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(error) => {
+                        let path = error.path().unwrap_or(root).to_path_buf();
+                        files.push(Err((path, error.into())));
+                    }
+                }
+            }
+        }
+
+        files
+    }
+
+    /// Parse all of the files in parallel, giving back the results in the same order as
+    /// `files`.
+    pub fn parse(&self) -> Vec<ParsedFile> {
+        self.map(|file| file)
+    }
+
+    /// Parse all of the files in parallel and pass each result to `f` as soon as it's ready,
+    /// so that the programs don't all have to be kept around at once.
+    pub fn map<T: Send>(&self, f: impl Fn(ParsedFile) -> T + Send + Sync) -> Vec<T> {
+        self.files()
+            .into_par_iter()
+            .map(|file| match file {
+                Ok(path) => self.parse_file(path),
+                Err((path, error)) => ParsedFile {
+                    path,
+                    source: Vec::new(),
+                    result: Err(FileError::Io(error)),
+                },
+            })
+            .map(f)
+            .collect()
+    }
+
+    fn parse_file(&self, path: PathBuf) -> ParsedFile {
+        let input = match std::fs::read(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                return ParsedFile {
+                    path,
+                    source: Vec::new(),
+                    result: Err(FileError::Io(error)),
+                }
+            }
+        };
+
+        ParsedFile {
+            path,
+            result: crate::parse_with(&input, self.options).map_err(FileError::Parse),
+            source: input,
+        }
+    }
+
+    fn included(&self, root: &Path, path: &Path) -> bool {
+        match &self.included {
+            Some(include) => matches(include, root, path),
+            None => path.extension().is_some_and(|extension| extension == "php"),
+        }
+    }
+
+    fn skipped(&self, root: &Path, entry: &DirEntry) -> bool {
+        if self.skip_vendor && entry.file_type().is_dir() && entry.file_name() == "vendor" {
+            return true;
+        }
+
+        matches(&self.excluded, root, entry.path())
+    }
+}
+
+impl Default for Files {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Only the first parse error is shown, the rest are often caused by it.
+impl Display for FileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{}", error),
+            Self::Parse(stack) => match stack.errors.first() {
+                Some(error) => write!(f, "{}", error),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+fn glob(pattern: &str) -> Result<globset::Glob, PatternError> {
+    GlobBuilder::new(pattern).literal_separator(true).build()
+}
+
+fn matches(set: &GlobSet, root: &Path, path: &Path) -> bool {
+    set.is_match(path.strip_prefix(root).unwrap_or(path))
+}
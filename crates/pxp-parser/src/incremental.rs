@@ -0,0 +1,539 @@
+use std::ops::Range;
+
+use crate::error::ParseError;
+use crate::error::ParseErrorStack;
+use crate::error::ParseResult;
+use crate::internal::classes;
+use crate::internal::functions;
+use crate::internal::keywords;
+use crate::internal::utils;
+use crate::options::ParserOptions;
+use crate::state::Scope;
+use crate::state::State;
+use pxp_ast::classes::ClassMember;
+use pxp_ast::classes::ClassStatement;
+use pxp_ast::comments::CommentGroup;
+use pxp_ast::functions::FunctionBody;
+use pxp_ast::functions::MethodBody;
+use pxp_ast::namespaces::NamespaceStatement;
+use pxp_ast::spanned::Spanned;
+use pxp_ast::spanned::SpannedMut;
+use pxp_ast::Program;
+use pxp_ast::Statement;
+use pxp_lexer::incremental::CheckpointedTokens;
+use pxp_lexer::incremental::Shift;
+use pxp_lexer::stream::is_comment;
+use pxp_lexer::stream::LazyTokenStream;
+use pxp_span::Span;
+use pxp_token::Token;
+use pxp_token::TokenKind;
+
+pub use pxp_lexer::incremental::TextEdit;
+
+// How many tokens past the end of the part being parsed again the parser gets to see, which is
+// more than it ever looks ahead.
+const LOOKAHEAD: usize = 8;
+
+/// A parsed file that can be brought up to date after an edit by only parsing the function
+/// body, class member or top-level statement that was edited.
+///
+/// Only the tokens and the AST are updated, the syntax tree from `parse_cst` isn't used here,
+/// so it has to be built again from scratch after an edit.
+///
+/// # Examples
+///
+/// ```rust
+/// use pxp_parser::incremental::Document;
+/// use pxp_parser::incremental::TextEdit;
+/// use pxp_parser::options::ParserOptions;
+///
+/// let input = "<?php function a() { return 1; } function b() { return 2; }";
+/// let document = Document::parse(input, ParserOptions::new());
+///
+/// let edit = TextEdit::new(28..29, "42");
+/// let edited = edit.apply(input.as_bytes()).unwrap();
+/// let document = document.edit(&edit, &edited);
+///
+/// assert_eq!(document.reparsed().unwrap().start.offset, 19);
+/// assert_eq!(
+///     document.into_result(),
+///     pxp_parser::parse_with(&edited, ParserOptions::new()),
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Document<'a> {
+    tokens: Option<CheckpointedTokens<'a>>,
+    program: Program,
+    errors: Vec<ParseError>,
+    options: ParserOptions,
+    reparsed: Option<Span>,
+}
+
+impl<'a> Document<'a> {
+    pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &'a B, options: ParserOptions) -> Self {
+        match options.lexer().tokenize_checkpointed(input) {
+            Ok(tokens) => Self::construct(tokens, options),
+            Err(error) => Self {
+                tokens: None,
+                program: Program::new(),
+                errors: vec![error.into()],
+                options,
+                reparsed: None,
+            },
+        }
+    }
+
+    fn construct(tokens: CheckpointedTokens<'a>, options: ParserOptions) -> Self {
+        let (program, errors) = match crate::construct_with(tokens.tokens(), options) {
+            Ok(program) => (program, Vec::new()),
+            Err(stack) => (stack.partial, stack.errors),
+        };
+
+        Self {
+            tokens: Some(tokens),
+            program,
+            errors,
+            options,
+            reparsed: None,
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// What was parsed again after the last edit, or `None` if it was the whole file.
+    pub fn reparsed(&self) -> Option<Span> {
+        self.reparsed
+    }
+
+    /// The same result that `parse_with` gives for the current input.
+    pub fn into_result(self) -> Result<Program, ParseErrorStack> {
+        if self.errors.is_empty() {
+            return Ok(self.program);
+        }
+
+        Err(ParseErrorStack {
+            errors: self.errors,
+            partial: self.program,
+        })
+    }
+
+    /// Update the document after `edit` was applied to its input, producing `input`.
+    ///
+    /// Anything that could come out differently when it's parsed on its own falls back to
+    /// parsing the whole file, such as a file with errors in it, or an edit that changes
+    /// which statement a comment belongs to.
+    pub fn edit<'b, B: ?Sized + AsRef<[u8]>>(
+        self,
+        edit: &TextEdit,
+        input: &'b B,
+    ) -> Document<'b> {
+        let Document {
+            tokens,
+            mut program,
+            errors,
+            options,
+            ..
+        } = self;
+
+        let Some(tokens) = tokens.filter(|_| errors.is_empty()) else {
+            return Document::parse(input, options);
+        };
+
+        let Ok(relexed) = options.lexer().relex(&tokens, edit, input) else {
+            return Document::parse(input, options);
+        };
+
+        let reparse = Reparse {
+            old: tokens.tokens(),
+            new: relexed.tokens.tokens(),
+            changed: relexed.changed.clone(),
+            shift: relexed.shift,
+            edit,
+            options,
+        };
+
+        match reparse.program(&mut program) {
+            Some(span) => Document {
+                tokens: Some(relexed.tokens),
+                program,
+                errors: Vec::new(),
+                options,
+                reparsed: Some(span),
+            },
+            None => Document::construct(relexed.tokens, options),
+        }
+    }
+}
+
+// Where the top-level statement that was edited is, looking inside of namespaces.
+struct Location {
+    namespace: Option<usize>,
+    index: usize,
+}
+
+impl Location {
+    fn find(program: &Program, edit: &TextEdit) -> Option<(Location, Option<Scope>)> {
+        let index = containing(program, edit)?;
+
+        let (statements, scope) = match &program[index] {
+            Statement::Namespace(NamespaceStatement::Unbraced(namespace)) => (
+                &namespace.statements,
+                Scope::Namespace(namespace.name.clone()),
+            ),
+            Statement::Namespace(NamespaceStatement::Braced(namespace)) => (
+                &namespace.body.statements,
+                Scope::BracedNamespace(namespace.name.clone()),
+            ),
+            _ => {
+                let location = Location {
+                    namespace: None,
+                    index,
+                };
+
+                return Some((location, None));
+            }
+        };
+
+        let location = Location {
+            namespace: Some(index),
+            index: containing(statements, edit)?,
+        };
+
+        Some((location, Some(scope)))
+    }
+
+    fn statements<'p>(&self, program: &'p mut Program) -> &'p mut Vec<Statement> {
+        let Some(namespace) = self.namespace else {
+            return program;
+        };
+
+        match &mut program[namespace] {
+            Statement::Namespace(NamespaceStatement::Unbraced(namespace)) => {
+                &mut namespace.statements
+            }
+            Statement::Namespace(NamespaceStatement::Braced(namespace)) => {
+                &mut namespace.body.statements
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+// A part of the program that was parsed again, along with where it goes.
+enum Replacement {
+    Statement(Box<Statement>),
+    FunctionBody(FunctionBody),
+    Member(usize, Box<ClassMember>),
+    MethodBody(usize, MethodBody),
+}
+
+impl Replacement {
+    fn span(&self) -> Span {
+        match self {
+            Replacement::Statement(statement) => statement.span(),
+            Replacement::FunctionBody(body) => body.span(),
+            Replacement::Member(_, member) => member.span(),
+            Replacement::MethodBody(_, body) => body.span(),
+        }
+    }
+
+    fn apply(self, statement: &mut Statement) {
+        match (self, statement) {
+            (Replacement::Statement(replacement), statement) => *statement = *replacement,
+            (Replacement::FunctionBody(body), Statement::Function(function)) => {
+                function.body = body;
+            }
+            (Replacement::Member(index, member), Statement::Class(class)) => {
+                class.body.members[index] = *member;
+            }
+            (Replacement::MethodBody(index, body), Statement::Class(class)) => {
+                match &mut class.body.members[index] {
+                    ClassMember::ConcreteMethod(method) => method.body = body,
+                    ClassMember::ConcreteConstructor(constructor) => constructor.body = body,
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct Reparse<'a, 'b> {
+    old: &'a [Token<'a>],
+    new: &'b [Token<'b>],
+    // The tokens that were produced again by the lexer, everything after them is known to be
+    // the same as before.
+    changed: Range<usize>,
+    shift: Shift,
+    edit: &'a TextEdit,
+    options: ParserOptions,
+}
+
+impl Reparse<'_, '_> {
+    // Parse the innermost part of the program that the edit is in again, returning its span.
+    fn program(&self, program: &mut Program) -> Option<Span> {
+        let (location, scope) = Location::find(program, self.edit)?;
+        let replacement = self.statement(location.statements(program), location.index, &scope)?;
+        let span = replacement.span();
+
+        program.for_each_span_mut(&mut |span| {
+            if span.start.offset >= self.edit.range.end {
+                span.start = self.shift.position(span.start);
+            }
+
+            if span.end.offset >= self.edit.range.end {
+                span.end = self.shift.position(span.end);
+            }
+        });
+
+        replacement.apply(&mut location.statements(program)[location.index]);
+
+        Some(span)
+    }
+
+    fn statement(
+        &self,
+        statements: &[Statement],
+        index: usize,
+        scope: &Option<Scope>,
+    ) -> Option<Replacement> {
+        let statement = &statements[index];
+
+        let inner = match statement {
+            Statement::Function(function) => self
+                .unit(function.body.span(), 1, scope, functions::function_body, |body| {
+                    *body == function.body
+                })
+                .map(Replacement::FunctionBody),
+            Statement::Class(class) => self.member(class, scope),
+            _ => None,
+        };
+
+        if inner.is_some() {
+            return inner;
+        }
+
+        // Comments in front of a statement are turned into statements of their own right before
+        // it's parsed, unless it's a declaration that they belong to.
+        let comments = statements[..index]
+            .iter()
+            .rev()
+            .take_while(|statement| matches!(statement, Statement::Comment(_)))
+            .count();
+        let comments = &statements[index - comments..index];
+
+        let parse = |state: &mut State| {
+            let comments = utils::free_standing_comments(state);
+
+            Ok((comments, crate::top_level_statement(state)?))
+        };
+
+        self.unit(statement.span(), 0, scope, parse, |reparsed| {
+            reparsed.0 == comments && reparsed.1 == *statement
+        })
+        .filter(|(reparsed, statement)| {
+            reparsed == comments && !matches!(statement, Statement::Namespace(_))
+        })
+        .map(|(_, statement)| Replacement::Statement(Box::new(statement)))
+    }
+
+    fn member(&self, class: &ClassStatement, scope: &Option<Scope>) -> Option<Replacement> {
+        let members = &class.body.members;
+        let index = members.iter().position(|member| inside(member.span(), self.edit))?;
+        let member = &members[index];
+
+        let body = match member {
+            ClassMember::ConcreteMethod(method) => Some(&method.body),
+            ClassMember::ConcreteConstructor(constructor) => Some(&constructor.body),
+            _ => None,
+        };
+
+        if let Some(body) = body {
+            let reparsed = self.unit(body.span(), 1, scope, functions::method_body, |reparsed| {
+                reparsed == body
+            });
+
+            if let Some(reparsed) = reparsed {
+                return Some(Replacement::MethodBody(index, reparsed));
+            }
+        }
+
+        // Trait uses don't take the comments in front of them, which leaves them for whatever
+        // comes next, so a different member would have to be parsed again too.
+        if matches!(member, ClassMember::TraitUsage(_)) {
+            return None;
+        }
+
+        let has_abstract = class.modifiers.has_abstract();
+        let parse = |state: &mut State| classes::member(state, has_abstract, Some(&class.name));
+
+        self.unit(member.span(), 1, scope, parse, |reparsed| reparsed == member)
+            .filter(|member| !matches!(member, ClassMember::TraitUsage(_)))
+            .map(|member| Replacement::Member(index, Box::new(member)))
+    }
+
+    // Parse the part of the program at `span` again, `depth` levels deep.
+    //
+    // The old tokens are parsed the same way first and have to give back the part of the old
+    // program that `unchanged` is looking for, which makes sure that parsing it on its own
+    // doesn't miss anything that came before it, such as comments that belong to it.
+    fn unit<T>(
+        &self,
+        span: Span,
+        depth: usize,
+        scope: &Option<Scope>,
+        parse: impl Fn(&mut State) -> ParseResult<T>,
+        unchanged: impl Fn(&T) -> bool,
+    ) -> Option<T> {
+        if !inside(span, self.edit) {
+            return None;
+        }
+
+        let old = self.old;
+        let first = old.partition_point(|token| token.span.start.offset < span.start.offset);
+        let last = old.partition_point(|token| token.span.end.offset < span.end.offset);
+
+        if old.get(first)?.span.start != span.start || old.get(last)?.span.end != span.end {
+            return None;
+        }
+
+        // The comments right in front of the unit could belong to it.
+        let from = old[..first]
+            .iter()
+            .rposition(|token| !is_comment(&token.kind))
+            .map_or(0, |previous| previous + 1);
+        let next = last + 1 + old[last + 1..].iter().position(|token| !is_comment(&token.kind))?;
+
+        // Everything after the unit is the same, so tokens can be matched up from the end.
+        let difference = self.new.len() as isize - old.len() as isize;
+        let new_last = last.checked_add_signed(difference)?;
+        let new_next = next.checked_add_signed(difference)?;
+
+        if new_last < first {
+            return None;
+        }
+
+        // The tokens in front of the unit that were lexed again have to come out the same...
+        let relexed = self.changed.start.min(first);
+        if self.new.get(relexed..first)? != &old[relexed..first] {
+            return None;
+        }
+
+        // ...and so do the ones after it, up to where the lexer picked the old tokens back up.
+        let checked = lookahead(self.new, new_next)
+            .max(self.changed.end)
+            .min(self.new.len());
+        for (token, previous) in self.new[new_last + 1..checked]
+            .iter()
+            .zip(&old[last + 1..])
+        {
+            if token.kind != previous.kind || token.span != self.shift.span(previous.span) {
+                return None;
+            }
+        }
+
+        let (reparsed, old_comments) =
+            self.parse(&old[from..lookahead(old, next)], &old[next], depth, scope, &parse)?;
+
+        if !unchanged(&reparsed) {
+            return None;
+        }
+
+        let new = &self.new[from..lookahead(self.new, new_next)];
+        let (unit, comments) = self.parse(new, &self.new[new_next], depth, scope, &parse)?;
+
+        // Comments that weren't used are left for whatever comes after the unit.
+        let same_comments = comments.comments.len() == old_comments.comments.len()
+            && comments
+                .iter()
+                .zip(old_comments.iter())
+                .all(|(comment, previous)| Some(comment.span) == self.moved(previous.span));
+
+        same_comments.then_some(unit)
+    }
+
+    fn parse<T>(
+        &self,
+        tokens: &[Token],
+        next: &Token,
+        depth: usize,
+        scope: &Option<Scope>,
+        parse: &impl Fn(&mut State) -> ParseResult<T>,
+    ) -> Option<(T, CommentGroup)> {
+        // The parser relies on there being an end of file token to stop at.
+        let end = tokens.last().map_or_else(Default::default, |token| token.span.end);
+        let tokens = tokens
+            .iter()
+            .cloned()
+            .chain([Token {
+                kind: TokenKind::Eof,
+                span: Span::new(end, end),
+                value: &[],
+            }])
+            .collect::<Vec<_>>();
+        let tokens = keywords::demote(&tokens, self.options.php_version);
+
+        let mut stream = LazyTokenStream::from(&tokens[..]);
+        let mut state = State::new(&mut stream, self.options).at_depth(depth);
+
+        if let Some(scope) = scope {
+            state.enter(scope.clone());
+        }
+
+        let result = parse(&mut state).ok()?;
+
+        if !state.errors.is_empty()
+            || !state.attributes.is_empty()
+            || state.stream.current().span != next.span
+        {
+            return None;
+        }
+
+        Some((result, state.stream.comments()))
+    }
+
+    // Where a span from before the edit is now, if it wasn't part of the edit.
+    fn moved(&self, span: Span) -> Option<Span> {
+        if span.end.offset <= self.edit.range.start {
+            Some(span)
+        } else if span.start.offset >= self.edit.range.end {
+            Some(self.shift.span(span))
+        } else {
+            None
+        }
+    }
+}
+
+// The index of the statement that `edit` is inside of.
+fn containing(statements: &[Statement], edit: &TextEdit) -> Option<usize> {
+    statements
+        .iter()
+        .position(|statement| inside(statement.span(), edit))
+}
+
+// Whether the edit is somewhere inside of `span`, without touching either end.
+fn inside(span: Span, edit: &TextEdit) -> bool {
+    span.start.offset < edit.range.start && edit.range.end < span.end.offset
+}
+
+// The end of the tokens that the parser could look at while it's at `next`.
+fn lookahead(tokens: &[Token], next: usize) -> usize {
+    let mut end = next;
+    let mut seen = 0;
+
+    while end < tokens.len() && seen <= LOOKAHEAD {
+        if !is_comment(&tokens[end].kind) {
+            seen += 1;
+        }
+
+        end += 1;
+    }
+
+    end
+}
@@ -6,6 +6,7 @@ use crate::state::State;
 use pxp_ast::Expression;
 use pxp_ast::ListEntry;
 use pxp_ast::{ArrayExpression, ArrayItem, ListExpression, ShortArrayExpression};
+use pxp_span::Span;
 use pxp_token::TokenKind;
 
 pub fn list_expression(state: &mut State) -> ParseResult<Expression> {
@@ -21,7 +22,8 @@ pub fn list_expression(state: &mut State) -> ParseResult<Expression> {
                 if current.kind == TokenKind::Comma {
                     state.stream.next();
 
-                    items.push(ListEntry::Skipped);
+                    // A skipped entry takes up no space, right before its comma.
+                    items.push(ListEntry::Skipped(Span::new(current.span.start, current.span.start)));
 
                     current = state.stream.current();
 
@@ -114,7 +116,7 @@ pub fn short_array_expression(state: &mut State) -> ParseResult<Expression> {
             &|state| {
                 let current = state.stream.current();
                 if current.kind == TokenKind::Comma {
-                    Ok(ArrayItem::Skipped)
+                    Ok(ArrayItem::Skipped(Span::new(current.span.start, current.span.start)))
                 } else {
                     array_pair(state)
                 }
@@ -5,6 +5,7 @@ use crate::internal::utils;
 use crate::state::State;
 use pxp_ast::attributes::Attribute;
 use pxp_ast::attributes::AttributeGroup;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub fn gather_attributes(state: &mut State) -> ParseResult<bool> {
@@ -13,6 +14,8 @@ pub fn gather_attributes(state: &mut State) -> ParseResult<bool> {
     }
 
     let start = state.stream.current().span;
+    state.requires(PhpVersion::Php80, "attributes", start);
+
     let mut members = vec![];
 
     state.stream.next();
@@ -25,7 +28,9 @@ pub fn gather_attributes(state: &mut State) -> ParseResult<bool> {
         } else {
             None
         };
-        let end = state.stream.current().span;
+        let end = arguments
+            .as_ref()
+            .map_or(name.span, |arguments| arguments.right_parenthesis);
 
         members.push(Attribute {
             start,
@@ -26,7 +26,7 @@ pub fn multiple_statements_until(
     let mut statements = Vec::new();
 
     let mut current = state.stream.current();
-    while &current.kind != until {
+    while &current.kind != until && current.kind != TokenKind::Eof {
         if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
             state.stream.next();
 
@@ -34,10 +34,13 @@ pub fn multiple_statements_until(
             continue;
         }
 
-        statements.push(statement(state)?);
+        statements.extend(utils::free_standing_comments(state));
+        statements.push(utils::recover(state, &statement).unwrap_or_else(Statement::Error));
         current = state.stream.current();
     }
 
+    statements.extend(utils::free_standing_comments(state));
+
     Ok(statements)
 }
 
@@ -48,7 +51,7 @@ pub fn multiple_statements_until_any(
     let mut statements = Vec::new();
 
     let mut current = state.stream.current();
-    while !until.contains(&current.kind) {
+    while !until.contains(&current.kind) && current.kind != TokenKind::Eof {
         if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
             state.stream.next();
 
@@ -56,9 +59,12 @@ pub fn multiple_statements_until_any(
             continue;
         }
 
-        statements.push(statement(state)?);
+        statements.extend(utils::free_standing_comments(state));
+        statements.push(utils::recover(state, &statement).unwrap_or_else(Statement::Error));
         current = state.stream.current();
     }
 
+    statements.extend(utils::free_standing_comments(state));
+
     Ok(statements)
 }
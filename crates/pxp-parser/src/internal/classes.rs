@@ -1,5 +1,6 @@
 use crate::error;
 use crate::error::ParseResult;
+use crate::expected_token_err;
 use crate::internal::attributes;
 use crate::internal::constants::classish;
 use crate::internal::functions::method;
@@ -22,14 +23,20 @@ use pxp_ast::classes::ClassMember;
 use pxp_ast::classes::ClassStatement;
 use pxp_ast::identifiers::SimpleIdentifier;
 use pxp_ast::Statement;
+use pxp_ast::spanned::Spanned;
 use pxp_ast::{Expression, NewExpression};
 use pxp_span::Span;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let attributes = state.get_attributes();
 
     let modifiers = modifiers::class_group(modifiers::collect(state)?)?;
+    if let Some(readonly) = modifiers.get_readonly() {
+        state.requires(PhpVersion::Php82, "readonly classes", readonly.span());
+    }
     let class = utils::skip(state, TokenKind::Class)?;
     let name = identifiers::type_identifier(state)?;
     let current = state.stream.current();
@@ -71,16 +78,21 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         left_brace: utils::skip_left_brace(state)?,
         members: {
             let mut members = Vec::new();
-            while state.stream.current().kind != TokenKind::RightBrace {
-                members.push(member(state, has_abstract, &name)?);
+            while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+                members.push(
+                    utils::recover(state, &|state| member(state, has_abstract, Some(&name)))
+                        .unwrap_or_else(ClassMember::Error),
+                );
             }
 
             members
         },
+        comments: state.stream.comments(),
         right_brace: utils::skip_right_brace(state)?,
     };
 
     Ok(Statement::Class(ClassStatement {
+        comments,
         class,
         name,
         modifiers,
@@ -143,11 +155,15 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
         left_brace: utils::skip_left_brace(state)?,
         members: {
             let mut members = Vec::new();
-            while state.stream.current().kind != TokenKind::RightBrace {
-                members.push(anonymous_member(state)?);
+            while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+                members.push(
+                    utils::recover(state, &anonymous_member)
+                        .unwrap_or_else(AnonymousClassMember::Error),
+                );
             }
             members
         },
+        comments: state.stream.comments(),
         right_brace: utils::skip_right_brace(state)?,
     };
 
@@ -164,10 +180,11 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
     }))
 }
 
-fn member(
+// `name` is only missing when a member is parsed on its own, outside of a class.
+pub fn member(
     state: &mut State,
     has_abstract: bool,
-    name: &SimpleIdentifier,
+    name: Option<&SimpleIdentifier>,
 ) -> ParseResult<ClassMember> {
     let has_attributes = attributes::gather_attributes(state)?;
 
@@ -176,7 +193,7 @@ fn member(
     }
 
     if state.stream.current().kind == TokenKind::Var {
-        return properties::parse_var(state, Some(name)).map(ClassMember::VariableProperty);
+        return properties::parse_var(state, name).map(ClassMember::VariableProperty);
     }
 
     let modifiers = modifiers::collect(state)?;
@@ -190,37 +207,40 @@ fn member(
             state,
             MethodType::DependingOnModifiers,
             modifiers::method_group(modifiers)?,
-            Some(name),
+            name,
         )?;
 
         return match method {
-            Method::Abstract(method) => {
-                if has_abstract {
-                    Ok(ClassMember::AbstractMethod(method))
-                } else {
+            Method::Abstract(method) => match name {
+                Some(name) if !has_abstract => {
                     Err(error::abstract_method_on_a_non_abstract_class(
                         state,
                         name,
                         &method.name,
-                        method.modifiers.get_abstract().unwrap().span(),
+                        method
+                            .modifiers
+                            .get_abstract()
+                            .map_or(method.semicolon, |modifier| modifier.span()),
                         method.semicolon,
                     ))
                 }
-            }
+                _ => Ok(ClassMember::AbstractMethod(method)),
+            },
             Method::Concrete(method) => Ok(ClassMember::ConcreteMethod(method)),
-            Method::AbstractConstructor(ctor) => {
-                if has_abstract {
-                    Ok(ClassMember::AbstractConstructor(ctor))
-                } else {
+            Method::AbstractConstructor(ctor) => match name {
+                Some(name) if !has_abstract => {
                     Err(error::abstract_method_on_a_non_abstract_class(
                         state,
                         name,
                         &ctor.name,
-                        ctor.modifiers.get_abstract().unwrap().span(),
+                        ctor.modifiers
+                            .get_abstract()
+                            .map_or(ctor.semicolon, |modifier| modifier.span()),
                         ctor.semicolon,
                     ))
                 }
-            }
+                _ => Ok(ClassMember::AbstractConstructor(ctor)),
+            },
             Method::ConcreteConstructor(ctor) => Ok(ClassMember::ConcreteConstructor(ctor)),
         };
     }
@@ -228,7 +248,7 @@ fn member(
     // e.g: public static
     let modifiers = modifiers::property_group(modifiers)?;
 
-    properties::parse(state, Some(name), modifiers).map(ClassMember::Property)
+    properties::parse(state, name, modifiers).map(ClassMember::Property)
 }
 
 fn anonymous_member(state: &mut State) -> ParseResult<AnonymousClassMember> {
@@ -264,7 +284,9 @@ fn anonymous_member(state: &mut State) -> ParseResult<AnonymousClassMember> {
             Method::ConcreteConstructor(ctor) => {
                 return Ok(AnonymousClassMember::ConcreteConstructor(ctor));
             }
-            Method::Abstract(_) | Method::AbstractConstructor(_) => unreachable!(),
+            Method::Abstract(_) | Method::AbstractConstructor(_) => {
+                return expected_token_err!("`{`", state);
+            }
         }
     }
 
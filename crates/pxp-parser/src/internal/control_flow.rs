@@ -20,10 +20,13 @@ use pxp_ast::MatchArm;
 use pxp_ast::Statement;
 use pxp_ast::SwitchStatement;
 use pxp_ast::{Block, MatchExpression};
+use pxp_ast::spanned::Spanned;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
     let keyword = utils::skip(state, TokenKind::Match)?;
+    state.requires(PhpVersion::Php80, "match expressions", keyword);
 
     let (left_parenthesis, condition, right_parenthesis) =
         utils::parenthesized(state, &|state: &mut State| {
@@ -126,11 +129,12 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
     while state.stream.current().kind != end_token {
         match state.stream.current().kind {
             TokenKind::Case => {
+                let start = state.stream.current().span;
                 state.stream.next();
 
                 let condition = expressions::create(state)?;
 
-                utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
+                let colon = utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
 
                 let mut body = Block::new();
 
@@ -138,30 +142,41 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
                     && state.stream.current().kind != TokenKind::Default
                     && state.stream.current().kind != TokenKind::RightBrace
                     && state.stream.current().kind != end_token
+                    && !state.stream.is_eof()
                 {
-                    body.push(statement(state)?);
+                    body.extend(utils::free_standing_comments(state));
+                    body.push(utils::recover(state, &statement).unwrap_or_else(Statement::Error));
                 }
 
+                body.extend(utils::free_standing_comments(state));
+
                 cases.push(Case {
+                    span: start.join(body.last().map_or(colon, Spanned::span)),
                     condition: Some(condition),
                     body,
                 });
             }
             TokenKind::Default => {
+                let start = state.stream.current().span;
                 state.stream.next();
 
-                utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
+                let colon = utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
 
                 let mut body = Block::new();
 
                 while state.stream.current().kind != TokenKind::Case
                     && state.stream.current().kind != TokenKind::Default
                     && state.stream.current().kind != end_token
+                    && !state.stream.is_eof()
                 {
-                    body.push(statement(state)?);
+                    body.extend(utils::free_standing_comments(state));
+                    body.push(utils::recover(state, &statement).unwrap_or_else(Statement::Error));
                 }
 
+                body.extend(utils::free_standing_comments(state));
+
                 cases.push(Case {
+                    span: start.join(body.last().map_or(colon, Spanned::span)),
                     condition: None,
                     body,
                 });
@@ -5,9 +5,27 @@ use crate::internal::utils;
 use crate::state::State;
 use crate::peek_token;
 use pxp_ast::data_type::Type;
+use pxp_ast::spanned::Spanned;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub fn data_type(state: &mut State) -> ParseResult<Type> {
+    let ty = any_data_type(state)?;
+    check_version(state, &ty);
+
+    Ok(ty)
+}
+
+pub fn optional_data_type(state: &mut State) -> ParseResult<Option<Type>> {
+    let ty = any_optional_data_type(state)?;
+    if let Some(ty) = &ty {
+        check_version(state, ty);
+    }
+
+    Ok(ty)
+}
+
+fn any_data_type(state: &mut State) -> ParseResult<Type> {
     if state.stream.current().kind == TokenKind::Question {
         return nullable(state);
     }
@@ -35,7 +53,7 @@ pub fn data_type(state: &mut State) -> ParseResult<Type> {
     Ok(ty)
 }
 
-pub fn optional_data_type(state: &mut State) -> ParseResult<Option<Type>> {
+fn any_optional_data_type(state: &mut State) -> ParseResult<Option<Type>> {
     if state.stream.current().kind == TokenKind::Question {
         return nullable(state).map(Some);
     }
@@ -68,6 +86,49 @@ pub fn optional_data_type(state: &mut State) -> ParseResult<Option<Type>> {
     }
 }
 
+fn check_version(state: &mut State, ty: &Type) {
+    if let Some((version, feature)) = required_version(ty, true) {
+        state.requires(version, feature, ty.span());
+    }
+}
+
+// The newest version of PHP needed for a type, and the feature that needs it.
+fn required_version(ty: &Type, standalone: bool) -> Option<(PhpVersion, &'static str)> {
+    let required = match ty {
+        Type::Nullable(_, inner) => {
+            return required_version(inner, false).max(Some((PhpVersion::Php71, "nullable types")))
+        }
+        Type::Union(types) | Type::Intersection(types) => {
+            let dnf = types
+                .iter()
+                .any(|inner| matches!(inner, Type::Union(_) | Type::Intersection(_)));
+            let required = match ty {
+                _ if dnf => (PhpVersion::Php82, "disjunctive normal form types"),
+                Type::Union(_) => (PhpVersion::Php80, "union types"),
+                _ => (PhpVersion::Php81, "intersection types"),
+            };
+
+            return types
+                .iter()
+                .map(|inner| required_version(inner, false))
+                .fold(Some(required), Ord::max);
+        }
+        Type::Void(_) => (PhpVersion::Php71, "`void` types"),
+        Type::Iterable(_) => (PhpVersion::Php71, "`iterable` types"),
+        Type::Object(_) => (PhpVersion::Php72, "`object` types"),
+        Type::Mixed(_) => (PhpVersion::Php80, "`mixed` types"),
+        Type::StaticReference(_) => (PhpVersion::Php80, "`static` types"),
+        Type::Never(_) => (PhpVersion::Php81, "`never` types"),
+        Type::True(_) => (PhpVersion::Php82, "`true` types"),
+        Type::Null(_) | Type::False(_) if standalone => {
+            (PhpVersion::Php82, "standalone `null` and `false` types")
+        }
+        _ => return None,
+    };
+
+    Some(required)
+}
+
 fn dnf(state: &mut State) -> ParseResult<Type> {
     // (A|B|..)&C.. or (A&B&..)|C..
     state.stream.next();
@@ -151,11 +212,10 @@ fn optional_simple_data_type(state: &mut State) -> ParseResult<Option<Type>> {
             Ok(Some(Type::Named(span, name)))
         }
         TokenKind::Identifier => {
-            let id = current.value.clone();
+            let name = current.value;
             let span = current.span;
             state.stream.next();
 
-            let name = &id[..];
             let lowered_name = name.to_ascii_lowercase();
             match lowered_name.as_slice() {
                 b"void" => Ok(Some(Type::Void(span))),
@@ -176,7 +236,7 @@ fn optional_simple_data_type(state: &mut State) -> ParseResult<Option<Type>> {
             }
         }
         TokenKind::QualifiedIdentifier | TokenKind::FullyQualifiedIdentifier => {
-            let name = current.value.clone();
+            let name = current.value.into();
             let span = current.span;
             state.stream.next();
 
@@ -1,5 +1,6 @@
 use crate::error;
 use crate::error::ParseResult;
+use crate::expected_token_err;
 use crate::expressions;
 use crate::internal::attributes;
 use crate::internal::constants;
@@ -20,14 +21,18 @@ use pxp_ast::enums::UnitEnumMember;
 use pxp_ast::enums::UnitEnumStatement;
 use pxp_ast::functions::ConcreteMethod;
 use pxp_ast::identifiers::SimpleIdentifier;
+use pxp_ast::spanned::Spanned;
 use pxp_ast::Statement;
 use pxp_span::Span;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 use super::traits;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Enum)?;
+    state.requires(PhpVersion::Php81, "enums", span);
 
     let name = identifiers::type_identifier(state)?;
 
@@ -37,8 +42,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         let identifier = identifiers::identifier_of(state, &["string", "int"])?;
         Some(match &identifier.value[..] {
             b"string" => BackedEnumType::String(span, identifier.span),
-            b"int" => BackedEnumType::Int(span, identifier.span),
-            _ => unreachable!(),
+            _ => BackedEnumType::Int(span, identifier.span),
         })
     } else {
         None
@@ -65,18 +69,21 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
             left_brace: utils::skip_left_brace(state)?,
             members: {
                 let mut members = Vec::new();
-                while state.stream.current().kind != TokenKind::RightBrace {
-                    if let Some(member) = backed_member(state, &name)? {
-                        members.push(member);
-                    }
+                while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+                    members.push(
+                        utils::recover(state, &|state| backed_member(state, &name))
+                            .unwrap_or_else(BackedEnumMember::Error),
+                    );
                 }
 
                 members
             },
+            comments: state.stream.comments(),
             right_brace: utils::skip_right_brace(state)?,
         };
 
         Ok(Statement::BackedEnum(BackedEnumStatement {
+            comments,
             r#enum: span,
             name,
             backed_type,
@@ -89,17 +96,20 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
             left_brace: utils::skip_left_brace(state)?,
             members: {
                 let mut members = Vec::new();
-                while state.stream.current().kind != TokenKind::RightBrace {
-                    if let Some(member) = unit_member(state, &name)? {
-                        members.push(member);
-                    }
+                while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+                    members.push(
+                        utils::recover(state, &|state| unit_member(state, &name))
+                            .unwrap_or_else(UnitEnumMember::Error),
+                    );
                 }
                 members
             },
+            comments: state.stream.comments(),
             right_brace: utils::skip_right_brace(state)?,
         };
 
         Ok(Statement::UnitEnum(UnitEnumStatement {
+            comments,
             r#enum: span,
             name,
             attributes,
@@ -112,17 +122,16 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
 fn unit_member(
     state: &mut State,
     enum_name: &SimpleIdentifier,
-) -> ParseResult<Option<UnitEnumMember>> {
+) -> ParseResult<UnitEnumMember> {
     let has_attributes = attributes::gather_attributes(state)?;
 
     if !has_attributes && state.stream.current().kind == TokenKind::Use {
-        return traits::usage(state)
-            .map(UnitEnumMember::TraitUsage)
-            .map(Some);
+        return traits::usage(state).map(UnitEnumMember::TraitUsage);
     }
 
     let current = state.stream.current();
     if current.kind == TokenKind::Case {
+        let comments = state.stream.comments();
         let attributes = state.get_attributes();
 
         let start = current.span;
@@ -135,50 +144,52 @@ fn unit_member(
             // parse the value, but don't do anything with it.
             let _ = utils::skip(state, TokenKind::Equals)?;
             let _ = expressions::create(state)?;
-            let _ = utils::skip_semicolon(state)?;
+            let end = utils::skip_semicolon(state)?;
 
             let error = error::case_value_for_unit_enum(state, enum_name, &name, current.span);
 
             state.record(error);
 
-            return Ok(None);
+            return Ok(UnitEnumMember::Error(start.join(end)));
         }
 
         let end = utils::skip_semicolon(state)?;
 
-        return Ok(Some(UnitEnumMember::Case(UnitEnumCase {
+        return Ok(UnitEnumMember::Case(UnitEnumCase {
+            comments,
             start,
             end,
             name,
             attributes,
-        })));
+        }));
     }
 
     let modifiers = modifiers::collect(state)?;
 
     if state.stream.current().kind == TokenKind::Const {
         return constants::classish(state, modifiers::constant_group(modifiers)?)
-            .map(UnitEnumMember::Constant)
-            .map(Some);
+            .map(UnitEnumMember::Constant);
     }
 
-    method(state, modifiers, enum_name).map(|method| method.map(UnitEnumMember::Method))
+    method(state, modifiers, enum_name).map(|method| match method {
+        Ok(method) => UnitEnumMember::Method(method),
+        Err(span) => UnitEnumMember::Error(span),
+    })
 }
 
 fn backed_member(
     state: &mut State,
     enum_name: &SimpleIdentifier,
-) -> ParseResult<Option<BackedEnumMember>> {
+) -> ParseResult<BackedEnumMember> {
     let has_attributes = attributes::gather_attributes(state)?;
 
     if !has_attributes && state.stream.current().kind == TokenKind::Use {
-        return traits::usage(state)
-            .map(BackedEnumMember::TraitUsage)
-            .map(Some);
+        return traits::usage(state).map(BackedEnumMember::TraitUsage);
     }
 
     let current = state.stream.current();
     if current.kind == TokenKind::Case {
+        let comments = state.stream.comments();
         let attributes = state.get_attributes();
 
         let case = current.span;
@@ -189,14 +200,14 @@ fn backed_member(
         let current = state.stream.current();
         if current.kind == TokenKind::SemiColon {
             // parse the semicolon, but don't do anything with it.
-            let _ = utils::skip_semicolon(state)?;
+            let semicolon = utils::skip_semicolon(state)?;
 
             let error =
                 error::missing_case_value_for_backed_enum(state, enum_name, &name, current.span);
 
             state.record(error);
 
-            return Ok(None);
+            return Ok(BackedEnumMember::Error(case.join(semicolon)));
         }
 
         let equals = utils::skip(state, TokenKind::Equals)?;
@@ -205,32 +216,36 @@ fn backed_member(
 
         let semicolon = utils::skip_semicolon(state)?;
 
-        return Ok(Some(BackedEnumMember::Case(BackedEnumCase {
+        return Ok(BackedEnumMember::Case(BackedEnumCase {
+            comments,
             attributes,
             case,
             name,
             equals,
             value,
             semicolon,
-        })));
+        }));
     }
 
     let modifiers = modifiers::collect(state)?;
 
     if state.stream.current().kind == TokenKind::Const {
         return constants::classish(state, modifiers::constant_group(modifiers)?)
-            .map(BackedEnumMember::Constant)
-            .map(Some);
+            .map(BackedEnumMember::Constant);
     }
 
-    method(state, modifiers, enum_name).map(|method| method.map(BackedEnumMember::Method))
+    method(state, modifiers, enum_name).map(|method| match method {
+        Ok(method) => BackedEnumMember::Method(method),
+        Err(span) => BackedEnumMember::Error(span),
+    })
 }
 
+// Parses a method, or gives back the span of a constructor, which enums can't have.
 fn method(
     state: &mut State,
     modifiers: Vec<(Span, TokenKind)>,
     enum_name: &SimpleIdentifier,
-) -> ParseResult<Option<ConcreteMethod>> {
+) -> ParseResult<Result<ConcreteMethod, Span>> {
     let method = functions::method(
         state,
         functions::MethodType::Concrete,
@@ -244,7 +259,7 @@ fn method(
 
             state.record(error);
 
-            Ok(None)
+            Ok(Err(constructor.span()))
         }
         Method::Concrete(method) => {
             match method.name.value[..].to_ascii_lowercase().as_slice() {
@@ -258,8 +273,8 @@ fn method(
                 _ => {}
             }
 
-            Ok(Some(method))
+            Ok(Ok(method))
         }
-        Method::Abstract(_) | Method::AbstractConstructor(_) => unreachable!(),
+        Method::Abstract(_) | Method::AbstractConstructor(_) => expected_token_err!("`{`", state),
     }
 }
@@ -23,6 +23,7 @@ use pxp_ast::identifiers::SimpleIdentifier;
 use pxp_ast::modifiers::MethodModifierGroup;
 use pxp_ast::Expression;
 use pxp_ast::Statement;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub enum MethodType {
@@ -109,12 +110,7 @@ pub fn anonymous_function(state: &mut State) -> ParseResult<Expression> {
         None
     };
 
-    let body = FunctionBody {
-        comments: state.stream.comments(),
-        left_brace: utils::skip_left_brace(state)?,
-        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
-        right_brace: utils::skip_right_brace(state)?,
-    };
+    let body = function_body(state)?;
 
     Ok(Expression::Closure(ClosureExpression {
         comments,
@@ -141,6 +137,7 @@ pub fn arrow_function(state: &mut State) -> ParseResult<Expression> {
     };
 
     let r#fn = utils::skip(state, TokenKind::Fn)?;
+    state.requires(PhpVersion::Php74, "arrow functions", r#fn);
 
     let current = state.stream.current();
     let ampersand = if state.stream.current().kind == TokenKind::Ampersand {
@@ -209,12 +206,7 @@ pub fn function(state: &mut State) -> ParseResult<Statement> {
         None
     };
 
-    let body = FunctionBody {
-        comments: state.stream.comments(),
-        left_brace: utils::skip_left_brace(state)?,
-        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
-        right_brace: utils::skip_right_brace(state)?,
-    };
+    let body = function_body(state)?;
 
     Ok(Statement::Function(FunctionStatement {
         comments,
@@ -228,6 +220,24 @@ pub fn function(state: &mut State) -> ParseResult<Statement> {
     }))
 }
 
+pub fn function_body(state: &mut State) -> ParseResult<FunctionBody> {
+    Ok(FunctionBody {
+        comments: state.stream.comments(),
+        left_brace: utils::skip_left_brace(state)?,
+        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+        right_brace: utils::skip_right_brace(state)?,
+    })
+}
+
+pub fn method_body(state: &mut State) -> ParseResult<MethodBody> {
+    Ok(MethodBody {
+        comments: state.stream.comments(),
+        left_brace: utils::skip_left_brace(state)?,
+        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+        right_brace: utils::skip_right_brace(state)?,
+    })
+}
+
 pub fn method(
     state: &mut State,
     r#type: MethodType,
@@ -257,12 +267,7 @@ pub fn method(
     if name.to_string().to_lowercase() == "__construct" {
         return if has_body {
             let parameters = parameters::constructor_parameter_list(state, class)?;
-            let body = MethodBody {
-                comments: state.stream.comments(),
-                left_brace: utils::skip_left_brace(state)?,
-                statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
-                right_brace: utils::skip_right_brace(state)?,
-            };
+            let body = method_body(state)?;
 
             Ok(Method::ConcreteConstructor(ConcreteConstructor {
                 comments,
@@ -311,12 +316,7 @@ pub fn method(
             name,
             parameters,
             return_type,
-            body: MethodBody {
-                comments: state.stream.comments(),
-                left_brace: utils::skip_left_brace(state)?,
-                statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
-                right_brace: utils::skip_right_brace(state)?,
-            },
+            body: method_body(state)?,
         }))
     } else {
         Ok(Method::Abstract(AbstractMethod {
@@ -33,7 +33,7 @@ pub fn type_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier {
                 span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         TokenKind::Enum | TokenKind::From => {
@@ -72,7 +72,7 @@ pub fn type_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
         }
         _ => Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         )),
     }
 }
@@ -88,7 +88,7 @@ pub fn label_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier {
                 span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         TokenKind::Enum | TokenKind::From => {
@@ -128,7 +128,7 @@ pub fn label_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
         }
         _ => Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         )),
     }
 }
@@ -144,7 +144,7 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier {
                 span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         TokenKind::Enum | TokenKind::From | TokenKind::Self_ | TokenKind::Parent => {
@@ -170,7 +170,7 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
         }
         _ => Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         )),
     }
 }
@@ -185,12 +185,12 @@ pub fn identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
         Ok(SimpleIdentifier {
             span,
-            value: current.value.clone(),
+            value: current.value.into(),
         })
     } else {
         Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         ))
     }
 }
@@ -199,7 +199,7 @@ pub fn identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 pub fn name(state: &mut State) -> ParseResult<SimpleIdentifier> {
     let name = peek_token!([
         TokenKind::Identifier | TokenKind::QualifiedIdentifier => {
-            state.stream.current().value.clone()
+            state.stream.current().value.into()
         },
     ], state, "an identifier");
 
@@ -219,7 +219,7 @@ pub fn optional_name(state: &mut State) -> Option<SimpleIdentifier> {
 
             Some(SimpleIdentifier {
                 span: current.span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         t if is_reserved_identifier(t) => {
@@ -227,7 +227,7 @@ pub fn optional_name(state: &mut State) -> Option<SimpleIdentifier> {
 
             Some(SimpleIdentifier {
                 span: current.span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         _ => None,
@@ -247,12 +247,12 @@ pub fn full_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier {
                 span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         _ => Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         )),
     }
 }
@@ -270,7 +270,7 @@ pub fn full_type_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier {
                 span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         TokenKind::Enum | TokenKind::From => {
@@ -309,7 +309,7 @@ pub fn full_type_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
         }
         _ => Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         )),
     }
 }
@@ -327,7 +327,7 @@ pub fn full_type_name_including_self(state: &mut State) -> ParseResult<SimpleIde
 
             Ok(SimpleIdentifier {
                 span,
-                value: current.value.clone(),
+                value: current.value.into(),
             })
         }
         TokenKind::Enum
@@ -357,7 +357,7 @@ pub fn full_type_name_including_self(state: &mut State) -> ParseResult<SimpleIde
         }
         _ => Err(error::unexpected_token(
             vec!["an identifier".to_owned()],
-            current,
+            &current,
         )),
     }
 }
@@ -1,4 +1,5 @@
 use crate::error::ParseResult;
+use crate::expected_token_err;
 use crate::internal::attributes;
 use crate::internal::constants;
 use crate::internal::functions::method;
@@ -17,6 +18,7 @@ use pxp_ast::Statement;
 use pxp_token::TokenKind;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Interface)?;
 
     let name = identifiers::type_identifier(state)?;
@@ -46,16 +48,21 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         left_brace: utils::skip_left_brace(state)?,
         members: {
             let mut members = Vec::new();
-            while state.stream.current().kind != TokenKind::RightBrace {
-                members.push(member(state, &name)?);
+            while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+                members.push(
+                    utils::recover(state, &|state| member(state, &name))
+                        .unwrap_or_else(InterfaceMember::Error),
+                );
             }
 
             members
         },
+        comments: state.stream.comments(),
         right_brace: utils::skip_right_brace(state)?,
     };
 
     Ok(Statement::Interface(InterfaceStatement {
+        comments,
         interface: span,
         name,
         attributes,
@@ -83,7 +90,9 @@ fn member(state: &mut State, interface_name: &SimpleIdentifier) -> ParseResult<I
         match method {
             Method::Abstract(method) => Ok(InterfaceMember::Method(method)),
             Method::AbstractConstructor(ctor) => Ok(InterfaceMember::Constructor(ctor)),
-            Method::ConcreteConstructor(_) | Method::Concrete(_) => unreachable!(),
+            Method::ConcreteConstructor(_) | Method::Concrete(_) => {
+                expected_token_err!("`;`", state)
+            }
         }
     }
 }
@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+
+use pxp_lexer::config::PhpVersion;
+use pxp_lexer::stream::is_comment;
+use pxp_token::Token;
+use pxp_token::TokenKind;
+
+/// Turns keywords from a newer version of PHP than `version` back into identifiers, unless
+/// they're followed by the syntax they were introduced for.
+///
+/// The lexer always tokenizes them as keywords, so that using the newer syntax can be reported
+/// as a version error instead of a confusing syntax error, but code written for an older
+/// version is free to use them as names, such as a function called `match`.
+pub fn demote<'a, 'b>(tokens: &'b [Token<'a>], version: PhpVersion) -> Cow<'b, [Token<'a>]> {
+    if !demotes(version) {
+        return Cow::Borrowed(tokens);
+    }
+
+    let positions = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !is_comment(&token.kind))
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    let kinds = positions
+        .iter()
+        .map(|position| &tokens[*position].kind)
+        .collect::<Vec<_>>();
+
+    let demoted = (0..kinds.len())
+        .filter(|index| {
+            let introduced = match kinds[*index] {
+                TokenKind::Fn => PhpVersion::Php74,
+                TokenKind::Match => PhpVersion::Php80,
+                TokenKind::Enum | TokenKind::Readonly => PhpVersion::Php81,
+                _ => return false,
+            };
+
+            version < introduced && !starts_newer_syntax(&kinds, *index)
+        })
+        .map(|index| positions[index])
+        .collect::<Vec<_>>();
+
+    if demoted.is_empty() {
+        return Cow::Borrowed(tokens);
+    }
+
+    let mut tokens = tokens.to_vec();
+    for position in demoted {
+        tokens[position].kind = TokenKind::Identifier;
+    }
+
+    Cow::Owned(tokens)
+}
+
+/// Whether `version` is old enough for `demote` to turn any keywords into identifiers.
+pub fn demotes(version: PhpVersion) -> bool {
+    version < PhpVersion::Php81
+}
+
+// Whether the keyword at `index` is used for the syntax it was introduced for, looking only at
+// the tokens that aren't comments.
+fn starts_newer_syntax(kinds: &[&TokenKind], index: usize) -> bool {
+    let previous = index.checked_sub(1).map(|previous| kinds[previous]);
+    let next = |index: usize| kinds.get(index).copied().unwrap_or(&TokenKind::Eof);
+
+    // Anything that comes straight after one of these is a name.
+    if matches!(
+        previous,
+        Some(
+            TokenKind::Function
+                | TokenKind::Const
+                | TokenKind::Arrow
+                | TokenKind::QuestionArrow
+                | TokenKind::DoubleColon
+                | TokenKind::New
+                | TokenKind::Class
+                | TokenKind::Interface
+                | TokenKind::Trait
+                | TokenKind::Extends
+                | TokenKind::Implements
+                | TokenKind::Instanceof
+        )
+    ) {
+        return false;
+    }
+
+    match kinds[index] {
+        // enum Foo {, enum Foo: string {, enum Foo implements Bar {
+        TokenKind::Enum => {
+            next(index + 1) == &TokenKind::Identifier
+                && matches!(
+                    next(index + 2),
+                    TokenKind::LeftBrace | TokenKind::Colon | TokenKind::Implements
+                )
+        }
+        // match ($a) {
+        TokenKind::Match => {
+            close(kinds, index + 1).is_some_and(|end| next(end) == &TokenKind::LeftBrace)
+        }
+        // fn ($a) =>, fn &($a) =>, fn ($a): int =>
+        TokenKind::Fn => {
+            let open = match next(index + 1) {
+                TokenKind::Ampersand => index + 2,
+                _ => index + 1,
+            };
+
+            let Some(mut end) = close(kinds, open) else {
+                return false;
+            };
+
+            if next(end) == &TokenKind::Colon {
+                end += 1;
+
+                while !matches!(
+                    next(end),
+                    TokenKind::DoubleArrow
+                        | TokenKind::Variable
+                        | TokenKind::SemiColon
+                        | TokenKind::Comma
+                        | TokenKind::LeftBrace
+                        | TokenKind::RightBrace
+                        | TokenKind::LeftBracket
+                        | TokenKind::RightBracket
+                        | TokenKind::Eof
+                ) {
+                    end += 1;
+                }
+            }
+
+            next(end) == &TokenKind::DoubleArrow
+        }
+        // readonly class Foo {}, public readonly int $a, readonly public int $a
+        TokenKind::Readonly => {
+            let modifier = |kind: &TokenKind| {
+                matches!(
+                    kind,
+                    TokenKind::Public
+                        | TokenKind::Protected
+                        | TokenKind::Private
+                        | TokenKind::Static
+                )
+            };
+
+            matches!(
+                next(index + 1),
+                TokenKind::Class | TokenKind::Final | TokenKind::Abstract
+            ) || modifier(next(index + 1))
+                || (previous.is_some_and(modifier)
+                    && !matches!(
+                        next(index + 1),
+                        TokenKind::Variable | TokenKind::Ampersand | TokenKind::Ellipsis
+                    ))
+        }
+        _ => false,
+    }
+}
+
+// The index right after the parenthesis that closes the one at `open`, if there is one.
+fn close(kinds: &[&TokenKind], open: usize) -> Option<usize> {
+    if kinds.get(open) != Some(&&TokenKind::LeftParen) {
+        return None;
+    }
+
+    let mut depth = 0;
+
+    for (index, kind) in kinds.iter().enumerate().skip(open) {
+        match kind {
+            TokenKind::LeftParen => depth += 1,
+            TokenKind::RightParen => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
@@ -18,6 +18,7 @@ use pxp_ast::loops::Level;
 use pxp_ast::loops::WhileStatement;
 use pxp_ast::loops::WhileStatementBody;
 use pxp_ast::Statement;
+use pxp_lexer::number;
 use pxp_token::Token;
 use pxp_token::TokenKind;
 
@@ -238,8 +239,8 @@ fn loop_level(state: &mut State) -> ParseResult<Level> {
         state.stream.next();
 
         return Ok(Level::Literal(LiteralInteger {
-            value: value.clone(),
-            span: *span,
+            value: number::integer(value).unwrap_or_default(),
+            span,
         }));
     }
 
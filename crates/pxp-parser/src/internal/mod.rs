@@ -10,6 +10,7 @@ pub(in crate) mod functions;
 pub(in crate) mod goto;
 pub(in crate) mod identifiers;
 pub(in crate) mod interfaces;
+pub(in crate) mod keywords;
 pub(in crate) mod loops;
 pub(in crate) mod modifiers;
 pub(in crate) mod namespaces;
@@ -240,7 +240,7 @@ pub fn interface_constant_group(
 pub fn collect(state: &mut State) -> ParseResult<Vec<(Span, TokenKind)>> {
     let mut collected: Vec<(Span, TokenKind)> = vec![];
 
-    let collectable_tokens = vec![
+    let collectable_tokens = [
         TokenKind::Private,
         TokenKind::Protected,
         TokenKind::Public,
@@ -58,9 +58,12 @@ fn unbraced_namespace(
         // `namespace` token as a top level statement, this namespace scope ends.
         // otherwise we will end up with nested namespace statements.
         while state.stream.current().kind != TokenKind::Namespace && !state.stream.is_eof() {
+            statements.extend(utils::free_standing_comments(state));
             statements.push(crate::top_level_statement(state)?);
         }
 
+        statements.extend(utils::free_standing_comments(state));
+
         statements
     });
 
@@ -84,9 +87,12 @@ fn braced_namespace(
 
         let mut statements = Block::new();
         while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+            statements.extend(utils::free_standing_comments(state));
             statements.push(crate::top_level_statement(state)?);
         }
 
+        statements.extend(utils::free_standing_comments(state));
+
         let end = utils::skip_right_brace(state)?;
 
         BracedNamespaceBody {
@@ -16,6 +16,8 @@ use pxp_ast::functions::ConstructorParameterList;
 use pxp_ast::functions::FunctionParameter;
 use pxp_ast::functions::FunctionParameterList;
 use pxp_ast::identifiers::SimpleIdentifier;
+use pxp_ast::spanned::Spanned;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterList, ParseError> {
@@ -90,6 +92,12 @@ pub fn constructor_parameter_list(
             attributes::gather_attributes(state)?;
 
             let modifiers = modifiers::promoted_property_group(modifiers::collect(state)?)?;
+            if let Some(modifier) = modifiers.modifiers.first() {
+                state.requires(PhpVersion::Php80, "promoted properties", modifier.span());
+            }
+            if let Some(readonly) = modifiers.get_readonly() {
+                state.requires(PhpVersion::Php81, "readonly properties", readonly.span());
+            }
 
             let ty = data_type::optional_data_type(state)?;
 
@@ -107,13 +115,13 @@ pub fn constructor_parameter_list(
             let (ellipsis, var) = if matches!(current.kind, TokenKind::Ellipsis) {
                 state.stream.next();
                 let var = variables::simple_variable(state)?;
-                if !modifiers.is_empty() {
+                if let Some(modifier) = modifiers.modifiers.first() {
                     return Err(error::variadic_promoted_property(
                         state,
                         class,
                         &var,
                         current.span,
-                        modifiers.modifiers.first().unwrap(),
+                        modifier,
                     ));
                 }
 
@@ -221,27 +229,31 @@ pub fn single_argument(
     state: &mut State,
     required: bool,
     only_positional: bool,
-) -> Option<ParseResult<SingleArgument>> {
+) -> ParseResult<Option<SingleArgument>> {
+    if state.stream.current().kind != TokenKind::LeftParen {
+        return Ok(None);
+    }
+
     let comments = state.stream.comments();
-    let start = utils::skip_left_parenthesis(state).ok()?;
+    let start = utils::skip_left_parenthesis(state)?;
 
     let mut first_argument = None;
 
     while !state.stream.is_eof() && state.stream.current().kind != TokenKind::RightParen {
         let span = state.stream.current().span;
-        let (named, argument) = argument(state).ok()?;
+        let (named, argument) = argument(state)?;
         if only_positional && named {
-            return Some(Err(error::only_positional_arguments_are_accepted(
+            return Err(error::only_positional_arguments_are_accepted(
                 span,
                 state.stream.current().span,
-            )));
+            ));
         }
 
         if first_argument.is_some() {
-            return Some(Err(error::only_one_argument_is_accepted(
+            return Err(error::only_one_argument_is_accepted(
                 span,
                 state.stream.current().span,
-            )));
+            ));
         }
 
         first_argument = Some(argument);
@@ -254,21 +266,19 @@ pub fn single_argument(
     }
 
     if required && first_argument.is_none() {
-        return Some(Err(error::argument_is_required(
+        return Err(error::argument_is_required(
             state.stream.current().span,
             state.stream.current().span,
-        )));
+        ));
     }
 
-    let end = utils::skip_right_parenthesis(state).ok()?;
-
-    first_argument.as_ref()?;
+    let end = utils::skip_right_parenthesis(state)?;
 
-    Some(Ok(SingleArgument {
+    Ok(first_argument.map(|argument| SingleArgument {
         comments,
         left_parenthesis: start,
         right_parenthesis: end,
-        argument: first_argument.unwrap(),
+        argument,
     }))
 }
 
@@ -277,6 +287,8 @@ fn argument(state: &mut State) -> ParseResult<(bool, Argument)> {
         && state.stream.peek().kind == TokenKind::Colon
     {
         let name = identifiers::identifier_maybe_reserved(state)?;
+        state.requires(PhpVersion::Php80, "named arguments", name.span);
+
         let colon = utils::skip(state, TokenKind::Colon)?;
         let ellipsis = if state.stream.current().kind == TokenKind::Ellipsis {
             Some(utils::skip(state, TokenKind::Ellipsis)?)
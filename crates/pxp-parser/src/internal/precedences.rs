@@ -41,10 +41,10 @@ pub enum Precedence {
 }
 
 impl Precedence {
-    pub fn infix(kind: &TokenKind) -> Self {
+    pub fn infix(kind: &TokenKind) -> Option<Self> {
         use TokenKind::*;
 
-        match kind {
+        Some(match kind {
             Pow => Self::Pow,
             Instanceof => Self::Instanceof,
             Asterisk | Slash | Percent => Self::MulDivMod,
@@ -68,20 +68,20 @@ impl Precedence {
             LogicalAnd => Self::KeyAnd,
             LogicalOr => Self::KeyOr,
             LogicalXor => Self::KeyXor,
-            _ => unimplemented!("precedence for op {:?}", kind),
-        }
+            _ => return None,
+        })
     }
 
-    pub fn postfix(kind: &TokenKind) -> Self {
+    pub fn postfix(kind: &TokenKind) -> Option<Self> {
         use TokenKind::*;
 
-        match kind {
+        Some(match kind {
             DoubleQuestion => Self::NullCoalesce,
             Increment | Decrement => Self::IncDec,
             LeftParen | LeftBracket => Self::CallDim,
             Arrow | QuestionArrow | DoubleColon => Self::ObjectAccess,
-            _ => unimplemented!("postfix precedence for op {:?}", kind),
-        }
+            _ => return None,
+        })
     }
 
     pub fn associativity(&self) -> Option<Associativity> {
@@ -10,6 +10,8 @@ use pxp_ast::modifiers::PropertyModifierGroup;
 use pxp_ast::properties::Property;
 use pxp_ast::properties::PropertyEntry;
 use pxp_ast::properties::VariableProperty;
+use pxp_ast::spanned::Spanned;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 pub fn parse(
@@ -17,7 +19,16 @@ pub fn parse(
     class_name: Option<&SimpleIdentifier>,
     modifiers: PropertyModifierGroup,
 ) -> ParseResult<Property> {
+    let comments = state.stream.comments();
+
+    if let Some(readonly) = modifiers.get_readonly() {
+        state.requires(PhpVersion::Php81, "readonly properties", readonly.span());
+    }
+
     let ty = data_type::optional_data_type(state)?;
+    if let Some(ty) = &ty {
+        state.requires(PhpVersion::Php74, "typed properties", ty.span());
+    }
 
     let mut entries = vec![];
     let mut type_checked = false;
@@ -26,13 +37,15 @@ pub fn parse(
 
         if !type_checked {
             type_checked = true;
-            if modifiers.has_readonly() && modifiers.has_static() {
+            if let (Some(r#static), Some(readonly)) =
+                (modifiers.get_static(), modifiers.get_readonly())
+            {
                 let error = error::static_property_cannot_be_readonly(
                     state,
                     class_name,
                     &variable,
-                    modifiers.get_static().unwrap().span(),
-                    modifiers.get_readonly().unwrap().span(),
+                    r#static.span(),
+                    readonly.span(),
                 );
 
                 state.record(error);
@@ -102,6 +115,7 @@ pub fn parse(
     let end = utils::skip_semicolon(state)?;
 
     Ok(Property {
+        comments,
         r#type: ty,
         modifiers,
         attributes: state.get_attributes(),
@@ -114,9 +128,13 @@ pub fn parse_var(
     state: &mut State,
     class_name: Option<&SimpleIdentifier>,
 ) -> ParseResult<VariableProperty> {
+    let comments = state.stream.comments();
     utils::skip(state, TokenKind::Var)?;
 
     let ty = data_type::optional_data_type(state)?;
+    if let Some(ty) = &ty {
+        state.requires(PhpVersion::Php74, "typed properties", ty.span());
+    }
 
     let mut entries = vec![];
     let mut type_checked = false;
@@ -165,6 +183,7 @@ pub fn parse_var(
     let end = utils::skip_semicolon(state)?;
 
     Ok(VariableProperty {
+        comments,
         r#type: ty,
         attributes: state.get_attributes(),
         entries,
@@ -1,4 +1,3 @@
-use crate::expect_token;
 use crate::expected_token_err;
 use crate::error::ParseResult;
 use crate::expressions::create;
@@ -23,219 +22,267 @@ use pxp_ast::{
 };
 use pxp_lexer::error::SyntaxError;
 use pxp_token::DocStringIndentationKind;
+use pxp_bytestring::ByteString;
+use pxp_lexer::number;
+use pxp_span::Span;
+use pxp_token::Token;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 #[inline(always)]
 pub fn interpolated(state: &mut State) -> ParseResult<Expression> {
+    let start = state.stream.current().span;
+    state.stream.next();
+
     let mut parts = Vec::new();
 
     while state.stream.current().kind != TokenKind::DoubleQuote {
-        if let Some(part) = part(state)? {
+        if let Some(part) = part(state, Some(Quote::Double))? {
             parts.push(part);
         }
     }
 
+    let end = state.stream.current().span;
     state.stream.next();
 
     Ok(Expression::InterpolatedString(
-        InterpolatedStringExpression { parts },
+        InterpolatedStringExpression { span: start.join(end), parts },
     ))
 }
 
 #[inline(always)]
 pub fn shell_exec(state: &mut State) -> ParseResult<Expression> {
+    let start = state.stream.current().span;
     state.stream.next();
 
     let mut parts = Vec::new();
 
     while state.stream.current().kind != TokenKind::Backtick {
-        if let Some(part) = part(state)? {
+        if let Some(part) = part(state, Some(Quote::Backtick))? {
             parts.push(part);
         }
     }
 
+    let end = state.stream.current().span;
     state.stream.next();
 
-    Ok(Expression::ShellExec(ShellExecExpression { parts }))
+    Ok(Expression::ShellExec(ShellExecExpression { span: start.join(end), parts }))
 }
 
 #[inline(always)]
 pub fn heredoc(state: &mut State) -> ParseResult<Expression> {
     let span = state.stream.current().span;
-    let label = state.stream.current().value.clone();
+    let label = doc_string_label(state.stream.current().value);
     state.stream.next();
 
+    // The indentation has to be removed before escape sequences are processed, otherwise an
+    // escaped new-line would be treated as the start of a new line, so keep the parts raw for now.
     let mut parts = Vec::new();
 
     while !matches!(state.stream.current().kind, TokenKind::EndDocString(_, _)) {
-        if let Some(part) = part(state)? {
+        if let Some(part) = part(state, None)? {
             parts.push(part);
         }
     }
 
+    // The line break before the closing label isn't part of the heredoc.
+    if let Some(StringPart::Literal(LiteralStringPart { value, .. })) = parts.last_mut() {
+        if value.ends_with(b"\n") {
+            value.pop();
+        }
+    }
+
     let (indentation_type, indentation_amount) = match &state.stream.current().kind {
         TokenKind::EndDocString(indentation_type, indentation_amount) => {
             (indentation_type.clone(), *indentation_amount)
         }
-        _ => unreachable!(),
+        _ => return expected_token_err!("end of doc string", state),
     };
 
+    let end = state.stream.current().span;
     state.stream.next();
 
-    let mut new_line = true;
     if indentation_type != DocStringIndentationKind::None {
         let indentation_char: u8 = indentation_type.into();
 
-        for part in parts.iter_mut() {
-            // We only need to strip and validate indentation
-            // for individual lines, so we can skip checks if
-            // we know we're not on a new line.
-            if !new_line {
-                continue;
-            }
+        // Whether the next part starts at the beginning of a line.
+        let mut new_line = true;
 
+        for part in parts.iter_mut() {
             match part {
-                StringPart::Literal(LiteralStringPart { value: bytes }) => {
-                    // 1. If this line doesn't start with any whitespace,
-                    //    we can return an error early because we know
-                    //    the label was indented.
-                    if !bytes.starts_with(&[b' ']) && !bytes.starts_with(&[b'\t']) {
-                        return Err(SyntaxError::InvalidDocBodyIndentationLevel(
-                            indentation_amount,
-                            span,
-                        )
-                        .into());
-                    }
+                StringPart::Literal(LiteralStringPart { value, .. }) => {
+                    let ends_line = value.ends_with(b"\n");
+                    let mut bytes = Vec::with_capacity(value.len());
 
-                    // 2. If this line doesn't start with the correct
-                    //    type of whitespace, we can also return an error.
-                    if !bytes.starts_with(&[indentation_char]) {
-                        return Err(SyntaxError::InvalidDocIndentation(span).into());
-                    }
+                    for (i, line) in value.split(|b| *b == b'\n').enumerate() {
+                        if i > 0 {
+                            bytes.push(b'\n');
+                        }
 
-                    // 3. We now know that the whitespace at the start of
-                    //    this line is correct, so we need to check that the
-                    //    amount of whitespace is correct too. In this case,
-                    //    the amount of whitespace just needs to be at least
-                    //    the same, so we can create a vector containing the
-                    //    minimum and check using `starts_with()`.
-                    let expected_whitespace_buffer = vec![indentation_char; indentation_amount];
-                    if !bytes.starts_with(&expected_whitespace_buffer) {
-                        return Err(SyntaxError::InvalidDocBodyIndentationLevel(
-                            indentation_amount,
-                            span,
-                        )
-                        .into());
+                        if (i > 0 || new_line) && !line.is_empty() {
+                            bytes.extend(dedent(line, indentation_char, indentation_amount, span)?);
+                        } else {
+                            bytes.extend(line);
+                        }
                     }
 
-                    // 4. All of the above checks have passed, so we know
-                    //    there are no more possible errors. Let's now
-                    //    strip the leading whitespace accordingly.
-                    *bytes = bytes
-                        .strip_prefix(&expected_whitespace_buffer[..])
-                        .unwrap()
-                        .into();
-                    new_line = bytes.ends_with(&[b'\n']);
+                    // Checked before removing the indentation, since a line with nothing but
+                    // indentation in front of an expression ends up empty.
+                    new_line = ends_line;
+                    *value = bytes.into();
+                }
+                // An interpolated expression at the start of a line can't be indented.
+                StringPart::Expression(_) if new_line => {
+                    return Err(SyntaxError::InvalidDocBodyIndentationLevel(
+                        indentation_amount,
+                        span,
+                    )
+                    .into());
                 }
-                _ => continue,
+                StringPart::Expression(_) => {}
             }
         }
     }
 
-    Ok(Expression::Heredoc(HeredocExpression { label, parts }))
+    let mut decoded = Vec::with_capacity(parts.len());
+
+    for part in parts {
+        match part {
+            StringPart::Literal(LiteralStringPart { span, value }) => {
+                let value = unescape(&value, span, Quote::Heredoc)?;
+
+                if !value.is_empty() {
+                    decoded.push(StringPart::Literal(LiteralStringPart {
+                        span,
+                        value: value.into(),
+                    }));
+                }
+            }
+            part => decoded.push(part),
+        }
+    }
+
+    Ok(Expression::Heredoc(HeredocExpression {
+        span: span.join(end),
+        label,
+        parts: decoded,
+    }))
 }
 
 #[inline(always)]
 pub fn nowdoc(state: &mut State) -> ParseResult<Expression> {
     let span = state.stream.current().span;
-    let label = state.stream.current().value.clone();
+    let label = doc_string_label(state.stream.current().value);
 
     state.stream.next();
 
-    let mut string_part = state.stream.current().value.clone();
-    expect_token!([TokenKind::StringPart => ()], state, "constant string");
+    let mut string_part: ByteString = match state.stream.current().kind {
+        TokenKind::StringPart => {
+            let value = state.stream.current().value;
+            state.stream.next();
+
+            // The line break before the closing label isn't part of the nowdoc.
+            value.strip_suffix(b"\n").unwrap_or(value).into()
+        }
+        _ => ByteString::default(),
+    };
+
+    if !matches!(state.stream.current().kind, TokenKind::EndDocString(..)) {
+        return expected_token_err!("constant string", state);
+    }
 
     let (indentation_type, indentation_amount) = match &state.stream.current().kind {
         TokenKind::EndDocString(indentation_type, indentation_amount) => {
             (indentation_type.clone(), *indentation_amount)
         }
-        _ => unreachable!(),
+        _ => return expected_token_err!("end of doc string", state),
     };
 
+    let end = state.stream.current().span;
     state.stream.next();
 
     if indentation_type != DocStringIndentationKind::None {
         let indentation_char: u8 = indentation_type.into();
 
-        let mut lines = string_part
-            .split(|b| *b == b'\n')
-            .map(|s| s.to_vec())
-            .collect::<Vec<Vec<u8>>>();
-
-        for line in lines.iter_mut() {
-            if line.is_empty() {
-                continue;
-            }
-
-            // 1. If this line doesn't start with any whitespace,
-            //    we can return an error early because we know
-            //    the label was indented.
-            if !line.starts_with(&[b' ']) && !line.starts_with(&[b'\t']) {
-                return Err(
-                    SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into(),
-                );
-            }
+        let mut bytes = Vec::with_capacity(string_part.len());
 
-            // 2. If this line doesn't start with the correct
-            //    type of whitespace, we can also return an error.
-            if !line.starts_with(&[indentation_char]) {
-                return Err(SyntaxError::InvalidDocIndentation(span).into());
+        for (i, line) in string_part.split(|b| *b == b'\n').enumerate() {
+            if i > 0 {
+                bytes.push(b'\n');
             }
 
-            // 3. We now know that the whitespace at the start of
-            //    this line is correct, so we need to check that the
-            //    amount of whitespace is correct too. In this case,
-            //    the amount of whitespace just needs to be at least
-            //    the same, so we can create a vector containing the
-            //    minimum and check using `starts_with()`.
-            let expected_whitespace_buffer = vec![indentation_char; indentation_amount];
-            if !line.starts_with(&expected_whitespace_buffer) {
-                return Err(
-                    SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into(),
-                );
+            if !line.is_empty() {
+                bytes.extend(dedent(line, indentation_char, indentation_amount, span)?);
             }
-
-            // 4. All of the above checks have passed, so we know
-            //    there are no more possible errors. Let's now
-            //    strip the leading whitespace accordingly.
-            *line = line
-                .strip_prefix(&expected_whitespace_buffer[..])
-                .unwrap()
-                .into();
         }
 
-        let mut bytes = Vec::new();
-        for (i, line) in lines.iter().enumerate() {
-            bytes.extend(line);
-            if i < lines.len() - 1 {
-                bytes.push(b'\n');
-            }
-        }
         string_part = bytes.into();
     }
 
     Ok(Expression::Nowdoc(NowdocExpression {
+        span: span.join(end),
         label,
         value: string_part,
     }))
 }
 
-fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
+// Removes the indentation of the closing label from the start of a line in a heredoc or nowdoc.
+fn dedent(
+    line: &[u8],
+    indentation_char: u8,
+    indentation_amount: usize,
+    span: Span,
+) -> ParseResult<&[u8]> {
+    // 1. If this line doesn't start with any whitespace,
+    //    we can return an error early because we know
+    //    the label was indented.
+    if !line.starts_with(b" ") && !line.starts_with(b"\t") {
+        return Err(SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into());
+    }
+
+    // 2. If this line doesn't start with the correct
+    //    type of whitespace, we can also return an error.
+    if !line.starts_with(&[indentation_char]) {
+        return Err(SyntaxError::InvalidDocIndentation(span).into());
+    }
+
+    // 3. We now know that the whitespace at the start of
+    //    this line is correct, so we need to check that the
+    //    amount of whitespace is correct too. In this case,
+    //    the amount of whitespace just needs to be at least
+    //    the same.
+    let indentation = line
+        .iter()
+        .take(indentation_amount)
+        .take_while(|b| **b == indentation_char)
+        .count();
+
+    if indentation < indentation_amount {
+        return Err(SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into());
+    }
+
+    // 4. All of the above checks have passed, so we know
+    //    there are no more possible errors. Let's now
+    //    strip the leading whitespace accordingly.
+    Ok(&line[indentation_amount..])
+}
+
+// Parses the next part of an interpolated string, processing the escape sequences in literal
+// parts for the given kind of string, or keeping them as they are when there isn't one.
+fn part(state: &mut State, quote: Option<Quote>) -> ParseResult<Option<StringPart>> {
     Ok(match &state.stream.current().kind {
         TokenKind::StringPart => {
-            let s = state.stream.current().value.clone();
-            let part = if s.len() > 0 {
-                Some(StringPart::Literal(LiteralStringPart { value: s }))
+            let current = state.stream.current();
+            let s: ByteString = match quote {
+                Some(quote) => unescape(current.value, current.span, quote)?.into(),
+                None => current.value.into(),
+            };
+
+            let part = if !s.is_empty() {
+                Some(StringPart::Literal(LiteralStringPart {
+                    span: current.span,
+                    value: s,
+                }))
             } else {
                 None
             };
@@ -274,10 +321,17 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
                         TokenKind::LiteralInteger => {
                             state.stream.next();
 
-                            Expression::Literal(Literal::Integer(LiteralInteger {
-                                span: current.span,
-                                value: current.value.clone(),
-                            }))
+                            match offset_integer(current.value) {
+                                Some(value) => Expression::Literal(Literal::Integer(LiteralInteger {
+                                    span: current.span,
+                                    value,
+                                })),
+                                None => Expression::Literal(Literal::String(LiteralString {
+                                    span: current.span,
+                                    value: current.value.into(),
+                                    kind: LiteralStringKind::SingleQuoted,
+                                })),
+                            }
                         }
                         TokenKind::Minus => {
                             let span = current.span;
@@ -286,17 +340,24 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
                             if let TokenKind::LiteralInteger = &literal.kind {
                                 state.stream.next();
 
-                                Expression::ArithmeticOperation(
-                                    ArithmeticOperationExpression::Negative {
-                                        minus: span,
-                                        right: Box::new(Expression::Literal(Literal::Integer(
-                                            LiteralInteger {
-                                                span: literal.span,
-                                                value: literal.value.clone(),
-                                            },
-                                        ))),
-                                    },
-                                )
+                                match offset_integer(literal.value) {
+                                    Some(value) => Expression::ArithmeticOperation(
+                                        ArithmeticOperationExpression::Negative {
+                                            minus: span,
+                                            right: Box::new(Expression::Literal(Literal::Integer(
+                                                LiteralInteger {
+                                                    span: literal.span,
+                                                    value,
+                                                },
+                                            ))),
+                                        },
+                                    ),
+                                    None => Expression::Literal(Literal::String(LiteralString {
+                                        span: Span::new(span.start, literal.span.end),
+                                        value: [b"-", literal.value].concat().into(),
+                                        kind: LiteralStringKind::SingleQuoted,
+                                    })),
+                                }
                             } else {
                                 return expected_token_err!("an integer", state);
                             }
@@ -306,7 +367,7 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
 
                             Expression::Literal(Literal::String(LiteralString {
                                 span: current.span,
-                                value: current.value.clone(),
+                                value: current.value.into(),
                                 kind: LiteralStringKind::SingleQuoted,
                             }))
                         }
@@ -343,6 +404,7 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
                 }
                 TokenKind::QuestionArrow => {
                     let span = current.span;
+                    state.requires(PhpVersion::Php80, "nullsafe operators", span);
                     state.stream.next();
                     Expression::NullsafePropertyFetch(NullsafePropertyFetchExpression {
                         target: Box::new(variable),
@@ -363,3 +425,151 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
         }
     })
 }
+
+/// Get the runtime value of a single or double quoted string literal token.
+///
+/// Token values are borrowed straight from the source code, so the escapes in them are decoded
+/// here rather than by the lexer, which used to store the decoded bytes in the token.
+pub fn literal_value(token: &Token) -> ParseResult<ByteString> {
+    let value = match token.value {
+        [b'b' | b'B', rest @ ..] => rest,
+        value => value,
+    };
+
+    // Strip the surrounding quotes.
+    let value = value
+        .get(1..value.len().saturating_sub(1))
+        .unwrap_or_default();
+
+    match token.kind {
+        TokenKind::LiteralSingleQuotedString => {
+            let mut buffer = Vec::with_capacity(value.len());
+            let mut bytes = value.iter().peekable();
+
+            while let Some(b) = bytes.next() {
+                match (b, bytes.peek()) {
+                    (b'\\', Some(b @ (b'\'' | b'\\'))) => {
+                        buffer.push(**b);
+                        bytes.next();
+                    }
+                    _ => buffer.push(*b),
+                }
+            }
+
+            Ok(buffer.into())
+        }
+        _ => Ok(unescape(value, token.span, Quote::Double)?.into()),
+    }
+}
+
+// Only offsets written as canonical decimal integers that fit in an `i64` are integers inside
+// of an interpolated string, everything else (e.g. `$a[0x1]` or `$a[01]`) is a string key.
+fn offset_integer(value: &[u8]) -> Option<i64> {
+    match value {
+        [b'0'] => Some(0),
+        [b'1'..=b'9', rest @ ..] if rest.iter().all(u8::is_ascii_digit) => number::integer(value),
+        _ => None,
+    }
+}
+
+// Gets the label from the opening token of a heredoc or nowdoc, without the trailing new-line.
+fn doc_string_label(value: &[u8]) -> ByteString {
+    value.strip_suffix(b"\n").unwrap_or(value).into()
+}
+
+// The kinds of string that support escape sequences. They only differ in which quote can be escaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quote {
+    Double,
+    Backtick,
+    Heredoc,
+}
+
+// Processes the escape sequences in a double quoted string, shell-exec or heredoc, or a literal
+// part of one of those.
+fn unescape(value: &[u8], span: Span, quote: Quote) -> ParseResult<Vec<u8>> {
+    let mut buffer = Vec::with_capacity(value.len());
+    let mut i = 0;
+
+    while i < value.len() {
+        if value[i] != b'\\' || i + 1 == value.len() {
+            buffer.push(value[i]);
+            i += 1;
+            continue;
+        }
+
+        i += 2;
+
+        match value[i - 1] {
+            b @ (b'\\' | b'$') => buffer.push(b),
+            b'"' if quote == Quote::Double => buffer.push(b'"'),
+            b'`' if quote == Quote::Backtick => buffer.push(b'`'),
+            b'n' => buffer.push(b'\n'),
+            b'r' => buffer.push(b'\r'),
+            b't' => buffer.push(b'\t'),
+            b'v' => buffer.push(b'\x0b'),
+            b'e' => buffer.push(b'\x1b'),
+            b'f' => buffer.push(b'\x0c'),
+            b'x' if value.get(i).is_some_and(u8::is_ascii_hexdigit) => {
+                let length = if value.get(i + 1).is_some_and(u8::is_ascii_hexdigit) {
+                    2
+                } else {
+                    1
+                };
+
+                buffer.push(hex_value(&value[i..i + length]) as u8);
+                i += length;
+            }
+            b'u' if value.get(i) == Some(&b'{') => {
+                let digits = value[i + 1..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_hexdigit())
+                    .count();
+
+                // Anything longer than 6 digits is out of range, and would overflow when decoded.
+                if !(1..=6).contains(&digits) || value.get(i + 1 + digits) != Some(&b'}') {
+                    return Err(SyntaxError::InvalidUnicodeEscape(span).into());
+                }
+
+                let c = match char::from_u32(hex_value(&value[i + 1..i + 1 + digits])) {
+                    Some(c) => c,
+                    None => return Err(SyntaxError::InvalidUnicodeEscape(span).into()),
+                };
+
+                let mut tmp = [0; 4];
+                buffer.extend(c.encode_utf8(&mut tmp).as_bytes());
+                i += digits + 2;
+            }
+            b'0'..=b'7' => {
+                let length = value[i - 1..]
+                    .iter()
+                    .take(3)
+                    .take_while(|b| matches!(b, b'0'..=b'7'))
+                    .count();
+
+                let octal = value[i - 1..i - 1 + length]
+                    .iter()
+                    .fold(0u32, |acc, b| acc * 8 + (b - b'0') as u32);
+
+                match u8::try_from(octal) {
+                    Ok(b) => buffer.push(b),
+                    Err(_) => return Err(SyntaxError::InvalidOctalEscape(span).into()),
+                }
+
+                i += length - 1;
+            }
+            b => {
+                buffer.push(b'\\');
+                buffer.push(b);
+            }
+        }
+    }
+
+    Ok(buffer)
+}
+
+fn hex_value(digits: &[u8]) -> u32 {
+    digits.iter().fold(0, |acc, b| {
+        acc * 16 + (*b as char).to_digit(16).unwrap_or_default()
+    })
+}
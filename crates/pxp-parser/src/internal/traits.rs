@@ -71,9 +71,9 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
                         match state.stream.current() {
                             Token { kind: TokenKind::Public | TokenKind::Protected | TokenKind::Private, span, .. }=> {
                                 let visibility = peek_token!([
-                                    TokenKind::Public => VisibilityModifier::Public(*span),
-                                    TokenKind::Protected => VisibilityModifier::Protected(*span),
-                                    TokenKind::Private => VisibilityModifier::Private(*span),
+                                    TokenKind::Public => VisibilityModifier::Public(span),
+                                    TokenKind::Protected => VisibilityModifier::Protected(span),
+                                    TokenKind::Private => VisibilityModifier::Private(span),
                                 ], state, ["`private`", "`protected`", "`public`"]);
 
                                 state.stream.next();
@@ -160,6 +160,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
 }
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Trait)?;
     let name = identifiers::type_identifier(state)?;
     let attributes = state.get_attributes();
@@ -169,14 +170,19 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         members: {
             let mut members = Vec::new();
             while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
-                members.push(member(state, &name)?);
+                members.push(
+                    utils::recover(state, &|state| member(state, &name))
+                        .unwrap_or_else(TraitMember::Error),
+                );
             }
             members
         },
+        comments: state.stream.comments(),
         right_brace: utils::skip_right_brace(state)?,
     };
 
     Ok(Statement::Trait(TraitStatement {
+        comments,
         r#trait: span,
         name,
         attributes,
@@ -9,6 +9,7 @@ use pxp_ast::try_block::CatchType;
 use pxp_ast::try_block::FinallyBlock;
 use pxp_ast::try_block::TryStatement;
 use pxp_ast::Statement;
+use pxp_lexer::config::PhpVersion;
 use pxp_token::TokenKind;
 
 use super::variables;
@@ -36,6 +37,12 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
         let types = catch_type(state)?;
         let var = if state.stream.current().kind == TokenKind::RightParen {
+            state.requires(
+                PhpVersion::Php80,
+                "catches without a variable",
+                state.stream.current().span,
+            );
+
             None
         } else {
             Some(variables::simple_variable(state)?)
@@ -46,9 +53,7 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
         let catch_body = blocks::multiple_statements_until(state, &TokenKind::RightBrace)?;
 
-        utils::skip_right_brace(state)?;
-
-        let catch_end = state.stream.current().span;
+        let catch_end = utils::skip_right_brace(state)?;
 
         catches.push(CatchBlock {
             start: catch_start,
@@ -67,8 +72,7 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
         let finally_body = blocks::multiple_statements_until(state, &TokenKind::RightBrace)?;
 
-        utils::skip_right_brace(state)?;
-        let finally_end = state.stream.current().span;
+        let finally_end = utils::skip_right_brace(state)?;
 
         finally = Some(FinallyBlock {
             start: finally_start,
@@ -81,7 +85,11 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
         return Err(error::try_without_catch_or_finally(start, last_right_brace));
     }
 
-    let end = state.stream.current().span;
+    let end = match (&finally, catches.last()) {
+        (Some(finally), _) => finally.end,
+        (None, Some(catch)) => catch.end,
+        (None, None) => last_right_brace,
+    };
 
     Ok(Statement::Try(TryStatement {
         start,
@@ -97,6 +105,11 @@ fn catch_type(state: &mut State) -> ParseResult<CatchType> {
     let id = identifiers::full_name(state)?;
 
     if state.stream.current().kind == TokenKind::Pipe {
+        state.requires(
+            PhpVersion::Php71,
+            "catches with multiple types",
+            state.stream.current().span,
+        );
         state.stream.next();
 
         let mut types = vec![id];
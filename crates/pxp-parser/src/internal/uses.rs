@@ -11,7 +11,7 @@ use pxp_ast::UseStatement;
 use pxp_token::TokenKind;
 
 pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
-    state.stream.next();
+    let r#use = utils::skip(state, TokenKind::Use)?;
 
     let kind = match state.stream.current().kind {
         TokenKind::Function => {
@@ -30,12 +30,13 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
         state.stream.next();
         let mut uses = Vec::new();
         while state.stream.current().kind != TokenKind::RightBrace {
+            let start = state.stream.current().span;
             let use_kind = match state.stream.current().kind {
                 TokenKind::Function => {
                     if kind != UseKind::Normal {
                         return Err(error::unexpected_token(
                             vec!["an identifier".to_string()],
-                            state.stream.current(),
+                            &state.stream.current(),
                         ));
                     }
 
@@ -46,7 +47,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                     if kind != UseKind::Normal {
                         return Err(error::unexpected_token(
                             vec!["an identifier".to_string()],
-                            state.stream.current(),
+                            &state.stream.current(),
                         ));
                     }
 
@@ -64,6 +65,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
             }
 
             uses.push(Use {
+                span: start.join(alias.as_ref().map_or(name.span, |alias| alias.span)),
                 name,
                 kind: use_kind,
                 alias,
@@ -76,15 +78,18 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
         }
 
         utils::skip_right_brace(state)?;
-        utils::skip_semicolon(state)?;
+        let semicolon = utils::skip_semicolon(state)?;
 
         Ok(Statement::GroupUse(GroupUseStatement {
+            r#use,
             prefix,
             kind,
             uses,
+            semicolon,
         }))
     } else {
         let mut uses = Vec::new();
+        let mut semicolon = r#use;
         while !state.stream.is_eof() {
             let name = identifiers::full_type_name(state)?;
             let mut alias = None;
@@ -94,6 +99,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
             }
 
             uses.push(Use {
+                span: name.span.join(alias.as_ref().map_or(name.span, |alias| alias.span)),
                 name,
                 kind: None,
                 alias,
@@ -104,10 +110,15 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                 continue;
             }
 
-            utils::skip_semicolon(state)?;
+            semicolon = utils::skip_semicolon(state)?;
             break;
         }
 
-        Ok(Statement::Use(UseStatement { uses, kind }))
+        Ok(Statement::Use(UseStatement {
+            r#use,
+            kind,
+            uses,
+            semicolon,
+        }))
     }
 }
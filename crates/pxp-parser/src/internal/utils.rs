@@ -3,6 +3,7 @@ use crate::error::ParseResult;
 use crate::state::State;
 use pxp_ast::utils::CommaSeparated;
 use pxp_ast::Ending;
+use pxp_ast::Statement;
 use pxp_span::Span;
 use pxp_token::TokenKind;
 
@@ -18,7 +19,7 @@ pub fn skip_ending(state: &mut State) -> ParseResult<Ending> {
 
         Ok(Ending::Semicolon(current.span))
     } else {
-        Err(error::unexpected_token(vec![";".to_string()], current))
+        Err(error::unexpected_token(vec![";".to_string()], &current))
     }
 }
 
@@ -30,7 +31,7 @@ pub fn skip_semicolon(state: &mut State) -> ParseResult<Span> {
 
         Ok(current.span)
     } else {
-        Err(error::unexpected_token(vec!["`;`".to_string()], current))
+        Err(error::unexpected_token(vec!["`;`".to_string()], &current))
     }
 }
 
@@ -80,7 +81,7 @@ pub fn skip(state: &mut State, kind: TokenKind) -> ParseResult<Span> {
 
         Ok(end)
     } else {
-        Err(error::unexpected_token(vec![kind.to_string()], current))
+        Err(error::unexpected_token(vec![kind.to_string()], &current))
     }
 }
 
@@ -96,7 +97,7 @@ pub fn skip_any_of(state: &mut State, kinds: &[TokenKind]) -> ParseResult<Span>
     } else {
         Err(error::unexpected_token(
             kinds.iter().map(|kind| kind.to_string()).collect(),
-            current,
+            &current,
         ))
     }
 }
@@ -107,7 +108,7 @@ pub fn skip_any_of(state: &mut State, kinds: &[TokenKind]) -> ParseResult<Span>
 /// and then skip the right parenthesis.
 pub fn parenthesized<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<(Span, T, Span)> {
     let left_parenthesis = skip_left_parenthesis(state)?;
     let inner = func(state)?;
@@ -122,7 +123,7 @@ pub fn parenthesized<T>(
 /// and then skip the right brace.
 pub fn braced<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<(Span, T, Span)> {
     let left_brace = skip_left_brace(state)?;
     let inner = func(state)?;
@@ -133,7 +134,7 @@ pub fn braced<T>(
 
 pub fn semicolon_terminated<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<(Span, T)> {
     let inner = func(state)?;
     let semicolon = skip_semicolon(state)?;
@@ -143,7 +144,7 @@ pub fn semicolon_terminated<T>(
 /// Parse a comma-separated list of items, allowing a trailing comma.
 pub fn comma_separated<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
     until: TokenKind,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
@@ -171,7 +172,7 @@ pub fn comma_separated<T>(
 /// Parse a comma-separated list of items, not allowing trailing commas.
 pub fn comma_separated_no_trailing<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
     until: TokenKind,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
@@ -205,7 +206,7 @@ pub fn comma_separated_no_trailing<T>(
 /// Parse a comma-separated list of items, requiring at least one item, and not allowing trailing commas.
 pub fn at_least_one_comma_separated_no_trailing<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
     let mut commas: Vec<Span> = vec![];
@@ -225,3 +226,131 @@ pub fn at_least_one_comma_separated_no_trailing<T>(
 
     Ok(CommaSeparated { inner, commas })
 }
+
+/// Parse an item, recovering from any error by recording it and skipping ahead to the next
+/// point that parsing can carry on from.
+///
+/// Tokens are skipped up to and including the next `;`, or up to the next `}`, close tag or
+/// keyword that starts a statement or class member. Braces are skipped as a whole. On failure,
+/// the span of the tokens that were skipped over is returned instead of the item.
+pub fn recover<T>(
+    state: &mut State,
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
+) -> Result<T, Span> {
+    let start = state.stream.current().span;
+
+    let error = match func(state) {
+        Ok(item) => return Ok(item),
+        Err(error) => error,
+    };
+
+    // A missing expression has already been reported at the same token, e.g. `$a = }`.
+    if state.errors.last().is_none_or(|last| last.span != error.span) {
+        state.record(error);
+    }
+
+    // Attributes that were gathered for the item don't belong to whatever comes after it.
+    state.get_attributes();
+
+    // Always skip at least one token so that we're guaranteed to make progress.
+    if state.stream.current().span == start && !state.stream.is_eof() {
+        state.stream.next();
+    }
+
+    let mut depth = 0;
+
+    loop {
+        let current = state.stream.current();
+
+        match &current.kind {
+            TokenKind::Eof | TokenKind::CloseTag => break,
+            TokenKind::SemiColon if depth == 0 => {
+                state.stream.next();
+                break;
+            }
+            TokenKind::LeftBrace | TokenKind::DollarLeftBrace => depth += 1,
+            TokenKind::RightBrace if depth == 0 => break,
+            TokenKind::RightBrace => depth -= 1,
+            kind if depth == 0 && is_synchronisation_point(kind) => break,
+            _ => {}
+        }
+
+        state.stream.next();
+    }
+
+    let end = if state.stream.current().span.start.offset > start.start.offset {
+        state.stream.previous().span.end
+    } else {
+        start.start
+    };
+
+    Err(Span::new(start.start, end))
+}
+
+/// Turn the comments in front of the current token into statements of their own, unless
+/// they belong to the declaration that's about to be parsed.
+pub fn free_standing_comments(state: &mut State) -> Vec<Statement> {
+    let peek = state.stream.peek();
+    let is_declaration = match state.stream.current().kind {
+        TokenKind::Attribute
+        | TokenKind::Abstract
+        | TokenKind::Final
+        | TokenKind::Class
+        | TokenKind::Interface
+        | TokenKind::Trait
+        | TokenKind::Function
+        | TokenKind::Const => true,
+        TokenKind::Readonly => peek.kind != TokenKind::LeftParen,
+        TokenKind::Enum => !matches!(
+            peek.kind,
+            TokenKind::LeftParen | TokenKind::DoubleColon | TokenKind::Colon
+        ),
+        _ => false,
+    };
+
+    if is_declaration {
+        return vec![];
+    }
+
+    state
+        .stream
+        .comments()
+        .into_iter()
+        .map(Statement::Comment)
+        .collect()
+}
+
+fn is_synchronisation_point(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Namespace
+            | TokenKind::Use
+            | TokenKind::Const
+            | TokenKind::Function
+            | TokenKind::Abstract
+            | TokenKind::Final
+            | TokenKind::Class
+            | TokenKind::Interface
+            | TokenKind::Trait
+            | TokenKind::If
+            | TokenKind::Switch
+            | TokenKind::Case
+            | TokenKind::Default
+            | TokenKind::While
+            | TokenKind::Do
+            | TokenKind::For
+            | TokenKind::Foreach
+            | TokenKind::Try
+            | TokenKind::Break
+            | TokenKind::Continue
+            | TokenKind::Return
+            | TokenKind::Echo
+            | TokenKind::Global
+            | TokenKind::Goto
+            | TokenKind::Declare
+            | TokenKind::Public
+            | TokenKind::Protected
+            | TokenKind::Private
+            | TokenKind::Var
+    )
+}
@@ -13,7 +13,7 @@ pub fn simple_variable(state: &mut State) -> ParseResult<SimpleVariable> {
     let current = state.stream.current();
     if let TokenKind::Variable = &current.kind {
         let span = current.span;
-        let name = current.value.clone();
+        let name = current.value.into();
         state.stream.next();
 
         return Ok(SimpleVariable { span, name });
@@ -27,7 +27,7 @@ pub fn dynamic_variable(state: &mut State) -> ParseResult<Variable> {
     match &current.kind {
         TokenKind::Variable => {
             let span = current.span;
-            let name = current.value.clone();
+            let name = current.value.into();
             state.stream.next();
 
             Ok(Variable::SimpleVariable(SimpleVariable { span, name }))
@@ -66,7 +66,7 @@ pub fn dynamic_variable(state: &mut State) -> ParseResult<Variable> {
             let span = current.span;
             state.stream.next();
 
-            let variable = dynamic_variable(state)?;
+            let variable = state.nested(dynamic_variable)?;
 
             Ok(Variable::VariableVariable(VariableVariable {
                 span,
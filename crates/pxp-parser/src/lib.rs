@@ -1,15 +1,19 @@
+#![allow(clippy::result_large_err)]
+
 use crate::error::ParseErrorStack;
 use crate::error::ParseResult;
 use crate::internal::attributes;
 use crate::internal::blocks;
 use crate::internal::classes;
 use crate::internal::constants;
+use crate::internal::data_type;
 use crate::internal::control_flow;
 use crate::internal::enums;
 use crate::internal::functions;
 use crate::internal::goto;
 use crate::internal::identifiers;
 use crate::internal::interfaces;
+use crate::internal::keywords;
 use crate::internal::loops;
 use crate::internal::namespaces;
 use crate::internal::traits;
@@ -17,14 +21,18 @@ use crate::internal::try_block;
 use crate::internal::uses;
 use crate::internal::utils;
 use crate::internal::variables;
+use crate::options::ParserOptions;
 use crate::state::State;
 use pxp_ast::declares::DeclareBody;
 use pxp_ast::declares::DeclareEntry;
 use pxp_ast::declares::DeclareEntryGroup;
 use pxp_ast::declares::DeclareStatement;
+use pxp_ast::classes::ClassMember;
+use pxp_ast::data_type::Type;
 use pxp_ast::variables::Variable;
-use pxp_ast::{Program, Statement, StaticVar};
-use pxp_lexer::stream::TokenStream;
+use pxp_ast::{Expression, Program, Statement, StaticVar};
+use pxp_cst::SyntaxTree;
+use pxp_lexer::stream::LazyTokenStream;
 use pxp_lexer::Lexer;
 use pxp_token::OpenTagKind;
 use pxp_token::Token;
@@ -44,6 +52,10 @@ use pxp_ast::ShortOpeningTagStatement;
 use pxp_ast::StaticStatement;
 
 pub mod error;
+#[cfg(feature = "files")]
+pub mod files;
+pub mod incremental;
+pub mod options;
 
 mod expressions;
 mod internal;
@@ -51,41 +63,154 @@ mod macros;
 mod state;
 
 pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Program, ParseErrorStack> {
-    let lexer = Lexer::new();
-    let tokens = match lexer.tokenize(input) {
-        Ok(tokens) => tokens,
-        Err(error) => {
-            return Err(ParseErrorStack {
-                errors: vec![error.into()],
-                partial: Vec::new(),
-            })
-        }
-    };
+    parse_with(input, ParserOptions::new())
+}
+
+pub fn parse_with<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+    options: ParserOptions,
+) -> Result<Program, ParseErrorStack> {
+    // Demoting keywords can depend on tokens that are arbitrarily far ahead, so older versions
+    // of PHP need all of them up front. Otherwise they're lexed as the parser asks for them.
+    if keywords::demotes(options.php_version) {
+        let tokens = tokenize(input, &options)?;
+
+        return construct_with(&tokens, options);
+    }
+
+    let mut stream = LazyTokenStream::new(options.lexer().tokens(input));
+
+    program(&mut stream, options)
+}
+
+/// Parse a file into a syntax tree that keeps all of its whitespace and comments, along with
+/// the AST.
+pub fn parse_cst<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<SyntaxTree, ParseErrorStack> {
+    parse_cst_with(input, ParserOptions::new())
+}
+
+pub fn parse_cst_with<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+    options: ParserOptions,
+) -> Result<SyntaxTree, ParseErrorStack> {
+    let tokens = tokenize(input, &options)?;
+    let program = construct_with(&tokens, options)?;
+
+    Ok(pxp_cst::tree(input.as_ref(), &tokens, program))
+}
+
+fn tokenize<'a, B: ?Sized + AsRef<[u8]>>(
+    input: &'a B,
+    options: &ParserOptions,
+) -> Result<Vec<Token<'a>>, ParseErrorStack> {
+    options.lexer().tokenize(input).map_err(|error| ParseErrorStack {
+        errors: vec![error.into()],
+        partial: Vec::new(),
+    })
+}
+
+/// Parse a single expression, such as `$a?->b(...)`.
+pub fn parse_expression<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+) -> Result<Expression, ParseErrorStack> {
+    fragment(input, expressions::create)
+}
+
+/// Parse a list of statements that aren't preceded by an opening tag.
+pub fn parse_statements<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Program, ParseErrorStack> {
+    let tokens = script_tokens(input)?;
 
     construct(&tokens)
 }
 
+/// Parse a type, such as `int|string|null`.
+pub fn parse_type<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Type, ParseErrorStack> {
+    fragment(input, data_type::data_type)
+}
+
+/// Parse a single class member, such as a method or a property.
+pub fn parse_class_member<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+) -> Result<ClassMember, ParseErrorStack> {
+    fragment(input, |state| classes::member(state, true, None))
+}
+
+fn script_tokens<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+) -> Result<Vec<Token<'_>>, ParseErrorStack> {
+    Lexer::new()
+        .tokenize_script(input)
+        .map_err(|error| ParseErrorStack {
+            errors: vec![error.into()],
+            partial: Vec::new(),
+        })
+}
+
+// Parse a piece of PHP code that isn't a whole file, making sure that all of it was used.
+fn fragment<B: ?Sized + AsRef<[u8]>, T>(
+    input: &B,
+    func: impl FnOnce(&mut State) -> ParseResult<T>,
+) -> Result<T, ParseErrorStack> {
+    let tokens = script_tokens(input)?;
+    let mut stream = LazyTokenStream::from(&tokens[..]);
+    let mut state = State::new(&mut stream, ParserOptions::new());
+
+    let result = func(&mut state);
+    match result {
+        Ok(_) if !state.stream.is_eof() => {
+            let error = error::unexpected_token(vec![], &state.stream.current());
+            state.record(error);
+        }
+        Err(ref error) => state.record(error.clone()),
+        _ => {}
+    }
+
+    match result {
+        Ok(node) if state.errors.is_empty() => Ok(node),
+        _ => Err(ParseErrorStack {
+            errors: state.errors,
+            partial: Vec::new(),
+        }),
+    }
+}
+
 pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
-    let mut stream = TokenStream::new(tokens);
-    let mut state = State::new(&mut stream);
+    construct_with(tokens, ParserOptions::new())
+}
+
+pub fn construct_with(
+    tokens: &[Token],
+    options: ParserOptions,
+) -> Result<Program, ParseErrorStack> {
+    let tokens = keywords::demote(tokens, options.php_version);
+    let mut stream = LazyTokenStream::from(&tokens[..]);
+
+    program(&mut stream, options)
+}
+
+fn program<'a>(
+    stream: &'a mut LazyTokenStream<'a>,
+    options: ParserOptions,
+) -> Result<Program, ParseErrorStack> {
+    let mut state = State::new(stream, options);
 
     let mut program = Program::new();
 
     while !state.stream.is_eof() {
-        let statement = match top_level_statement(&mut state) {
-            Ok(statement) => statement,
-            Err(error) => {
-                let mut previous = state.errors;
-                previous.push(error);
+        program.extend(utils::free_standing_comments(&mut state));
+        program.push(
+            utils::recover(&mut state, &top_level_statement).unwrap_or_else(Statement::Error),
+        );
+    }
 
-                return Err(ParseErrorStack {
-                    errors: previous,
-                    partial: program,
-                });
-            }
-        };
+    program.extend(utils::free_standing_comments(&mut state));
 
-        program.push(statement);
+    // The lexer stopping early makes the rest of the errors meaningless.
+    if let Some(error) = state.stream.take_error() {
+        return Err(ParseErrorStack {
+            errors: vec![error.into()],
+            partial: Vec::new(),
+        });
     }
 
     let errors = state.errors;
@@ -96,7 +221,7 @@ pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
         });
     }
 
-    Ok(program.to_vec())
+    Ok(program)
 }
 
 fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
@@ -105,17 +230,19 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
         TokenKind::Use => uses::use_statement(state)?,
         TokenKind::Const => Statement::Constant(constants::parse(state)?),
         TokenKind::HaltCompiler => {
+            let mut span = state.stream.current().span;
             state.stream.next();
 
             let content = if let TokenKind::InlineHtml = state.stream.current().kind.clone() {
-                let content = state.stream.current().value.clone();
+                let content = state.stream.current().value.into();
+                span = span.join(state.stream.current().span);
                 state.stream.next();
                 Some(content)
             } else {
                 None
             };
 
-            Statement::HaltCompiler(HaltCompilerStatement { content })
+            Statement::HaltCompiler(HaltCompilerStatement { span, content })
         }
         _ => statement(state)?,
     };
@@ -124,6 +251,10 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
 }
 
 fn statement(state: &mut State) -> ParseResult<Statement> {
+    state.nested(any_statement)
+}
+
+fn any_statement(state: &mut State) -> ParseResult<Statement> {
     let has_attributes = attributes::gather_attributes(state)?;
 
     let current = state.stream.current();
@@ -339,6 +470,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                 })
             }
             TokenKind::Static if matches!(peek.kind, TokenKind::Variable) => {
+                let r#static = current.span;
                 state.stream.next();
 
                 let mut vars = vec![];
@@ -366,15 +498,20 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                     }
                 }
 
-                utils::skip_semicolon(state)?;
+                let semicolon = utils::skip_semicolon(state)?;
 
-                Statement::Static(StaticStatement { vars })
+                Statement::Static(StaticStatement {
+                    r#static,
+                    vars,
+                    semicolon,
+                })
             }
             TokenKind::InlineHtml => {
-                let html = state.stream.current().value.clone();
+                let span = current.span;
+                let html = current.value.into();
                 state.stream.next();
 
-                Statement::InlineHtml(InlineHtmlStatement { html })
+                Statement::InlineHtml(InlineHtmlStatement { span, html })
             }
             TokenKind::Do => loops::do_while_statement(state)?,
             TokenKind::While => loops::while_statement(state)?,
@@ -440,3 +577,942 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
 
     Ok(statement)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::PhpVersion;
+    use pxp_ast::comments::CommentGroup;
+    use pxp_ast::control_flow::IfStatement;
+    use pxp_ast::control_flow::IfStatementBody;
+    use pxp_ast::enums::BackedEnumMember;
+    use pxp_ast::enums::UnitEnumMember;
+    use pxp_ast::classes::ClassMember;
+    use pxp_ast::classes::ClassStatement;
+    use pxp_ast::downcast::downcast;
+    use pxp_ast::functions::ConcreteMethod;
+    use pxp_ast::identifiers::Identifier;
+    use pxp_ast::identifiers::SimpleIdentifier;
+    use pxp_ast::interfaces::InterfaceMember;
+    use pxp_ast::literals::Literal;
+    use pxp_ast::operators::ArithmeticOperationExpression;
+    use pxp_ast::parents::ParentMap;
+    use pxp_ast::spanned::Spanned;
+    use pxp_ast::traits::TraitMember;
+    use pxp_ast::MethodCallExpression;
+    use pxp_ast::Expression;
+    use pxp_ast::StringPart;
+    use pxp_span::Span;
+
+    fn error_ids(input: &str) -> Vec<String> {
+        match parse(input) {
+            Ok(_) => vec![],
+            Err(stack) => stack.errors.into_iter().map(|error| error.id).collect(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_single_argument_does_not_panic() {
+        for input in [
+            "<?php eval(;",
+            "<?php eval(...);",
+            "<?php empty(",
+            "<?php empty(a: $b);",
+            "<?php if (empty($_GET[)) {}",
+            "<?php $a = eval(1 2);",
+        ] {
+            assert!(!error_ids(input).is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_deep_nesting_is_an_error() {
+        for (open, close) in [
+            ("(", ")"),
+            ("[", "]"),
+            ("{", "}"),
+            ("$$", ""),
+            ("function(){", "}"),
+        ] {
+            let input = format!("<?php {}$a{};", open.repeat(10_000), close.repeat(10_000));
+
+            assert!(error_ids(&input).contains(&"E052".to_string()), "{open}");
+        }
+    }
+
+    #[test]
+    fn test_version_gated_syntax() {
+        let options = ParserOptions {
+            php_version: PhpVersion::Php74,
+            ..ParserOptions::new()
+        };
+
+        let stack = parse_with("<?php enum A {}", options).unwrap_err();
+        assert_eq!(stack.errors.len(), 1);
+        assert_eq!(stack.errors[0].id, "E053");
+        assert_eq!(stack.errors[0].message, "enums require PHP 8.1");
+        assert!(matches!(stack.partial[1], Statement::UnitEnum(_)));
+
+        for (input, message) in [
+            ("<?php readonly class A {}", "readonly classes require PHP 8.2"),
+            ("<?php match ($a) {};", "match expressions require PHP 8.0"),
+            ("<?php $a?->b();", "nullsafe operators require PHP 8.0"),
+            ("<?php strlen(...);", "first-class callables require PHP 8.1"),
+            ("<?php f(a: 1);", "named arguments require PHP 8.0"),
+            ("<?php function f((A&B)|null $a) {}", "disjunctive normal form types require PHP 8.2"),
+            ("<?php function f(): int|string {}", "union types require PHP 8.0"),
+        ] {
+            let stack = parse_with(input, options).unwrap_err();
+
+            assert_eq!(stack.errors.len(), 1, "{input}");
+            assert_eq!(stack.errors[0].message, message);
+        }
+
+        assert!(parse_with("<?php $a ??= fn () => 1_000;", options).is_ok());
+        assert!(parse("<?php readonly class A {} enum B {}").is_ok());
+
+        // Keywords that didn't exist yet are just names.
+        let input = "<?php
+            function match($a) {}
+            match(1);
+            function enum() {}
+            enum();
+            class readonly { public readonly $a; }
+            $a = new readonly;
+            $a->match(1) + Foo::enum();
+            $a = match ($b) { default => 1 };";
+        let stack = parse_with(input, options).unwrap_err();
+        assert_eq!(stack.errors.len(), 1);
+        assert_eq!(stack.errors[0].message, "match expressions require PHP 8.0");
+        let options = ParserOptions {
+            php_version: PhpVersion::Php73,
+            ..options
+        };
+        assert!(parse_with("<?php function fn() {} fn(1);", options).is_ok());
+    }
+
+    #[test]
+    fn test_parse_fragments() {
+        assert!(matches!(
+            parse_expression("$a?->b(...$args)"),
+            Ok(Expression::NullsafeMethodCall(_))
+        ));
+        assert!(matches!(
+            parse_type("int|string|null"),
+            Ok(Type::Union(types)) if types.len() == 3
+        ));
+        assert!(matches!(
+            parse_class_member("public function __construct(private readonly Foo $foo) {}"),
+            Ok(ClassMember::ConcreteConstructor(_))
+        ));
+        assert!(matches!(
+            parse_class_member("abstract protected function foo(): void;"),
+            Ok(ClassMember::AbstractMethod(_))
+        ));
+        assert_eq!(parse_statements("$a = 1; echo $a;").unwrap().len(), 2);
+
+        let stack = parse_expression("$a + 1 $b").unwrap_err();
+        assert_eq!(stack.errors[0].id, "E003");
+        assert!(parse_type("int|").is_err());
+        assert!(parse_expression("").is_err());
+    }
+
+    #[test]
+    fn test_comments() {
+        let program = parse(
+            "<?php
+            // leading
+            $a = 1;
+            /** A class. */
+            #[Attr]
+            final class A {
+                /** A property. */
+                public int $x;
+            }
+            enum B { /** A case. */ case C; }
+            // trailing",
+        )
+        .unwrap();
+
+        let doc = |comments: &CommentGroup| comments.doc_comment().unwrap().content.to_string();
+
+        assert!(matches!(
+            &program[1],
+            Statement::Comment(comment) if comment.content.to_string() == "// leading"
+        ));
+        let Statement::Class(class) = &program[3] else {
+            panic!("expected a class, found {:?}", program[3]);
+        };
+        assert_eq!(doc(&class.comments), "/** A class. */");
+        let ClassMember::Property(property) = &class.body.members[0] else {
+            panic!("expected a property, found {:?}", class.body.members[0]);
+        };
+        assert_eq!(doc(&property.comments), "/** A property. */");
+        let Statement::UnitEnum(r#enum) = &program[4] else {
+            panic!("expected an enum, found {:?}", program[4]);
+        };
+        let UnitEnumMember::Case(case) = &r#enum.body.members[0] else {
+            panic!("expected a case, found {:?}", r#enum.body.members[0]);
+        };
+        assert_eq!(doc(&case.comments), "/** A case. */");
+        assert!(matches!(
+            &program[5],
+            Statement::Comment(comment) if comment.content.to_string() == "// trailing"
+        ));
+    }
+
+    #[test]
+    fn test_comments_at_the_end_of_a_body() {
+        let program = parse(
+            "<?php
+            class A { public $a; // end of A
+            }
+            function f() {}
+            enum B { case C; /* end of B */ }
+            interface D { // end of D
+            }
+            trait E { public $e; # end of E
+            }
+            function g() {}",
+        )
+        .unwrap();
+
+        let text = |comments: &CommentGroup| {
+            comments
+                .iter()
+                .map(|comment| comment.content.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let comments = program[1..]
+            .iter()
+            .map(|statement| match statement {
+                Statement::Class(class) => text(&class.body.comments),
+                Statement::UnitEnum(r#enum) => text(&r#enum.body.comments),
+                Statement::Interface(interface) => text(&interface.body.comments),
+                Statement::Trait(r#trait) => text(&r#trait.body.comments),
+                Statement::Function(function) => text(&function.comments),
+                statement => panic!("unexpected statement {:?}", statement),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            comments,
+            [
+                vec!["// end of A"],
+                vec![],
+                vec!["/* end of B */"],
+                vec!["// end of D"],
+                vec!["# end of E"],
+                vec![],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spans() {
+        let input = "<?php
+static $a = null;
+#[A] final class B {}
+use C\\D as E;
+$f = <<<EOT
+  g
+  EOT;
+try {} catch (H) {}
+";
+        let text = |span: Span| &input[span.start.offset..span.end.offset];
+
+        let program = parse(input).unwrap();
+        let statements: Vec<&str> = program[1..].iter().map(|s| text(s.span())).collect();
+        assert_eq!(
+            statements,
+            [
+                "static $a = null;",
+                "#[A] final class B {}",
+                "use C\\D as E;",
+                "$f = <<<EOT\n  g\n  EOT;",
+                "try {} catch (H) {}",
+            ]
+        );
+
+        let ty = parse_type("int|string|null").unwrap();
+        assert_eq!(ty.span().start.offset, 0);
+        assert_eq!(ty.span().end.offset, 15);
+        let expression = parse_expression("self::A ?? null").unwrap();
+        assert_eq!(expression.span().end.offset, 15);
+    }
+
+    #[test]
+    fn test_string_values() {
+        // The runtime value of every literal part of a string.
+        let text = |value: &[u8]| String::from_utf8(value.to_vec()).unwrap();
+        let values = |input: &str| -> Vec<String> {
+            let parts = match parse_expression(input).unwrap() {
+                Expression::Literal(Literal::String(string)) => return vec![text(&string.value)],
+                Expression::Nowdoc(nowdoc) => return vec![text(&nowdoc.value)],
+                Expression::InterpolatedString(string) => string.parts,
+                Expression::ShellExec(string) => string.parts,
+                Expression::Heredoc(heredoc) => heredoc.parts,
+                expression => panic!("expected a string, found {:?}", expression),
+            };
+
+            parts
+                .into_iter()
+                .filter_map(|part| match part {
+                    StringPart::Literal(literal) => Some(text(&literal.value)),
+                    StringPart::Expression(_) => None,
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            values(r#""\u{1F600}\u{41}\x41\x4g\101\0\$\\\"\e\q""#),
+            ["😀AA\x04gA\0$\\\"\x1b\\q"]
+        );
+        assert_eq!(values(r#"'\'\\\n'"#), ["'\\\\n"]);
+        assert_eq!(values(r#""a\x41$b\u{42}{$c}\101""#), ["aA", "B", "A"]);
+        assert_eq!(values(r#"`\`\"$a`"#), ["`\\\""]);
+        assert_eq!(values("<<<EOT\n    a\\t\n      b $c\n    EOT"), ["a\t\n  b "]);
+        assert_eq!(values("<<<EOT\n\ta\\x41\n\t\t\"b\"\n\tEOT"), ["aA\n\t\"b\""]);
+        assert_eq!(values("<<<'EOT'\n  a\\t\n\n   b\n  EOT"), ["a\\t\n\n b"]);
+        assert_eq!(values("<<<EOT\n  a\n  $b\n    {$c}d\n  EOT"), ["a\n", "\n  ", "d"]);
+
+        for (input, message) in [
+            (r#""\u{}""#, "invalid unicode escape"),
+            (r#""\u{41""#, "invalid unicode escape"),
+            (r#""\u{110000}""#, "invalid unicode escape"),
+            (r#""\u{100000000}""#, "invalid unicode escape"),
+            (r#""\u{0000000041}""#, "invalid unicode escape"),
+            (r#""$a\u{D800}""#, "invalid unicode escape"),
+            (r#""\400""#, "invalid octal escape"),
+            ("<<<EOT\n  \\u{}\n  EOT", "invalid unicode escape"),
+            ("<<<EOT\n\ta\n  EOT", "cannot use tabs and spaces"),
+            ("<<<'EOT'\n\t a\n  EOT", "cannot use tabs and spaces"),
+            ("<<<EOT\n \ta\n  EOT", "indentation level of at least 2"),
+            ("<<<'EOT'\n a\n  EOT", "indentation level of at least 2"),
+            ("<<<EOT\n  a\n$b\n  EOT", "indentation level of at least 2"),
+        ] {
+            let stack = parse_expression(input).unwrap_err();
+
+            assert_eq!(stack.errors[0].id, "E001", "{input}");
+            assert!(stack.errors[0].message.contains(message), "{input}");
+        }
+
+        // Only canonical decimal integers are integer offsets, anything else is a string key.
+        let Expression::InterpolatedString(string) =
+            parse_expression(r#""$a[0] $a[7] $a[-7] $a[01] $a[0x1] $a[9223372036854775808]""#)
+                .unwrap()
+        else {
+            panic!("expected an interpolated string");
+        };
+
+        let offsets = string
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                StringPart::Expression(part) => match &*part.expression {
+                    Expression::ArrayIndex(index) => index.index.as_deref(),
+                    _ => None,
+                },
+                StringPart::Literal(_) => None,
+            })
+            .map(|index| match index {
+                Expression::Literal(Literal::Integer(integer)) => format!("{}", integer.value),
+                Expression::Literal(Literal::String(string)) => format!("'{}'", string.value),
+                Expression::ArithmeticOperation(ArithmeticOperationExpression::Negative {
+                    right,
+                    ..
+                }) => match &**right {
+                    Expression::Literal(Literal::Integer(integer)) => format!("-{}", integer.value),
+                    right => panic!("expected an integer, found {:?}", right),
+                },
+                index => panic!("expected a literal, found {:?}", index),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            offsets,
+            ["0", "7", "-7", "'01'", "'0x1'", "'9223372036854775808'"]
+        );
+    }
+
+    #[test]
+    fn test_parent_map() {
+        let input = "<?php class A { function b() { return $this->c(); } }";
+        let mut program = parse(input).unwrap();
+        let map = ParentMap::new(&mut program);
+
+        let call = map.node_at_offset(input.find("c(").unwrap()).unwrap();
+        assert!(map.is::<SimpleIdentifier>(call));
+        assert!(map.is::<Identifier>(map.parent(call).unwrap()));
+        let call = map.enclosing::<MethodCallExpression>(call).unwrap();
+        assert_eq!(map.span(call).start.offset, input.find("$this").unwrap());
+
+        let method = map.enclosing::<ConcreteMethod>(call).unwrap();
+        let class = map.enclosing::<ClassStatement>(call).unwrap();
+        assert!(map.ancestors(method).any(|ancestor| ancestor == class));
+        assert!(map.is::<Statement>(map.parent(class).unwrap()));
+        assert_eq!(map.ancestors(class).last(), Some(map.root()));
+
+        let id = method;
+        let method = downcast::<ConcreteMethod>(map.get(&mut program, id).unwrap()).unwrap();
+        assert_eq!(method.name.value.to_string(), "b");
+        assert_eq!(map.id(method), Some(id));
+    }
+
+    #[test]
+    fn test_recovering_from_errors() {
+        let stack = parse("<?php $a = ; class A { public function } echo 1;").unwrap_err();
+
+        assert_eq!(stack.errors.len(), 2);
+        assert!(matches!(
+            &stack.partial[1],
+            Statement::Expression(ExpressionStatement {
+                expression: Expression::AssignmentOperation(_),
+                ..
+            })
+        ));
+        assert!(matches!(stack.partial.last(), Some(Statement::Echo(_))));
+
+        // Members that fail to parse are kept as errors, along with the ones after them.
+        let input = "<?php class A { public function a() {} public $; public function b() {} }";
+        let stack = parse(input).unwrap_err();
+        let Statement::Class(class) = &stack.partial[1] else {
+            panic!("expected a class, found {:?}", stack.partial[1]);
+        };
+        let [ClassMember::ConcreteMethod(_), error, ClassMember::ConcreteMethod(b)] =
+            &class.body.members[..]
+        else {
+            panic!("unexpected members {:?}", class.body.members);
+        };
+        let ClassMember::Error(span) = error else {
+            panic!("expected an error, found {:?}", error);
+        };
+        assert_eq!(&input[span.start.offset..span.end.offset], "public $;");
+        assert_eq!(b.name.value, b"b");
+
+        let input = "<?php enum E: int { case A = 1; case B; const = 2; public function c() {} }";
+        let stack = parse(input).unwrap_err();
+        let Statement::BackedEnum(r#enum) = &stack.partial[1] else {
+            panic!("expected an enum, found {:?}", stack.partial[1]);
+        };
+        let [BackedEnumMember::Case(_), case, constant, BackedEnumMember::Method(_)] =
+            &r#enum.body.members[..]
+        else {
+            panic!("unexpected members {:?}", r#enum.body.members);
+        };
+        for (member, text) in [(case, "case B;"), (constant, "const = 2;")] {
+            let BackedEnumMember::Error(span) = member else {
+                panic!("expected an error, found {:?}", member);
+            };
+            assert_eq!(&input[span.start.offset..span.end.offset], text);
+        }
+
+        for input in [
+            "<?php interface A { public function a(); const = 1; public function b(); }",
+            "<?php trait A { public function a() {} public $; public function b() {} }",
+        ] {
+            let members = match &parse(input).unwrap_err().partial[1] {
+                Statement::Interface(interface) => interface
+                    .body
+                    .members
+                    .iter()
+                    .map(|member| matches!(member, InterfaceMember::Error(_)))
+                    .collect::<Vec<_>>(),
+                Statement::Trait(r#trait) => r#trait
+                    .body
+                    .members
+                    .iter()
+                    .map(|member| matches!(member, TraitMember::Error(_)))
+                    .collect(),
+                statement => panic!("unexpected statement {:?}", statement),
+            };
+
+            assert_eq!(members, [false, true, false], "{}", input);
+        }
+
+        // Statements in nested blocks recover without giving up on the rest of the block.
+        let input = "<?php function a() { if ($a) { namespace A; echo 1; } echo 2; }";
+        let stack = parse(input).unwrap_err();
+        let Statement::Function(function) = &stack.partial[1] else {
+            panic!("expected a function, found {:?}", stack.partial[1]);
+        };
+        let [Statement::If(IfStatement {
+            body: IfStatementBody::Statement { statement, .. },
+            ..
+        }), Statement::Echo(_)] = &function.body.statements[..]
+        else {
+            panic!("unexpected statements {:?}", function.body.statements);
+        };
+        let Statement::Block(block) = &**statement else {
+            panic!("expected a block, found {:?}", statement);
+        };
+        let [Statement::Error(span), Statement::Echo(_)] = &block.statements[..] else {
+            panic!("unexpected statements {:?}", block.statements);
+        };
+        assert_eq!(&input[span.start.offset..span.end.offset], "namespace A;");
+
+        // Errors from the lexer only mention where they are once.
+        let stack = parse("<?php\n  $a = 01238;").unwrap_err();
+        assert_eq!(
+            stack.errors[0].to_string(),
+            "[E001] Error: syntax error, invalid octal literal on line 2 column 12"
+        );
+    }
+
+    #[test]
+    fn test_cst_round_trip() {
+        let input = concat!(
+            "<?php\n\n// A comment.\n",
+            "FINAL  CLASS A {\n    # Another.\n    PUBLIC Function b( ) { }\n}\n",
+        );
+        let mut tree = parse_cst(input).unwrap();
+
+        assert_eq!(tree.root.text().bytes, input.as_bytes());
+
+        let class = tree
+            .root
+            .descendants()
+            .into_iter()
+            .find(|node| node.is::<ClassStatement>())
+            .unwrap();
+        assert_eq!(
+            class.text(),
+            b"FINAL  CLASS A {\n    # Another.\n    PUBLIC Function b( ) { }\n}"
+        );
+
+        let class = downcast::<ClassStatement>(tree.ast(&class).unwrap()).unwrap();
+        assert_eq!(class.name.value, b"A");
+    }
+
+    #[test]
+    fn test_every_node_has_a_syntax_node() {
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fuzz/corpus/parse");
+
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let path = entry.unwrap().path();
+            let Ok(mut tree) = parse_cst(&std::fs::read(&path).unwrap()) else {
+                continue;
+            };
+
+            let syntax = tree
+                .root
+                .descendants()
+                .into_iter()
+                .map(|node| node.id())
+                .collect::<std::collections::HashSet<_>>();
+            let map = ParentMap::new(&mut tree.program);
+
+            for id in map.ids() {
+                let span = map.span(id);
+
+                if span.start.offset < span.end.offset && !syntax.contains(&id) {
+                    let node = map.get(&mut tree.program, id).unwrap();
+
+                    panic!("{} {:?} in {}", node.kind(), span, path.display());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lazy_parse_matches_parsing_all_tokens() {
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fuzz/corpus/parse");
+
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read(&path).unwrap();
+
+            // Cutting files short makes the lexer fail part of the way through.
+            for end in (0..=input.len()).step_by(7).chain([input.len()]) {
+                let input = &input[..end];
+                let eager = tokenize(input, &ParserOptions::new())
+                    .and_then(|tokens| construct(&tokens));
+
+                assert_eq!(parse(input), eager, "{} up to {}", path.display(), end);
+            }
+        }
+    }
+
+    #[test]
+    fn test_incremental_matches_full_parse() {
+        use crate::incremental::Document;
+        use crate::incremental::TextEdit;
+
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fuzz/corpus/parse");
+        let replacements = [" ", "x", "1", "$a", "'", "}", "/* c */", "// c\n"];
+        let mut reparsed = 0;
+
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read(&path).unwrap();
+
+            for (i, offset) in (1..input.len()).step_by(3).enumerate() {
+                let end = if i % 3 == 0 { offset + 1 } else { offset };
+                let edit = TextEdit::new(offset..end, replacements[i % replacements.len()]);
+                let edited = edit.apply(&input).unwrap();
+
+                let document = Document::parse(&input, ParserOptions::new()).edit(&edit, &edited);
+                reparsed += document.reparsed().is_some() as usize;
+
+                assert_eq!(
+                    document.into_result(),
+                    parse_with(&edited, ParserOptions::new()),
+                    "{} with {:?}",
+                    path.display(),
+                    edit,
+                );
+            }
+        }
+
+        assert!(reparsed > 0);
+
+        // An edit that doesn't match the new input is parsed from scratch.
+        let input = "<?php $a = 1; $b = 2;";
+        let edited = "<?php $a = 1; $b = 42; $c = 3;";
+
+        for edit in [
+            TextEdit::new(19..20, "42"),
+            TextEdit::new(28..29, "42"),
+            TextEdit::new(40..50, ""),
+        ] {
+            let document = Document::parse(input, ParserOptions::new()).edit(&edit, edited);
+
+            assert!(document.reparsed().is_none());
+            assert_eq!(
+                document.into_result(),
+                parse_with(edited, ParserOptions::new()),
+                "{:?}",
+                edit
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "files")]
+    fn test_parse_files() {
+        use crate::files::FileError;
+        use crate::files::Files;
+
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fuzz/corpus/parse");
+        let files = Files::new()
+            .path(corpus)
+            .exclude("regression_*.php")
+            .unwrap()
+            .parse();
+
+        assert!(!files.is_empty());
+        assert!(files.windows(2).all(|pair| pair[0].path < pair[1].path));
+
+        for file in files {
+            let name = file.path.file_name().unwrap().to_str().unwrap();
+            assert!(!name.starts_with("regression_"));
+
+            let input = std::fs::read(&file.path).unwrap();
+            assert_eq!(file.source, input);
+
+            match (file.result, parse(&input)) {
+                (Ok(program), Ok(expected)) => assert_eq!(program, expected),
+                (Err(FileError::Parse(stack)), Err(expected)) => assert_eq!(stack, expected),
+                _ => panic!("{} wasn't parsed the same way", name),
+            }
+        }
+    }
+
+    #[test]
+    fn test_visitors() {
+        use pxp_ast::attributes::Attribute;
+        use pxp_ast::node::Node;
+        use pxp_ast::variables::SimpleVariable;
+        use pxp_ast::visitor::{walk_attribute, walk_method_call_expression, Visitor, Walk};
+        use pxp_ast::visitor_mut::VisitorMut;
+
+        #[derive(Default)]
+        struct Collect {
+            attributes: Vec<String>,
+            calls: usize,
+            variables: Vec<String>,
+            depth: usize,
+            skip: bool,
+        }
+
+        impl Visitor for Collect {
+            fn enter(&mut self, _: &dyn Node) -> Walk {
+                self.depth += 1;
+
+                if self.skip {
+                    Walk::Skip
+                } else {
+                    Walk::Continue
+                }
+            }
+
+            fn leave(&mut self, _: &dyn Node) {
+                self.depth -= 1;
+            }
+
+            fn visit_attribute(&mut self, node: &Attribute) {
+                self.attributes.push(node.name.value.to_string());
+                walk_attribute(self, node);
+            }
+
+            fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+                self.calls += 1;
+                walk_method_call_expression(self, node);
+            }
+
+            fn visit_simple_variable(&mut self, node: &SimpleVariable) {
+                self.variables.push(node.name.to_string());
+            }
+
+            // Nothing in a class is walked.
+            fn visit_class_statement(&mut self, _: &ClassStatement) {}
+        }
+
+        struct Rename;
+
+        impl VisitorMut for Rename {
+            fn visit_simple_variable(&mut self, node: &mut SimpleVariable) {
+                node.name = b"$renamed".into();
+            }
+        }
+
+        let input = concat!(
+            "<?php #[Route('/')] function a() { $b->c($d->e()); } ",
+            "class F { function g() { $h->i(); } }",
+        );
+        let mut program = parse(input).unwrap();
+
+        let mut collect = Collect::default();
+        program.iter().for_each(|statement| collect.visit_statement(statement));
+        assert_eq!(collect.attributes, ["Route"]);
+        assert_eq!(collect.calls, 2);
+        assert_eq!(collect.variables, ["$b", "$d"]);
+        assert_eq!(collect.depth, 0);
+
+        let mut collect = Collect {
+            skip: true,
+            ..Collect::default()
+        };
+        program.iter().for_each(|statement| collect.visit_statement(statement));
+        assert!(collect.attributes.is_empty());
+        assert_eq!(collect.calls, 0);
+
+        program.iter_mut().for_each(|statement| Rename.visit_statement(statement));
+        let mut collect = Collect::default();
+        program.iter().for_each(|statement| collect.visit_statement(statement));
+        assert_eq!(collect.variables, ["$renamed", "$renamed"]);
+    }
+
+    #[test]
+    fn test_node_traversal_is_complete() {
+        use pxp_ast::node::Node;
+        use pxp_ast::visitor::{Visitor, Walk};
+
+        fn children(node: &mut dyn Node, kinds: &mut Vec<(&'static str, Span)>) {
+            kinds.push((node.kind(), node.span()));
+            node.children().into_iter().for_each(|child| children(child, kinds));
+        }
+
+        struct Enter(Vec<(&'static str, Span)>);
+
+        impl Visitor for Enter {
+            fn enter(&mut self, node: &dyn Node) -> Walk {
+                self.0.push((node.kind(), node.span()));
+                Walk::Continue
+            }
+        }
+
+        let input = concat!(
+            "<html><?php\n// A comment.\n#[Route('/')]\nfinal readonly class A ",
+            "{ public const B = 1; }\n?></html>",
+        );
+        let mut program = parse(input).unwrap();
+        let mut kinds = Vec::new();
+        children(&mut program, &mut kinds);
+
+        assert_eq!(kinds[0].0, "Program");
+        for kind in [
+            "InlineHtmlStatement",
+            "FullOpeningTagStatement",
+            "CommentGroup",
+            "AttributeGroup",
+            "Attribute",
+            "ClassModifierGroup",
+            "ConstantModifierGroup",
+            "ClosingTagStatement",
+        ] {
+            assert!(kinds.iter().any(|(k, _)| *k == kind), "{} wasn't reached", kind);
+        }
+
+        let class = program.iter_mut().find(|s| matches!(s, Statement::Class(_))).unwrap();
+        let Statement::Class(class) = class else { unreachable!() };
+        let fields: Vec<_> = class.fields().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(fields, ["comments", "attributes", "modifiers", "name", "body"]);
+
+        // `children` and the visitors are both generated, and have to agree about everything.
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fuzz/corpus/parse");
+
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let Ok(mut program) = parse(&std::fs::read(entry.unwrap().path()).unwrap()) else {
+                continue;
+            };
+
+            let mut kinds = Vec::new();
+            children(&mut program, &mut kinds);
+            let mut enter = Enter(Vec::new());
+            program.iter().for_each(|statement| enter.visit_statement(statement));
+
+            assert_eq!(kinds[1..], enter.0[..]);
+        }
+    }
+
+    #[test]
+    fn test_fold() {
+        use pxp_ast::fold::{fold_expression, fold_statement, Fold};
+        use pxp_ast::operators::AssignmentOperationExpression;
+        use pxp_ast::{ArrayItem, ShortArrayExpression};
+
+        struct Rewrite;
+
+        impl Fold for Rewrite {
+            fn fold_expression(&mut self, node: Expression) -> Expression {
+                match fold_expression(self, node) {
+                    Expression::Array(array) => Expression::ShortArray(ShortArrayExpression {
+                        start: array.start,
+                        items: array.items,
+                        end: array.end,
+                    }),
+                    node => node,
+                }
+            }
+
+            fn fold_statements(&mut self, node: Statement) -> Vec<Statement> {
+                match fold_statement(self, node) {
+                    Statement::Echo(_) => vec![],
+                    node @ Statement::Expression(_) => vec![node.clone(), node],
+                    node => vec![node],
+                }
+            }
+        }
+
+        let program = parse("<?php function a() { echo 1; $b = array(array(1)); return; }");
+        let program = Rewrite.fold_block(program.unwrap());
+
+        let [_, Statement::Function(function)] = &program[..] else {
+            panic!("expected an opening tag and a function, found {:?}", program);
+        };
+        let statements = &function.body.statements;
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0], statements[1]);
+        assert!(matches!(statements[2], Statement::Return(_)));
+
+        let Statement::Expression(ExpressionStatement {
+            expression: Expression::AssignmentOperation(assignment),
+            ..
+        }) = &statements[0]
+        else {
+            panic!("expected an assignment, found {:?}", statements[0]);
+        };
+        let AssignmentOperationExpression::Assign { right, .. } = assignment else {
+            panic!("expected `=`, found {:?}", assignment);
+        };
+        let Expression::ShortArray(outer) = &**right else {
+            panic!("expected a short array, found {:?}", right);
+        };
+        assert!(matches!(
+            &outer.items.inner[0],
+            ArrayItem::Value { value: Expression::ShortArray(_) }
+        ));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let input = "<?php #[A] final class B { public function c(): int { return 1.5 + 'd'; } }";
+        let program = parse(input).unwrap();
+        let json = serde_json::to_value(&program).unwrap();
+
+        // Nodes are tagged with the name of their variant.
+        assert!(json[1]["Class"]["modifiers"].is_object());
+        assert_eq!(serde_json::from_value::<Program>(json).unwrap(), program);
+
+        // Infinity can't be written as a JSON number.
+        let program = parse("<?php 1e999;").unwrap();
+        let json = serde_json::to_string(&program).unwrap();
+
+        assert!(json.contains(r#""value":"INF""#), "{}", json);
+        assert_eq!(serde_json::from_str::<Program>(&json).unwrap(), program);
+
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fuzz/corpus/parse");
+
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let Ok(program) = parse(&std::fs::read(entry.unwrap().path()).unwrap()) else {
+                continue;
+            };
+
+            let json = serde_json::to_string(&program).unwrap();
+            assert_eq!(serde_json::from_str::<Program>(&json).unwrap(), program);
+        }
+    }
+
+    #[test]
+    fn test_token_serde_round_trip() {
+        use pxp_token::OwnedToken;
+
+        // Bytes that aren't valid UTF-8 are written as an array instead of a string.
+        let input = b"<?php\n/** a */ echo \"b{$c}\" . '\xff';";
+        let tokens = Lexer::new().tokenize(input).unwrap();
+        let json = serde_json::to_string(&tokens).unwrap();
+        let owned = serde_json::from_str::<Vec<OwnedToken>>(&json).unwrap();
+
+        assert_eq!(owned, tokens.iter().map(OwnedToken::from).collect::<Vec<_>>());
+        assert_eq!(owned.iter().map(OwnedToken::as_token).collect::<Vec<_>>(), tokens);
+    }
+
+    #[test]
+    fn test_query() {
+        use pxp_ast::query::Selector;
+
+        let input = concat!(
+            "<?php class A { public function __construct() { DB::raw($b); new C(); } }\n",
+            "function d() { DB::raw('e'); DB::table('f'); Other::raw($g); new H(); }",
+        );
+        let mut program = parse(input).unwrap();
+        let find = |program: &Program, selector: &str| -> Vec<&str> {
+            let selector = Selector::parse(selector).unwrap();
+            let matches = selector.find(program, input.as_bytes());
+
+            matches.iter().map(|m| &input[m.span.start.offset..m.span.end.offset]).collect()
+        };
+
+        // The examples from the request for the query language.
+        assert_eq!(
+            find(&program, r#"ClassStatement ConcreteMethod[name="__construct"] NewExpression"#),
+            ["new C()"]
+        );
+        assert_eq!(
+            find(&program, r#"StaticMethodCallExpression[target="DB"][method="raw"]"#),
+            ["DB::raw($b)", "DB::raw('e')"]
+        );
+
+        let selector = "ClassStatement ConcreteConstructor[name=__construct] NewExpression";
+        assert_eq!(find(&program, selector), ["new C()"]);
+        assert_eq!(find(&program, r#"StaticMethodCallExpression[method='raw']"#).len(), 3);
+        assert_eq!(find(&program, "StaticMethodCallExpression SimpleVariable"), ["$b", "$g"]);
+        assert_eq!(find(&program, "ArgumentList > * > PositionalArgument").len(), 4);
+        assert!(find(&program, "ArgumentList > PositionalArgument").is_empty());
+        assert_eq!(find(&program, "FunctionStatement[return_type]").len(), 0);
+
+        let selector = Selector::parse("NewExpression").unwrap();
+        let found = selector.find(&program, input.as_bytes());
+        let map = ParentMap::new(&mut program);
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|m| m.kind == "NewExpression" && map.span(m.id) == m.span));
+
+        let error = Selector::parse("A[b=").unwrap_err();
+        assert_eq!(error.to_string(), "expected a value at offset 4");
+        assert!(Selector::parse("A[b='c]").is_err());
+        assert!(Selector::parse("> A").is_err());
+        assert!(Selector::parse("A B[c]D").is_err());
+    }
+}
@@ -37,7 +37,7 @@ macro_rules! expect_token {
             _ => {
                 return Err($crate::error::unexpected_token(
                     vec![$($message.into(),)+],
-                    token,
+                    &token,
                 ))
             }
         }
@@ -58,7 +58,7 @@ macro_rules! expect_literal {
 
                 pxp_ast::literals::Literal::Integer(pxp_ast::literals::LiteralInteger {
                     span: current.span,
-                    value: current.value.clone(),
+                    value: pxp_lexer::number::integer(current.value).unwrap_or_default(),
                 })
             }
             TokenKind::LiteralFloat => {
@@ -66,7 +66,7 @@ macro_rules! expect_literal {
 
                 pxp_ast::literals::Literal::Float(pxp_ast::literals::LiteralFloat {
                     span: current.span,
-                    value: current.value.clone(),
+                    value: pxp_lexer::number::float(current.value),
                 })
             }
             TokenKind::LiteralSingleQuotedString | TokenKind::LiteralDoubleQuotedString => {
@@ -74,7 +74,7 @@ macro_rules! expect_literal {
 
                 pxp_ast::literals::Literal::String(pxp_ast::literals::LiteralString {
                     span: current.span,
-                    value: current.value.clone(),
+                    value: $crate::internal::strings::literal_value(&current)?,
                     kind: if matches!(current.kind, TokenKind::LiteralSingleQuotedString) {
                         pxp_ast::literals::LiteralStringKind::SingleQuoted
                     } else {
@@ -105,7 +105,7 @@ macro_rules! expected_token {
     ([ $($expected:literal),+ $(,)? ], $state:expr $(,)?) => {{
         $crate::error::unexpected_token(
             vec![$($expected.into()),+],
-            $state.stream.current(),
+            &$state.stream.current(),
         )
     }};
 
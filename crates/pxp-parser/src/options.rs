@@ -0,0 +1,40 @@
+pub use pxp_lexer::config::PhpVersion;
+
+use pxp_lexer::config::LexerConfig;
+use pxp_lexer::Lexer;
+
+/// Options that change how source code is parsed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParserOptions {
+    /// The version of PHP being parsed. Syntax that was introduced in a later version is
+    /// still parsed, but an error is reported for it.
+    pub php_version: PhpVersion,
+    /// Whether `<?` opens a block of PHP code, just like the `short_open_tag` ini setting.
+    pub short_open_tag: bool,
+}
+
+impl ParserOptions {
+    pub const fn new() -> Self {
+        Self {
+            php_version: PhpVersion::Php83,
+            short_open_tag: true,
+        }
+    }
+
+    // The lexer that source code is tokenized with before it's parsed with these options.
+    //
+    // It always targets the latest version of PHP, and keywords that don't exist in
+    // `php_version` are turned back into identifiers by the parser where they're used as names.
+    pub(crate) fn lexer(&self) -> Lexer {
+        Lexer::with_config(LexerConfig {
+            short_open_tag: self.short_open_tag,
+            ..LexerConfig::new()
+        })
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,10 +1,15 @@
 use std::collections::VecDeque;
 use std::fmt::Display;
 
+use crate::error;
 use crate::error::ParseError;
+use crate::error::ParseResult;
+use crate::options::ParserOptions;
 use pxp_ast::attributes::AttributeGroup;
 use pxp_ast::identifiers::SimpleIdentifier;
-use pxp_lexer::stream::TokenStream;
+use pxp_lexer::config::PhpVersion;
+use pxp_lexer::stream::LazyTokenStream;
+use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum NamespaceType {
@@ -18,26 +23,45 @@ pub enum Scope {
     BracedNamespace(Option<SimpleIdentifier>),
 }
 
+/// How deeply statements and expressions can be nested before parsing gives up.
+pub const MAX_DEPTH: usize = 256;
+
+// Parsing a single level of nesting can take a lot of stack, especially in debug builds, so
+// more is allocated whenever less than `RED_ZONE` bytes are left.
+const RED_ZONE: usize = 1024 * 1024;
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
 #[derive(Debug)]
 pub struct State<'a> {
     pub stack: VecDeque<Scope>,
-    pub stream: &'a mut TokenStream<'a>,
+    pub stream: &'a mut LazyTokenStream<'a>,
     pub attributes: Vec<AttributeGroup>,
     pub namespace_type: Option<NamespaceType>,
     pub errors: Vec<ParseError>,
+    pub options: ParserOptions,
+    depth: usize,
 }
 
 impl<'a> State<'a> {
-    pub fn new(tokens: &'a mut TokenStream<'a>) -> Self {
+    pub fn new(tokens: &'a mut LazyTokenStream<'a>, options: ParserOptions) -> Self {
         Self {
             stack: VecDeque::with_capacity(32),
             stream: tokens,
             namespace_type: None,
             attributes: vec![],
             errors: vec![],
+            options,
+            depth: 0,
         }
     }
 
+    /// Start out `depth` levels deep, for parsing something that's nested inside other code on
+    /// its own.
+    pub fn at_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
     pub fn attribute(&mut self, attr: AttributeGroup) {
         self.attributes.push(attr);
     }
@@ -54,6 +78,31 @@ impl<'a> State<'a> {
         self.errors.push(error);
     }
 
+    /// Record an error if `feature` isn't available in the targeted version of PHP.
+    pub fn requires(&mut self, version: PhpVersion, feature: &str, span: Span) {
+        if self.options.php_version < version {
+            self.record(error::feature_requires_newer_php_version(
+                feature,
+                version,
+                self.options.php_version,
+                span,
+            ));
+        }
+    }
+
+    /// Parse something that can contain itself, failing once things are nested too deeply.
+    pub fn nested<T>(&mut self, func: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        if self.depth >= MAX_DEPTH {
+            return Err(error::nesting_too_deep(self.stream.current().span));
+        }
+
+        self.depth += 1;
+        let result = stacker::maybe_grow(RED_ZONE, STACK_SIZE, || func(self));
+        self.depth -= 1;
+
+        result
+    }
+
     /// Return the namespace type used in the current state
     ///
     /// The namespace type is retrieve from the last entered
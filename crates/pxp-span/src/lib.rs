@@ -1,4 +1,5 @@
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Span {
     pub start: Position,
     pub end: Position,
@@ -8,9 +9,15 @@ impl Span {
     pub fn new(start: Position, end: Position) -> Self {
         Self { start, end }
     }
+
+    /// A span that starts where this one starts and ends where `other` ends.
+    pub fn join(self, other: Span) -> Self {
+        Self::new(self.start, other.end)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     pub offset: usize,
     pub line: usize,
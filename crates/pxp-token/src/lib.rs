@@ -1,10 +1,11 @@
+use std::fmt::Debug;
 use std::fmt::Display;
 
 use pxp_bytestring::ByteString;
 use pxp_span::Span;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OpenTagKind {
     Full,  // `<?php`
     Short, // `<?`
@@ -12,7 +13,7 @@ pub enum OpenTagKind {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DocStringKind {
     Heredoc,
     Nowdoc,
@@ -21,7 +22,7 @@ pub enum DocStringKind {
 pub type DocStringIndentationAmount = usize;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DocStringIndentationKind {
     Space,
     Tab,
@@ -50,7 +51,7 @@ impl From<DocStringIndentationKind> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenKind {
     Die,
     // Can't use `Self` as a name here, so suffixing with an underscore.
@@ -146,6 +147,8 @@ pub enum TokenKind {
     EndWhile,
     Enum,
     Eof,
+    // Bytes the lexer could not make sense of, only produced when recovering from errors.
+    Error,
     Equals,
     Extends,
     False,
@@ -242,40 +245,336 @@ pub enum TokenKind {
     LogicalXor,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-
-pub struct Token {
+#[derive(PartialEq, Eq, Clone)]
+// Tokens borrow their bytes from the source code, so they're deserialized as an `OwnedToken`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Token<'a> {
     pub kind: TokenKind,
     pub span: Span,
-    pub value: ByteString,
+    /// The bytes of the token, borrowed straight from the source code.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "pxp_bytestring::serialize_bytes"))]
+    pub value: &'a [u8],
 }
 
-impl Default for Token {
+impl<'a> Default for Token<'a> {
     fn default() -> Self {
         Self {
             kind: TokenKind::Eof,
             span: Span::default(),
-            value: ByteString::default(),
+            value: &[],
         }
     }
 }
 
-impl Display for Token {
+impl<'a> Debug for Token<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        f.debug_struct("Token")
+            .field("kind", &self.kind)
+            .field("span", &self.span)
+            .field("value", &ByteString::from(self.value))
+            .finish()
     }
 }
 
-impl Display for TokenKind {
+impl<'a> Display for Token<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
+        write!(f, "{}", String::from_utf8_lossy(self.value))
+    }
+}
+
+/// A token that owns its bytes, so that it can outlive the source code.
+///
+/// It's serialized the same way as a `Token`, which makes it the type to deserialize tokens
+/// into.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedToken {
+    pub kind: TokenKind,
+    pub span: Span,
+    pub value: ByteString,
+}
+
+impl OwnedToken {
+    /// Borrow the token as a `Token`.
+    pub fn as_token(&self) -> Token<'_> {
+        Token {
+            kind: self.kind.clone(),
+            span: self.span,
+            value: &self.value,
+        }
+    }
+}
+
+impl<'a> From<&Token<'a>> for OwnedToken {
+    fn from(token: &Token<'a>) -> Self {
+        Self {
+            kind: token.kind.clone(),
+            span: token.span,
+            value: token.value.into(),
+        }
+    }
+}
+
+/// The kind of a piece of trivia.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriviaKind {
+    Whitespace,
+    SingleLineComment,
+    HashMarkComment,
+    MultiLineComment,
+    DocumentComment,
+}
+
+/// Whitespace or a comment that sits between two significant tokens.
+#[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Trivia<'a> {
+    pub kind: TriviaKind,
+    pub span: Span,
+    /// The bytes of the trivia, borrowed straight from the source code.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "pxp_bytestring::serialize_bytes"))]
+    pub value: &'a [u8],
+}
+
+impl<'a> Debug for Trivia<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Trivia")
+            .field("kind", &self.kind)
+            .field("span", &self.span)
+            .field("value", &ByteString::from(self.value))
+            .finish()
+    }
+}
+
+/// A significant token along with the trivia attached to either side of it.
+///
+/// Trailing trivia runs up to and including the first line break after the token, everything
+/// after that is leading trivia for the next token.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TriviaToken<'a> {
+    pub leading: Vec<Trivia<'a>>,
+    pub token: Token<'a>,
+    pub trailing: Vec<Trivia<'a>>,
+}
+
+impl<'a> TriviaToken<'a> {
+    /// Write the token and its trivia to `buffer`, exactly as they appeared in the source code.
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        for trivia in &self.leading {
+            buffer.extend_from_slice(trivia.value);
+        }
+
+        buffer.extend_from_slice(self.token.value);
+
+        for trivia in &self.trailing {
+            buffer.extend_from_slice(trivia.value);
+        }
+    }
+}
+
+impl TokenKind {
+    /// Get the name of the `T_*` constant that PHP's tokenizer uses for this kind of token.
+    ///
+    /// Returns `None` for tokens that PHP represents as plain strings, i.e. single characters
+    /// and `?:`, which PHP splits into `?` and `:`. `Eof` has no equivalent either.
+    pub const fn php_name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Die | Self::Exit => "T_EXIT",
+            Self::Self_
+            | Self::Parent
+            | Self::From
+            | Self::True
+            | Self::False
+            | Self::Null
+            | Self::Identifier
+            | Self::CompilerHaltOffsetConstant => "T_STRING",
+            Self::StartDocString(_) => "T_START_HEREDOC",
+            Self::EndDocString(..) => "T_END_HEREDOC",
+            Self::Print => "T_PRINT",
+            Self::HaltCompiler => "T_HALT_COMPILER",
+            Self::Readonly => "T_READONLY",
+            Self::Global => "T_GLOBAL",
+            Self::Abstract => "T_ABSTRACT",
+            Self::Ampersand => "T_AMPERSAND_NOT_FOLLOWED_BY_VAR_OR_VARARG",
+            Self::And | Self::BooleanAnd => "T_BOOLEAN_AND",
+            Self::AndEquals | Self::AmpersandEquals => "T_AND_EQUAL",
+            Self::Array => "T_ARRAY",
+            Self::ArrayCast => "T_ARRAY_CAST",
+            Self::Arrow => "T_OBJECT_OPERATOR",
+            Self::QuestionArrow => "T_NULLSAFE_OBJECT_OPERATOR",
+            Self::As => "T_AS",
+            Self::Attribute => "T_ATTRIBUTE",
+            Self::BangEquals | Self::AngledLeftRight => "T_IS_NOT_EQUAL",
+            Self::BangDoubleEquals => "T_IS_NOT_IDENTICAL",
+            Self::Spaceship => "T_SPACESHIP",
+            Self::BoolCast | Self::BooleanCast => "T_BOOL_CAST",
+            Self::BooleanOr => "T_BOOLEAN_OR",
+            Self::Break => "T_BREAK",
+            Self::Callable => "T_CALLABLE",
+            Self::CaretEquals => "T_XOR_EQUAL",
+            Self::Case => "T_CASE",
+            Self::Catch => "T_CATCH",
+            Self::Class => "T_CLASS",
+            Self::ClassConstant => "T_CLASS_C",
+            Self::TraitConstant => "T_TRAIT_C",
+            Self::FunctionConstant => "T_FUNC_C",
+            Self::MethodConstant => "T_METHOD_C",
+            Self::LineConstant => "T_LINE",
+            Self::FileConstant => "T_FILE",
+            Self::DirConstant => "T_DIR",
+            Self::NamespaceConstant => "T_NS_C",
+            Self::Clone => "T_CLONE",
+            Self::MinusEquals => "T_MINUS_EQUAL",
+            Self::CloseTag => "T_CLOSE_TAG",
+            Self::DoubleQuestion => "T_COALESCE",
+            Self::DoubleQuestionEquals => "T_COALESCE_EQUAL",
+            Self::AsteriskEquals => "T_MUL_EQUAL",
+            Self::SingleLineComment | Self::HashMarkComment | Self::MultiLineComment => {
+                "T_COMMENT"
+            }
+            Self::DocumentComment => "T_DOC_COMMENT",
+            Self::Const => "T_CONST",
+            Self::LiteralSingleQuotedString | Self::LiteralDoubleQuotedString => {
+                "T_CONSTANT_ENCAPSED_STRING"
+            }
+            Self::Continue => "T_CONTINUE",
+            Self::CurlyOpen => "T_CURLY_OPEN",
+            Self::Declare => "T_DECLARE",
+            Self::Decrement => "T_DEC",
+            Self::Default => "T_DEFAULT",
+            Self::DivEquals | Self::SlashEquals => "T_DIV_EQUAL",
+            Self::Do => "T_DO",
+            Self::DollarLeftBrace => "T_DOLLAR_OPEN_CURLY_BRACES",
+            Self::DotEquals => "T_CONCAT_EQUAL",
+            Self::DoubleArrow => "T_DOUBLE_ARROW",
+            Self::DoubleCast | Self::RealCast | Self::FloatCast => "T_DOUBLE_CAST",
+            Self::DoubleColon => "T_DOUBLE_COLON",
+            Self::DoubleEquals => "T_IS_EQUAL",
+            Self::Echo => "T_ECHO",
+            Self::Ellipsis => "T_ELLIPSIS",
+            Self::Else => "T_ELSE",
+            Self::ElseIf => "T_ELSEIF",
+            Self::Empty => "T_EMPTY",
+            Self::EndDeclare => "T_ENDDECLARE",
+            Self::EndFor => "T_ENDFOR",
+            Self::EndForeach => "T_ENDFOREACH",
+            Self::EndIf => "T_ENDIF",
+            Self::EndSwitch => "T_ENDSWITCH",
+            Self::EndWhile => "T_ENDWHILE",
+            Self::Enum => "T_ENUM",
+            Self::Error => "T_BAD_CHARACTER",
+            Self::Extends => "T_EXTENDS",
+            Self::Final => "T_FINAL",
+            Self::Finally => "T_FINALLY",
+            Self::LiteralFloat => "T_DNUMBER",
+            Self::Fn => "T_FN",
+            Self::For => "T_FOR",
+            Self::Foreach => "T_FOREACH",
+            Self::FullyQualifiedIdentifier => "T_NAME_FULLY_QUALIFIED",
+            Self::Function => "T_FUNCTION",
+            Self::Goto => "T_GOTO",
+            Self::GreaterThanEquals => "T_IS_GREATER_OR_EQUAL",
+            Self::If => "T_IF",
+            Self::Implements => "T_IMPLEMENTS",
+            Self::Include => "T_INCLUDE",
+            Self::IncludeOnce => "T_INCLUDE_ONCE",
+            Self::Increment => "T_INC",
+            Self::InlineHtml => "T_INLINE_HTML",
+            Self::Instanceof => "T_INSTANCEOF",
+            Self::Insteadof => "T_INSTEADOF",
+            Self::Eval => "T_EVAL",
+            Self::Unset => "T_UNSET",
+            Self::Isset => "T_ISSET",
+            Self::List => "T_LIST",
+            Self::LiteralInteger => "T_LNUMBER",
+            Self::IntCast | Self::IntegerCast => "T_INT_CAST",
+            Self::Interface => "T_INTERFACE",
+            Self::LeftShift => "T_SL",
+            Self::LeftShiftEquals => "T_SL_EQUAL",
+            Self::RightShift => "T_SR",
+            Self::RightShiftEquals => "T_SR_EQUAL",
+            Self::LessThanEquals => "T_IS_SMALLER_OR_EQUAL",
+            Self::Match => "T_MATCH",
+            Self::Namespace => "T_NAMESPACE",
+            Self::NamespaceSeparator => "T_NS_SEPARATOR",
+            Self::New => "T_NEW",
+            Self::ObjectCast => "T_OBJECT_CAST",
+            Self::UnsetCast => "T_UNSET_CAST",
+            Self::OpenTag(OpenTagKind::Echo) => "T_OPEN_TAG_WITH_ECHO",
+            Self::OpenTag(_) => "T_OPEN_TAG",
+            Self::PercentEquals => "T_MOD_EQUAL",
+            Self::PipeEquals => "T_OR_EQUAL",
+            Self::PlusEquals => "T_PLUS_EQUAL",
+            Self::Pow => "T_POW",
+            Self::PowEquals => "T_POW_EQUAL",
+            Self::Private => "T_PRIVATE",
+            Self::Protected => "T_PROTECTED",
+            Self::Public => "T_PUBLIC",
+            Self::QualifiedIdentifier => "T_NAME_QUALIFIED",
+            Self::Require => "T_REQUIRE",
+            Self::RequireOnce => "T_REQUIRE_ONCE",
+            Self::Return => "T_RETURN",
+            Self::Static => "T_STATIC",
+            Self::StringCast | Self::BinaryCast => "T_STRING_CAST",
+            Self::StringPart => "T_ENCAPSED_AND_WHITESPACE",
+            Self::Switch => "T_SWITCH",
+            Self::Throw => "T_THROW",
+            Self::Trait => "T_TRAIT",
+            Self::TripleEquals => "T_IS_IDENTICAL",
+            Self::Try => "T_TRY",
+            Self::Use => "T_USE",
+            Self::Var => "T_VAR",
+            Self::Variable => "T_VARIABLE",
+            Self::Yield => "T_YIELD",
+            Self::While => "T_WHILE",
+            Self::LogicalAnd => "T_LOGICAL_AND",
+            Self::LogicalOr => "T_LOGICAL_OR",
+            Self::LogicalXor => "T_LOGICAL_XOR",
+            Self::Backtick
+            | Self::Dollar
+            | Self::BitwiseNot
+            | Self::Asterisk
+            | Self::At
+            | Self::Bang
+            | Self::Caret
+            | Self::Colon
+            | Self::Comma
+            | Self::Dot
+            | Self::DoubleQuote
+            | Self::Equals
+            | Self::GreaterThan
+            | Self::LessThan
+            | Self::LeftBrace
+            | Self::LeftBracket
+            | Self::LeftParen
+            | Self::RightBrace
+            | Self::RightBracket
+            | Self::RightParen
+            | Self::Minus
+            | Self::Percent
+            | Self::Pipe
+            | Self::Plus
+            | Self::Question
+            | Self::QuestionColon
+            | Self::SemiColon
+            | Self::Slash
+            | Self::Eof => return None,
+        })
+    }
+}
+
+impl TokenKind {
+    /// Get the exact source code of tokens that can only ever be written one way, e.g. `instanceof`
+    /// or `(int)`. Tokens that carry text of their own, like identifiers and comments, return `None`.
+    pub const fn spelling(&self) -> Option<&'static str> {
+        Some(match self {
             Self::CompilerHaltOffsetConstant => "__COMPILER_HALT_OFFSET__",
             Self::Die => "die",
             Self::Self_ => "self",
             Self::Parent => "parent",
             Self::Backtick => "`",
-            Self::StartDocString(_) => todo!(),
-            Self::EndDocString(..) => todo!(),
             Self::BangEquals => "!=",
             Self::From => "from",
             Self::Print => "print",
@@ -347,13 +646,11 @@ impl Display for TokenKind {
             Self::EndSwitch => "endswitch",
             Self::EndWhile => "endwhile",
             Self::Enum => "enum",
-            Self::Eof => "[end of file]",
             Self::Equals => "=",
             Self::Extends => "extends",
             Self::False => "false",
             Self::Final => "final",
             Self::Finally => "finally",
-            Self::LiteralFloat => return write!(f, "float literal"),
             Self::Fn => "fn",
             Self::For => "for",
             Self::Function => "function",
@@ -363,8 +660,6 @@ impl Display for TokenKind {
             Self::If => "if",
             Self::Implements => "implements",
             Self::Increment => "++",
-            Self::InlineHtml => "InlineHtml",
-            Self::LiteralInteger => return write!(f, "integer literal"),
             Self::LeftBrace => "{",
             Self::LeftBracket => "[",
             Self::LeftParen => "(",
@@ -428,7 +723,7 @@ impl Display for TokenKind {
             Self::LogicalXor => "xor",
             Self::Foreach => "foreach",
             Self::AmpersandEquals => "&=",
-            Self::At => "at",
+            Self::At => "@",
             Self::BangDoubleEquals => "!==",
             Self::TraitConstant => "__TRAIT__",
             Self::FunctionConstant => "__FUNCTION__",
@@ -449,6 +744,39 @@ impl Display for TokenKind {
             Self::Interface => "interface",
             Self::NamespaceConstant => "__NAMESPACE__",
             Self::PowEquals => "**=",
+            Self::StartDocString(_)
+            | Self::EndDocString(..)
+            | Self::Eof
+            | Self::Error
+            | Self::InlineHtml
+            | Self::LiteralFloat
+            | Self::LiteralInteger
+            | Self::StringPart
+            | Self::Variable
+            | Self::QualifiedIdentifier
+            | Self::Identifier
+            | Self::FullyQualifiedIdentifier
+            | Self::LiteralSingleQuotedString
+            | Self::LiteralDoubleQuotedString
+            | Self::SingleLineComment
+            | Self::MultiLineComment
+            | Self::HashMarkComment
+            | Self::DocumentComment => return None,
+        })
+    }
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::StartDocString(DocStringKind::Heredoc) => "start of heredoc",
+            Self::StartDocString(DocStringKind::Nowdoc) => "start of nowdoc",
+            Self::EndDocString(..) => "end of doc string",
+            Self::Eof => "[end of file]",
+            Self::Error => "[error]",
+            Self::LiteralFloat => "float literal",
+            Self::InlineHtml => "InlineHtml",
+            Self::LiteralInteger => "integer literal",
             Self::StringPart
             | Self::Variable
             | Self::QualifiedIdentifier
@@ -462,6 +790,7 @@ impl Display for TokenKind {
             | Self::DocumentComment => {
                 return write!(f, "{:?}", self);
             }
+            _ => self.spelling().unwrap_or_default(),
         };
 
         write!(f, "{}", s)
@@ -0,0 +1,25 @@
+<?php
+
+namespace App\Models;
+
+use App\Contracts\{HasName, HasId as Identified};
+
+#[Attribute(Attribute::TARGET_CLASS)]
+abstract readonly class User extends Model implements HasName, Identified
+{
+    use SoftDeletes, Timestamps { Timestamps::touch insteadof SoftDeletes; }
+
+    public const LIMIT = 10;
+
+    public function __construct(
+        private int $id,
+        protected ?string $name = null,
+    ) {}
+
+    abstract protected function boot(): void;
+
+    public static function find(int|string $id): static|null
+    {
+        return new static($id);
+    }
+}
@@ -0,0 +1,34 @@
+<?php
+
+declare(strict_types=1);
+
+if ($a): echo 1; elseif ($b): echo 2; else: echo 3; endif;
+
+foreach ($items as $key => &$value) {
+    if ($value) { continue 1; } else { break; }
+}
+
+for ($i = 0, $j = 1; $i < 10; $i++, --$j);
+
+while (true): do { $x--; } while ($x > 0); endwhile;
+
+switch ($x) {
+    case 1:
+    case 2;
+        goto end;
+    default:
+        break;
+}
+
+try {
+    throw new Exception();
+} catch (TypeError | ValueError $e) {
+} catch (Exception) {
+} finally {
+    end:
+    unset($a);
+}
+
+global $g;
+static $s = 1, $t;
+const A = 1, B = 2;
@@ -0,0 +1,27 @@
+<?php
+
+enum Suit: string implements HasLabel
+{
+    case Hearts = 'H';
+    case Spades = 'S';
+
+    const Wild = self::Spades;
+
+    public function label(): string
+    {
+        return match ($this) {
+            self::Hearts => 'Hearts',
+            default => 'Spades',
+        };
+    }
+}
+
+interface HasLabel
+{
+    public function label(): string;
+}
+
+trait Greets
+{
+    abstract public function name(): string;
+}
@@ -0,0 +1,18 @@
+<?php
+
+echo "\n\r\t\v\e\f\\\$\"\0 \7 \101\60\377 \x41\x4\xfG \u{41}\u{1F600}\u{000041}\u{10FFFF} \u \{$a}";
+echo "$a\x41 {$b}\101 ${c}\u{42} $d[0]\t $e->f\n";
+echo '\'\\\n\x41\u{41}';
+echo `ls \x41 $a\u{42}`;
+echo b"\x00\xff", b'\xff';
+echo <<<EOT
+  \x41\101\u{1F600}\t\$a\\
+  {$a}\u{42} $b[1]\x43
+    $c\
+  EOT;
+echo <<<"EOT"
+\"quoted\" \u{0}
+EOT;
+echo <<<'EOT'
+  \x41\u{41}\101 $a
+  EOT;
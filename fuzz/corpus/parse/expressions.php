@@ -0,0 +1,17 @@
+<?php
+
+$a = 1 + 2 * 3 ** 4 <=> $b ?? $c ?: $d;
+$a ??= [1, 'b' => 2, ...$c];
+[$x, [, $y]] = list($a, $b) = $pair;
+$f = fn ($x) => $x?->y::$z[0];
+$g = static function &(&$a, ...$b) use ($c, &$d): iterable { yield from $a; };
+$h = new class(1) extends Foo { public $x; };
+$i = (int) $a . (string) $b instanceof Foo;
+$j = isset($a, $b) && !empty($c) || print $d;
+$k = clone $a?->b() and $c xor $d or $e;
+$l = $a ? $b : ($c ? $d : $e);
+$m = @file_get_contents(__FILE__, named: true);
+$n = $obj::CONSTANT . $obj::class . Foo::{$bar}();
+$o = `ls -la $dir`;
+$p = include_once 'file.php';
+exit(eval('return 1;'));
@@ -0,0 +1,15 @@
+<?php
+
+function &byRef(int $a = 1, string ...$rest): never
+{
+    return $a;
+}
+
+function dnf((A&B)|null $x, mixed $y): A&B
+{
+    return $x;
+}
+
+function generic(): ?\Fully\Qualified\Name
+{
+}
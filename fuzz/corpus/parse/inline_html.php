@@ -0,0 +1,3 @@
+<p>Inline <?= $html ?> html</p>
+<?php echo 1 ?>
+<?php __halt_compiler(); raw data
\ No newline at end of file
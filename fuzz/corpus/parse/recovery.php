@@ -0,0 +1 @@
+<?php $a = ; class A { public function } echo 1;
\ No newline at end of file
@@ -0,0 +1 @@
+<?php empty(
\ No newline at end of file
@@ -0,0 +1 @@
+<?php eval(;
\ No newline at end of file
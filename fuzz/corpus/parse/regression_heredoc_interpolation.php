@@ -0,0 +1,6 @@
+<?php
+echo <<<EOT
+  a
+  $b
+    {$c}d
+  EOT;
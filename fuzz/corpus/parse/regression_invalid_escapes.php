@@ -0,0 +1,6 @@
+<?php
+"\u{110000}"; "\u{}"; "\u{zz}"; "\u{00000041}"; "\400"; "\u{7FFFFFFF}";
+echo <<<EOT
+  a
+$b
+  EOT;
@@ -0,0 +1 @@
+<?php ((((((((((((((((((((((((((((((((((((($a
\ No newline at end of file
@@ -0,0 +1 @@
+<?php "\u{100000000}";
@@ -0,0 +1,13 @@
+<?php
+
+$name = "World";
+echo "Hello {$name}, ${name}, $name[0], $obj->prop, {$obj->method()[1]}\n";
+echo 'single $quoted';
+echo <<<EOT
+    Heredoc {$name}
+      $name->foo
+    EOT;
+echo <<<'EOT'
+Nowdoc $name
+EOT;
+echo b"binary", 0x1F, 0b101, 0o17, 1_000, 1.5e3;
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Parse errors are fine, panics are not.
+fuzz_target!(|data: &[u8]| {
+    let _ = pxp_parser::parse(data);
+});
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pxp_parser::incremental::{Document, TextEdit};
+use pxp_parser::options::ParserOptions;
+
+// The first bytes say where the edit goes and how much it replaces, the rest is split into the
+// source and what it's replaced with. Editing has to give the same result as parsing again.
+fuzz_target!(|data: &[u8]| {
+    let [at, removed, inserted, data @ ..] = data else {
+        return;
+    };
+
+    let (replacement, input) = data.split_at((*inserted as usize).min(data.len()));
+    let start = (*at as usize).min(input.len());
+    let end = (start + *removed as usize).min(input.len());
+    let edit = TextEdit::new(start..end, replacement);
+    let edited = edit.apply(input).unwrap();
+
+    let document = Document::parse(input, ParserOptions::new()).edit(&edit, &edited);
+
+    assert_eq!(
+        document.into_result(),
+        pxp_parser::parse_with(&edited, ParserOptions::new())
+    );
+});